@@ -2,6 +2,7 @@ use std::io::Stdout;
 use std::sync::Arc;
 use std::time::Duration;
 
+use indexmap::IndexMap;
 use parking_lot::{Mutex, RwLock};
 use ratatui::backend::{Backend, CrosstermBackend};
 use ratatui::crossterm::terminal::disable_raw_mode;
@@ -9,17 +10,27 @@ use ratatui::Terminal;
 use strum::VariantArray;
 use throbber_widgets_tui::ThrobberState;
 
+use crate::app::business_logic::request::grpc::grpc::GrpcMethodsListing;
+use crate::app::business_logic::response_diff::ResponseDiff;
 use crate::app::files::config::Config;
 use crate::models::collection::Collection;
 use crate::models::environment::Environment;
 use crate::models::export::ExportFormat;
+use crate::models::history::ResponseHistoryEntry;
+use crate::models::auth::oauth2::DEFAULT_OAUTH2_REDIRECT_URL;
 use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
 use crate::tui::ui::result_tabs::RequestResultTabs;
 use crate::tui::ui::views::RequestView;
 use crate::tui::utils::stateful::choice_popup::ChoicePopup;
+use crate::tui::utils::stateful::collection_runner_popup::CollectionRunnerPopup;
+use crate::tui::utils::stateful::response_history_popup::ResponseHistoryPopup;
+use crate::tui::utils::stateful::response_diff_panel::ResponseDiffPanel;
+use crate::tui::utils::stateful::response_body_filter::ResponseBodyFilter;
 use crate::tui::utils::stateful::cookies_popup::CookiesPopup;
 use crate::tui::utils::stateful::display_popup::DisplayPopup;
+use crate::tui::utils::stateful::graphql_editor::GraphqlEditor;
+use crate::tui::utils::stateful::grpc_editor::GrpcEditor;
 use crate::tui::utils::stateful::help_popup::HelpPopup;
 use crate::tui::utils::stateful::new_request_popup::NewRequestPopup;
 use crate::tui::utils::stateful::script_console::ScriptConsole;
@@ -80,9 +91,17 @@ pub struct App<'a> {
     pub rename_collection_input: TextInput,
     pub new_request_popup: NewRequestPopup,
     pub rename_request_input: TextInput,
+    pub new_folder_input: TextInput,
+    pub rename_folder_input: TextInput,
+    pub move_to_folder_input: TextInput,
 
     pub delete_collection_popup: ValidationPopup,
     pub delete_request_popup: ValidationPopup,
+    pub delete_folder_popup: ValidationPopup,
+
+    pub collection_runner_popup: CollectionRunnerPopup,
+    /// Data file row of the collection run iteration being sent, replaced like environment values
+    pub iteration_values: IndexMap<String, String>,
 
     /* Request */
 
@@ -111,9 +130,29 @@ pub struct App<'a> {
     pub auth_digest_nonce_text_input: TextInput,
     pub auth_digest_opaque_text_input: TextInput,
 
+    pub auth_oauth2_token_url_text_input: TextInput,
+    pub auth_oauth2_authorization_url_text_input: TextInput,
+    pub auth_oauth2_redirect_url_text_input: TextInput,
+    pub auth_oauth2_client_id_text_input: TextInput,
+    pub auth_oauth2_client_secret_text_input: TextInput,
+    pub auth_oauth2_scope_text_input: TextInput,
+    pub auth_oauth2_username_text_input: TextInput,
+    pub auth_oauth2_password_text_input: TextInput,
+    pub auth_oauth2_refresh_token_text_input: TextInput,
+
+    pub auth_aws_sigv4_access_key_id_text_input: TextInput,
+    pub auth_aws_sigv4_secret_access_key_text_input: TextInput,
+    pub auth_aws_sigv4_region_text_input: TextInput,
+    pub auth_aws_sigv4_service_text_input: TextInput,
+    pub auth_aws_sigv4_session_token_text_input: TextInput,
+
+    pub auth_api_key_key_text_input: TextInput,
+    pub auth_api_key_value_text_input: TextInput,
+
     /* Headers */
     
     pub headers_table: StatefulCustomTable<'a>,
+    pub captures_table: StatefulCustomTable<'a>,
 
     /* Body */
     
@@ -125,6 +164,14 @@ pub struct App<'a> {
     
     pub message_text_area: TextInput,
 
+    /* GraphQL */
+
+    pub graphql_editor: GraphqlEditor,
+
+    /* gRPC */
+
+    pub grpc_editor: GrpcEditor,
+
     /* Settings */
     
     pub request_settings_popup: SettingsPopup,
@@ -139,6 +186,18 @@ pub struct App<'a> {
 
     pub last_messages_area_size: (u16, u16),
 
+    pub response_history_popup: ResponseHistoryPopup,
+    /// Past response displayed in the result tabs instead of the selected request's one, until a request is selected or sent
+    pub viewed_history_entry: Option<ResponseHistoryEntry>,
+    pub response_diff_panel: Option<ResponseDiffPanel>,
+    /// Names of the environments the selected one can be compared with
+    pub diff_environment_popup: ChoicePopup<String>,
+    /// Environments diff computed in the background for a request (collection index, request index), displayed once both responses are received
+    pub received_response_diff: Arc<Mutex<Option<(usize, usize, ResponseDiff)>>>,
+    /// gRPC methods listing done in the background, saved once received
+    pub received_grpc_methods: Arc<Mutex<Option<GrpcMethodsListing>>>,
+    pub response_body_filter: ResponseBodyFilter,
+
     /* Scripts */
     
     pub script_console: ScriptConsole,
@@ -204,7 +263,7 @@ impl App<'_> {
             request_result_tab: RequestResultTabs::Body,
 
             creation_popup: ChoicePopup {
-              choices: vec![String::from("Collection"), String::from("Request")],
+              choices: vec![String::from("Collection"), String::from("Request"), String::from("Folder")],
               selection: 0
             },
             
@@ -212,9 +271,16 @@ impl App<'_> {
             rename_collection_input: TextInput::new(None),
             new_request_popup: NewRequestPopup::default(),
             rename_request_input: TextInput::new(None),
+            new_folder_input: TextInput::new(None),
+            rename_folder_input: TextInput::new(None),
+            move_to_folder_input: TextInput::new(Some(String::from("Folder path, e.g. auth/v2, empty for the collection root"))),
 
             delete_collection_popup: ValidationPopup::default(),
             delete_request_popup: ValidationPopup::default(),
+            delete_folder_popup: ValidationPopup::default(),
+
+            collection_runner_popup: CollectionRunnerPopup::default(),
+            iteration_values: IndexMap::new(),
             
             /* Request */
             
@@ -250,6 +316,25 @@ impl App<'_> {
             auth_digest_realm_text_input: TextInput::new(Some(String::from("Realm"))),
             auth_digest_nonce_text_input: TextInput::new(Some(String::from("Nonce"))),
             auth_digest_opaque_text_input: TextInput::new(Some(String::from("Opaque"))),
+
+            auth_oauth2_token_url_text_input: TextInput::new(Some(String::from("Token URL"))),
+            auth_oauth2_authorization_url_text_input: TextInput::new(Some(String::from("Authorization URL (authorization code)"))),
+            auth_oauth2_redirect_url_text_input: TextInput::new(Some(format!("Redirect URL (authorization code, defaults to {DEFAULT_OAUTH2_REDIRECT_URL})"))),
+            auth_oauth2_client_id_text_input: TextInput::new(Some(String::from("Client ID"))),
+            auth_oauth2_client_secret_text_input: TextInput::new(Some(String::from("Client secret"))),
+            auth_oauth2_scope_text_input: TextInput::new(Some(String::from("Scope"))),
+            auth_oauth2_username_text_input: TextInput::new(Some(String::from("Username (password)"))),
+            auth_oauth2_password_text_input: TextInput::new(Some(String::from("Password (password)"))),
+            auth_oauth2_refresh_token_text_input: TextInput::new(Some(String::from("Refresh token"))),
+
+            auth_aws_sigv4_access_key_id_text_input: TextInput::new(Some(String::from("Access key ID"))),
+            auth_aws_sigv4_secret_access_key_text_input: TextInput::new(Some(String::from("Secret access key"))),
+            auth_aws_sigv4_region_text_input: TextInput::new(Some(String::from("Region (e.g. us-east-1)"))),
+            auth_aws_sigv4_service_text_input: TextInput::new(Some(String::from("Service (e.g. execute-api, s3)"))),
+            auth_aws_sigv4_session_token_text_input: TextInput::new(Some(String::from("Session token (temporary credentials only)"))),
+
+            auth_api_key_key_text_input: TextInput::new(Some(String::from("Key"))),
+            auth_api_key_value_text_input: TextInput::new(Some(String::from("Value"))),
             
             /* Headers */
             
@@ -263,6 +348,18 @@ impl App<'_> {
                 "Value"
             ),
 
+            /* Captures */
+
+            captures_table: StatefulCustomTable::new(
+                vec![
+                    Line::default(),
+                    Line::from("No captures").fg(THEME.read().ui.font_color),
+                    Line::from("(Add one with n)").fg(THEME.read().ui.secondary_foreground_color)
+                ],
+                "Variable",
+                "Rule"
+            ),
+
             /* Body */
             
             body_file_text_input: TextInput::new(Some(String::from("File path"))),
@@ -281,6 +378,25 @@ impl App<'_> {
             
             message_text_area: TextInput::new(None),
 
+            /* GraphQL */
+
+            graphql_editor: GraphqlEditor {
+                query_text_area: TextInput::new(Some(String::from("Query"))),
+                variables_text_area: TextInput::new(Some(String::from("Variables"))),
+                operation_name_text_input: TextInput::new(Some(String::from("Operation name"))),
+                selection: 0,
+            },
+
+            /* gRPC */
+
+            grpc_editor: GrpcEditor {
+                proto_files_text_input: TextInput::new(Some(String::from("Proto files (comma-separated, empty for server reflection)"))),
+                service_text_input: TextInput::new(Some(String::from("Service"))),
+                method_text_input: TextInput::new(Some(String::from("Method"))),
+                message_text_area: TextInput::new(Some(String::from("Message (JSON)"))),
+                selection: 0,
+            },
+
             /* Settings */
             
             request_settings_popup: SettingsPopup::default(),
@@ -293,6 +409,18 @@ impl App<'_> {
             result_horizontal_scrollbar: StatefulScrollbar::default(),
 
             last_messages_area_size: (0, 0),
+
+            response_history_popup: ResponseHistoryPopup::default(),
+            viewed_history_entry: None,
+            response_diff_panel: None,
+            diff_environment_popup: ChoicePopup::default(),
+            received_response_diff: Arc::new(Mutex::new(None)),
+            received_grpc_methods: Arc::new(Mutex::new(None)),
+            response_body_filter: ResponseBodyFilter {
+                text_input: TextInput::new(Some(String::from("Filter ($.json.path, .jq | .filter or //xpath)"))),
+                filter: String::new(),
+                filtered_body: None,
+            },
             script_console: ScriptConsole {
                 pre_request_text_area: TextInput::new(None),
                 post_request_text_area: TextInput::new(None),
@@ -322,6 +450,8 @@ impl App<'_> {
             self.handle_events(&mut terminal).await;
         }
 
+        self.save_cookie_jar();
+
         Ok(())
     }
 
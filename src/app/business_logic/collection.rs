@@ -7,8 +7,10 @@ use tracing::info;
 use crate::app::app::App;
 use crate::app::business_logic::collection::CollectionError::{CollectionNameAlreadyExists, CollectionNameIsEmpty};
 use crate::app::business_logic::collection::RequestError::RequestNameIsEmpty;
+use crate::app::files::graphql_schema::{delete_graphql_schema_file, rename_graphql_schema_file};
+use crate::app::files::history::{delete_response_history, get_response_history_folder_path, move_response_history};
 use crate::cli::args::ARGS;
-use crate::models::collection::Collection;
+use crate::models::collection::{Collection, CollectionDefaults};
 use crate::models::request::Request;
 
 #[derive(Error, Debug)]
@@ -57,6 +59,8 @@ impl App<'_> {
             name: new_collection_name.clone(),
             last_position,
             requests: vec![],
+            folders: vec![],
+            defaults: CollectionDefaults::default(),
             path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", new_collection_name, file_format.to_string())),
             file_format,
         };
@@ -92,13 +96,18 @@ impl App<'_> {
         info!("Collection deleted");
 
         let collection = self.collections.remove(collection_index);
+        delete_response_history(&get_response_history_folder_path(&collection, &[]));
         self.delete_collection_file(collection);
     }
 
     pub fn delete_request(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
         info!("Request deleted");
         
-        self.collections[collection_index].requests.remove(request_index);
+        delete_response_history(&self.get_request_response_history_path(collection_index, request_index));
+
+        let request = self.collections[collection_index].requests.remove(request_index);
+        delete_graphql_schema_file(&self.collections[collection_index].path, &request.read().name);
+
         self.save_collection_to_file(collection_index);
         
         Ok(())
@@ -130,14 +139,19 @@ impl App<'_> {
         }
 
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let old_history_path = self.get_request_response_history_path(collection_index, request_index);
 
         {
             let mut selected_request = local_selected_request.write();
 
             info!("Request renamed to \"{new_request_name}\"");
 
+            rename_graphql_schema_file(&self.collections[collection_index].path, &selected_request.name, &new_request_name);
+
             selected_request.name = new_request_name.to_string();
         }
+
+        move_response_history(&old_history_path, &self.get_request_response_history_path(collection_index, request_index));
         
         self.save_collection_to_file(collection_index);
         
@@ -0,0 +1,119 @@
+use anyhow::anyhow;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::collection_defaults::CollectionDefaultsError::CollectionCannotInheritAuth;
+use crate::app::business_logic::key_value::find_key;
+use crate::models::auth::auth::Auth;
+use crate::models::request::KeyValue;
+use crate::models::scripts::ScriptType;
+
+#[derive(Error, Debug)]
+pub enum CollectionDefaultsError {
+    #[error("A collection has nothing to inherit its auth method from")]
+    CollectionCannotInheritAuth,
+}
+
+impl App<'_> {
+    pub fn modify_collection_base_url(&mut self, collection_index: usize, base_url: String) -> anyhow::Result<()> {
+        info!("Collection base URL set to \"{base_url}\"");
+
+        self.collections[collection_index].defaults.base_url = base_url;
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn find_collection_header(&mut self, collection_index: usize, key: &str) -> anyhow::Result<usize> {
+        find_key(&self.collections[collection_index].defaults.headers, key)
+    }
+
+    pub fn modify_collection_header(&mut self, collection_index: usize, value: String, column: usize, row: usize) -> anyhow::Result<()> {
+        let header = &mut self.collections[collection_index].defaults.headers[row];
+
+        match column {
+            0 => {
+                info!("Collection header key set to \"{value}\"");
+                header.data.0 = value;
+            },
+            1 => {
+                info!("Collection header value set to \"{value}\"");
+                header.data.1 = value;
+            },
+            _ => {}
+        };
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn create_new_collection_header(&mut self, collection_index: usize, key: String, value: String) -> anyhow::Result<()> {
+        info!("Key \"{key}\" with value \"{value}\" added to the collection headers");
+
+        self.collections[collection_index].defaults.headers.push(KeyValue {
+            enabled: true,
+            data: (key, value)
+        });
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn delete_collection_header(&mut self, collection_index: usize, row: usize) -> anyhow::Result<()> {
+        info!("Collection header deleted");
+
+        self.collections[collection_index].defaults.headers.remove(row);
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn toggle_collection_header(&mut self, collection_index: usize, state: Option<bool>, row: usize) -> anyhow::Result<()> {
+        let header = &mut self.collections[collection_index].defaults.headers[row];
+
+        let new_state = match state {
+            None => {
+                let state = !header.enabled;
+                // Better user feedback
+                println!("{state}");
+                state
+            },
+            Some(state) => state
+        };
+
+        info!("Collection header state set to \"{new_state}\"");
+
+        header.enabled = new_state;
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn modify_collection_auth(&mut self, collection_index: usize, auth: Auth) -> anyhow::Result<()> {
+        if let Auth::InheritFromCollection = auth {
+            return Err(anyhow!(CollectionCannotInheritAuth));
+        }
+
+        info!("Collection auth method set to \"{}\"", auth);
+
+        *self.collections[collection_index].defaults.auth.write() = auth;
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn modify_collection_script(&mut self, collection_index: usize, script_type: &ScriptType, script: Option<String>) -> anyhow::Result<()> {
+        let scripts = &mut self.collections[collection_index].defaults.scripts;
+
+        match script_type {
+            ScriptType::Pre => scripts.pre_request_script = script,
+            ScriptType::Post => scripts.post_request_script = script,
+        }
+
+        info!("Collection {}-request script set", script_type);
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
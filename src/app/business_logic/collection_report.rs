@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use strum::Display;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::business_logic::collection_report::CollectionReportError::{CouldNotSerializeReport, CouldNotWriteReport};
+use crate::app::business_logic::collection_runner::CollectionRun;
+use crate::models::response::parse_duration;
+
+#[derive(Error, Debug)]
+pub enum CollectionReportError {
+    #[error("Could not serialize the {0} report\n\t{1}")]
+    CouldNotSerializeReport(ReportFormat, String),
+    #[error("Could not write report \"{0}\"\n\t{1}")]
+    CouldNotWriteReport(String, String),
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, Display)]
+pub enum ReportFormat {
+    #[strum(to_string = "JUnit")]
+    Junit,
+    #[strum(to_string = "JSON")]
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionReport {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+impl CollectionReport {
+    pub fn write(&self, run: &CollectionRun, collection_name: &str) -> Result<(), CollectionReportError> {
+        let content = match self.format {
+            ReportFormat::Junit => to_junit_report(run, collection_name),
+            ReportFormat::Json => serde_json::to_string_pretty(&to_json_report(run, collection_name))
+                .map_err(|error| CouldNotSerializeReport(self.format, error.to_string()))?
+        };
+
+        fs::write(&self.path, content).map_err(|error| CouldNotWriteReport(self.path.display().to_string(), error.to_string()))?;
+
+        info!("{} report written to \"{}\"", self.format, self.path.display());
+
+        Ok(())
+    }
+}
+
+fn to_json_report(run: &CollectionRun, collection_name: &str) -> Value {
+    let results: Vec<Value> = run.results
+        .iter()
+        .map(|result| json!({
+            "iteration": result.iteration + 1,
+            "name": result.request_name,
+            "url": result.url,
+            "status_code": result.status_code,
+            "duration": result.duration,
+            "passed": run.is_passed(result),
+            "error": result.error,
+            "console_output": result.console_output,
+            "tests": result.test_results,
+        }))
+        .collect();
+
+    json!({
+        "collection": collection_name,
+        "iterations": run.iterations,
+        "total": run.total(),
+        "passed": run.passed_count(),
+        "failed": run.failed_count(),
+        "skipped": run.total() - run.results.len(),
+        "results": results,
+    })
+}
+
+/// One test suite per sent request, with a test case for its status code and one per script test
+fn to_junit_report(run: &CollectionRun, collection_name: &str) -> String {
+    let mut test_suites = String::new();
+    let mut total_tests = 0;
+    let mut total_failures = 0;
+    let mut total_time = 0.0;
+
+    for result in &run.results {
+        let suite_name = match run.iterations {
+            1 => result.request_name.clone(),
+            _ => format!("{} (iteration {})", result.request_name, result.iteration + 1)
+        };
+        let class_name = format!("{collection_name}.{}", result.request_name);
+        let time = result.duration.as_deref().and_then(parse_duration).map_or(0.0, |duration| duration.as_secs_f64());
+
+        let mut test_cases = String::new();
+
+        let status_failure = run.status_failure(result);
+
+        test_cases.push_str(&junit_test_case("Status code", &class_name, Some(time), status_failure.as_deref()));
+
+        for test_result in &result.test_results {
+            let failure = match test_result.passed {
+                true => None,
+                false => Some(test_result.error.as_deref().unwrap_or_default())
+            };
+
+            test_cases.push_str(&junit_test_case(&test_result.name, &class_name, None, failure));
+        }
+
+        let tests = 1 + result.test_results.len();
+        let failures = status_failure.is_some() as usize + result.failed_tests_count();
+
+        let mut properties = format!("      <property name=\"iteration\" value=\"{}\"/>\n", result.iteration + 1);
+
+        if let Some(url) = &result.url {
+            properties.push_str(&format!("      <property name=\"url\" value=\"{}\"/>\n", escape_xml(url)));
+        }
+
+        if let Some(status_code) = &result.status_code {
+            properties.push_str(&format!("      <property name=\"status_code\" value=\"{}\"/>\n", escape_xml(status_code)));
+        }
+
+        let system_out = match &result.console_output {
+            Some(console_output) if !console_output.is_empty() => format!("    <system-out>{}</system-out>\n", escape_xml(console_output)),
+            _ => String::new()
+        };
+
+        test_suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\" time=\"{time:.3}\">\n    <properties>\n{properties}    </properties>\n{test_cases}{system_out}  </testsuite>\n",
+            escape_xml(&suite_name)
+        ));
+
+        total_tests += tests;
+        total_failures += failures;
+        total_time += time;
+    }
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"{}\" tests=\"{total_tests}\" failures=\"{total_failures}\" errors=\"0\" time=\"{total_time:.3}\">\n{test_suites}</testsuites>\n",
+        escape_xml(collection_name)
+    )
+}
+
+fn junit_test_case(name: &str, class_name: &str, time: Option<f64>, failure: Option<&str>) -> String {
+    let time = match time {
+        None => String::new(),
+        Some(time) => format!(" time=\"{time:.3}\"")
+    };
+
+    match failure {
+        None => format!("    <testcase name=\"{}\" classname=\"{}\"{time}/>\n", escape_xml(name), escape_xml(class_name)),
+        Some(failure) => format!(
+            "    <testcase name=\"{}\" classname=\"{}\"{time}>\n      <failure message=\"{}\"/>\n    </testcase>\n",
+            escape_xml(name),
+            escape_xml(class_name),
+            escape_xml(failure)
+        )
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters other than tabs and line breaks are not allowed in XML 1.0
+            character if character.is_control() && !matches!(character, '\t' | '\n' | '\r') => {},
+            character => escaped.push(character)
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,356 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+use serde_json::Value;
+use thiserror::Error;
+use tracing::trace;
+
+use crate::app::app::App;
+use crate::app::business_logic::collection_runner::CollectionRunnerError::{CollectionHasNoRequest, CouldNotParseCsvDataFile, CouldNotParseJsonDataFile, CouldNotReadDataFile, DataFileIsEmpty, InvalidJsonDataFile};
+use crate::models::protocol::protocol::Protocol;
+use crate::models::response::RequestResponse;
+use crate::models::scripts::TestResult;
+
+#[derive(Error, Debug)]
+pub enum CollectionRunnerError {
+    #[error("The collection has no request to run")]
+    CollectionHasNoRequest,
+    #[error("Could not read data file \"{0}\"\n\t{1}")]
+    CouldNotReadDataFile(String, String),
+    #[error("Could not parse CSV data file\n\t{0}")]
+    CouldNotParseCsvDataFile(String),
+    #[error("Could not parse JSON data file\n\t{0}")]
+    CouldNotParseJsonDataFile(String),
+    #[error("The JSON data file must contain an array of objects")]
+    InvalidJsonDataFile,
+    #[error("The data file contains no row")]
+    DataFileIsEmpty,
+    #[error("{0} request(s) failed")]
+    CollectionRunFailed(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionRunOptions {
+    /// Defaults to the number of data file rows, or to 1 without data file
+    pub iterations: Option<u32>,
+    /// CSV file with a header line, or JSON array of objects. Each row is exposed as variables during its iteration
+    pub data_file: Option<PathBuf>,
+    /// Waited between two requests
+    pub delay: Duration,
+    /// Stop the run at the first failed request
+    pub bail: bool,
+    /// Also fail the requests whose status is an error, otherwise only the send errors and the failed tests count
+    pub fail_on_status: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionRun {
+    pub collection_index: usize,
+    /// Requests to send at each iteration, in the collection tree order
+    pub requests_indexes: Vec<usize>,
+    /// Names of the WebSocket requests left out of the run
+    pub skipped_ws_requests: Vec<String>,
+    pub data_rows: Vec<IndexMap<String, String>>,
+    pub iterations: usize,
+    pub delay: Duration,
+    pub bail: bool,
+    pub fail_on_status: bool,
+    /// Position of the next request to send, across all the iterations
+    pub position: usize,
+    pub results: Vec<CollectionRunResult>,
+    pub is_canceled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionRunResult {
+    pub iteration: usize,
+    pub request_name: String,
+    /// URL the request was sent to, only set by the CLI
+    pub url: Option<String>,
+    pub status_code: Option<String>,
+    /// gRPC statuses are codes such as "0 OK" or "5 NotFound" rather than HTTP ones
+    pub is_grpc: bool,
+    pub duration: Option<String>,
+    /// Set when the request could not be prepared or sent
+    pub error: Option<String>,
+    /// Pre and post-request scripts console output, only set by the CLI
+    pub console_output: Option<String>,
+    /// Results of the post-request script tests
+    pub test_results: Vec<TestResult>,
+}
+
+impl App<'_> {
+    pub fn new_collection_run(&self, collection_index: usize, options: &CollectionRunOptions) -> anyhow::Result<CollectionRun> {
+        let collection = &self.collections[collection_index];
+
+        // A WebSocket connection stays open until it is closed by hand, it would never let the run go on
+        let (ws_requests_indexes, requests_indexes): (Vec<usize>, Vec<usize>) = collection.get_requests_indexes_in_tree_order()
+            .into_iter()
+            .partition(|request_index| matches!(collection.requests[*request_index].read().protocol, Protocol::WsRequest(_)));
+
+        let skipped_ws_requests = ws_requests_indexes
+            .iter()
+            .map(|request_index| collection.requests[*request_index].read().name.clone())
+            .collect();
+
+        if requests_indexes.is_empty() {
+            return Err(anyhow!(CollectionHasNoRequest));
+        }
+
+        let data_rows = match &options.data_file {
+            None => vec![],
+            Some(data_file) => load_data_file(data_file)?
+        };
+
+        let iterations = match options.iterations {
+            Some(iterations) => iterations as usize,
+            None if !data_rows.is_empty() => data_rows.len(),
+            None => 1
+        };
+
+        Ok(CollectionRun {
+            collection_index,
+            requests_indexes,
+            skipped_ws_requests,
+            data_rows,
+            iterations,
+            delay: options.delay,
+            bail: options.bail,
+            fail_on_status: options.fail_on_status,
+            position: 0,
+            results: vec![],
+            is_canceled: false,
+        })
+    }
+}
+
+impl CollectionRun {
+    pub fn total(&self) -> usize {
+        self.iterations * self.requests_indexes.len()
+    }
+
+    /// Iteration and request index of the next request to send
+    pub fn current(&self) -> (usize, usize) {
+        let iteration = self.position / self.requests_indexes.len();
+        let request_index = self.requests_indexes[self.position % self.requests_indexes.len()];
+
+        (iteration, request_index)
+    }
+
+    /// Data file row of the iteration, the rows are cycled through if there are more iterations than rows
+    pub fn iteration_values(&self, iteration: usize) -> IndexMap<String, String> {
+        match self.data_rows.is_empty() {
+            true => IndexMap::new(),
+            false => self.data_rows[iteration % self.data_rows.len()].clone()
+        }
+    }
+
+    pub fn push_result(&mut self, result: CollectionRunResult) {
+        self.results.push(result);
+        self.position += 1;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_canceled || self.position >= self.total() || (self.bail && self.failed_count() > 0)
+    }
+
+    /// A request passes if it received a response and all its tests passed, its status code only counts with fail_on_status
+    pub fn is_passed(&self, result: &CollectionRunResult) -> bool {
+        result.error.is_none() && result.failed_tests_count() == 0 && self.status_failure(result).is_none()
+    }
+
+    /// Why the request status makes it fail, if it does
+    pub fn status_failure(&self, result: &CollectionRunResult) -> Option<String> {
+        match (&result.error, &result.status_code) {
+            (Some(error), _) => Some(error.clone()),
+            // e.g. CANCELED, TIMEOUT or a send error displayed as the status
+            (None, Some(status_code)) if result.status_code_number().is_none() => Some(status_code.clone()),
+            (None, Some(status_code)) if self.fail_on_status && !result.is_status_code_passed() => Some(status_code.clone()),
+            (None, Some(_)) => None,
+            (None, None) => Some(String::from("No response"))
+        }
+    }
+
+    pub fn passed_count(&self) -> usize {
+        self.results.iter().filter(|result| self.is_passed(result)).count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.results.len() - self.passed_count()
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} passed, {} failed, {} skipped",
+            self.passed_count(),
+            self.failed_count(),
+            self.total() - self.results.len()
+        )
+    }
+}
+
+impl CollectionRunResult {
+    pub fn from_response(iteration: usize, request_name: String, protocol: &Protocol, response: &RequestResponse, test_results: Vec<TestResult>) -> Self {
+        CollectionRunResult {
+            iteration,
+            request_name,
+            url: None,
+            status_code: response.status_code.clone(),
+            is_grpc: matches!(protocol, Protocol::GrpcRequest(_)),
+            duration: response.duration.clone(),
+            error: None,
+            console_output: None,
+            test_results,
+        }
+    }
+
+    pub fn from_error(iteration: usize, request_name: String, error: String) -> Self {
+        CollectionRunResult {
+            iteration,
+            request_name,
+            url: None,
+            status_code: None,
+            is_grpc: false,
+            duration: None,
+            error: Some(error),
+            console_output: None,
+            test_results: vec![],
+        }
+    }
+
+    pub fn failed_tests_count(&self) -> usize {
+        self.test_results.iter().filter(|test_result| !test_result.passed).count()
+    }
+
+    /// None when no response was received, e.g. "TIMEOUT"
+    pub fn status_code_number(&self) -> Option<u16> {
+        self.status_code
+            .as_deref()
+            .and_then(|status_code| status_code.split_whitespace().next())
+            .and_then(|status_code| status_code.parse::<u16>().ok())
+    }
+
+    /// Not a client or server error for HTTP, OK for gRPC
+    pub fn is_status_code_passed(&self) -> bool {
+        match (self.is_grpc, self.status_code_number()) {
+            (_, None) => false,
+            (true, Some(status_code)) => status_code == 0,
+            (false, Some(status_code)) => status_code < 400
+        }
+    }
+
+    pub fn outcome(&self) -> String {
+        let outcome = match (&self.error, &self.status_code) {
+            (Some(error), _) => error.clone(),
+            (None, Some(status_code)) => status_code.clone(),
+            (None, None) => String::from("NO RESPONSE")
+        };
+
+        match self.failed_tests_count() {
+            0 => outcome,
+            failed_tests_count => format!("{outcome}, {failed_tests_count}/{} test(s) failed", self.test_results.len())
+        }
+    }
+}
+
+/// Read the rows of a CSV (with a header line) or JSON (array of objects) data file, depending on its extension
+pub fn load_data_file(path: &Path) -> Result<Vec<IndexMap<String, String>>, CollectionRunnerError> {
+    trace!("Loading data file \"{}\"", path.display());
+
+    let content = fs::read_to_string(path).map_err(|error| CouldNotReadDataFile(path.display().to_string(), error.to_string()))?;
+
+    let is_json = path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+
+    let rows = match is_json {
+        true => parse_json_data(&content)?,
+        false => parse_csv_data(&content)?
+    };
+
+    if rows.is_empty() {
+        return Err(DataFileIsEmpty);
+    }
+
+    Ok(rows)
+}
+
+fn parse_csv_data(content: &str) -> Result<Vec<IndexMap<String, String>>, CollectionRunnerError> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+
+    let headers = reader.headers()
+        .map_err(|error| CouldNotParseCsvDataFile(error.to_string()))?
+        .clone();
+
+    let mut rows = vec![];
+
+    for record in reader.records() {
+        let record = record.map_err(|error| CouldNotParseCsvDataFile(error.to_string()))?;
+
+        let row = headers
+            .iter()
+            .zip(record.iter())
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+fn parse_json_data(content: &str) -> Result<Vec<IndexMap<String, String>>, CollectionRunnerError> {
+    let json: Value = serde_json::from_str(content).map_err(|error| CouldNotParseJsonDataFile(error.to_string()))?;
+
+    let Value::Array(array) = json else {
+        return Err(InvalidJsonDataFile);
+    };
+
+    let mut rows = vec![];
+
+    for element in array {
+        let Value::Object(object) = element else {
+            return Err(InvalidJsonDataFile);
+        };
+
+        let row = object
+            .into_iter()
+            .map(|(key, value)| {
+                // Strings are taken as is, without their quotes
+                let value = match value {
+                    Value::String(string) => string,
+                    value => value.to_string()
+                };
+
+                (key, value)
+            })
+            .collect();
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::business_logic::collection_runner::CollectionRunResult;
+
+    #[test]
+    fn status_code_passed() {
+        let result = |status_code: &str, is_grpc: bool| CollectionRunResult {
+            status_code: Some(status_code.to_string()),
+            is_grpc,
+            ..CollectionRunResult::from_error(0, String::from("request"), String::new())
+        };
+
+        assert!(result("200 OK", false).is_status_code_passed());
+        assert!(result("302 Found", false).is_status_code_passed());
+        assert!(!result("404 Not Found", false).is_status_code_passed());
+        assert!(!result("TIMEOUT", false).is_status_code_passed());
+        assert!(result("0 Ok", true).is_status_code_passed());
+        assert!(!result("5 NotFound", true).is_status_code_passed());
+        assert!(!result("CANCELED", true).is_status_code_passed());
+    }
+}
@@ -0,0 +1,202 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use reqwest::header::HeaderValue;
+use reqwest::Url;
+use reqwest_cookie_store::CookieStoreRwLock;
+use thiserror::Error;
+use tracing::{info, trace};
+
+use crate::app::app::App;
+use crate::app::business_logic::cookies::CookieError::{CookieAlreadyExists, CookieExpired, CookieNotFound, InvalidCookie};
+use crate::app::files::cookies::{get_cookie_jar_path, load_cookie_jar, save_cookie_jar};
+use crate::models::cookie::{cookie_row_key, row_to_cookie, COOKIES_COLUMNS_NUMBER};
+
+#[derive(Error, Debug)]
+pub enum CookieError {
+    #[error("Cookie not found")]
+    CookieNotFound,
+
+    #[error("Cookie already exists")]
+    CookieAlreadyExists,
+
+    #[error("Cookie expiration is in the past")]
+    CookieExpired,
+
+    #[error("Invalid cookie domain \"{0}\"")]
+    InvalidCookieDomain(String),
+
+    #[error("Invalid cookie path \"{0}\", it should start with \"/\"")]
+    InvalidCookiePath(String),
+
+    #[error("Invalid cookie expiration \"{0}\", expected \"session\" or a RFC 3339 date such as 2030-01-01T00:00:00Z")]
+    InvalidCookieExpiration(String),
+
+    #[error("Invalid cookie flag \"{0}\", expected \"true\" or \"false\"")]
+    InvalidCookieFlag(String),
+
+    #[error("Invalid cookie SameSite \"{0}\", expected \"Strict\", \"Lax\" or \"None\"")]
+    InvalidCookieSameSite(String),
+
+    #[error("Invalid cookie: {0}")]
+    InvalidCookie(String),
+}
+
+/// Cookie provider of the HTTP clients, the jar is saved as soon as a response sets cookies
+pub struct PersistedCookieStore {
+    pub cookie_store: Arc<CookieStoreRwLock>,
+    /// Jar which the cookie store was loaded from, it follows the environment
+    pub cookie_jar_path: Arc<RwLock<Option<PathBuf>>>,
+}
+
+impl reqwest::cookie::CookieStore for PersistedCookieStore {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        self.cookie_store.set_cookies(cookie_headers, url);
+
+        if let Some(path) = self.cookie_jar_path.read().as_ref() {
+            save_cookie_jar(path, &self.cookie_store.read().unwrap());
+        }
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        self.cookie_store.cookies(url)
+    }
+}
+
+impl App<'_> {
+    fn get_selected_cookie_jar_path(&self) -> Option<PathBuf> {
+        let environment_name = self.get_selected_env_as_local().map(|local_env| local_env.read().name.clone());
+
+        get_cookie_jar_path(environment_name.as_deref())
+    }
+
+    /// Replace the cookies in memory with the jar of the selected environment
+    pub fn load_cookie_jar(&mut self) {
+        let Some(path) = self.get_selected_cookie_jar_path() else {
+            return;
+        };
+
+        let cookie_store = load_cookie_jar(&path);
+
+        *self.cookies_popup.cookie_store.write().unwrap() = cookie_store;
+        *self.cookies_popup.cookie_jar_path.write() = Some(path);
+    }
+
+    pub fn save_cookie_jar(&self) {
+        let Some(path) = self.get_selected_cookie_jar_path() else {
+            return;
+        };
+
+        let cookie_store = self.cookies_popup.cookie_store.read().unwrap();
+
+        save_cookie_jar(&path, &cookie_store);
+    }
+
+    /// Switch environment, the cookie jar follows
+    pub fn select_environment(&mut self, environment_index: usize) {
+        if environment_index == self.selected_environment {
+            return;
+        }
+
+        self.save_cookie_jar();
+        self.selected_environment = environment_index;
+        self.load_cookie_jar();
+    }
+
+    pub fn clear_cookies(&mut self) -> anyhow::Result<()> {
+        self.cookies_popup.cookie_store.write().unwrap().clear();
+
+        info!("Cookies cleared");
+
+        self.save_cookie_jar();
+        Ok(())
+    }
+
+    /// Delete every cookie with this name, optionally restricted to a domain and a path
+    pub fn delete_cookies(&mut self, cookie_name: &str, domain: Option<&str>, path: Option<&str>) -> anyhow::Result<()> {
+        trace!("Trying to delete cookie \"{cookie_name}\"");
+
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        let matching_cookies: Vec<(String, String)> = cookie_store
+            .iter_any()
+            .filter(|cookie| cookie.name() == cookie_name)
+            .map(|cookie| (String::from(&cookie.domain), String::from(&cookie.path)))
+            .filter(|(cookie_domain, cookie_path)| {
+                domain.is_none_or(|domain| domain == cookie_domain) && path.is_none_or(|path| path == cookie_path)
+            })
+            .collect();
+
+        if matching_cookies.is_empty() {
+            return Err(anyhow!(CookieNotFound));
+        }
+
+        for (cookie_domain, cookie_path) in &matching_cookies {
+            cookie_store.remove(cookie_domain, cookie_path, cookie_name);
+        }
+
+        drop(cookie_store);
+
+        info!("{} cookie(s) \"{cookie_name}\" deleted", matching_cookies.len());
+
+        self.save_cookie_jar();
+        Ok(())
+    }
+
+    /// Insert the cookie described by the row, the previous cookie is removed first when editing one.
+    /// Returns the (domain, path, name) key of the inserted cookie
+    pub fn insert_cookie(&mut self, previous_cookie_row: Option<&[String; COOKIES_COLUMNS_NUMBER]>, cookie_row: &[String; COOKIES_COLUMNS_NUMBER]) -> anyhow::Result<(String, String, String)> {
+        let cookie_key = self.insert_cookie_in_store(previous_cookie_row, cookie_row)?;
+
+        info!("Cookie \"{}\" set for \"{}{}\"", cookie_key.2, cookie_key.0, cookie_key.1);
+
+        self.save_cookie_jar();
+        Ok(cookie_key)
+    }
+
+    /// Insert or replace every cookie, the jar is saved once at the end.
+    /// Returns the index of the cookies that could not be inserted, with the reason
+    pub fn import_cookies(&mut self, cookie_rows: &[[String; COOKIES_COLUMNS_NUMBER]]) -> Vec<(usize, anyhow::Error)> {
+        let mut skipped_cookies = vec![];
+
+        for (index, cookie_row) in cookie_rows.iter().enumerate() {
+            // Replacing the cookie with the same key if it already exists
+            if let Err(error) = self.insert_cookie_in_store(Some(cookie_row), cookie_row) {
+                skipped_cookies.push((index, error));
+            }
+        }
+
+        info!("{} cookie(s) imported", cookie_rows.len() - skipped_cookies.len());
+
+        self.save_cookie_jar();
+        skipped_cookies
+    }
+
+    fn insert_cookie_in_store(&self, previous_cookie_row: Option<&[String; COOKIES_COLUMNS_NUMBER]>, cookie_row: &[String; COOKIES_COLUMNS_NUMBER]) -> anyhow::Result<(String, String, String)> {
+        let (cookie, url) = row_to_cookie(cookie_row)?;
+        let cookie_key = cookie_row_key(cookie_row);
+        let previous_cookie_key = previous_cookie_row.map(cookie_row_key);
+
+        // The store refuses expired cookies, check it before touching the previous one
+        if cookie.is_expired() {
+            return Err(anyhow!(CookieExpired));
+        }
+
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        // Would silently replace another cookie
+        if previous_cookie_key.as_ref() != Some(&cookie_key) && cookie_store.contains_any(&cookie_key.0, &cookie_key.1, &cookie_key.2) {
+            return Err(anyhow!(CookieAlreadyExists));
+        }
+
+        if let Some((domain, path, name)) = &previous_cookie_key {
+            cookie_store.remove(domain, path, name);
+        }
+
+        cookie_store.insert(cookie, &url).map_err(|error| InvalidCookie(error.to_string()))?;
+
+        Ok(cookie_key)
+    }
+}
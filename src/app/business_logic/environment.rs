@@ -2,6 +2,7 @@ use std::sync::Arc;
 use rayon::prelude::*;
 use anyhow::anyhow;
 use chrono::Utc;
+use indexmap::IndexMap;
 use indexmap::map::MutableKeys;
 use parking_lot::RwLock;
 use thiserror::Error;
@@ -214,31 +215,40 @@ impl App<'_> {
     }
     
     pub fn replace_env_keys_by_value(&self, input: &String) -> String {
-        if self.environments.is_empty() {
-            return input.to_string();
-        }
+        replace_env_keys_by_value(input, &self.iteration_values, &self.environments, self.selected_environment)
+    }
+}
 
-        let mut tmp_string = input.to_string();
+/// The collection runner data file row takes precedence over the selected environment, then come the OS environment variables
+pub fn replace_env_keys_by_value(input: &String, iteration_values: &IndexMap<String, String>, environments: &[Arc<RwLock<Environment>>], selected_environment: usize) -> String {
+    let mut tmp_string = input.to_string();
 
-        let local_env = self.get_selected_env_as_local();
+    for (key, value) in iteration_values {
+        tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
+    }
 
-        if let Some(local_env) = local_env {
-            let env = local_env.read();
-            let mut values = env.values.clone();
-            values.extend(OS_ENV_VARS.clone());
-            
-            for (key, value) in &values {
-                tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
-            }
-        }
+    if environments.is_empty() {
+        return tmp_string;
+    }
 
+    let local_env = environments.get(selected_environment);
+
+    if let Some(local_env) = local_env {
+        let env = local_env.read();
+        let mut values = env.values.clone();
+        values.extend(OS_ENV_VARS.clone());
         
-        tmp_string = tmp_string
-            .replace("{{NOW}}", &Utc::now().to_string())
-            .replace("{{TIMESTAMP}}", &Utc::now().timestamp().to_string())
-            .replace("{{UUIDv4}}", &Uuid::new_v4().to_string())
-            .replace("{{UUIDv7}}", &Uuid::now_v7().to_string());
-        
-        return tmp_string;
+        for (key, value) in &values {
+            tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
+        }
     }
+
+    
+    tmp_string = tmp_string
+        .replace("{{NOW}}", &Utc::now().to_string())
+        .replace("{{TIMESTAMP}}", &Utc::now().timestamp().to_string())
+        .replace("{{UUIDv4}}", &Uuid::new_v4().to_string())
+        .replace("{{UUIDv7}}", &Uuid::now_v7().to_string());
+    
+    return tmp_string;
 }
\ No newline at end of file
@@ -0,0 +1,223 @@
+use anyhow::anyhow;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::folder::FolderError::{CannotMoveFolderIntoItself, FolderNameAlreadyExists, FolderNameIsEmpty, FolderNotFound};
+use crate::app::files::history::{delete_response_history, get_response_history_folder_path, move_response_history};
+use crate::models::collection::Folder;
+
+#[derive(Error, Debug)]
+pub enum FolderError {
+    #[error("The folder name is empty")]
+    FolderNameIsEmpty,
+    #[error("A folder with this name already exists here")]
+    FolderNameAlreadyExists,
+    #[error("Folder \"{0}\" not found")]
+    FolderNotFound(String),
+    #[error("A folder cannot be moved into itself")]
+    CannotMoveFolderIntoItself,
+}
+
+impl App<'_> {
+    pub fn new_folder(&mut self, collection_index: usize, parent_path: &[String], new_folder_name: String) -> anyhow::Result<()> {
+        let new_folder_name = sanitize_folder_name(&new_folder_name);
+
+        if new_folder_name.is_empty() {
+            return Err(anyhow!(FolderNameIsEmpty));
+        }
+
+        let folders = self.get_folders_mut(collection_index, parent_path)?;
+
+        if folders.iter().any(|folder| folder.name == new_folder_name) {
+            return Err(anyhow!(FolderNameAlreadyExists));
+        }
+
+        info!("Folder \"{new_folder_name}\" created");
+
+        folders.push(Folder {
+            name: new_folder_name,
+            folders: vec![],
+        });
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn rename_folder(&mut self, collection_index: usize, path: &[String], new_folder_name: String) -> anyhow::Result<()> {
+        let new_folder_name = sanitize_folder_name(&new_folder_name);
+
+        if new_folder_name.is_empty() {
+            return Err(anyhow!(FolderNameIsEmpty));
+        }
+
+        let (parent_path, folder_name) = split_folder_path(path)?;
+        let folders = self.get_folders_mut(collection_index, parent_path)?;
+
+        if folders.iter().any(|folder| folder.name == new_folder_name) {
+            return Err(anyhow!(FolderNameAlreadyExists));
+        }
+
+        match folders.iter_mut().find(|folder| &folder.name == folder_name) {
+            Some(folder) => folder.name = new_folder_name.clone(),
+            None => return Err(anyhow!(FolderNotFound(path.join("/"))))
+        }
+
+        info!("Folder renamed to \"{new_folder_name}\"");
+
+        let new_path = [parent_path, &[new_folder_name]].concat();
+        self.replace_requests_folder_prefix(collection_index, path, &new_path);
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Delete a folder along with its sub-folders and requests
+    pub fn delete_folder(&mut self, collection_index: usize, path: &[String]) -> anyhow::Result<()> {
+        let (parent_path, folder_name) = split_folder_path(path)?;
+        let folders = self.get_folders_mut(collection_index, parent_path)?;
+
+        match folders.iter().position(|folder| &folder.name == folder_name) {
+            Some(folder_index) => folders.remove(folder_index),
+            None => return Err(anyhow!(FolderNotFound(path.join("/"))))
+        };
+
+        info!("Folder deleted");
+
+        delete_response_history(&get_response_history_folder_path(&self.collections[collection_index], path));
+
+        self.collections[collection_index].requests.retain(|request| !request.read().folder.starts_with(path));
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Move a folder with its content into another folder, or at the collection root if the new parent path is empty
+    pub fn move_folder(&mut self, collection_index: usize, path: &[String], new_parent_path: &[String]) -> anyhow::Result<()> {
+        if new_parent_path.starts_with(path) {
+            return Err(anyhow!(CannotMoveFolderIntoItself));
+        }
+
+        let (parent_path, folder_name) = split_folder_path(path)?;
+
+        if self.collections[collection_index].find_folder(path).is_none() {
+            return Err(anyhow!(FolderNotFound(path.join("/"))));
+        }
+
+        let new_parent_folders = self.get_folders_mut(collection_index, new_parent_path)?;
+
+        if new_parent_folders.iter().any(|folder| &folder.name == folder_name) {
+            return Err(anyhow!(FolderNameAlreadyExists));
+        }
+
+        let folders = self.get_folders_mut(collection_index, parent_path)?;
+        let folder_index = folders.iter().position(|folder| &folder.name == folder_name).unwrap();
+        let folder = folders.remove(folder_index);
+
+        // The new parent is not inside the moved folder, so its path is still valid
+        self.get_folders_mut(collection_index, new_parent_path)?.push(folder);
+
+        info!("Folder moved to \"{}\"", new_parent_path.join("/"));
+
+        let new_path = [new_parent_path, std::slice::from_ref(folder_name)].concat();
+        self.replace_requests_folder_prefix(collection_index, path, &new_path);
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Move a request into a folder, or at the collection root if the folder path is empty
+    pub fn move_request_to_folder(&mut self, collection_index: usize, request_index: usize, path: &[String]) -> anyhow::Result<()> {
+        if !path.is_empty() && self.collections[collection_index].find_folder(path).is_none() {
+            return Err(anyhow!(FolderNotFound(path.join("/"))));
+        }
+
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let old_history_path = self.get_request_response_history_path(collection_index, request_index);
+
+        {
+            let mut request = local_request.write();
+
+            info!("Request moved to \"{}\"", path.join("/"));
+
+            request.folder = path.to_vec();
+        }
+
+        move_response_history(&old_history_path, &self.get_request_response_history_path(collection_index, request_index));
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Swap a folder with its previous (offset -1) or next (offset 1) sibling, returns the new position if it moved
+    pub fn move_folder_among_siblings(&mut self, collection_index: usize, path: &[String], offset: isize) -> anyhow::Result<Option<usize>> {
+        let (parent_path, folder_name) = split_folder_path(path)?;
+        let folders = self.get_folders_mut(collection_index, parent_path)?;
+
+        let folder_index = match folders.iter().position(|folder| &folder.name == folder_name) {
+            Some(folder_index) => folder_index,
+            None => return Err(anyhow!(FolderNotFound(path.join("/"))))
+        };
+
+        let new_folder_index = match folder_index.checked_add_signed(offset) {
+            Some(new_folder_index) if new_folder_index < folders.len() => new_folder_index,
+            _ => return Ok(None)
+        };
+
+        folders.swap(folder_index, new_folder_index);
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(Some(new_folder_index))
+    }
+
+    fn get_folders_mut(&mut self, collection_index: usize, path: &[String]) -> anyhow::Result<&mut Vec<Folder>> {
+        match self.collections[collection_index].find_folders_mut(path) {
+            Some(folders) => Ok(folders),
+            None => Err(anyhow!(FolderNotFound(path.join("/"))))
+        }
+    }
+
+    /// The folder's response history directory follows
+    fn replace_requests_folder_prefix(&mut self, collection_index: usize, old_path: &[String], new_path: &[String]) {
+        let collection = &self.collections[collection_index];
+        move_response_history(&get_response_history_folder_path(collection, old_path), &get_response_history_folder_path(collection, new_path));
+
+        for request in &self.collections[collection_index].requests {
+            let mut request = request.write();
+
+            if request.folder.starts_with(old_path) {
+                request.folder = [new_path, &request.folder[old_path.len()..]].concat();
+            }
+        }
+    }
+}
+
+/// Parse a folder path written as "folder/sub_folder"
+pub fn parse_folder_path(path: &str) -> Vec<String> {
+    path
+        .split('/')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn sanitize_folder_name(folder_name: &str) -> String {
+    folder_name
+        .trim()
+        .replace("/", "")
+        .replace("\"", "")
+}
+
+fn split_folder_path(path: &[String]) -> anyhow::Result<(&[String], &String)> {
+    match path.split_last() {
+        Some((folder_name, parent_path)) => Ok((parent_path, folder_name)),
+        None => Err(anyhow!(FolderNameIsEmpty))
+    }
+}
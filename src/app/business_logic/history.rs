@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+use tracing::{info, trace, warn};
+
+use crate::app::app::App;
+use crate::app::business_logic::request::preparer::RequestPreparer;
+use crate::app::business_logic::request::send::PreparedUrl;
+use crate::app::files::history::{delete_response_history, get_response_history_path, load_response_history, update_response_history};
+use crate::models::history::ResponseHistoryEntry;
+use crate::models::request::Request;
+use crate::models::response::RequestResponse;
+use crate::app::business_logic::history::ResponseHistoryError::EntryNotFound;
+
+#[derive(Error, Debug)]
+pub enum ResponseHistoryError {
+    #[error("Response history entry {0} not found")]
+    EntryNotFound(usize),
+}
+
+/// Everything needed to record a response once it is received, since it may be received in another task
+pub struct ResponseHistoryRecorder {
+    history_path: PathBuf,
+    request_path: String,
+    url: String,
+    history_size: usize,
+}
+
+impl RequestPreparer {
+    /// The URL is the one of the prepared request, the pre-request script may have changed it. Gives None when the history is disabled
+    pub fn new_response_history_recorder(&self, collection_index: usize, request: &Request, prepared_request: &mut reqwest_middleware::RequestBuilder) -> Option<ResponseHistoryRecorder> {
+        let history_size = self.response_history_size;
+
+        if history_size == 0 {
+            return None;
+        }
+
+        Some(ResponseHistoryRecorder {
+            history_path: get_response_history_path(&self.collections[collection_index], &request.folder, &request.name),
+            request_path: request.get_path(),
+            url: prepared_request.extensions().get::<PreparedUrl>().map(|prepared_url| prepared_url.0.clone()).unwrap_or_default(),
+            history_size,
+        })
+    }
+}
+
+impl App<'_> {
+    /// Past responses of the request, oldest first
+    pub fn get_response_history(&self, collection_index: usize, request_index: usize) -> Vec<ResponseHistoryEntry> {
+        let history_path = self.get_request_response_history_path(collection_index, request_index);
+
+        load_response_history(&history_path)
+    }
+
+    /// Entries are counted from the most recent one, which is 0
+    pub fn get_response_history_entry(&self, collection_index: usize, request_index: usize, entry_index: usize) -> Result<ResponseHistoryEntry, ResponseHistoryError> {
+        let mut history = self.get_response_history(collection_index, request_index);
+
+        match entry_index < history.len() {
+            true => Ok(history.remove(history.len() - 1 - entry_index)),
+            false => Err(EntryNotFound(entry_index))
+        }
+    }
+
+    pub fn clear_response_history(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let history_path = self.get_request_response_history_path(collection_index, request_index);
+        let request_path = self.collections[collection_index].requests[request_index].read().get_path();
+
+        delete_response_history(&history_path);
+
+        info!("Response history of \"{request_path}\" cleared");
+
+        Ok(())
+    }
+
+    pub fn get_request_response_history_path(&self, collection_index: usize, request_index: usize) -> PathBuf {
+        let request = self.collections[collection_index].requests[request_index].read();
+
+        get_response_history_path(&self.collections[collection_index], &request.folder, &request.name)
+    }
+}
+
+impl ResponseHistoryRecorder {
+    /// The file access is done on a blocking thread, it must not hold the async runtime up
+    pub async fn record(&self, response: &RequestResponse) {
+        trace!("Recording response of \"{}\"", self.request_path);
+
+        let history_path = self.history_path.clone();
+        let history_size = self.history_size;
+        let entry = ResponseHistoryEntry::new(self.url.clone(), response);
+
+        let recording = tokio::task::spawn_blocking(move || update_response_history(&history_path, |history| {
+            history.push(entry);
+
+            if history.len() > history_size {
+                let overflow = history.len() - history_size;
+                history.drain(..overflow);
+            }
+
+            true
+        }));
+
+        if let Err(error) = recording.await {
+            warn!("Could not record response: {error}");
+        }
+    }
+}
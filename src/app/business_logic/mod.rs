@@ -1,5 +1,13 @@
 pub mod request;
 pub mod collection;
+pub mod collection_defaults;
+pub mod folder;
+pub mod collection_runner;
+pub mod collection_report;
+pub mod history;
+pub mod response_diff;
+pub mod response_filter;
 pub mod environment;
+pub mod cookies;
 pub mod key_value;
 mod utils;
\ No newline at end of file
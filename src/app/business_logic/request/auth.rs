@@ -203,4 +203,245 @@ impl App<'_> {
 
         self.save_collection_to_file(collection_index);
     }
+
+    pub fn modify_request_auth_oauth2_token_url(&mut self, collection_index: usize, request_index: usize, token_url: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 token URL set to \"{}\"", token_url);
+
+            oauth2.token_url = token_url;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_authorization_url(&mut self, collection_index: usize, request_index: usize, authorization_url: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 authorization URL set to \"{}\"", authorization_url);
+
+            oauth2.authorization_url = authorization_url;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_redirect_url(&mut self, collection_index: usize, request_index: usize, redirect_url: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 redirect URL set to \"{}\"", redirect_url);
+
+            oauth2.redirect_url = redirect_url;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_client_id(&mut self, collection_index: usize, request_index: usize, client_id: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 client ID set to \"{}\"", client_id);
+
+            oauth2.client_id = client_id;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_client_secret(&mut self, collection_index: usize, request_index: usize, client_secret: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 client secret set to \"{}\"", client_secret);
+
+            oauth2.client_secret = client_secret;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_scope(&mut self, collection_index: usize, request_index: usize, scope: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 scope set to \"{}\"", scope);
+
+            oauth2.scope = scope;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_username(&mut self, collection_index: usize, request_index: usize, username: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 username set to \"{}\"", username);
+
+            oauth2.username = username;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_password(&mut self, collection_index: usize, request_index: usize, password: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 password set to \"{}\"", password);
+
+            oauth2.password = password;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth2_refresh_token(&mut self, collection_index: usize, request_index: usize, refresh_token: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            info!("Auth OAuth2 refresh token set to \"{}\"", refresh_token);
+
+            oauth2.refresh_token = refresh_token;
+            oauth2.issued_refresh_token.clear();
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_aws_sigv4_access_key_id(&mut self, collection_index: usize, request_index: usize, access_key_id: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let aws_sigv4 = selected_request.auth.get_aws_sigv4_mut();
+
+            info!("Auth AWS SigV4 access key ID set to \"{}\"", access_key_id);
+
+            aws_sigv4.access_key_id = access_key_id;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_aws_sigv4_secret_access_key(&mut self, collection_index: usize, request_index: usize, secret_access_key: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let aws_sigv4 = selected_request.auth.get_aws_sigv4_mut();
+
+            info!("Auth AWS SigV4 secret access key set to \"{}\"", secret_access_key);
+
+            aws_sigv4.secret_access_key = secret_access_key;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_aws_sigv4_region(&mut self, collection_index: usize, request_index: usize, region: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let aws_sigv4 = selected_request.auth.get_aws_sigv4_mut();
+
+            info!("Auth AWS SigV4 region set to \"{}\"", region);
+
+            aws_sigv4.region = region;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_aws_sigv4_service(&mut self, collection_index: usize, request_index: usize, service: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let aws_sigv4 = selected_request.auth.get_aws_sigv4_mut();
+
+            info!("Auth AWS SigV4 service set to \"{}\"", service);
+
+            aws_sigv4.service = service;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_aws_sigv4_session_token(&mut self, collection_index: usize, request_index: usize, session_token: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let aws_sigv4 = selected_request.auth.get_aws_sigv4_mut();
+
+            info!("Auth AWS SigV4 session token set to \"{}\"", session_token);
+
+            aws_sigv4.session_token = session_token;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_api_key_key(&mut self, collection_index: usize, request_index: usize, key: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let api_key = selected_request.auth.get_api_key_mut();
+
+            info!("Auth API key key set to \"{}\"", key);
+
+            api_key.key = key;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_api_key_value(&mut self, collection_index: usize, request_index: usize, value: String) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let api_key = selected_request.auth.get_api_key_mut();
+
+            info!("Auth API key value set to \"{}\"", value);
+
+            api_key.value = value;
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
 }
@@ -0,0 +1,237 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::app::app::App;
+use crate::app::business_logic::key_value::find_key;
+use crate::app::business_logic::request::captures::CaptureError::{HeaderNotFound, InvalidCaptureRule, NoEnvironment, NoResponseBody, NoStatusCode};
+use crate::app::business_logic::response_filter::select_response_body_value;
+use crate::app::files::environment::save_environment_to_file;
+use crate::models::environment::Environment;
+use crate::models::request::{KeyValue, Request};
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum CaptureError {
+    #[error("Invalid capture rule \"{0}\", expected \"body <JSONPath, jq or XPath filter>\", \"header <name>\" or \"status\"")]
+    InvalidCaptureRule(String),
+    #[error("No environment selected to store the captured values")]
+    NoEnvironment,
+    #[error("The response has no text body")]
+    NoResponseBody,
+    #[error("Header \"{0}\" not found in the response")]
+    HeaderNotFound(String),
+    #[error("The response has no status code")]
+    NoStatusCode,
+}
+
+/// Where a captured value comes from, written as the value of a capture row
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureSource {
+    /// e.g. `body $.access_token`, `body .data.id`, `body //token/text()`
+    Body(String),
+    /// e.g. `header set-cookie`
+    Header(String),
+    /// Numeric status code, e.g. 200
+    Status,
+}
+
+impl FromStr for CaptureSource {
+    type Err = CaptureError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.trim();
+        let (source, argument) = rule.split_once(char::is_whitespace).unwrap_or((rule, ""));
+        let argument = argument.trim();
+
+        match (source.to_lowercase().as_str(), argument.is_empty()) {
+            ("body", false) => Ok(CaptureSource::Body(argument.to_string())),
+            ("header", false) => Ok(CaptureSource::Header(argument.to_string())),
+            ("status", true) => Ok(CaptureSource::Status),
+            _ => Err(InvalidCaptureRule(rule.to_string()))
+        }
+    }
+}
+
+impl CaptureSource {
+    pub fn capture(&self, response: &RequestResponse) -> anyhow::Result<String> {
+        match self {
+            CaptureSource::Body(filter) => match &response.content {
+                Some(ResponseContent::Body(body)) => Ok(select_response_body_value(body, filter)?),
+                _ => Err(NoResponseBody.into())
+            },
+            CaptureSource::Header(name) => response.headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.clone())
+                .ok_or_else(|| HeaderNotFound(name.clone()).into()),
+            CaptureSource::Status => match &response.status_code {
+                Some(status_code) => Ok(status_code.split_whitespace().next().unwrap_or(status_code).to_string()),
+                None => Err(NoStatusCode.into())
+            }
+        }
+    }
+}
+
+impl App<'_> {
+    /// Store the values captured from the response in the environment, returns a console line per enabled capture
+    pub fn handle_response_captures(request: &Request, response: &RequestResponse, env: &Option<Arc<RwLock<Environment>>>) -> Option<String> {
+        let captures: Vec<&KeyValue> = request.captures
+            .iter()
+            .filter(|capture| capture.enabled)
+            .collect();
+
+        if captures.is_empty() {
+            return None;
+        }
+
+        let mut console_output = String::new();
+        let mut captured_values = vec![];
+
+        for capture in captures {
+            let (variable, rule) = &capture.data;
+
+            let captured_value = match env {
+                None => Err(NoEnvironment.into()),
+                Some(_) => CaptureSource::from_str(rule)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|capture_source| capture_source.capture(response))
+            };
+
+            match captured_value {
+                Ok(value) => {
+                    info!("Captured \"{variable}\"");
+                    console_output.push_str(&format!("Captured {variable}\n"));
+                    captured_values.push((variable.clone(), value));
+                },
+                Err(error) => {
+                    warn!("Could not capture \"{variable}\": {error}");
+                    console_output.push_str(&format!("Could not capture {variable}: {error}\n"));
+                }
+            }
+        }
+
+        if let Some(env) = env && !captured_values.is_empty() {
+            let mut env = env.write();
+            env.values.extend(captured_values);
+            save_environment_to_file(&env);
+        }
+
+        Some(console_output)
+    }
+
+    pub fn find_capture(&mut self, collection_index: usize, request_index: usize, key: &str) -> anyhow::Result<usize> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let selected_request = local_selected_request.read();
+
+        find_key(&selected_request.captures, key)
+    }
+
+    pub fn modify_request_capture(&mut self, collection_index: usize, request_index: usize, value: String, column: usize, row: usize) -> anyhow::Result<()> {
+        if column == 1 {
+            CaptureSource::from_str(&value)?;
+        }
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let capture_type = match column {
+                0 => "variable",
+                1 => "rule",
+                _ => ""
+            };
+
+            info!("Capture {capture_type} set to \"{value}\"");
+
+            match column {
+                0 => selected_request.captures[row].data.0 = value.clone(),
+                1 => selected_request.captures[row].data.1 = value.clone(),
+                _ => {}
+            };
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn create_new_capture(&mut self, collection_index: usize, request_index: usize, variable: String, rule: String) -> anyhow::Result<()> {
+        CaptureSource::from_str(&rule)?;
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Variable \"{variable}\" captured from \"{rule}\" added to the captures");
+
+            selected_request.captures.push(KeyValue {
+                enabled: true,
+                data: (variable, rule)
+            });
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn delete_capture(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Capture deleted");
+
+            selected_request.captures.remove(row);
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn toggle_capture(&mut self, collection_index: usize, request_index: usize, state: Option<bool>, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let new_state = match state {
+                None => {
+                    let state = !selected_request.captures[row].enabled;
+                    // Better user feedback
+                    println!("{state}");
+                    state
+                },
+                Some(state) => state
+            };
+
+            info!("Capture state set to \"{new_state}\"");
+
+            selected_request.captures[row].enabled = new_state;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn duplicate_capture(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Capture duplicated");
+
+            let capture = selected_request.captures[row].clone();
+            selected_request.captures.insert(row, capture);
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
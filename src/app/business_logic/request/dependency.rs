@@ -0,0 +1,322 @@
+use std::time::Duration;
+
+use chrono::Local;
+use thiserror::Error;
+use tracing::{info, trace};
+
+use crate::app::business_logic::request::dependency::RequestDependencyError::{DependencyCycle, DependencyFailed, DependencyNotFound, InvalidCacheLifetime};
+use crate::app::business_logic::request::grpc::send::send_grpc_request;
+use crate::app::business_logic::request::http::send::send_http_request;
+use crate::app::business_logic::request::preparer::RequestPreparer;
+use crate::app::business_logic::request::ws::send::send_ws_request;
+use crate::app::files::history::{get_dependency_timestamps_path, load_dependency_timestamps, update_dependency_timestamps};
+use crate::models::collection::CollectionDefaults;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::Request;
+
+#[derive(Error, Debug)]
+pub enum RequestDependencyError {
+    #[error("DEPENDENCY \"{0}\" NOT FOUND, EXPECTED \"collection/request\"")]
+    DependencyNotFound(String),
+    #[error("DEPENDENCY CYCLE {0}")]
+    DependencyCycle(String),
+    #[error("INVALID DEPENDENCY CACHE LIFETIME \"{0}\", EXPECTED E.G. 300, 15m OR 1h")]
+    InvalidCacheLifetime(String),
+    #[error("DEPENDENCY \"{0}\" FAILED: {1}")]
+    DependencyFailed(String, String),
+}
+
+/// Seconds, or a number followed by s, m, h or d. None when empty, meaning that it never expires
+pub fn parse_dependency_cache_lifetime(cache_lifetime: &str) -> Result<Option<Duration>, RequestDependencyError> {
+    let cache_lifetime = cache_lifetime.trim();
+
+    if cache_lifetime.is_empty() {
+        return Ok(None);
+    }
+
+    let (number, unit_seconds) = match cache_lifetime.char_indices().last() {
+        Some((index, 's')) => (&cache_lifetime[..index], 1),
+        Some((index, 'm')) => (&cache_lifetime[..index], 60),
+        Some((index, 'h')) => (&cache_lifetime[..index], 60 * 60),
+        Some((index, 'd')) => (&cache_lifetime[..index], 24 * 60 * 60),
+        _ => (cache_lifetime, 1)
+    };
+
+    match number.trim().parse::<u64>().ok().and_then(|number| number.checked_mul(unit_seconds)) {
+        Some(seconds) => Ok(Some(Duration::from_secs(seconds))),
+        None => Err(InvalidCacheLifetime(cache_lifetime.to_string()))
+    }
+}
+
+struct ResolvedDependency {
+    /// e.g. "my_collection/login"
+    path: String,
+    index: (usize, usize),
+    /// Set by the request depending on it
+    cache_lifetime: Option<Duration>,
+}
+
+impl RequestPreparer {
+    /// Send the request's dependency first when its captured values are missing or expired, after the dependency's own dependency and so on.
+    /// Once a dependency is sent, the ones depending on it are sent too since their values may have changed.
+    /// The request being prepared is a copy of the one at the given indexes, if it belongs to a collection
+    pub async fn send_request_dependencies(&self, request_index: Option<(usize, usize)>, request: &Request, collection_defaults: &CollectionDefaults) -> Result<(), RequestDependencyError> {
+        let dependencies = self.resolve_request_dependencies(request_index, request, collection_defaults)?;
+
+        let mut is_chain_outdated = false;
+
+        for dependency in dependencies.into_iter().rev() {
+            if !is_chain_outdated && self.is_dependency_fresh(dependency.index, dependency.cache_lifetime) {
+                trace!("Dependency \"{}\" is still fresh", dependency.path);
+                continue;
+            }
+
+            self.send_request_dependency(&dependency.path, dependency.index).await?;
+            is_chain_outdated = true;
+        }
+
+        Ok(())
+    }
+
+    /// Every dependency, starting from the direct one.
+    /// Fails when a dependency comes back, the request being prepared included
+    fn resolve_request_dependencies(&self, request_index: Option<(usize, usize)>, request: &Request, collection_defaults: &CollectionDefaults) -> Result<Vec<ResolvedDependency>, RequestDependencyError> {
+        let mut dependencies: Vec<ResolvedDependency> = vec![];
+        let mut chain = vec![self.get_request_dependency_path(request_index, request)];
+        let mut current_index: Option<(usize, usize)> = None;
+
+        loop {
+            let settings = match current_index {
+                None => collection_defaults.inherit_settings(&request.settings),
+                Some(current_index) => {
+                    let local_current = self.get_request_as_local_from_indexes(&current_index);
+                    let current = local_current.read();
+
+                    self.collections[current_index.0].defaults.inherit_settings(&current.settings)
+                }
+            };
+
+            let dependency_path = settings.depends_on.as_string().trim().to_string();
+
+            if dependency_path.is_empty() {
+                return Ok(dependencies);
+            }
+
+            let dependency_index = self.find_dependency(&dependency_path, request_index, request)?;
+
+            // A request is never its own dependency, e.g. the login request inheriting its collection's dependency
+            let is_itself = match current_index {
+                None => Some(dependency_index) == request_index,
+                Some(current_index) => current_index == dependency_index
+            };
+
+            if is_itself {
+                return Ok(dependencies);
+            }
+
+            chain.push(dependency_path.clone());
+
+            let is_already_met = Some(dependency_index) == request_index || dependencies.iter().any(|dependency| dependency.index == dependency_index);
+
+            if is_already_met {
+                return Err(DependencyCycle(chain.join(" -> ")));
+            }
+
+            let cache_lifetime = parse_dependency_cache_lifetime(settings.dependency_cache_lifetime.as_string())?;
+
+            dependencies.push(ResolvedDependency {
+                path: dependency_path,
+                index: dependency_index,
+                cache_lifetime,
+            });
+            current_index = Some(dependency_index);
+        }
+    }
+
+    async fn send_request_dependency(&self, dependency_path: &str, dependency_index: (usize, usize)) -> Result<(), RequestDependencyError> {
+        info!("Sending dependency \"{dependency_path}\"");
+
+        let dependency_failed = |error: String| DependencyFailed(dependency_path.to_string(), error);
+
+        let local_dependency = self.get_request_as_local_from_indexes(&dependency_index);
+        let dependency_collection_defaults = self.collections[dependency_index.0].defaults.clone();
+
+        // Prepared as a copy so that it is not locked across await points, then written back to keep e.g. a fetched OAuth2 token
+        let mut dependency = local_dependency.read().clone();
+
+        let mut prepared_request = self.prepare_request_without_dependencies(&mut dependency, &dependency_collection_defaults)
+            .await
+            .map_err(|error| dependency_failed(error.to_string()))?;
+
+        let response_history_recorder = self.new_response_history_recorder(dependency_index.0, &dependency, &mut prepared_request);
+        let protocol = dependency.protocol.clone();
+
+        *local_dependency.write() = dependency;
+
+        let local_env = self.get_selected_env_as_local();
+
+        let response = match protocol {
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => send_http_request(prepared_request, local_dependency.clone(), &dependency_collection_defaults, &local_env, self.received_response.clone()).await,
+            Protocol::WsRequest(_) => send_ws_request(prepared_request, local_dependency.clone(), &dependency_collection_defaults, &local_env, self.received_response.clone()).await,
+            Protocol::GrpcRequest(_) => send_grpc_request(prepared_request, local_dependency.clone(), &dependency_collection_defaults, &local_env).await,
+        }.map_err(|error| dependency_failed(error.to_string()))?;
+
+        if let Some(response_history_recorder) = &response_history_recorder {
+            response_history_recorder.record(&response).await;
+        }
+
+        local_dependency.write().response = response;
+
+        self.save_dependency_timestamp(dependency_index);
+
+        Ok(())
+    }
+
+    fn save_dependency_timestamp(&self, dependency_index: (usize, usize)) {
+        let timestamps_path = get_dependency_timestamps_path(&self.collections[dependency_index.0]);
+        let dependency_path = self.get_request_as_local_from_indexes(&dependency_index).read().get_path();
+
+        update_dependency_timestamps(&timestamps_path, |timestamps| {
+            timestamps.requests.insert(dependency_path, Local::now());
+        });
+    }
+
+    /// Indexes of a "collection/request" dependency, the request path including its folders.
+    /// The path of the request being prepared is read from its copy, the stored request may be locked by the caller
+    fn find_dependency(&self, dependency_path: &str, request_index: Option<(usize, usize)>, request: &Request) -> Result<(usize, usize), RequestDependencyError> {
+        let (collection_name, request_path) = dependency_path
+            .split_once('/')
+            .ok_or_else(|| DependencyNotFound(dependency_path.to_string()))?;
+
+        let collection_index = self.collections
+            .iter()
+            .position(|collection| collection.name == collection_name)
+            .ok_or_else(|| DependencyNotFound(dependency_path.to_string()))?;
+
+        let request_index = self.collections[collection_index].requests
+            .iter()
+            .enumerate()
+            .position(|(index, local_request)| match Some((collection_index, index)) == request_index {
+                true => request.get_path() == request_path,
+                false => local_request.read().get_path() == request_path
+            })
+            .ok_or_else(|| DependencyNotFound(dependency_path.to_string()))?;
+
+        Ok((collection_index, request_index))
+    }
+
+    /// "collection/request" path of the request being prepared, or only its own path when it belongs to no collection
+    fn get_request_dependency_path(&self, request_index: Option<(usize, usize)>, request: &Request) -> String {
+        match request_index {
+            Some((collection_index, _)) => format!("{}/{}", self.collections[collection_index].name, request.get_path()),
+            None => request.get_path()
+        }
+    }
+
+    /// A dependency is fresh when each of its enabled captures is in the selected environment,
+    /// and when it was last sent as a dependency more recently than the cache lifetime, if any.
+    /// A dependency that captures nothing and has no cache lifetime is sent every time
+    fn is_dependency_fresh(&self, dependency_index: (usize, usize), cache_lifetime: Option<Duration>) -> bool {
+        let captured_variables: Vec<String> = {
+            let local_dependency = self.get_request_as_local_from_indexes(&dependency_index);
+            let dependency = local_dependency.read();
+
+            dependency.captures
+                .iter()
+                .filter(|capture| capture.enabled)
+                .map(|capture| capture.data.0.clone())
+                .collect()
+        };
+
+        if captured_variables.is_empty() && cache_lifetime.is_none() {
+            return false;
+        }
+
+        let are_captured_values_present = match self.get_selected_env_as_local() {
+            None => captured_variables.is_empty(),
+            Some(local_env) => {
+                let env = local_env.read();
+                captured_variables.iter().all(|variable| env.values.contains_key(variable))
+            }
+        };
+
+        if !are_captured_values_present {
+            return false;
+        }
+
+        match cache_lifetime {
+            None => true,
+            Some(cache_lifetime) => {
+                let timestamps_path = get_dependency_timestamps_path(&self.collections[dependency_index.0]);
+                let dependency_path = self.get_request_as_local_from_indexes(&dependency_index).read().get_path();
+
+                match load_dependency_timestamps(&timestamps_path).requests.get(&dependency_path) {
+                    None => false,
+                    Some(timestamp) => match Local::now().signed_duration_since(*timestamp).to_std() {
+                        Ok(age) => age < cache_lifetime,
+                        Err(_) => true
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use parking_lot::RwLock;
+
+    use crate::app::app::App;
+    use crate::models::collection::{Collection, CollectionDefaults};
+    use crate::models::request::Request;
+    use crate::models::settings::{RequestSettings, Setting};
+
+    fn new_request(name: &str, depends_on: &str) -> Request {
+        Request {
+            name: name.to_string(),
+            settings: RequestSettings {
+                depends_on: Setting::String(depends_on.to_string()),
+                ..RequestSettings::default()
+            },
+            ..Request::default()
+        }
+    }
+
+    /// The requests are always prepared as copies, they must be recognized all the same
+    #[tokio::test]
+    async fn dependencies_of_cloned_requests() {
+        let mut app = App::new().unwrap();
+
+        app.collections.push(Collection {
+            name: String::from("auth"),
+            requests: vec![
+                Arc::new(RwLock::new(new_request("login", ""))),
+                Arc::new(RwLock::new(new_request("me", ""))),
+            ],
+            defaults: CollectionDefaults {
+                settings: Some(new_request("", "auth/login").settings),
+                ..CollectionDefaults::default()
+            },
+            ..Collection::default()
+        });
+
+        let collection_defaults = app.collections[0].defaults.clone();
+        let request_preparer = app.request_preparer();
+
+        // Sending the login request to itself would fail, its URL is empty
+        let login = app.collections[0].requests[0].read().clone();
+        assert!(request_preparer.send_request_dependencies(Some((0, 0)), &login, &collection_defaults).await.is_ok());
+
+        let me = app.collections[0].requests[1].read().clone();
+        let dependencies = request_preparer.resolve_request_dependencies(Some((0, 1)), &me, &collection_defaults).unwrap();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].path, "auth/login");
+        assert_eq!(dependencies[0].index, (0, 0));
+
+        *app.collections[0].requests[0].write() = new_request("login", "auth/me");
+        let error = request_preparer.resolve_request_dependencies(Some((0, 1)), &me, &collection_defaults).err().unwrap();
+        assert_eq!(error.to_string(), "DEPENDENCY CYCLE auth/me -> auth/login -> auth/me");
+    }
+}
@@ -13,9 +13,14 @@ use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{digest_to_authorization_header, Digest};
 use crate::models::auth::jwt::{jwt_do_jaat, JwtToken};
+use crate::models::auth::oauth2::OAuth2;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::collection::{join_base_url, CollectionDefaults};
 use crate::models::protocol::http::body::ContentType::{File, Form, Html, Javascript, Json, Multipart, NoBody, Raw, Xml};
 use crate::models::export::ExportFormat;
 use crate::models::export::ExportFormat::{Curl, NodeJsAxios, PhpGuzzle, RustReqwest, HTTP};
+use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::request::Request;
@@ -33,17 +38,34 @@ enum ExportError {
 }
 
 impl App<'_> {
-    pub fn export_request_to_string_with_format(&self, export_format: &ExportFormat, request: &Request) -> anyhow::Result<String> {
+    pub fn export_request_to_string_with_format(&self, export_format: &ExportFormat, request: &Request, collection_defaults: &CollectionDefaults) -> anyhow::Result<String> {
+        let request = collection_defaults.apply_to(request);
+        let base_url = self.replace_env_keys_by_value(&collection_defaults.base_url);
+
+        self.export_resolved_request(export_format, &request, &base_url)
+    }
+
+    /// Export a request that already carries its collection's headers, auth and settings
+    fn export_resolved_request(&self, export_format: &ExportFormat, request: &Request, base_url: &str) -> anyhow::Result<String> {
         let output = String::new();
 
         let params = self.key_value_vec_to_tuple_vec(&request.params);
         let url = self.replace_env_keys_by_value(&request.url);
+        let url = join_base_url(base_url, &url);
 
-        let url = match Url::parse_with_params(&url, &params) {
+        let mut url = match Url::parse_with_params(&url, &params) {
             Ok(url) => url,
             Err(_) => return Err(anyhow!(CouldNotParseUrl))
         };
 
+        // The other API key placements are exported along with the auth headers
+        if let Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::QueryParam }) = &request.auth {
+            let key = self.replace_env_keys_by_value(key);
+            let value = self.replace_env_keys_by_value(value);
+
+            url.query_pairs_mut().append_pair(&key, &value);
+        }
+
         let headers = self
             .key_value_vec_to_tuple_vec(&request.headers)
             .iter()
@@ -62,6 +84,17 @@ impl App<'_> {
                 RustReqwest => self.rust_request(output, request, url, headers),
                 PhpGuzzle | NodeJsAxios | HTTP | Curl => return Err(anyhow!(ExportFormatNotSupported(request.protocol.to_string())))
             }
+            // A GraphQL request is exported as the equivalent JSON POST request
+            Protocol::GraphqlRequest(ref graphql_request) => {
+                let mut http_equivalent = request.clone();
+                http_equivalent.protocol = Protocol::HttpRequest(HttpRequest {
+                    method: Method::POST,
+                    body: Json(graphql_request.to_json_body()),
+                });
+
+                self.export_resolved_request(export_format, &http_equivalent, base_url)
+            }
+            Protocol::GrpcRequest(_) => return Err(anyhow!(ExportFormatNotSupported(request.protocol.to_string())))
         };
 
         export
@@ -75,51 +108,50 @@ impl App<'_> {
         }
     }
 
-    fn raw_html(&self, mut output: String, request: &Request, url: Url, headers: Vec<(String, String)>) -> anyhow::Result<String> {
-        let http_request = request.get_http_request()?;
-
-        /* URL & Query params */
-
-        output += &format!(
-            "{} {}{} HTTP/1.1",
-            http_request.method.to_string(),
-            url.path(),
-            match url.query() {
-                None => String::new(),
-                Some(query) => format!("?{query}")
-            }
-        );
-
-        output += &format!("\nHost: {}", url.host_str().unwrap_or("localhost"));
-
-        /* Headers */
+    /// Header carrying the API key, none if it is sent as a query param
+    fn api_key_to_header(&self, api_key: &ApiKey) -> Option<(String, String)> {
+        let key = self.replace_env_keys_by_value(&api_key.key);
+        let value = self.replace_env_keys_by_value(&api_key.value);
 
-        for (header, value) in &headers {
-            output += &format!("\n{}: {}", header, value);
+        match api_key.placement {
+            ApiKeyPlacement::Header => Some((key, value)),
+            ApiKeyPlacement::QueryParam => None,
+            ApiKeyPlacement::Cookie => Some((String::from("Cookie"), format!("{key}={value}")))
         }
+    }
 
-        /* Auth */
+    /// Header sent for the given auth, none if it has no header equivalent
+    pub fn auth_to_header(&self, auth: &Auth, url: &Url) -> anyhow::Result<Option<(String, String)>> {
+        let authorization = |value: String| Some((String::from("Authorization"), value));
 
-        output += &match &request.auth {
-            Auth::NoAuth => String::new(),
+        let header = match auth {
+            // Already replaced by the collection's auth
+            Auth::NoAuth | Auth::InheritFromCollection => None,
             Auth::BasicAuth(BasicAuth { username, password }) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
 
-                format!("\nAuthorization: {}", encode_basic_auth(&username, &password))
+                authorization(encode_basic_auth(&username, &password))
             },
             Auth::BearerToken(BearerToken { token }) => {
                 let bearer_token = self.replace_env_keys_by_value(token);
 
-                format!("\nAuthorization: Bearer {}", bearer_token)
+                authorization(format!("Bearer {}", bearer_token))
             },
             Auth::JwtToken(JwtToken { algorithm, secret_type, secret, payload }) => {
                 let secret = self.replace_env_keys_by_value(secret);
                 let payload = self.replace_env_keys_by_value(payload);
 
                 let token = jwt_do_jaat(algorithm, secret_type, secret, payload)?;
-                format!("\nAuthorization: Bearer {}", token)
+                authorization(format!("Bearer {}", token))
             }
+            Auth::OAuth2(OAuth2 { access_token, .. }) => match access_token.is_empty() {
+                true => None,
+                false => authorization(format!("Bearer {}", access_token))
+            },
+            // The signature depends on the sending time, it cannot be exported
+            Auth::AwsSigV4(_) => None,
+            Auth::ApiKey(api_key) => self.api_key_to_header(api_key),
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, nc }) => {
                 let digest_header = digest_to_authorization_header(
                     username,
@@ -137,10 +169,42 @@ impl App<'_> {
                     *nc
                 );
 
-                format!("\nAuthorization: {}", digest_header)
+                authorization(digest_header)
             }
         };
 
+        Ok(header)
+    }
+
+    fn raw_html(&self, mut output: String, request: &Request, url: Url, headers: Vec<(String, String)>) -> anyhow::Result<String> {
+        let http_request = request.get_http_request()?;
+
+        /* URL & Query params */
+
+        output += &format!(
+            "{} {}{} HTTP/1.1",
+            http_request.method.to_string(),
+            url.path(),
+            match url.query() {
+                None => String::new(),
+                Some(query) => format!("?{query}")
+            }
+        );
+
+        output += &format!("\nHost: {}", url.host_str().unwrap_or("localhost"));
+
+        /* Headers */
+
+        for (header, value) in &headers {
+            output += &format!("\n{}: {}", header, value);
+        }
+
+        /* Auth */
+
+        if let Some((header, value)) = self.auth_to_header(&request.auth, &url)? {
+            output += &format!("\n{}: {}", header, value);
+        }
+
         /* Body */
 
         output += &match &http_request.body {
@@ -242,7 +306,8 @@ impl App<'_> {
         /* Auth */
 
         output += &match &request.auth {
-            Auth::NoAuth => String::new(),
+            // Already replaced by the collection's auth
+            Auth::NoAuth | Auth::InheritFromCollection => String::new(),
             Auth::BasicAuth(BasicAuth { username, password }) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
@@ -261,6 +326,30 @@ impl App<'_> {
                 let token = jwt_do_jaat(algorithm, secret_type, secret, payload)?;
                 format!("\n--header 'Authorization: Bearer {}' \\", token)
             },
+            Auth::OAuth2(OAuth2 { access_token, .. }) => match access_token.is_empty() {
+                true => String::new(),
+                false => format!("\n--header 'Authorization: Bearer {}' \\", access_token)
+            },
+            // cURL signs the request by itself
+            Auth::AwsSigV4(AwsSigV4 { access_key_id, secret_access_key, session_token, region, service }) => {
+                let access_key_id = self.replace_env_keys_by_value(access_key_id);
+                let secret_access_key = self.replace_env_keys_by_value(secret_access_key);
+                let session_token = self.replace_env_keys_by_value(session_token);
+                let region = self.replace_env_keys_by_value(region);
+                let service = self.replace_env_keys_by_value(service);
+
+                let mut aws_sigv4 = format!("\n--aws-sigv4 'aws:amz:{}:{}' \\\n--user '{}:{}' \\", region, service, access_key_id, secret_access_key);
+
+                if !session_token.is_empty() {
+                    aws_sigv4 += &format!("\n--header 'X-Amz-Security-Token: {}' \\", session_token);
+                }
+
+                aws_sigv4
+            },
+            Auth::ApiKey(api_key) => match self.api_key_to_header(api_key) {
+                None => String::new(),
+                Some((header, value)) => format!("\n--header '{}: {}' \\", header, value)
+            },
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, nc }) => {
                 let digest_header = digest_to_authorization_header(
                     username,
@@ -370,7 +459,8 @@ impl App<'_> {
         /* Auth */
 
         headers_str += &match &request.auth {
-            Auth::NoAuth => String::new(),
+            // Already replaced by the collection's auth
+            Auth::NoAuth | Auth::InheritFromCollection => String::new(),
             Auth::BasicAuth(BasicAuth { username, password }) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
@@ -389,6 +479,16 @@ impl App<'_> {
                 let token = jwt_do_jaat(algorithm, secret_type, secret, payload)?;
                 format!("\n    'Authorization' => 'Bearer {}',", escape(token, escape_char))
             },
+            Auth::OAuth2(OAuth2 { access_token, .. }) => match access_token.is_empty() {
+                true => String::new(),
+                false => format!("\n    'Authorization' => 'Bearer {}',", escape(access_token, escape_char))
+            },
+            // The signature depends on the sending time, it cannot be exported
+            Auth::AwsSigV4(_) => String::new(),
+            Auth::ApiKey(api_key) => match self.api_key_to_header(api_key) {
+                None => String::new(),
+                Some((header, value)) => format!("\n    '{}' => '{}',", escape(header, escape_char), escape(value, escape_char))
+            },
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, nc }) => {
                 let digest_header = digest_to_authorization_header(
                     username,
@@ -549,7 +649,8 @@ impl App<'_> {
 
         /* Auth */
         match &request.auth {
-            Auth::NoAuth => {},
+            // Already replaced by the collection's auth
+            Auth::NoAuth | Auth::InheritFromCollection => {},
             Auth::BasicAuth(BasicAuth { username, password }) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
@@ -566,6 +667,14 @@ impl App<'_> {
                 let token = jwt_do_jaat(algorithm, secret_type, secret, payload)?;
                 output += &format!("    'Authorization': 'Bearer {}',\n", escape(token, escape_char));
             },
+            Auth::OAuth2(OAuth2 { access_token, .. }) => if !access_token.is_empty() {
+                output += &format!("    'Authorization': 'Bearer {}',\n", escape(access_token, escape_char));
+            },
+            // The signature depends on the sending time, it cannot be exported
+            Auth::AwsSigV4(_) => {},
+            Auth::ApiKey(api_key) => if let Some((header, value)) = self.api_key_to_header(api_key) {
+                output += &format!("    '{}': '{}',\n", escape(header, escape_char), escape(value, escape_char));
+            },
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, nc }) => {
                 let digest_header = digest_to_authorization_header(
                     username,
@@ -694,7 +803,8 @@ impl App<'_> {
 
         let method = match &request.protocol {
             Protocol::HttpRequest(http_request) => http_request.method.to_string(),
-            Protocol::WsRequest(_) => Method::GET.to_string()
+            Protocol::WsRequest(_) => Method::GET.to_string(),
+            Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => Method::POST.to_string()
         };
 
         /* Headers */
@@ -708,7 +818,8 @@ impl App<'_> {
 
         /* Auth */
         match &request.auth {
-            Auth::NoAuth => {},
+            // Already replaced by the collection's auth
+            Auth::NoAuth | Auth::InheritFromCollection => {},
             Auth::BasicAuth(BasicAuth { username, password }) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
@@ -727,6 +838,16 @@ impl App<'_> {
                 let token = jwt_do_jaat(algorithm, secret_type, secret, payload)?;
                 headers_str += &format!("        .header(\"Authorization\", \"Bearer {}\")\n", escape(token, escape_char));
             },
+            Auth::OAuth2(OAuth2 { access_token, .. }) => if !access_token.is_empty() {
+                has_headers = true;
+                headers_str += &format!("        .header(\"Authorization\", \"Bearer {}\")\n", escape(access_token, escape_char));
+            },
+            // The signature depends on the sending time, it cannot be exported
+            Auth::AwsSigV4(_) => {},
+            Auth::ApiKey(api_key) => if let Some((header, value)) = self.api_key_to_header(api_key) {
+                has_headers = true;
+                headers_str += &format!("        .header(\"{}\", \"{}\")\n", escape(header, escape_char), escape(value, escape_char));
+            },
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, nc }) => {
                 let digest_header = digest_to_authorization_header(
                     username,
@@ -772,6 +893,7 @@ impl App<'_> {
                 },
                 _ => {}
             }
+            Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {}
             Protocol::WsRequest(_) => {
                 output += "use reqwest_websocket::{Error, Message, RequestBuilderExt};\nuse futures_util::{SinkExt, StreamExt, TryStreamExt};\n";
             }
@@ -837,7 +959,7 @@ impl App<'_> {
                     body_str += &format!("        .body(r#\"{}\"#)\n", body);
                 }
             }
-            Protocol::WsRequest(_) => {}
+            Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {}
         }
 
         /* Request and response */
@@ -857,7 +979,7 @@ impl App<'_> {
         output += "        .await?;\n\n";
 
         match request.protocol {
-            Protocol::HttpRequest(_) => {
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {
                 output += "    let status = response.status();\n";
                 output += "    let body = response.text().await?;\n\n";
 
@@ -0,0 +1,56 @@
+use tracing::info;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    pub fn modify_request_graphql_query(&mut self, collection_index: usize, request_index: usize, query: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let graphql_request = selected_request.get_graphql_request_mut()?;
+
+            info!("GraphQL query set");
+
+            graphql_request.query = query;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_graphql_variables(&mut self, collection_index: usize, request_index: usize, variables: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let graphql_request = selected_request.get_graphql_request_mut()?;
+
+            info!("GraphQL variables set");
+
+            graphql_request.variables = variables;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_graphql_operation_name(&mut self, collection_index: usize, request_index: usize, operation_name: Option<String>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let graphql_request = selected_request.get_graphql_request_mut()?;
+
+            info!("GraphQL operation name set to \"{}\"", operation_name.as_deref().unwrap_or(""));
+
+            graphql_request.operation_name = operation_name;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use serde_json::Value;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::http::send::send_http_request;
+use crate::app::business_logic::request::send::{PrepareRequestError, RequestResponseError};
+use crate::app::files::graphql_schema::save_graphql_schema_to_file;
+use crate::models::collection::CollectionDefaults;
+use crate::models::protocol::graphql::schema::{GraphqlSchema, INTROSPECTION_QUERY};
+use crate::models::request::Request;
+use crate::models::response::ResponseContent;
+use crate::models::scripts::RequestScripts;
+
+#[derive(Error, Debug)]
+pub enum GraphqlIntrospectionError {
+    #[error("{0}")]
+    PrepareRequest(#[from] PrepareRequestError),
+    #[error("{0}")]
+    RequestResponse(#[from] RequestResponseError),
+    #[error("The server did not return an introspection result (status: {0})")]
+    InvalidIntrospectionResult(String),
+}
+
+/// Introspection request ready to be sent, it does not borrow the app so that it can be sent from another task
+pub struct GraphqlIntrospection {
+    prepared_request: reqwest_middleware::RequestBuilder,
+    introspection_request: Request,
+    collection_defaults: CollectionDefaults,
+    collection_path: PathBuf,
+    local_selected_request: Arc<RwLock<Request>>,
+    received_response: Arc<Mutex<bool>>,
+}
+
+impl App<'_> {
+    /// Send the introspection query through the request's URL, auth and headers, then cache the resulting schema
+    pub async fn introspect_graphql_schema(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<usize> {
+        let introspection = self.prepare_graphql_introspection(collection_index, request_index).await?;
+
+        introspection.send().await
+    }
+
+    pub async fn prepare_graphql_introspection(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<GraphqlIntrospection> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        let mut introspection_request = local_selected_request.read().clone();
+
+        {
+            let graphql_request = introspection_request.get_graphql_request_mut()?;
+            graphql_request.query = String::from(INTROSPECTION_QUERY);
+            graphql_request.variables = String::new();
+            graphql_request.operation_name = Some(String::from("IntrospectionQuery"));
+        }
+
+        // The request's own scripts are meant for its query, not for the introspection one
+        introspection_request.scripts = RequestScripts::default();
+
+        // Neither are the collection's ones
+        let mut collection_defaults = self.collections[collection_index].defaults.clone();
+        collection_defaults.scripts = RequestScripts::default();
+
+        info!("Introspecting GraphQL schema");
+
+        let prepared_request = self.request_preparer().prepare_request(Some((collection_index, request_index)), &mut introspection_request, &collection_defaults).await.map_err(GraphqlIntrospectionError::from)?;
+
+        Ok(GraphqlIntrospection {
+            prepared_request,
+            introspection_request,
+            collection_defaults,
+            collection_path: self.collections[collection_index].path.clone(),
+            local_selected_request,
+            received_response: self.received_response.clone(),
+        })
+    }
+}
+
+impl GraphqlIntrospection {
+    /// Returns the number of type and field names found in the schema
+    pub async fn send(self) -> anyhow::Result<usize> {
+        let response = send_http_request(self.prepared_request, Arc::new(RwLock::new(self.introspection_request)), &self.collection_defaults, &None, self.received_response.clone()).await.map_err(GraphqlIntrospectionError::from)?;
+
+        let status_code = response.status_code.unwrap_or_default();
+
+        let introspection = match &response.content {
+            Some(ResponseContent::Body(body)) => serde_json::from_str::<Value>(body).ok(),
+            _ => None
+        };
+
+        let (introspection, schema) = match introspection {
+            Some(introspection) => match GraphqlSchema::from_introspection(&introspection) {
+                Some(schema) => (introspection, schema),
+                None => return Err(GraphqlIntrospectionError::InvalidIntrospectionResult(status_code).into())
+            },
+            None => return Err(GraphqlIntrospectionError::InvalidIntrospectionResult(status_code).into())
+        };
+
+        let names_count = schema.type_names.len() + schema.field_names.len();
+
+        {
+            let mut selected_request = self.local_selected_request.write();
+
+            save_graphql_schema_to_file(&self.collection_path, &selected_request.name, &introspection)?;
+
+            selected_request.get_graphql_request_mut()?.schema = Some(schema);
+        }
+
+        info!("GraphQL schema cached ({names_count} names)");
+
+        *self.received_response.lock() = true;
+
+        Ok(names_count)
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use futures_util::stream;
+use prost_reflect::prost::Message;
+use prost_reflect::prost_types::FileDescriptorProto;
+use prost_reflect::{DescriptorPool, MethodDescriptor};
+use tonic::transport::Channel;
+use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
+use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
+use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use tonic_reflection::pb::v1::ServerReflectionRequest;
+use tracing::{info, trace};
+
+use crate::app::business_logic::request::grpc::send::GrpcError;
+use crate::models::protocol::grpc::grpc::{GrpcMethod, GrpcMethodKind};
+
+/// Parse the .proto files and their imports, each file's directory is used as an include path
+pub fn load_proto_files(proto_files: &[String]) -> Result<DescriptorPool, GrpcError> {
+    let inputs: Vec<PathBuf> = proto_files
+        .iter()
+        .map(|proto_file| PathBuf::from(proto_file.trim()))
+        .collect();
+
+    let includes: Vec<PathBuf> = inputs
+        .iter()
+        .map(|input| match input.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from(".")
+        })
+        .collect();
+
+    info!("Parsing {} proto file(s)", inputs.len());
+
+    let parsed = protobuf_parse::Parser::new()
+        .pure()
+        .includes(includes)
+        .inputs(inputs)
+        .parse_and_typecheck()
+        .map_err(|error| GrpcError::ProtoFiles(format!("{error:#}")))?;
+
+    let mut file_descriptor_protos = vec![];
+
+    for file_descriptor in parsed.file_descriptors {
+        let bytes = protobuf::Message::write_to_bytes(&file_descriptor)
+            .map_err(|error| GrpcError::ProtoFiles(error.to_string()))?;
+
+        let file_descriptor_proto = FileDescriptorProto::decode(bytes.as_slice())
+            .map_err(|error| GrpcError::ProtoFiles(error.to_string()))?;
+
+        file_descriptor_protos.push(file_descriptor_proto);
+    }
+
+    let mut pool = DescriptorPool::new();
+    pool.add_file_descriptor_protos(file_descriptor_protos)?;
+
+    Ok(pool)
+}
+
+/// Ask the server for its services through the gRPC server reflection protocol (v1)
+pub async fn load_from_server_reflection(channel: Channel) -> Result<DescriptorPool, GrpcError> {
+    let mut client = ServerReflectionClient::new(channel);
+
+    info!("Fetching descriptors through server reflection");
+
+    let services = match reflection_request(&mut client, MessageRequest::ListServices(String::new())).await? {
+        MessageResponse::ListServicesResponse(list_services_response) => list_services_response.service,
+        _ => return Err(GrpcError::Reflection(String::from("unexpected response to the services listing")))
+    };
+
+    let mut file_descriptor_protos: HashMap<String, FileDescriptorProto> = HashMap::new();
+
+    for service in services {
+        // The reflection service itself is of no use to the user
+        if service.name.starts_with("grpc.reflection.") {
+            continue;
+        }
+
+        let response = reflection_request(&mut client, MessageRequest::FileContainingSymbol(service.name)).await?;
+        add_file_descriptor_response(response, &mut file_descriptor_protos)?;
+    }
+
+    // Servers are not required to send the whole dependency tree at once
+    loop {
+        let missing_dependencies: Vec<String> = file_descriptor_protos
+            .values()
+            .flat_map(|file_descriptor_proto| file_descriptor_proto.dependency.clone())
+            .filter(|dependency| !file_descriptor_protos.contains_key(dependency))
+            .collect();
+
+        if missing_dependencies.is_empty() {
+            break;
+        }
+
+        for dependency in missing_dependencies {
+            trace!("Fetching dependency \"{dependency}\"");
+
+            let response = reflection_request(&mut client, MessageRequest::FileByFilename(dependency.clone())).await?;
+            let fetched_count = add_file_descriptor_response(response, &mut file_descriptor_protos)?;
+
+            if fetched_count == 0 || !file_descriptor_protos.contains_key(&dependency) {
+                return Err(GrpcError::Reflection(format!("server did not send \"{dependency}\"")));
+            }
+        }
+    }
+
+    let mut pool = DescriptorPool::new();
+    pool.add_file_descriptor_protos(file_descriptor_protos.into_values())?;
+
+    Ok(pool)
+}
+
+async fn reflection_request(client: &mut ServerReflectionClient<Channel>, message_request: MessageRequest) -> Result<MessageResponse, GrpcError> {
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(message_request),
+    };
+
+    let mut responses = client
+        .server_reflection_info(stream::iter(vec![request]))
+        .await
+        .map_err(|status| GrpcError::Reflection(status.message().to_string()))?
+        .into_inner();
+
+    let response = responses
+        .message()
+        .await
+        .map_err(|status| GrpcError::Reflection(status.message().to_string()))?;
+
+    match response.and_then(|response| response.message_response) {
+        Some(MessageResponse::ErrorResponse(error_response)) => Err(GrpcError::Reflection(error_response.error_message)),
+        Some(message_response) => Ok(message_response),
+        None => Err(GrpcError::Reflection(String::from("empty response")))
+    }
+}
+
+/// Decode the received file descriptors, returns how many were received
+fn add_file_descriptor_response(response: MessageResponse, file_descriptor_protos: &mut HashMap<String, FileDescriptorProto>) -> Result<usize, GrpcError> {
+    let MessageResponse::FileDescriptorResponse(file_descriptor_response) = response else {
+        return Err(GrpcError::Reflection(String::from("expected file descriptors")));
+    };
+
+    let fetched_count = file_descriptor_response.file_descriptor_proto.len();
+
+    for bytes in file_descriptor_response.file_descriptor_proto {
+        let file_descriptor_proto = FileDescriptorProto::decode(bytes.as_slice())
+            .map_err(|error| GrpcError::Reflection(error.to_string()))?;
+
+        file_descriptor_protos.insert(file_descriptor_proto.name().to_string(), file_descriptor_proto);
+    }
+
+    Ok(fetched_count)
+}
+
+pub fn find_method(pool: &DescriptorPool, service: &str, method: &str) -> Result<MethodDescriptor, GrpcError> {
+    pool.get_service_by_name(service.trim())
+        .and_then(|service| service.methods().find(|service_method| service_method.name() == method.trim()))
+        .ok_or_else(|| GrpcError::UnknownMethod(format!("{}/{}", service.trim(), method.trim())))
+}
+
+pub fn list_methods(pool: &DescriptorPool) -> Vec<GrpcMethod> {
+    pool.services()
+        .flat_map(|service| service.methods().collect::<Vec<MethodDescriptor>>())
+        .map(|method| GrpcMethod {
+            service: method.parent_service().full_name().to_string(),
+            name: method.name().to_string(),
+            kind: GrpcMethodKind::from_streaming(method.is_client_streaming(), method.is_server_streaming()),
+        })
+        .collect()
+}
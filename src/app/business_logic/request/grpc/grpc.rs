@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::grpc::descriptors::{list_methods, load_from_server_reflection, load_proto_files};
+use crate::app::business_logic::request::grpc::send::{connect, GrpcError};
+use crate::app::business_logic::request::preparer::RequestPreparer;
+use crate::app::business_logic::request::send::PrepareRequestError;
+use crate::app::business_logic::request::tls::GrpcTlsConfig;
+use crate::models::scripts::RequestScripts;
+
+/// Methods count or error of the listing for a request (collection index, request index)
+pub type GrpcMethodsListing = (usize, usize, Result<usize, String>);
+
+#[derive(Error, Debug)]
+pub enum GrpcMethodsError {
+    #[error("{0}")]
+    PrepareRequest(#[from] PrepareRequestError),
+    #[error("{0}")]
+    Grpc(#[from] GrpcError),
+    #[error("GRPC SERVER REFLECTION TIMED OUT")]
+    Timeout,
+}
+
+impl App<'_> {
+    pub fn modify_request_grpc_proto_files(&mut self, collection_index: usize, request_index: usize, proto_files: Vec<String>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let grpc_request = selected_request.get_grpc_request_mut()?;
+
+            info!("gRPC proto files set to \"{}\"", proto_files.join(", "));
+
+            grpc_request.proto_files = proto_files;
+            grpc_request.methods = vec![];
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_grpc_service(&mut self, collection_index: usize, request_index: usize, service: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let grpc_request = selected_request.get_grpc_request_mut()?;
+
+            info!("gRPC service set to \"{}\"", service);
+
+            grpc_request.service = service;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_grpc_method(&mut self, collection_index: usize, request_index: usize, method: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let grpc_request = selected_request.get_grpc_request_mut()?;
+
+            info!("gRPC method set to \"{}\"", method);
+
+            grpc_request.method = method;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_grpc_message(&mut self, collection_index: usize, request_index: usize, message: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let grpc_request = selected_request.get_grpc_request_mut()?;
+
+            info!("gRPC message set");
+
+            grpc_request.message = message;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Load the services from the .proto files or the server reflection, then cache their methods.
+    /// The first method is selected if none was yet.
+    pub async fn list_grpc_methods(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<usize> {
+        let methods_count = self.request_preparer().load_grpc_methods(collection_index, request_index).await?;
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(methods_count)
+    }
+}
+
+impl RequestPreparer {
+    /// Cache the request's methods without saving them, so that it can be done in another task.
+    /// The server reflection is given the request timeout
+    pub async fn load_grpc_methods(&self, collection_index: usize, request_index: usize) -> anyhow::Result<usize> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        let mut listing_request = local_selected_request.read().clone();
+        let proto_files = listing_request.get_grpc_request()?.proto_files.clone();
+
+        // The request's own scripts are meant for its call, not for the listing
+        listing_request.scripts = RequestScripts::default();
+
+        // Neither are the collection's ones
+        let mut collection_defaults = self.collections[collection_index].defaults.clone();
+        collection_defaults.scripts = RequestScripts::default();
+
+        info!("Listing gRPC methods");
+
+        let pool = match proto_files.is_empty() {
+            true => {
+                let mut prepared_request = self.prepare_request(Some((collection_index, request_index)), &mut listing_request, &collection_defaults).await.map_err(GrpcMethodsError::from)?;
+                let tls_config = prepared_request.extensions().get::<GrpcTlsConfig>().cloned();
+                let http_request = prepared_request.build().map_err(|_| GrpcMethodsError::from(GrpcError::InvalidRequest))?;
+
+                let settings = collection_defaults.inherit_settings(&listing_request.settings);
+                let timeout = Duration::from_millis(settings.timeout.as_u32() as u64);
+
+                let server_reflection = async {
+                    let channel = connect(http_request.url(), tls_config).await?;
+                    load_from_server_reflection(channel).await
+                };
+
+                match tokio::time::timeout(timeout, server_reflection).await {
+                    Ok(pool) => pool.map_err(GrpcMethodsError::from)?,
+                    Err(_) => return Err(GrpcMethodsError::Timeout.into())
+                }
+            },
+            false => load_proto_files(&proto_files).map_err(GrpcMethodsError::from)?
+        };
+
+        let methods = list_methods(&pool);
+        let methods_count = methods.len();
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let grpc_request = selected_request.get_grpc_request_mut()?;
+
+            if grpc_request.service.is_empty() && grpc_request.method.is_empty() && let Some(first_method) = methods.first() {
+                grpc_request.service = first_method.service.clone();
+                grpc_request.method = first_method.name.clone();
+            }
+
+            grpc_request.methods = methods;
+        }
+
+        info!("{methods_count} gRPC method(s) found");
+
+        Ok(methods_count)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod grpc;
+pub mod codec;
+pub mod descriptors;
+pub mod send;
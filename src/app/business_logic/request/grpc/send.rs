@@ -0,0 +1,325 @@
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures_util::stream;
+use parking_lot::RwLock;
+use prost_reflect::{DescriptorError, DescriptorPool, DynamicMessage, MethodDescriptor};
+use serde_json::Value;
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+use hyper_rustls::HttpsConnectorBuilder;
+use tonic::codegen::Service;
+use tonic::codegen::http::Uri;
+use tonic::codegen::http::uri::{PathAndQuery, Scheme};
+use tonic::metadata::{AsciiMetadataValue, KeyAndValueRef, MetadataKey, MetadataMap};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::{Code, Status};
+use tracing::{error, info, trace};
+
+use crate::app::app::App;
+use crate::app::business_logic::request::grpc::codec::DynamicCodec;
+use crate::app::business_logic::request::grpc::descriptors::{find_method, load_from_server_reflection, load_proto_files};
+use crate::app::business_logic::request::send::RequestResponseError;
+use crate::app::business_logic::request::tls::GrpcTlsConfig;
+use crate::models::collection::CollectionDefaults;
+use crate::models::environment::Environment;
+use crate::models::protocol::grpc::grpc::{GrpcMethodKind, GrpcRequest};
+use crate::models::request::Request;
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum GrpcError {
+    #[error("COULD NOT BUILD GRPC REQUEST")]
+    InvalidRequest,
+    #[error("COULD NOT CONNECT: {0}")]
+    Connection(String),
+    #[error("COULD NOT PARSE PROTO FILES: {0}")]
+    ProtoFiles(String),
+    #[error("SERVER REFLECTION ERROR: {0}")]
+    Reflection(String),
+    #[error("INVALID DESCRIPTORS: {0}")]
+    Descriptors(#[from] DescriptorError),
+    #[error("UNKNOWN GRPC METHOD \"{0}\"")]
+    UnknownMethod(String),
+    #[error("INVALID GRPC MESSAGE: {0}")]
+    InvalidMessage(String),
+}
+
+/// Headers that are part of the HTTP/2 transport and must not be sent as gRPC metadata
+const TRANSPORT_HEADERS: [&str; 6] = ["content-type", "content-length", "host", "te", "connection", "user-agent"];
+
+pub async fn send_grpc_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, collection_defaults: &CollectionDefaults, env: &Option<Arc<RwLock<Environment>>>) -> Result<RequestResponse, RequestResponseError> {
+    info!("Sending request");
+
+    let (grpc_request, cancellation_token, timeout, pretty_print) = {
+        let mut request = local_request.write();
+        request.is_pending = true;
+
+        let settings = collection_defaults.inherit_settings(&request.settings);
+
+        (
+            request.get_grpc_request().unwrap().clone(),
+            request.cancellation_token.clone(),
+            settings.timeout.as_u32() as u64,
+            settings.pretty_print_response_content.as_bool()
+        )
+    };
+
+    let timeout = tokio::time::sleep(Duration::from_millis(timeout));
+
+    let request_start = Instant::now();
+    let elapsed_time: Duration;
+
+    let mut response = tokio::select! {
+        _ = cancellation_token.cancelled() => {
+            elapsed_time = request_start.elapsed();
+
+            RequestResponse {
+                duration: None,
+                status_code: Some(String::from("CANCELED")),
+                content: None,
+                cookies: None,
+                headers: vec![]
+            }
+        },
+        _ = timeout => {
+            elapsed_time = request_start.elapsed();
+
+            RequestResponse {
+                duration: None,
+                status_code: Some(String::from("TIMEOUT")),
+                content: None,
+                cookies: None,
+                headers: vec![]
+            }
+        },
+        response = call_grpc_method(prepared_request, &grpc_request, pretty_print) => {
+            elapsed_time = request_start.elapsed();
+
+            match response {
+                Ok(response) => response,
+                Err(grpc_error) => {
+                    error!("gRPC error: {}", grpc_error);
+
+                    RequestResponse {
+                        duration: None,
+                        status_code: None,
+                        content: Some(ResponseContent::Body(grpc_error.to_string())),
+                        cookies: None,
+                        headers: vec![]
+                    }
+                }
+            }
+        }
+    };
+
+    response.duration = Some(format!("{:?}", elapsed_time));
+
+    trace!("Request sent");
+
+    /* POST-REQUEST SCRIPT */
+
+    let (modified_response, post_request_output, test_results) = {
+        let request = local_request.read();
+        App::handle_post_request_script(&request, &collection_defaults.scripts, response, env)?
+    };
+
+    {
+        let mut request = local_request.write();
+
+        request.console_output.post_request_output = post_request_output;
+        request.test_results = test_results;
+        request.is_pending = false;
+        request.cancellation_token = CancellationToken::new();
+    }
+
+    Ok(modified_response)
+}
+
+async fn call_grpc_method(mut prepared_request: reqwest_middleware::RequestBuilder, grpc_request: &GrpcRequest, pretty_print: bool) -> Result<RequestResponse, GrpcError> {
+    let tls_config = prepared_request.extensions().get::<GrpcTlsConfig>().cloned();
+    let http_request = prepared_request.build().map_err(|_| GrpcError::InvalidRequest)?;
+
+    let channel = connect(http_request.url(), tls_config).await?;
+    let pool = load_descriptors(channel.clone(), &grpc_request.proto_files).await?;
+    let method = find_method(&pool, &grpc_request.service, &grpc_request.method)?;
+
+    let message = http_request.body()
+        .and_then(|body| body.as_bytes())
+        .map(|bytes| String::from_utf8_lossy(bytes).to_string())
+        .unwrap_or_default();
+
+    let messages = parse_messages(&method, &message)?;
+
+    let mut request = tonic::Request::new(stream::iter(messages));
+    headers_to_metadata(http_request.headers(), request.metadata_mut());
+
+    let path = PathAndQuery::try_from(grpc_request.to_path()).map_err(|_| GrpcError::UnknownMethod(grpc_request.to_path()))?;
+
+    let mut client = tonic::client::Grpc::new(channel);
+    client.ready().await.map_err(|error| GrpcError::Connection(error.to_string()))?;
+
+    let method_kind = GrpcMethodKind::from_streaming(method.is_client_streaming(), method.is_server_streaming());
+    info!("Calling \"{}\" ({} call)", method.full_name(), method_kind);
+
+    // Every kind of call is a stream of messages on the wire, unary ones simply carry a single message
+    let response = match client.streaming(request, path, DynamicCodec::new(method.output())).await {
+        Ok(response) => response,
+        Err(status) => return Ok(status_to_response(&status, metadata_to_headers(status.metadata()), vec![]))
+    };
+
+    let mut headers = metadata_to_headers(response.metadata());
+    let mut response_stream = response.into_inner();
+    let mut received_messages = vec![];
+
+    loop {
+        match response_stream.message().await {
+            Ok(Some(message)) => received_messages.push(message_to_json(&message, pretty_print)),
+            Ok(None) => break,
+            Err(status) => return Ok(status_to_response(&status, headers, received_messages))
+        }
+    }
+
+    if let Ok(Some(trailers)) = response_stream.trailers().await {
+        headers.extend(metadata_to_headers(&trailers));
+    }
+
+    info!("Response received");
+
+    Ok(status_to_response(&Status::new(Code::Ok, ""), headers, received_messages))
+}
+
+/// Open an HTTP/2 channel to the URL's origin, using TLS for https URLs
+pub async fn connect(url: &reqwest::Url, tls_config: Option<GrpcTlsConfig>) -> Result<Channel, GrpcError> {
+    let origin = url.origin().ascii_serialization();
+
+    let endpoint = Endpoint::from_shared(origin).map_err(|error| GrpcError::Connection(error.to_string()))?;
+
+    if url.scheme() != "https" {
+        return endpoint.connect().await.map_err(|error| GrpcError::Connection(format!("{error:?}")));
+    }
+
+    let channel = match tls_config {
+        // Built from the request settings, see prepare_request
+        Some(GrpcTlsConfig(tls_config)) => {
+            let connector = HttpsConnectorBuilder::new()
+                .with_tls_config((*tls_config).clone())
+                .https_or_http()
+                .enable_http2()
+                .build();
+
+            // Tonic refuses https endpoints when it does not handle the TLS itself
+            let origin = format!("http://{}", url.origin().ascii_serialization().trim_start_matches("https://"));
+            let endpoint = Endpoint::from_shared(origin).map_err(|error| GrpcError::Connection(error.to_string()))?;
+
+            endpoint.connect_with_connector(HttpsOnlyConnector(connector)).await
+        },
+        None => endpoint
+            .tls_config(ClientTlsConfig::new().with_webpki_roots())
+            .map_err(|error| GrpcError::Connection(error.to_string()))?
+            .connect()
+            .await
+    };
+
+    channel.map_err(|error| GrpcError::Connection(format!("{error:?}")))
+}
+
+/// Connects with TLS whatever the scheme of the URI given by tonic
+struct HttpsOnlyConnector<C>(C);
+
+impl<C: Service<Uri>> Service<Uri> for HttpsOnlyConnector<C> {
+    type Response = C::Response;
+    type Error = C::Error;
+    type Future = C::Future;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(context)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let mut uri_parts = uri.into_parts();
+        uri_parts.scheme = Some(Scheme::HTTPS);
+
+        self.0.call(Uri::from_parts(uri_parts).unwrap_or_default())
+    }
+}
+
+/// Load the given .proto files, or use the server reflection if there are none
+async fn load_descriptors(channel: Channel, proto_files: &[String]) -> Result<DescriptorPool, GrpcError> {
+    match proto_files.is_empty() {
+        true => load_from_server_reflection(channel).await,
+        false => load_proto_files(proto_files)
+    }
+}
+
+/// A JSON array sends one message per element to client streaming methods
+fn parse_messages(method: &MethodDescriptor, message: &str) -> Result<Vec<DynamicMessage>, GrpcError> {
+    let message = match message.trim().is_empty() {
+        true => Value::Object(serde_json::Map::new()),
+        false => serde_json::from_str::<Value>(message).map_err(|error| GrpcError::InvalidMessage(error.to_string()))?
+    };
+
+    let messages = match message {
+        Value::Array(messages) if method.is_client_streaming() => messages,
+        message => vec![message]
+    };
+
+    messages
+        .into_iter()
+        .map(|message| DynamicMessage::deserialize(method.input(), message).map_err(|error| GrpcError::InvalidMessage(error.to_string())))
+        .collect()
+}
+
+fn headers_to_metadata(headers: &reqwest::header::HeaderMap, metadata: &mut MetadataMap) {
+    for (header_name, header_value) in headers {
+        if TRANSPORT_HEADERS.contains(&header_name.as_str()) {
+            continue;
+        }
+
+        let (Ok(key), Ok(value)) = (MetadataKey::from_bytes(header_name.as_str().as_bytes()), AsciiMetadataValue::try_from(header_value.as_bytes())) else {
+            continue;
+        };
+
+        metadata.append(key, value);
+    }
+}
+
+fn metadata_to_headers(metadata: &MetadataMap) -> Vec<(String, String)> {
+    metadata
+        .iter()
+        .map(|key_and_value| match key_and_value {
+            KeyAndValueRef::Ascii(key, value) => (key.to_string(), value.to_str().unwrap_or("").to_string()),
+            KeyAndValueRef::Binary(key, value) => (key.to_string(), format!("{:?}", value))
+        })
+        .collect()
+}
+
+fn message_to_json(message: &DynamicMessage, pretty_print: bool) -> String {
+    let json = match pretty_print {
+        true => serde_json::to_string_pretty(message),
+        false => serde_json::to_string(message)
+    };
+
+    json.unwrap_or_else(|error| error.to_string())
+}
+
+fn status_to_response(status: &Status, headers: Vec<(String, String)>, messages: Vec<String>) -> RequestResponse {
+    let mut body = messages.join("\n\n");
+
+    if status.code() != Code::Ok && !status.message().is_empty() {
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+
+        body.push_str(status.message());
+    }
+
+    RequestResponse {
+        duration: None,
+        status_code: Some(format!("{} {:?}", status.code() as i32, status.code())),
+        content: Some(ResponseContent::Body(body)),
+        cookies: None,
+        headers
+    }
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 
 use rayon::prelude::*;
 use reqwest::header::{CONTENT_TYPE};
@@ -10,24 +10,30 @@ use crate::app::app::App;
 use crate::app::business_logic::request::send::RequestResponseError;
 use crate::app::business_logic::request::send::RequestResponseError::CouldNotDecodeResponse;
 use crate::models::protocol::http::body::find_file_format_in_content_type;
+use crate::models::protocol::http::event_stream::{is_event_stream, EventStreamParser};
+use crate::models::collection::CollectionDefaults;
 use crate::models::environment::Environment;
 use crate::models::request::Request;
 use crate::models::response::{ImageResponse, RequestResponse, ResponseContent};
 
 
-pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, env: &Option<Arc<RwLock<Environment>>>) -> Result<RequestResponse, RequestResponseError> {
+pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, collection_defaults: &CollectionDefaults, env: &Option<Arc<RwLock<Environment>>>, received_response: Arc<Mutex<bool>>) -> Result<RequestResponse, RequestResponseError> {
     info!("Sending request");
 
     local_request.write().is_pending = true;
 
     let request = local_request.read();
+    let settings = collection_defaults.inherit_settings(&request.settings);
 
     let cancellation_token = request.cancellation_token.clone();
-    let timeout = tokio::time::sleep(Duration::from_millis(request.settings.timeout.as_u32() as u64));
+    let timeout = tokio::time::sleep(Duration::from_millis(settings.timeout.as_u32() as u64));
 
     let request_start = Instant::now();
     let elapsed_time: Duration;
 
+    // Set when the response body is a server-sent events stream, which is then read in the background
+    let mut event_stream_response: Option<reqwest::Response> = None;
+
     let mut response = tokio::select! {
         _ = cancellation_token.cancelled() => {
             elapsed_time = request_start.elapsed();
@@ -83,14 +89,21 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
                     .join("\n");
 
                 let response_content = match is_image {
+                    _ if settings.force_event_stream.as_bool() || is_event_stream(&headers) => {
+                        info!("Streaming server-sent events");
+
+                        event_stream_response = Some(response);
+
+                        None
+                    },
                     true => {
                         let content = response.bytes().await.unwrap();
                         let image = image::load_from_memory(content.as_ref());
 
-                        ResponseContent::Image(ImageResponse {
+                        Some(ResponseContent::Image(ImageResponse {
                             data: content.to_vec(),
                             image: image.ok(),
-                        })
+                        }))
                     },
                     false => match response.bytes().await {
                         Ok(bytes) => match String::from_utf8(bytes.to_vec()) {
@@ -98,7 +111,7 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
                                 // If a file format has been found in the content-type header
                                 if let Some(file_format) = find_file_format_in_content_type(&headers) {
                                     // If the request response content can be pretty printed
-                                    if request.settings.pretty_print_response_content.as_bool() {
+                                    if settings.pretty_print_response_content.as_bool() {
                                         // Match the file format
                                         match file_format.as_str() {
                                             "json" => {
@@ -109,9 +122,9 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
                                     }
                                 }
     
-                                ResponseContent::Body(result_body)
+                                Some(ResponseContent::Body(result_body))
                             },
-                            Err(_) => ResponseContent::Body(format!("{:#X?}", bytes))
+                            Err(_) => Some(ResponseContent::Body(format!("{:#X?}", bytes)))
                         },
                         Err(_) => return Err(CouldNotDecodeResponse)
                     }
@@ -120,7 +133,7 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
                 RequestResponse {
                     duration: None,
                     status_code: Some(status_code),
-                    content: Some(response_content),
+                    content: response_content,
                     cookies: Some(cookies),
                     headers
                 }
@@ -157,7 +170,7 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
 
     /* POST-REQUEST SCRIPT */
 
-    let (modified_response, post_request_output) = App::handle_post_request_script(&request, response, env)?;
+    let (modified_response, post_request_output, test_results) = App::handle_post_request_script(&request, &collection_defaults.scripts, response, env)?;
 
     drop(request);
 
@@ -165,9 +178,60 @@ pub async fn send_http_request(prepared_request: reqwest_middleware::RequestBuil
         let mut request = local_request.write();
 
         request.console_output.post_request_output = post_request_output;
+        request.test_results = test_results;
         request.is_pending = false;
         request.cancellation_token = CancellationToken::new();
+        request.events = vec![];
+        request.is_streaming = event_stream_response.is_some();
+    }
+
+    if let Some(event_stream_response) = event_stream_response {
+        let cancellation_token = local_request.read().cancellation_token.clone();
+
+        tokio::spawn(read_event_stream(event_stream_response, local_request, cancellation_token, received_response));
     }
-        
+
     return Ok(modified_response);
+}
+
+/// Push the events to the request as they arrive, until the stream ends or the request is canceled
+async fn read_event_stream(mut response: reqwest::Response, local_request: Arc<RwLock<Request>>, cancellation_token: CancellationToken, received_response: Arc<Mutex<bool>>) {
+    let mut parser = EventStreamParser::default();
+
+    loop {
+        let chunk = tokio::select! {
+            _ = cancellation_token.cancelled() => {
+                info!("Event stream canceled");
+                break;
+            },
+            chunk = response.chunk() => chunk
+        };
+
+        match chunk {
+            Ok(Some(chunk)) => {
+                let events = parser.feed(&chunk);
+
+                if !events.is_empty() {
+                    trace!("{} event(s) received", events.len());
+
+                    local_request.write().events.extend(events);
+                    *received_response.lock() = true;
+                }
+            },
+            Ok(None) => {
+                info!("Event stream closed");
+                break;
+            },
+            Err(error) => {
+                error!("Event stream error: {}", error);
+                break;
+            }
+        }
+    }
+
+    let mut request = local_request.write();
+    request.is_streaming = false;
+    request.cancellation_token = CancellationToken::new();
+
+    *received_response.lock() = true;
 }
\ No newline at end of file
@@ -1,10 +1,17 @@
 pub(super) mod utils;
 pub mod send;
+pub mod preparer;
 pub mod url;
 pub mod query_params;
 pub mod auth;
+pub mod oauth2;
+pub mod tls;
 pub mod headers;
+pub mod captures;
+pub mod dependency;
 pub mod scripts;
 pub mod export;
 pub mod http;
-pub mod ws;
\ No newline at end of file
+pub mod ws;
+pub mod graphql;
+pub mod grpc;
\ No newline at end of file
@@ -0,0 +1,400 @@
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use chrono::{Local, TimeDelta};
+use reqwest::header::ACCEPT;
+use reqwest::Url;
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::{info, trace, warn};
+use uuid::Uuid;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::preparer::RequestPreparer;
+use crate::cli::args::ARGS;
+use crate::models::auth::auth::Auth;
+use crate::models::auth::oauth2::{generate_pkce_pair, OAuth2, OAuth2GrantType};
+
+/// Time given to the user to log in when using the authorization code grant
+const OAUTH2_AUTHORIZATION_TIMEOUT: Duration = Duration::from_secs(180);
+
+#[derive(Error, Debug)]
+pub enum OAuth2Error {
+    #[error("The request auth method is not OAuth2")]
+    NotOAuth2,
+    #[error("INVALID OAUTH2 {0} URL")]
+    InvalidUrl(&'static str),
+    #[error("OAUTH2 TOKEN REQUEST FAILED: {0}")]
+    TokenRequest(String),
+    #[error("OAUTH2 TOKEN ERROR: {0}")]
+    TokenError(String),
+    #[error("INVALID OAUTH2 TOKEN RESPONSE")]
+    InvalidTokenResponse,
+    #[error("MISSING OAUTH2 REFRESH TOKEN")]
+    MissingRefreshToken,
+    #[error("COULD NOT LISTEN ON THE OAUTH2 REDIRECT URL: {0}")]
+    RedirectListener(String),
+    #[error("OAUTH2 AUTHORIZATION FAILED: {0}")]
+    Authorization(String),
+    #[error("OAUTH2 AUTHORIZATION TIMED OUT")]
+    AuthorizationTimeout,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+    refresh_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+impl RequestPreparer {
+    /// Return the cached access token, or fetch a new one if it is missing or about to expire.
+    /// When a refresh token is known, it is tried before the configured grant.
+    /// A refresh token issued by the server is kept in memory only, the configured one is left untouched
+    pub async fn get_oauth2_access_token(&self, oauth2: &mut OAuth2) -> Result<String, OAuth2Error> {
+        if oauth2.has_valid_access_token() {
+            trace!("Using cached OAuth2 access token");
+            return Ok(oauth2.access_token.clone());
+        }
+
+        let resolved_oauth2 = self.replace_oauth2_env_keys_by_value(oauth2);
+
+        let token_response = match resolved_oauth2.refresh_token.is_empty() {
+            true => fetch_oauth2_token(&resolved_oauth2).await?,
+            false => {
+                info!("Refreshing OAuth2 access token");
+
+                match refresh_oauth2_token(&resolved_oauth2).await {
+                    Ok(token_response) => token_response,
+                    Err(error) if resolved_oauth2.grant_type != OAuth2GrantType::RefreshToken => {
+                        warn!("Could not refresh the OAuth2 access token, requesting a new one: {error}");
+                        fetch_oauth2_token(&resolved_oauth2).await?
+                    },
+                    Err(error) => return Err(error)
+                }
+            }
+        };
+
+        oauth2.access_token = token_response.access_token;
+        oauth2.expires_at = token_response.expires_in.map(|expires_in| Local::now() + TimeDelta::seconds(expires_in));
+
+        if let Some(refresh_token) = token_response.refresh_token {
+            oauth2.issued_refresh_token = refresh_token;
+        }
+
+        info!("OAuth2 access token received");
+
+        Ok(oauth2.access_token.clone())
+    }
+
+    fn replace_oauth2_env_keys_by_value(&self, oauth2: &OAuth2) -> OAuth2 {
+        OAuth2 {
+            grant_type: oauth2.grant_type.clone(),
+            token_url: self.replace_env_keys_by_value(&oauth2.token_url),
+            client_id: self.replace_env_keys_by_value(&oauth2.client_id),
+            client_secret: self.replace_env_keys_by_value(&oauth2.client_secret),
+            scope: self.replace_env_keys_by_value(&oauth2.scope),
+            username: self.replace_env_keys_by_value(&oauth2.username),
+            password: self.replace_env_keys_by_value(&oauth2.password),
+            authorization_url: self.replace_env_keys_by_value(&oauth2.authorization_url),
+            redirect_url: self.replace_env_keys_by_value(&oauth2.redirect_url),
+            refresh_token: match oauth2.issued_refresh_token.is_empty() {
+                true => self.replace_env_keys_by_value(&oauth2.refresh_token),
+                false => oauth2.issued_refresh_token.clone()
+            },
+            access_token: oauth2.access_token.clone(),
+            expires_at: oauth2.expires_at,
+            issued_refresh_token: oauth2.issued_refresh_token.clone(),
+        }
+    }
+}
+
+impl App<'_> {
+    /// Forget the cached access token of a request, then fetch a new one
+    pub async fn renew_request_oauth2_token(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<String> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        let mut oauth2 = match &local_selected_request.read().auth {
+            Auth::OAuth2(oauth2) => oauth2.clone(),
+            _ => return Err(OAuth2Error::NotOAuth2.into())
+        };
+
+        oauth2.clear_access_token();
+
+        let access_token = self.request_preparer().get_oauth2_access_token(&mut oauth2).await?;
+
+        local_selected_request.write().auth = Auth::OAuth2(oauth2);
+
+        Ok(access_token)
+    }
+
+    pub fn clear_request_oauth2_token(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let Auth::OAuth2(oauth2) = &mut selected_request.auth else {
+                return Err(OAuth2Error::NotOAuth2.into());
+            };
+
+            info!("OAuth2 access token cleared");
+
+            oauth2.clear_access_token();
+        }
+
+        Ok(())
+    }
+
+    /// Forget the cached access token of a collection, then fetch a new one
+    pub async fn renew_collection_oauth2_token(&mut self, collection_index: usize) -> anyhow::Result<String> {
+        let local_collection_auth = self.collections[collection_index].defaults.auth.clone();
+
+        let mut oauth2 = match &*local_collection_auth.read() {
+            Auth::OAuth2(oauth2) => oauth2.clone(),
+            _ => return Err(OAuth2Error::NotOAuth2.into())
+        };
+
+        oauth2.clear_access_token();
+
+        let access_token = self.request_preparer().get_oauth2_access_token(&mut oauth2).await?;
+
+        *local_collection_auth.write() = Auth::OAuth2(oauth2);
+
+        Ok(access_token)
+    }
+
+    pub fn clear_collection_oauth2_token(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        {
+            let mut collection_auth = self.collections[collection_index].defaults.auth.write();
+
+            let Auth::OAuth2(oauth2) = &mut *collection_auth else {
+                return Err(OAuth2Error::NotOAuth2.into());
+            };
+
+            info!("OAuth2 access token cleared");
+
+            oauth2.clear_access_token();
+        }
+
+        Ok(())
+    }
+}
+
+/// Request a token using the configured grant
+async fn fetch_oauth2_token(oauth2: &OAuth2) -> Result<TokenResponse, OAuth2Error> {
+    info!("Requesting OAuth2 access token ({} grant)", oauth2.grant_type);
+
+    let mut params: Vec<(&str, String)> = match oauth2.grant_type {
+        OAuth2GrantType::ClientCredentials => vec![
+            ("grant_type", String::from("client_credentials")),
+        ],
+        OAuth2GrantType::Password => vec![
+            ("grant_type", String::from("password")),
+            ("username", oauth2.username.clone()),
+            ("password", oauth2.password.clone()),
+        ],
+        OAuth2GrantType::RefreshToken => return refresh_oauth2_token(oauth2).await,
+        OAuth2GrantType::AuthorizationCode => {
+            let (code, code_verifier) = authorize(oauth2).await?;
+
+            vec![
+                ("grant_type", String::from("authorization_code")),
+                ("code", code),
+                ("redirect_uri", oauth2.get_redirect_url().to_string()),
+                ("code_verifier", code_verifier),
+            ]
+        }
+    };
+
+    if !oauth2.scope.is_empty() && oauth2.grant_type != OAuth2GrantType::AuthorizationCode {
+        params.push(("scope", oauth2.scope.clone()));
+    }
+
+    request_token(oauth2, params).await
+}
+
+async fn refresh_oauth2_token(oauth2: &OAuth2) -> Result<TokenResponse, OAuth2Error> {
+    if oauth2.refresh_token.is_empty() {
+        return Err(OAuth2Error::MissingRefreshToken);
+    }
+
+    let mut params = vec![
+        ("grant_type", String::from("refresh_token")),
+        ("refresh_token", oauth2.refresh_token.clone()),
+    ];
+
+    if !oauth2.scope.is_empty() {
+        params.push(("scope", oauth2.scope.clone()));
+    }
+
+    request_token(oauth2, params).await
+}
+
+/// POST the grant to the token endpoint, the client authenticates with basic auth when it has a secret
+async fn request_token(oauth2: &OAuth2, mut params: Vec<(&str, String)>) -> Result<TokenResponse, OAuth2Error> {
+    let token_url = Url::parse(&oauth2.token_url).map_err(|_| OAuth2Error::InvalidUrl("TOKEN"))?;
+
+    let mut request = reqwest::Client::new()
+        .post(token_url)
+        .header(ACCEPT, "application/json");
+
+    match oauth2.client_secret.is_empty() {
+        true => params.push(("client_id", oauth2.client_id.clone())),
+        false => request = request.basic_auth(&oauth2.client_id, Some(&oauth2.client_secret))
+    }
+
+    let response = request
+        .form(&params)
+        .send()
+        .await
+        .map_err(|error| OAuth2Error::TokenRequest(error.to_string()))?;
+
+    let status = response.status();
+    let body = response.text().await.map_err(|error| OAuth2Error::TokenRequest(error.to_string()))?;
+
+    if !status.is_success() {
+        return Err(match serde_json::from_str::<TokenErrorResponse>(&body) {
+            Ok(TokenErrorResponse { error, error_description: Some(error_description) }) => OAuth2Error::TokenError(format!("{error}, {error_description}")),
+            Ok(TokenErrorResponse { error, error_description: None }) => OAuth2Error::TokenError(error),
+            Err(_) => OAuth2Error::TokenError(status.to_string())
+        });
+    }
+
+    serde_json::from_str::<TokenResponse>(&body).map_err(|_| OAuth2Error::InvalidTokenResponse)
+}
+
+/// Send the user to the authorization page, then catch the redirect with a one-shot loopback listener.
+/// Returns the authorization code and the PKCE code verifier.
+async fn authorize(oauth2: &OAuth2) -> Result<(String, String), OAuth2Error> {
+    let redirect_url = Url::parse(oauth2.get_redirect_url()).map_err(|_| OAuth2Error::InvalidUrl("REDIRECT"))?;
+
+    let (Some(host), Some(port)) = (redirect_url.host_str(), redirect_url.port_or_known_default()) else {
+        return Err(OAuth2Error::InvalidUrl("REDIRECT"));
+    };
+
+    let listener = TcpListener::bind((host, port)).await.map_err(|error| OAuth2Error::RedirectListener(error.to_string()))?;
+
+    let (code_verifier, code_challenge) = generate_pkce_pair();
+    let state = Uuid::new_v4().simple().to_string();
+
+    let mut authorization_url = Url::parse(&oauth2.authorization_url).map_err(|_| OAuth2Error::InvalidUrl("AUTHORIZATION"))?;
+
+    {
+        let mut query_pairs = authorization_url.query_pairs_mut();
+
+        query_pairs
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &oauth2.client_id)
+            .append_pair("redirect_uri", oauth2.get_redirect_url())
+            .append_pair("state", &state)
+            .append_pair("code_challenge", &code_challenge)
+            .append_pair("code_challenge_method", "S256");
+
+        if !oauth2.scope.is_empty() {
+            query_pairs.append_pair("scope", &oauth2.scope);
+        }
+    }
+
+    info!("Waiting for the OAuth2 authorization on \"{}\"", authorization_url);
+
+    // The TUI cannot print anything, the URL is only opened in the browser and logged
+    if ARGS.command.is_some() {
+        println!("Open the following URL to authorize the request:\n{authorization_url}");
+    }
+
+    open_in_browser(authorization_url.as_str());
+
+    let query_pairs = tokio::time::timeout(OAUTH2_AUTHORIZATION_TIMEOUT, wait_for_redirect(&listener, redirect_url.path()))
+        .await
+        .map_err(|_| OAuth2Error::AuthorizationTimeout)??;
+
+    let get_param = |name: &str| query_pairs
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.clone());
+
+    if let Some(error) = get_param("error") {
+        let error = match get_param("error_description") {
+            None => error,
+            Some(error_description) => format!("{error}, {error_description}")
+        };
+
+        return Err(OAuth2Error::Authorization(error));
+    }
+
+    if get_param("state").as_ref() != Some(&state) {
+        return Err(OAuth2Error::Authorization(String::from("state mismatch")));
+    }
+
+    match get_param("code") {
+        None => Err(OAuth2Error::Authorization(String::from("no authorization code received"))),
+        Some(code) => Ok((code, code_verifier))
+    }
+}
+
+/// Accept connections until the browser hits the redirect path, then return its query parameters
+async fn wait_for_redirect(listener: &TcpListener, redirect_path: &str) -> Result<Vec<(String, String)>, OAuth2Error> {
+    loop {
+        let (mut stream, _) = listener.accept().await.map_err(|error| OAuth2Error::RedirectListener(error.to_string()))?;
+
+        let mut buffer = vec![0u8; 8192];
+        let mut length = 0;
+
+        // Only the request line is needed
+        while length < buffer.len() && !buffer[..length].windows(2).any(|window| window == b"\r\n") {
+            match stream.read(&mut buffer[length..]).await {
+                Ok(0) | Err(_) => break,
+                Ok(read) => length += read
+            }
+        }
+
+        let request = String::from_utf8_lossy(&buffer[..length]);
+        let target = request.split_whitespace().nth(1).unwrap_or("/");
+
+        let Ok(url) = Url::parse(&format!("http://localhost{target}")) else {
+            continue;
+        };
+
+        if url.path() != redirect_path {
+            let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").await;
+            continue;
+        }
+
+        let body = "<html><body><h3>ATAC received the authorization, you can close this page.</h3></body></html>";
+        let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+
+        let _ = stream.write_all(response.as_bytes()).await;
+        let _ = stream.shutdown().await;
+
+        return Ok(url.query_pairs().into_owned().collect());
+    }
+}
+
+fn open_in_browser(url: &str) {
+    let (program, args): (&str, &[&str]) = match std::env::consts::OS {
+        "windows" => ("rundll32", &["url.dll,FileProtocolHandler"]),
+        "macos" => ("open", &[]),
+        _ => ("xdg-open", &[])
+    };
+
+    let result = Command::new(program)
+        .args(args)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    if let Err(error) = result {
+        warn!("Could not open a web browser: {error}");
+    }
+}
@@ -0,0 +1,70 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use parking_lot::{Mutex, RwLock};
+use reqwest_cookie_store::CookieStoreRwLock;
+
+use crate::app::app::App;
+use crate::app::business_logic::environment::replace_env_keys_by_value;
+use crate::app::files::config::Proxy;
+use crate::models::collection::Collection;
+use crate::models::environment::Environment;
+use crate::models::request::{KeyValue, Request};
+
+/// Everything needed to prepare a request, since it may be prepared in another task.
+/// Preparing can take long, e.g. when an OAuth2 token is fetched or when the dependencies are sent
+pub struct RequestPreparer {
+    pub environments: Vec<Arc<RwLock<Environment>>>,
+    pub selected_environment: usize,
+    /// Data file row of the collection run iteration being sent, replaced like environment values
+    pub iteration_values: IndexMap<String, String>,
+
+    pub proxy: Option<Proxy>,
+    pub response_history_size: usize,
+
+    pub cookie_store: Arc<CookieStoreRwLock>,
+    pub cookie_jar_path: Arc<RwLock<Option<PathBuf>>>,
+
+    /// The requests are shared with the app, so that the dependencies sent are updated there too
+    pub collections: Vec<Collection>,
+    pub received_response: Arc<Mutex<bool>>,
+}
+
+impl App<'_> {
+    pub fn request_preparer(&self) -> RequestPreparer {
+        RequestPreparer {
+            environments: self.environments.clone(),
+            selected_environment: self.selected_environment,
+            iteration_values: self.iteration_values.clone(),
+            proxy: self.config.get_proxy().clone(),
+            response_history_size: self.config.get_response_history_size(),
+            cookie_store: Arc::clone(&self.cookies_popup.cookie_store),
+            cookie_jar_path: Arc::clone(&self.cookies_popup.cookie_jar_path),
+            collections: self.collections.clone(),
+            received_response: Arc::clone(&self.received_response),
+        }
+    }
+}
+
+impl RequestPreparer {
+    pub fn get_selected_env_as_local(&self) -> Option<Arc<RwLock<Environment>>> {
+        self.environments.get(self.selected_environment).cloned()
+    }
+
+    pub fn get_request_as_local_from_indexes(&self, request_index: &(usize, usize)) -> Arc<RwLock<Request>> {
+        self.collections[request_index.0].requests[request_index.1].clone()
+    }
+
+    pub fn replace_env_keys_by_value(&self, input: &String) -> String {
+        replace_env_keys_by_value(input, &self.iteration_values, &self.environments, self.selected_environment)
+    }
+
+    pub fn key_value_vec_to_tuple_vec(&self, key_value: &[KeyValue]) -> Vec<(String, String)> {
+        key_value
+            .iter()
+            .filter(|param| param.enabled)
+            .map(|param| (self.replace_env_keys_by_value(&param.data.0), self.replace_env_keys_by_value(&param.data.1)))
+            .collect()
+    }
+}
@@ -1,11 +1,18 @@
 use boa_engine::{Context, Source};
 use indexmap::IndexMap;
+use thiserror::Error;
 use tracing::{info, trace};
 
 use crate::app::app::App;
 use crate::models::request::Request;
 use crate::models::response::RequestResponse;
-use crate::models::scripts::ScriptType;
+use crate::models::scripts::{ScriptType, TestResult};
+
+#[derive(Error, Debug)]
+pub enum ScriptTestsError {
+    #[error("{0} test(s) failed")]
+    TestsFailed(usize),
+}
 
 impl App<'_> {
     pub fn modify_request_script(&mut self, collection_index: usize, request_index: usize, script_type: &ScriptType, script: Option<String>) -> anyhow::Result<()> {
@@ -39,6 +46,75 @@ globalThis.console = {
 }
 "#;
 
+const JS_TESTS: &str = r#"
+let test_results = [];
+
+function test(name, fn) {
+    try {
+        fn();
+        test_results.push({ name: String(name), passed: true, error: null });
+    }
+    catch (error) {
+        let message = (error !== null && typeof error === 'object' && 'message' in error) ? error.message : String(error);
+        test_results.push({ name: String(name), passed: false, error: message });
+    }
+}
+
+function expect(actual) {
+    const format = (value) => {
+        if (value === undefined) {
+            return 'undefined';
+        }
+
+        try {
+            return JSON.stringify(value);
+        }
+        catch (error) {
+            return String(value);
+        }
+    };
+
+    const is_equal = (a, b) => a === b || format(a) === format(b);
+
+    const make_matchers = (negate) => {
+        const assert = (condition, description, ...expected) => {
+            if (condition === negate) {
+                let message = 'expected ' + format(actual) + (negate ? ' not ' : ' ') + description;
+
+                if (expected.length > 0) {
+                    message += ' ' + format(expected[0]);
+                }
+
+                throw new Error(message);
+            }
+        };
+
+        return {
+            toBe: (expected) => assert(actual === expected, 'to be', expected),
+            toEqual: (expected) => assert(is_equal(actual, expected), 'to equal', expected),
+            toBeTruthy: () => assert(!!actual, 'to be truthy'),
+            toBeFalsy: () => assert(!actual, 'to be falsy'),
+            toBeDefined: () => assert(actual !== undefined, 'to be defined'),
+            toBeUndefined: () => assert(actual === undefined, 'to be undefined'),
+            toBeNull: () => assert(actual === null, 'to be null'),
+            toBeGreaterThan: (expected) => assert(actual > expected, 'to be greater than', expected),
+            toBeGreaterThanOrEqual: (expected) => assert(actual >= expected, 'to be greater than or equal to', expected),
+            toBeLessThan: (expected) => assert(actual < expected, 'to be less than', expected),
+            toBeLessThanOrEqual: (expected) => assert(actual <= expected, 'to be less than or equal to', expected),
+            toContain: (expected) => assert(actual !== null && actual !== undefined && actual.includes(expected), 'to contain', expected),
+            toMatch: (expected) => assert(new RegExp(expected).test(actual), 'to match', String(expected)),
+            toHaveProperty: (property) => assert(actual !== null && actual !== undefined && Object.prototype.hasOwnProperty.call(Object(actual), property), 'to have property', property),
+            toHaveLength: (expected) => assert(actual !== null && actual !== undefined && actual.length === expected, 'to have length', expected),
+        };
+    };
+
+    let matchers = make_matchers(false);
+    matchers.not = make_matchers(true);
+
+    return matchers;
+}
+"#;
+
 const JS_UTILS: &str = r#"
 function pretty_print(data) {
     console.log(JSON.stringify(data, null, 2));
@@ -90,7 +166,7 @@ pub fn execute_pre_request_script(user_script: &String, request: &Request, env:
     return (result_request, result_env_values, console_output);
 }
 
-pub fn execute_post_request_script(user_script: &String, response: &RequestResponse, env: Option<IndexMap<String, String>>) -> (Option<RequestResponse>, Option<IndexMap<String, String>>, String) {
+pub fn execute_post_request_script(user_script: &String, response: &RequestResponse, env: Option<IndexMap<String, String>>) -> (Option<RequestResponse>, Option<IndexMap<String, String>>, String, Vec<TestResult>) {
     // Instantiate the execution context
     let mut context = Context::default();
 
@@ -105,15 +181,18 @@ pub fn execute_post_request_script(user_script: &String, response: &RequestRespo
         let env = {env_json};
 
         {JS_CONSOLE}
+        {JS_TESTS}
         {JS_UTILS}
 
-        /* Start of the user script */
+        /* Start of the user script, in its own scope so that it can declare a test, expect or test_results of its own */
 
-        {user_script}
+        (function () {{
+            {user_script}
+        }})();
 
         /* End of the user script */
 
-        JSON.stringify([response, env, console_log_output])
+        JSON.stringify([response, env, console_log_output, test_results])
     "#);
 
     trace!("Executing post-request script");
@@ -121,22 +200,47 @@ pub fn execute_post_request_script(user_script: &String, response: &RequestRespo
     let result = match context.eval(Source::from_bytes(&script)) {
         Ok(result) => result,
         Err(error) => {
-            return (None, env, error.to_string())
+            return (None, env, error.to_string(), vec![])
         }
     };
 
     let stringed_result = result.as_string().unwrap().to_std_string_escaped();
 
-    let (response_result, result_env_values, console_output) = match serde_json::from_str::<(RequestResponse, Option<IndexMap<String, String>>, String)>(&stringed_result) {
-        Ok((mut response_result, result_env_values, console_output)) => {
+    let (response_result, result_env_values, console_output, test_results) = match serde_json::from_str::<(RequestResponse, Option<IndexMap<String, String>>, String, Vec<TestResult>)>(&stringed_result) {
+        Ok((mut response_result, result_env_values, console_output, test_results)) => {
             // Avoid losing those fields since they are not serialized
             response_result.duration = response.duration.clone();
             response_result.status_code = response.status_code.clone();
 
-            (Some(response_result), result_env_values, console_output)
+            (Some(response_result), result_env_values, console_output, test_results)
         },
-        Err(error) => (None, env, error.to_string())
+        Err(error) => (None, env, error.to_string(), vec![])
     };
 
-    return (response_result, result_env_values, console_output);
-}
\ No newline at end of file
+    return (response_result, result_env_values, console_output, test_results);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_request_script_with_its_own_helpers() {
+        let user_script = String::from(r#"
+            let test_results = "mine";
+            function expect(value) { return value; }
+
+            test("own helpers", () => {
+                if (expect(1) !== 1) {
+                    throw new Error("wrong expect");
+                }
+            });
+        "#);
+
+        let (response, _, console_output, test_results) = execute_post_request_script(&user_script, &RequestResponse::default(), None);
+
+        assert!(response.is_some(), "{console_output}");
+        assert_eq!(test_results.len(), 1);
+        assert!(test_results[0].passed);
+    }
+}
@@ -1,20 +1,30 @@
 use std::io::Read;
+use chrono::Utc;
 use std::path::PathBuf;
 use std::sync::Arc;
 use parking_lot::RwLock;
 use reqwest::multipart::Part;
-use reqwest::{ClientBuilder, Proxy, Url};
-use reqwest::header::HeaderMap;
+use reqwest::{ClientBuilder, Identity, Proxy, Url};
+use reqwest::cookie::CookieStore;
+use reqwest::header::{HeaderMap, CONTENT_TYPE, COOKIE};
 use reqwest::redirect::Policy;
 use reqwest_middleware::Extension;
 use reqwest_tracing::{DisableOtelPropagation, OtelName, TracingMiddleware};
 use thiserror::Error;
 use tracing_log::log::trace;
 use crate::app::app::App;
+use crate::app::business_logic::cookies::PersistedCookieStore;
+use crate::app::business_logic::request::dependency::RequestDependencyError;
+use crate::app::business_logic::request::oauth2::OAuth2Error;
+use crate::app::business_logic::request::preparer::RequestPreparer;
 use crate::app::business_logic::request::scripts::{execute_post_request_script, execute_pre_request_script};
 use crate::app::business_logic::request::send::RequestResponseError::PostRequestScript;
+use crate::app::business_logic::request::tls::{build_rustls_client_config, load_ca_certificates, load_client_identity_pem, GrpcTlsConfig, TlsError};
 use crate::app::files::environment::save_environment_to_file;
 use crate::models::auth::auth::Auth;
+use crate::models::collection::{join_base_url, CollectionDefaults};
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::aws_sigv4::{sign_aws_sigv4_request, AwsSigV4, AwsSigV4Error};
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{digest_to_authorization_header, Digest};
@@ -24,6 +34,7 @@ use crate::models::environment::Environment;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::request::Request;
 use crate::models::response::RequestResponse;
+use crate::models::scripts::{RequestScripts, TestResult};
 use crate::panic_error;
 
 #[derive(Error, Debug)]
@@ -36,6 +47,18 @@ pub enum PrepareRequestError {
     CouldNotOpenFile,
     #[error("{0}")]
     JwtError(#[from] JwtError),
+    #[error("{0}")]
+    OAuth2Error(#[from] OAuth2Error),
+    #[error("{0}")]
+    AwsSigV4Error(#[from] AwsSigV4Error),
+    #[error("{0}")]
+    TlsError(#[from] TlsError),
+    #[error("COULD NOT BUILD HTTP CLIENT: {0}")]
+    CouldNotBuildClient(String),
+    #[error("INVALID GRAPHQL VARIABLES, EXPECTED A JSON OBJECT")]
+    InvalidGraphqlVariables,
+    #[error("{0}")]
+    RequestDependencyError(#[from] RequestDependencyError),
 }
 
 #[derive(Error, Debug)]
@@ -48,12 +71,28 @@ pub enum RequestResponseError {
     WebsocketError(#[from] reqwest_websocket::Error),
 }
 
-impl App<'_> {
+/// Final URL of the request, once the pre-request script has run and the query params are added.
+/// Given to the response history as an extension of the prepared request.
+#[derive(Clone)]
+pub struct PreparedUrl(pub String);
+
+impl RequestPreparer {
+    /// The request is a copy of the one at the given indexes, if it belongs to a collection
+    pub async fn prepare_request(&self, request_index: Option<(usize, usize)>, request: &mut Request, collection_defaults: &CollectionDefaults) -> Result<reqwest_middleware::RequestBuilder, PrepareRequestError> {
+        /* DEPENDENCIES */
+
+        self.send_request_dependencies(request_index, request, collection_defaults).await?;
+
+        self.prepare_request_without_dependencies(request, collection_defaults).await
+    }
+
+    /// Used for the dependencies themselves, which are all sent beforehand
     #[allow(deprecated)]
-    pub async fn prepare_request(&self, request: &mut Request) -> Result<reqwest_middleware::RequestBuilder, PrepareRequestError> {
+    pub async fn prepare_request_without_dependencies(&self, request: &mut Request, collection_defaults: &CollectionDefaults) -> Result<reqwest_middleware::RequestBuilder, PrepareRequestError> {
         trace!("Preparing request");
 
         let env = self.get_selected_env_as_local();
+        let settings = collection_defaults.inherit_settings(&request.settings);
 
         let mut client_builder = ClientBuilder::new()
             .default_headers(HeaderMap::new())
@@ -61,20 +100,20 @@ impl App<'_> {
 
         /* REDIRECTS */
 
-        if !request.settings.allow_redirects.as_bool() {
+        if !settings.allow_redirects.as_bool() {
             client_builder = client_builder.redirect(Policy::none());
         }
 
         /* STORE COOKIES */
 
-        let should_store_cookies = request.settings.store_received_cookies.as_bool();
+        let should_store_cookies = settings.store_received_cookies.as_bool();
 
         client_builder = client_builder.cookie_store(should_store_cookies);
 
         /* PROXY */
 
-        if request.settings.use_config_proxy.as_bool() {
-            match &self.config.get_proxy() {
+        if settings.use_config_proxy.as_bool() {
+            match &self.proxy {
                 None => {}
                 Some(proxy) => {
                     match &proxy.http_proxy {
@@ -104,31 +143,68 @@ impl App<'_> {
 
         /* COOKIES */
 
-        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
+        let local_cookie_store = Arc::new(PersistedCookieStore {
+            cookie_store: Arc::clone(&self.cookie_store),
+            cookie_jar_path: Arc::clone(&self.cookie_jar_path),
+        });
         client_builder = client_builder.cookie_provider(local_cookie_store);
 
         /* PRE-REQUEST SCRIPT */
 
-        let modified_request = self.handle_pre_request_script(request, env)?;
+        // The tests of the previous response are not relevant anymore
+        request.test_results.clear();
+
+        let mut modified_request = self.handle_pre_request_script(request, &collection_defaults.scripts, env)?;
+
+        modified_request.headers = collection_defaults.inherit_headers(&modified_request.headers);
+
+        // The collection's auth is mutated instead of the request's one when it is inherited, see below
+        let inherits_auth = matches!(modified_request.auth, Auth::InheritFromCollection);
+        modified_request.auth = collection_defaults.inherit_auth(&modified_request.auth);
 
         /* INVALID CERTS */
 
-        if request.settings.accept_invalid_certs.as_bool() {
+        if settings.accept_invalid_certs.as_bool() {
             client_builder = client_builder.danger_accept_invalid_certs(true);
         }
 
         /* INVALID HOSTNAMES */
 
-        if request.settings.accept_invalid_hostnames.as_bool() {
+        if settings.accept_invalid_hostnames.as_bool() {
             client_builder = client_builder.danger_accept_invalid_hostnames(true);
         }
 
+        /* CLIENT CERTIFICATE */
+
+        let identity_pem = match settings.client_certificate.as_string().is_empty() {
+            true => None,
+            false => {
+                let certificate_path = self.replace_env_keys_by_value(settings.client_certificate.as_string());
+                let key_path = self.replace_env_keys_by_value(settings.client_key.as_string());
+                let password = self.replace_env_keys_by_value(settings.client_certificate_password.as_string());
+
+                Some(load_client_identity_pem(&certificate_path, &key_path, &password)?)
+            }
+        };
+
+        if let Some(identity_pem) = &identity_pem {
+            client_builder = client_builder.identity(Identity::from_pem(identity_pem).map_err(|_| TlsError::InvalidClientCertificate)?);
+        }
+
+        /* CA CERTIFICATES */
+
+        let ca_certificates_paths = self.replace_env_keys_by_value(settings.ca_certificates.as_string());
+        client_builder = client_builder.tls_certs_merge(load_ca_certificates(&ca_certificates_paths)?);
+
         /* CLIENT */
 
-        let untraced_client = client_builder.build().expect("Could not build HTTP client");
+        let untraced_client = match client_builder.build() {
+            Ok(client) => client,
+            Err(error) => return Err(PrepareRequestError::CouldNotBuildClient(error.to_string()))
+        };
         let client = reqwest_middleware::ClientBuilder::new(untraced_client)
             .with(TracingMiddleware::default())
-            .with_init(Extension(OtelName(modified_request.name.into())))
+            .with_init(Extension(OtelName(modified_request.name.clone().into())))
             .with_init(Extension(DisableOtelPropagation))
             .build();
 
@@ -140,7 +216,9 @@ impl App<'_> {
 
         /* URL */
 
-        let mut url = self.replace_env_keys_by_value(&modified_request.url);
+        let url = self.replace_env_keys_by_value(&modified_request.url);
+        let base_url = self.replace_env_keys_by_value(&collection_defaults.base_url);
+        let mut url = join_base_url(&base_url, &url);
 
         for (key, value) in path_params {
             url = url.replace(key, value);
@@ -153,11 +231,18 @@ impl App<'_> {
             Url::parse_with_params(&url, query_params)
         };
 
-        let url = match url {
+        let mut url = match url {
             Ok(url) => url,
             Err(_) => return Err(PrepareRequestError::InvalidUrl)
         };
 
+        if let Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::QueryParam }) = &modified_request.auth {
+            let key = self.replace_env_keys_by_value(key);
+            let value = self.replace_env_keys_by_value(value);
+
+            url.query_pairs_mut().append_pair(&key, &value);
+        }
+
         let url_path = url.path().to_owned();
 
         /* REQUEST */
@@ -165,17 +250,21 @@ impl App<'_> {
         let method = match &modified_request.protocol {
             Protocol::HttpRequest(http_request) => http_request.method.to_reqwest(),
             Protocol::WsRequest(_) => reqwest::Method::GET,
+            Protocol::GraphqlRequest(_) => reqwest::Method::POST,
+            Protocol::GrpcRequest(_) => reqwest::Method::POST,
         };
 
         let mut request_builder = client.request(
             method,
-            url
+            url.clone()
         );
 
         /* AUTH */
 
         match &modified_request.auth {
             Auth::NoAuth => {}
+            // Already replaced by the collection's auth
+            Auth::InheritFromCollection => {}
             Auth::BasicAuth(BasicAuth { username, password}) => {
                 let username = self.replace_env_keys_by_value(username);
                 let password = self.replace_env_keys_by_value(password);
@@ -195,9 +284,18 @@ impl App<'_> {
                 request_builder = request_builder.bearer_auth(bearer_token);
             }
             Auth::Digest(Digest { username, password, domains, realm, nonce, opaque, stale, algorithm, qop, user_hash, charset, .. }) => {
-                let digest = request.auth.get_digest_mut();
-                digest.nc += 1;
-                
+                let nc = match inherits_auth {
+                    true => increment_digest_nc(&mut collection_defaults.auth.write()),
+                    false => match &mut request.auth {
+                        Auth::Digest(digest) => {
+                            digest.nc += 1;
+                            digest.nc
+                        },
+                        // Set by the pre-request script, the count cannot be kept in the request
+                        _ => 1
+                    }
+                };
+
                 let digest_header = digest_to_authorization_header(
                     username,
                     password,
@@ -211,11 +309,54 @@ impl App<'_> {
                     &qop,
                     *user_hash,
                     &charset,
-                    digest.nc
+                    nc
                 );
 
                 request_builder = request_builder.header("Authorization", &digest_header);
             }
+            Auth::OAuth2(modified_oauth2) => {
+                // The token is cached in the original request, so that it survives the pre-request script copy
+                let access_token = match inherits_auth {
+                    true => {
+                        // Not locked while the token is fetched
+                        let mut oauth2 = collection_defaults.auth.read().get_oauth2().clone();
+                        let access_token = self.get_oauth2_access_token(&mut oauth2).await;
+                        *collection_defaults.auth.write() = Auth::OAuth2(oauth2);
+
+                        access_token?
+                    },
+                    false => match &mut request.auth {
+                        Auth::OAuth2(oauth2) => self.get_oauth2_access_token(oauth2).await?,
+                        // Set by the pre-request script, the token cannot be cached in the request
+                        _ => self.get_oauth2_access_token(&mut modified_oauth2.clone()).await?
+                    }
+                };
+
+                request_builder = request_builder.bearer_auth(access_token);
+            }
+            // Signed once the request is fully prepared, see below
+            Auth::AwsSigV4(_) => {}
+            Auth::ApiKey(ApiKey { key, value, placement }) => {
+                let key = self.replace_env_keys_by_value(key);
+                let value = self.replace_env_keys_by_value(value);
+
+                match placement {
+                    ApiKeyPlacement::Header => request_builder = request_builder.header(key, value),
+                    // Already added to the URL
+                    ApiKeyPlacement::QueryParam => {},
+                    ApiKeyPlacement::Cookie => {
+                        let api_key_cookie = format!("{key}={value}");
+
+                        // A Cookie header prevents the client from adding the stored cookies, so they are merged here
+                        let cookie_header = match self.cookie_store.cookies(&url) {
+                            Some(stored_cookies) => format!("{}; {}", stored_cookies.to_str().unwrap_or_default(), api_key_cookie),
+                            None => api_key_cookie
+                        };
+
+                        request_builder = request_builder.header(COOKIE, cookie_header);
+                    }
+                }
+            }
         }
 
         /* BODY */
@@ -260,11 +401,22 @@ impl App<'_> {
                     let file_path_with_env_values = self.replace_env_keys_by_value(&file_path);
                     let path = PathBuf::from(file_path_with_env_values);
 
-                    match tokio::fs::File::open(path).await {
-                        Ok(file) => {
-                            request_builder = request_builder.body(file);
+                    // A signed payload has to be hashed, so the file cannot be streamed
+                    if let Auth::AwsSigV4(_) = &modified_request.auth {
+                        match tokio::fs::read(path).await {
+                            Ok(file_content) => {
+                                request_builder = request_builder.body(file_content);
+                            }
+                            Err(_) => return Err(PrepareRequestError::CouldNotOpenFile)
+                        }
+                    }
+                    else {
+                        match tokio::fs::File::open(path).await {
+                            Ok(file) => {
+                                request_builder = request_builder.body(file);
+                            }
+                            Err(_) => return Err(PrepareRequestError::CouldNotOpenFile)
                         }
-                        Err(_) => return Err(PrepareRequestError::CouldNotOpenFile)
                     }
                 },
                 Raw(body) | Json(body) | Xml(body) | Html(body) | Javascript(body) => {
@@ -273,6 +425,42 @@ impl App<'_> {
                 }
             };
         }
+        else if let Protocol::GraphqlRequest(graphql_request) = &modified_request.protocol {
+            let query = self.replace_env_keys_by_value(&graphql_request.query);
+            let variables = self.replace_env_keys_by_value(&graphql_request.variables);
+
+            let variables = match variables.trim().is_empty() {
+                true => serde_json::Value::Null,
+                false => match serde_json::from_str::<serde_json::Value>(&variables) {
+                    Ok(variables) if variables.is_object() => variables,
+                    _ => return Err(PrepareRequestError::InvalidGraphqlVariables)
+                }
+            };
+
+            let mut graphql_body = serde_json::json!({
+                "query": query,
+                "variables": variables,
+            });
+
+            if let Some(operation_name) = &graphql_request.operation_name {
+                graphql_body["operationName"] = serde_json::Value::String(self.replace_env_keys_by_value(operation_name));
+            }
+
+            let has_content_type_header = modified_request.headers
+                .iter()
+                .any(|header| header.enabled && header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()));
+
+            if !has_content_type_header {
+                request_builder = request_builder.header(CONTENT_TYPE, "application/json");
+            }
+
+            request_builder = request_builder.body(graphql_body.to_string());
+        }
+        else if let Protocol::GrpcRequest(grpc_request) = &modified_request.protocol {
+            // The JSON message is encoded to protobuf when the call is made
+            let message = self.replace_env_keys_by_value(&grpc_request.message);
+            request_builder = request_builder.body(message);
+        }
 
         /* HEADERS */
 
@@ -287,87 +475,174 @@ impl App<'_> {
             request_builder = request_builder.header(header_name, header_value);
         }
 
+        /* AWS SIGNATURE V4 */
+
+        // Must come last, the signature covers the final method, URL, headers and body
+        if let Auth::AwsSigV4(AwsSigV4 { access_key_id, secret_access_key, session_token, region, service }) = &modified_request.auth {
+            let access_key_id = self.replace_env_keys_by_value(access_key_id);
+            let secret_access_key = self.replace_env_keys_by_value(secret_access_key);
+            let session_token = self.replace_env_keys_by_value(session_token);
+            let region = self.replace_env_keys_by_value(region);
+            let service = self.replace_env_keys_by_value(service);
+
+            let (client, built_request) = request_builder.build_split();
+
+            let mut built_request = match built_request {
+                Ok(built_request) => built_request,
+                Err(_) => return Err(PrepareRequestError::from(AwsSigV4Error::InvalidRequest))
+            };
+
+            sign_aws_sigv4_request(&mut built_request, &access_key_id, &secret_access_key, &session_token, &region, &service, Utc::now())?;
+
+            // Rebuilding the request drops the extensions set by the client initializers
+            request_builder = reqwest_middleware::RequestBuilder::from_parts(client, built_request)
+                .with_extension(OtelName(modified_request.name.clone().into()))
+                .with_extension(DisableOtelPropagation);
+        }
+
+        /* GRPC TLS */
+
+        // Must come after the AWS signature, rebuilding the request drops the extensions
+        if let Protocol::GrpcRequest(_) = &modified_request.protocol {
+            let tls_config = build_rustls_client_config(
+                settings.accept_invalid_certs.as_bool(),
+                settings.accept_invalid_hostnames.as_bool(),
+                identity_pem.as_deref(),
+                &ca_certificates_paths
+            )?;
+
+            request_builder = request_builder.with_extension(GrpcTlsConfig(Arc::new(tls_config)));
+        }
+
+        /* PREPARED URL */
+
+        // Must come after the AWS signature, rebuilding the request drops the extensions
+        request_builder = request_builder.with_extension(PreparedUrl(url.to_string()));
+
         trace!("Request prepared");
 
         Ok(request_builder)
     }
 
-    pub fn handle_pre_request_script(&self, request: &mut Request, env: Option<Arc<RwLock<Environment>>>) -> anyhow::Result<Request, PrepareRequestError> {
-        match &request.scripts.pre_request_script {
-            None => {
-                request.console_output.pre_request_output = None;
-                Ok(request.clone())
-            },
-            Some(pre_request_script) => {
-                let env_values = match &env {
-                    None => None,
-                    Some(local_env) => {
-                        let env = local_env.read();
-                        Some(env.values.clone())
-                    }
-                };
+    /// Runs the collection's pre-request script, then the request's one on the result
+    pub fn handle_pre_request_script(&self, request: &mut Request, collection_scripts: &RequestScripts, env: Option<Arc<RwLock<Environment>>>) -> anyhow::Result<Request, PrepareRequestError> {
+        let pre_request_scripts: Vec<&String> = [&collection_scripts.pre_request_script, &request.scripts.pre_request_script]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if pre_request_scripts.is_empty() {
+            request.console_output.pre_request_output = None;
+            return Ok(request.clone());
+        }
+
+        let mut result_request = request.clone();
+        let mut console_outputs = String::new();
+
+        for pre_request_script in pre_request_scripts {
+            let env_values = match &env {
+                None => None,
+                Some(local_env) => {
+                    let env = local_env.read();
+                    Some(env.values.clone())
+                }
+            };
 
-                let (result_request, env_variables, console_output) = execute_pre_request_script(pre_request_script, &request, env_values);
+            let (script_request, env_variables, console_output) = execute_pre_request_script(pre_request_script, &result_request, env_values);
 
-                match &env {
+            match &env {
+                None => {},
+                Some(local_env) => match env_variables {
                     None => {},
-                    Some(local_env) => match env_variables {
-                        None => {},
-                        Some(env_variables) => {
-                            let mut env = local_env.write();
-                            env.values = env_variables;
-                            save_environment_to_file(&*env);
-                        }
+                    Some(env_variables) => {
+                        let mut env = local_env.write();
+                        env.values = env_variables;
+                        save_environment_to_file(&*env);
                     }
                 }
+            }
 
-                request.console_output.pre_request_output = Some(console_output);
+            console_outputs.push_str(&console_output);
 
-                match result_request {
-                    None => Err(PrepareRequestError::PreRequestScript),
-                    Some(request) => Ok(request)
-                }
+            match script_request {
+                None => {
+                    request.console_output.pre_request_output = Some(console_outputs);
+                    return Err(PrepareRequestError::PreRequestScript);
+                },
+                Some(script_request) => result_request = script_request
             }
         }
+
+        request.console_output.pre_request_output = Some(console_outputs);
+
+        Ok(result_request)
     }
+}
 
-    pub fn handle_post_request_script(request: &Request, response: RequestResponse, env: &Option<Arc<RwLock<Environment>>>) -> anyhow::Result<(RequestResponse, Option<String>), RequestResponseError> {
-        match &request.scripts.post_request_script {
-            None => {
-                Ok((response, None))
-            },
-            Some(post_request_script) => {
-                let env_values = match &env {
-                    None => None,
-                    Some(env) => {
-                        let env = env.read();
-                        Some(env.values.clone())
-                    }
-                };
+impl App<'_> {
+    /// Runs the collection's post-request script, then the request's one on the result, and gathers the results of their tests.
+    /// The request's captures are then stored in the environment
+    pub fn handle_post_request_script(request: &Request, collection_scripts: &RequestScripts, response: RequestResponse, env: &Option<Arc<RwLock<Environment>>>) -> anyhow::Result<(RequestResponse, Option<String>, Vec<TestResult>), RequestResponseError> {
+        let post_request_scripts: Vec<&String> = [&collection_scripts.post_request_script, &request.scripts.post_request_script]
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if post_request_scripts.is_empty() {
+            let captures_output = App::handle_response_captures(request, &response, env);
+            return Ok((response, captures_output, vec![]));
+        }
+
+        let mut result_response = response;
+        let mut console_outputs = String::new();
+        let mut test_results = vec![];
 
-                let (result_response, env_variables, result_console_output) = execute_post_request_script(post_request_script, &response, env_values);
+        for post_request_script in post_request_scripts {
+            let env_values = match &env {
+                None => None,
+                Some(env) => {
+                    let env = env.read();
+                    Some(env.values.clone())
+                }
+            };
+
+            let (script_response, env_variables, console_output, script_test_results) = execute_post_request_script(post_request_script, &result_response, env_values);
 
-                match env {
+            match env {
+                None => {},
+                Some(env) => match env_variables {
                     None => {},
-                    Some(env) => match env_variables {
-                        None => {},
-                        Some(env_variables) => {
-                            let mut env = env.write();
-                            env.values = env_variables;
-                            save_environment_to_file(&*env);
-                        }
+                    Some(env_variables) => {
+                        let mut env = env.write();
+                        env.values = env_variables;
+                        save_environment_to_file(&*env);
                     }
                 }
+            }
 
-                match result_response {
-                    None => Err(PostRequestScript),
-                    Some(result_response) => Ok((result_response, Some(result_console_output)))
-                }
+            console_outputs.push_str(&console_output);
+            test_results.extend(script_test_results);
+
+            match script_response {
+                None => return Err(PostRequestScript),
+                Some(script_response) => result_response = script_response
             }
         }
+
+        if let Some(captures_output) = App::handle_response_captures(request, &result_response, env) {
+            console_outputs.push_str(&captures_output);
+        }
+
+        Ok((result_response, Some(console_outputs), test_results))
     }
 }
 
+fn increment_digest_nc(auth: &mut Auth) -> u32 {
+    let digest = auth.get_digest_mut();
+    digest.nc += 1;
+    digest.nc
+}
+
 pub fn get_file_content_with_name(path: PathBuf) -> std::io::Result<(Vec<u8>, String)> {
     let mut buffer: Vec<u8> = vec![];
     let mut file = std::fs::File::open(path.clone())?;
@@ -0,0 +1,213 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use p12_keystore::KeyStore;
+use reqwest::Certificate;
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use thiserror::Error;
+use tracing::trace;
+
+#[derive(Error, Debug)]
+pub enum TlsError {
+    #[error("COULD NOT READ TLS FILE \"{0}\"")]
+    CouldNotReadFile(String),
+    #[error("INVALID CLIENT CERTIFICATE OR KEY")]
+    InvalidClientCertificate,
+    #[error("INVALID PKCS#12 CLIENT CERTIFICATE OR PASSWORD")]
+    InvalidPkcs12,
+    #[error("INVALID CA CERTIFICATES \"{0}\"")]
+    InvalidCaCertificates(String),
+    #[error("INVALID TLS CONFIGURATION")]
+    InvalidTlsConfiguration,
+}
+
+/// Load a client certificate for mutual TLS.
+/// PKCS#12 files are recognized by their .p12 or .pfx extension, anything else is read as PEM.
+/// A PEM certificate may already contain its private key, in which case the key path can be left empty.
+/// Gives the certificate chain followed by the private key, as PEM, so that it can be used by both reqwest and the gRPC channels.
+pub fn load_client_identity_pem(certificate_path: &str, key_path: &str, password: &str) -> Result<Vec<u8>, TlsError> {
+    trace!("Loading client certificate \"{certificate_path}\"");
+
+    let certificate = read_tls_file(certificate_path)?;
+
+    match is_pkcs12_file(certificate_path) {
+        true => pkcs12_to_pem(&certificate, password),
+        false => {
+            let mut pem = certificate;
+
+            if !key_path.is_empty() {
+                pem.push(b'\n');
+                pem.extend(read_tls_file(key_path)?);
+            }
+
+            Ok(pem)
+        }
+    }
+}
+
+/// Load every certificate of the given comma separated PEM bundle file paths
+pub fn load_ca_certificates(ca_certificates_paths: &str) -> Result<Vec<Certificate>, TlsError> {
+    let mut certificates = vec![];
+
+    for ca_certificates_path in ca_certificates_paths.split(',').map(str::trim).filter(|path| !path.is_empty()) {
+        trace!("Loading CA certificates \"{ca_certificates_path}\"");
+
+        let bundle = read_tls_file(ca_certificates_path)?;
+        let bundle_certificates = Certificate::from_pem_bundle(&bundle).map_err(|_| TlsError::InvalidCaCertificates(ca_certificates_path.to_string()))?;
+
+        if bundle_certificates.is_empty() {
+            return Err(TlsError::InvalidCaCertificates(ca_certificates_path.to_string()));
+        }
+
+        certificates.extend(bundle_certificates);
+    }
+
+    Ok(certificates)
+}
+
+/// TLS configuration of the gRPC channels, which are not built with reqwest.
+/// Given to the gRPC call as an extension of the prepared request.
+#[derive(Clone)]
+pub struct GrpcTlsConfig(pub Arc<ClientConfig>);
+
+/// Same settings as the reqwest client ones, applied to a rustls configuration
+pub fn build_rustls_client_config(accept_invalid_certs: bool, accept_invalid_hostnames: bool, identity_pem: Option<&[u8]>, ca_certificates_paths: &str) -> Result<ClientConfig, TlsError> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let mut root_store = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+
+    for ca_certificates_path in ca_certificates_paths.split(',').map(str::trim).filter(|path| !path.is_empty()) {
+        let bundle = read_tls_file(ca_certificates_path)?;
+        let bundle_certificates: Vec<CertificateDer> = CertificateDer::pem_slice_iter(&bundle)
+            .collect::<Result<_, _>>()
+            .map_err(|_| TlsError::InvalidCaCertificates(ca_certificates_path.to_string()))?;
+
+        if bundle_certificates.is_empty() {
+            return Err(TlsError::InvalidCaCertificates(ca_certificates_path.to_string()));
+        }
+
+        for certificate in bundle_certificates {
+            root_store.add(certificate).map_err(|_| TlsError::InvalidCaCertificates(ca_certificates_path.to_string()))?;
+        }
+    }
+
+    let config_builder = ClientConfig::builder_with_provider(provider.clone())
+        .with_safe_default_protocol_versions()
+        .map_err(|_| TlsError::InvalidTlsConfiguration)?;
+
+    let config_builder = match accept_invalid_certs || accept_invalid_hostnames {
+        false => config_builder.with_root_certificates(root_store),
+        true => {
+            let verifier = WebPkiServerVerifier::builder_with_provider(Arc::new(root_store), provider)
+                .build()
+                .map_err(|_| TlsError::InvalidTlsConfiguration)?;
+
+            config_builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(RelaxedServerCertVerifier {
+                    verifier,
+                    accept_invalid_certs,
+                }))
+        }
+    };
+
+    match identity_pem {
+        None => Ok(config_builder.with_no_client_auth()),
+        Some(identity_pem) => {
+            let certificate_chain: Vec<CertificateDer> = CertificateDer::pem_slice_iter(identity_pem)
+                .collect::<Result<_, _>>()
+                .map_err(|_| TlsError::InvalidClientCertificate)?;
+            let private_key = PrivateKeyDer::from_pem_slice(identity_pem).map_err(|_| TlsError::InvalidClientCertificate)?;
+
+            config_builder
+                .with_client_auth_cert(certificate_chain, private_key)
+                .map_err(|_| TlsError::InvalidClientCertificate)
+        }
+    }
+}
+
+/// Accepts invalid certificates, or only certificates that are not valid for the host name
+#[derive(Debug)]
+struct RelaxedServerCertVerifier {
+    verifier: Arc<WebPkiServerVerifier>,
+    accept_invalid_certs: bool,
+}
+
+impl ServerCertVerifier for RelaxedServerCertVerifier {
+    fn verify_server_cert(&self, end_entity: &CertificateDer<'_>, intermediates: &[CertificateDer<'_>], server_name: &ServerName<'_>, ocsp_response: &[u8], now: UnixTime) -> Result<ServerCertVerified, rustls::Error> {
+        match self.verifier.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now) {
+            Ok(verified) => Ok(verified),
+            Err(_) if self.accept_invalid_certs => Ok(ServerCertVerified::assertion()),
+            Err(rustls::Error::InvalidCertificate(CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. })) => Ok(ServerCertVerified::assertion()),
+            Err(error) => Err(error)
+        }
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], certificate: &CertificateDer<'_>, signature: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verifier.verify_tls12_signature(message, certificate, signature)
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], certificate: &CertificateDer<'_>, signature: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.verifier.verify_tls13_signature(message, certificate, signature)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.verifier.supported_verify_schemes()
+    }
+}
+
+fn read_tls_file(path: &str) -> Result<Vec<u8>, TlsError> {
+    fs::read(path).map_err(|_| TlsError::CouldNotReadFile(path.to_string()))
+}
+
+fn is_pkcs12_file(path: &str) -> bool {
+    match Path::new(path).extension() {
+        Some(extension) => {
+            let extension = extension.to_string_lossy().to_lowercase();
+            extension == "p12" || extension == "pfx"
+        },
+        None => false
+    }
+}
+
+/// Rustls can only load PEM identities, so the PKCS#12 key and certificate chain are converted
+fn pkcs12_to_pem(pkcs12: &[u8], password: &str) -> Result<Vec<u8>, TlsError> {
+    let key_store = KeyStore::from_pkcs12(pkcs12, password).map_err(|_| TlsError::InvalidPkcs12)?;
+
+    let (_, private_key_chain) = match key_store.private_key_chain() {
+        Some(private_key_chain) => private_key_chain,
+        None => return Err(TlsError::InvalidPkcs12)
+    };
+
+    let mut pem = String::new();
+
+    for certificate in private_key_chain.chain() {
+        pem.push_str(&to_pem_block("CERTIFICATE", certificate.as_der()));
+    }
+
+    pem.push_str(&to_pem_block("PRIVATE KEY", private_key_chain.key()));
+
+    Ok(pem.into_bytes())
+}
+
+fn to_pem_block(label: &str, der: &[u8]) -> String {
+    let encoded = BASE64_STANDARD.encode(der);
+
+    let lines = encoded
+        .as_bytes()
+        .chunks(64)
+        .map(|chunk| String::from_utf8_lossy(chunk).to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    format!("-----BEGIN {label}-----\n{lines}\n-----END {label}-----\n")
+}
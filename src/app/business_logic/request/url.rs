@@ -1,8 +1,11 @@
 use anyhow::anyhow;
+use reqwest::Url;
 use thiserror::Error;
 use tracing::{info};
 
 use crate::app::app::App;
+use crate::models::collection::{join_base_url, CollectionDefaults};
+use crate::models::request::Request;
 
 #[derive(Error, Debug)]
 pub enum UrlError {
@@ -30,4 +33,28 @@ impl App<'_> {
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// URL the request is sent to, with the collection base URL, the environment values and the params
+    pub fn get_request_url(&self, request: &Request, collection_defaults: &CollectionDefaults) -> String {
+        let params = self.key_value_vec_to_tuple_vec(&request.params);
+
+        let url = self.replace_env_keys_by_value(&request.url);
+        let base_url = self.replace_env_keys_by_value(&collection_defaults.base_url);
+        let mut url = join_base_url(&base_url, &url);
+
+        for (key, value) in params.iter().filter(|(key, _)| key.starts_with("{") && key.ends_with("}")) {
+            url = url.replace(key, value);
+        }
+
+        let query_params: Vec<&(String, String)> = params.iter().filter(|(key, _)| !(key.starts_with("{") && key.ends_with("}"))).collect();
+
+        if query_params.is_empty() {
+            return url;
+        }
+
+        match Url::parse_with_params(&url, query_params) {
+            Ok(url) => url.to_string(),
+            Err(_) => url
+        }
+    }
+}
@@ -11,13 +11,14 @@ use tokio_util::sync::CancellationToken;
 use tracing::{error, info, trace};
 use crate::app::app::App;
 use crate::app::business_logic::request::send::RequestResponseError;
+use crate::models::collection::CollectionDefaults;
 use crate::models::environment::Environment;
 use crate::models::protocol::ws::message_type::MessageType;
 use crate::models::protocol::ws::ws::{Message, Sender, Websocket};
 use crate::models::request::Request;
 use crate::models::response::{RequestResponse, ResponseContent};
 
-pub async fn send_ws_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, env: &Option<Arc<RwLock<Environment>>>, received_response: Arc<Mutex<bool>>) -> Result<RequestResponse, RequestResponseError> {
+pub async fn send_ws_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, collection_defaults: &CollectionDefaults, env: &Option<Arc<RwLock<Environment>>>, received_response: Arc<Mutex<bool>>) -> Result<RequestResponse, RequestResponseError> {
     info!("Sending request");
 
     let mut request = local_request.write();
@@ -132,7 +133,7 @@ pub async fn send_ws_request(prepared_request: reqwest_middleware::RequestBuilde
 
     /* POST-REQUEST SCRIPT */
 
-    let (modified_response, post_request_output) = App::handle_post_request_script(&request, response, env)?;
+    let (modified_response, post_request_output, test_results) = App::handle_post_request_script(&request, &collection_defaults.scripts, response, env)?;
 
     drop(request);
 
@@ -140,6 +141,7 @@ pub async fn send_ws_request(prepared_request: reqwest_middleware::RequestBuilde
         let mut request = local_request.write();
 
         request.console_output.post_request_output = post_request_output;
+        request.test_results = test_results;
         request.is_pending = false;
         request.cancellation_token = CancellationToken::new();
 
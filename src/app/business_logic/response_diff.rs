@@ -0,0 +1,340 @@
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures_util::Sink;
+use parking_lot::{Mutex, RwLock};
+use serde_json::Value;
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+use reqwest_cookie_store::CookieStoreRwLock;
+use tracing::warn;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::grpc::send::send_grpc_request;
+use crate::app::business_logic::request::http::send::send_http_request;
+use crate::app::business_logic::request::preparer::RequestPreparer;
+use crate::app::business_logic::request::ws::send::send_ws_request;
+use crate::app::files::cookies::{get_cookie_jar_path, load_cookie_jar};
+use crate::models::collection::CollectionDefaults;
+use crate::models::environment::Environment;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::Request;
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum ResponseDiffError {
+    #[error("{0} line(s) differ")]
+    ResponsesDiffer(usize),
+}
+
+/// Headers that change from one response to the other, not compared unless asked to
+pub const VOLATILE_HEADERS: [&str; 12] = [
+    "date",
+    "age",
+    "expires",
+    "last-modified",
+    "x-request-id",
+    "x-correlation-id",
+    "x-amzn-requestid",
+    "x-amz-request-id",
+    "x-amz-cf-id",
+    "cf-ray",
+    "traceparent",
+    "server-timing",
+];
+
+/// Above this amount of line comparisons, the changed part is shown as entirely removed then added
+const MAX_COMPARED_LINES: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffRowKind {
+    Section,
+    Unchanged,
+    Changed,
+    Removed,
+    Added,
+}
+
+/// One side-by-side line, a side being None when the line only exists on the other one
+#[derive(Debug, Clone)]
+pub struct DiffRow {
+    pub kind: DiffRowKind,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResponseDiff {
+    pub left_title: String,
+    pub right_title: String,
+    pub rows: Vec<DiffRow>,
+}
+
+impl ResponseDiff {
+    /// Compare the status, headers and body of two responses. JSON bodies are normalized so that key order doesn't matter,
+    /// and the ignored headers (case-insensitive) are left out
+    pub fn new(left_title: String, left: &RequestResponse, right_title: String, right: &RequestResponse, ignored_headers: &[String]) -> ResponseDiff {
+        let mut rows = vec![];
+
+        let sections = [
+            ("Status", status_lines(left), status_lines(right)),
+            ("Headers", header_lines(left, ignored_headers), header_lines(right, ignored_headers)),
+            ("Body", body_lines(left), body_lines(right)),
+        ];
+
+        for (section, left_lines, right_lines) in sections {
+            rows.push(DiffRow {
+                kind: DiffRowKind::Section,
+                left: Some(section.to_string()),
+                right: Some(section.to_string()),
+            });
+
+            rows.extend(diff_lines(&left_lines, &right_lines));
+        }
+
+        ResponseDiff {
+            left_title,
+            right_title,
+            rows,
+        }
+    }
+
+    pub fn default_ignored_headers() -> Vec<String> {
+        VOLATILE_HEADERS.iter().map(|header| header.to_string()).collect()
+    }
+
+    pub fn differences_count(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row.kind, DiffRowKind::Changed | DiffRowKind::Removed | DiffRowKind::Added))
+            .count()
+    }
+}
+
+/// A copy of a request prepared with another environment than the selected one
+pub struct EnvironmentRequest {
+    request: Request,
+    prepared_request: reqwest_middleware::RequestBuilder,
+    collection_defaults: CollectionDefaults,
+    env: Option<Arc<RwLock<Environment>>>,
+    received_response: Arc<Mutex<bool>>,
+}
+
+impl App<'_> {
+    /// Prepares the requests with another environment than the selected one, along with its cookie jar
+    pub fn request_preparer_with_environment(&self, env_index: usize) -> RequestPreparer {
+        let mut request_preparer = self.request_preparer();
+
+        if env_index == self.selected_environment {
+            return request_preparer;
+        }
+
+        request_preparer.selected_environment = env_index;
+
+        let environment_name = self.environments[env_index].read().name.clone();
+
+        if let Some(cookie_jar_path) = get_cookie_jar_path(Some(&environment_name)) {
+            request_preparer.cookie_store = Arc::new(CookieStoreRwLock::new(load_cookie_jar(&cookie_jar_path)));
+            request_preparer.cookie_jar_path = Arc::new(RwLock::new(Some(cookie_jar_path)));
+        }
+
+        request_preparer
+    }
+}
+
+impl RequestPreparer {
+    /// Prepare a copy of the request, leaving the request itself untouched
+    pub async fn prepare_environment_request(&self, collection_index: usize, request_index: usize) -> anyhow::Result<EnvironmentRequest> {
+        let mut request = self.collections[collection_index].requests[request_index].read().clone();
+        // A cloned token would be shared with the request itself
+        request.cancellation_token = CancellationToken::new();
+        let collection_defaults = self.collections[collection_index].defaults.clone();
+
+        let prepared_request = self.prepare_request(Some((collection_index, request_index)), &mut request, &collection_defaults).await?;
+
+        Ok(EnvironmentRequest {
+            request,
+            prepared_request,
+            collection_defaults,
+            env: self.get_selected_env_as_local(),
+            received_response: self.received_response.clone(),
+        })
+    }
+}
+
+impl EnvironmentRequest {
+    /// Only the response is kept, a WebSocket connection is closed as soon as it is established
+    pub async fn send(self) -> anyhow::Result<RequestResponse> {
+        let protocol = self.request.protocol.clone();
+        // The request gets a new token once sent, the WebSocket messages loop keeps this one
+        let cancellation_token = self.request.cancellation_token.clone();
+        let local_request = Arc::new(RwLock::new(self.request));
+
+        let response = match protocol {
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => send_http_request(self.prepared_request, local_request.clone(), &self.collection_defaults, &self.env, self.received_response).await,
+            Protocol::WsRequest(_) => send_ws_request(self.prepared_request, local_request.clone(), &self.collection_defaults, &self.env, self.received_response).await,
+            Protocol::GrpcRequest(_) => send_grpc_request(self.prepared_request, local_request.clone(), &self.collection_defaults, &self.env).await,
+        };
+
+        close_websocket(&local_request, &cancellation_token).await;
+
+        response.map_err(anyhow::Error::from)
+    }
+}
+
+/// Stop listening to the server messages and close the connection, if one was established
+async fn close_websocket(local_request: &Arc<RwLock<Request>>, cancellation_token: &CancellationToken) {
+    cancellation_token.cancel();
+
+    let websocket = {
+        let mut request = local_request.write();
+
+        match request.get_ws_request_mut() {
+            Ok(ws_request) => {
+                ws_request.is_connected = false;
+                ws_request.websocket.take()
+            },
+            Err(_) => None
+        }
+    };
+
+    if let Some(websocket) = websocket {
+        // Locked on each poll, so that the lock is never held across an await point
+        let close_result = poll_fn(|context| Pin::new(&mut *websocket.tx.lock()).poll_close(context)).await;
+
+        if let Err(error) = close_result {
+            warn!("Could not close the WebSocket connection: {error}");
+        }
+    }
+}
+
+fn status_lines(response: &RequestResponse) -> Vec<String> {
+    vec![response.status_code.clone().unwrap_or(String::from("NO RESPONSE"))]
+}
+
+/// Sorted by name, so that only the headers values and presence matter
+fn header_lines(response: &RequestResponse, ignored_headers: &[String]) -> Vec<String> {
+    let mut headers: Vec<String> = response.headers
+        .iter()
+        .filter(|(header, _)| !ignored_headers.iter().any(|ignored_header| ignored_header.eq_ignore_ascii_case(header)))
+        .map(|(header, value)| format!("{}: {value}", header.to_lowercase()))
+        .collect();
+
+    headers.sort();
+
+    headers
+}
+
+fn body_lines(response: &RequestResponse) -> Vec<String> {
+    match &response.content {
+        None => vec![],
+        Some(ResponseContent::Image(image)) => vec![format!("<image, {} bytes>", image.data.len())],
+        // serde_json objects are sorted by key
+        Some(ResponseContent::Body(body)) => match serde_json::from_str::<Value>(body) {
+            Ok(json_body) => match serde_json::to_string_pretty(&json_body) {
+                Ok(normalized_body) => normalized_body.lines().map(String::from).collect(),
+                Err(_) => body.lines().map(String::from).collect()
+            },
+            Err(_) => body.lines().map(String::from).collect()
+        }
+    }
+}
+
+/// Line by line diff based on the longest common subsequence, with consecutive removed and added lines paired up
+fn diff_lines(left: &[String], right: &[String]) -> Vec<DiffRow> {
+    let prefix_length = left
+        .iter()
+        .zip(right)
+        .take_while(|(left_line, right_line)| left_line == right_line)
+        .count();
+
+    let suffix_length = left[prefix_length..]
+        .iter()
+        .rev()
+        .zip(right[prefix_length..].iter().rev())
+        .take_while(|(left_line, right_line)| left_line == right_line)
+        .count();
+
+    let left_middle = &left[prefix_length..left.len() - suffix_length];
+    let right_middle = &right[prefix_length..right.len() - suffix_length];
+
+    let mut rows: Vec<DiffRow> = left[..prefix_length].iter().map(|line| unchanged_row(line)).collect();
+
+    let mut removed: Vec<&String> = vec![];
+    let mut added: Vec<&String> = vec![];
+
+    if left_middle.len() * right_middle.len() > MAX_COMPARED_LINES {
+        removed.extend(left_middle);
+        added.extend(right_middle);
+    }
+    else {
+        // lcs[i][j] is the longest common subsequence length of left_middle[i..] and right_middle[j..]
+        let width = right_middle.len() + 1;
+        let mut lcs = vec![0u32; (left_middle.len() + 1) * width];
+
+        for i in (0..left_middle.len()).rev() {
+            for j in (0..right_middle.len()).rev() {
+                lcs[i * width + j] = match left_middle[i] == right_middle[j] {
+                    true => lcs[(i + 1) * width + j + 1] + 1,
+                    false => lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+
+        while i < left_middle.len() || j < right_middle.len() {
+            if i < left_middle.len() && j < right_middle.len() && left_middle[i] == right_middle[j] {
+                flush_changes(&mut rows, &mut removed, &mut added);
+                rows.push(unchanged_row(&left_middle[i]));
+                i += 1;
+                j += 1;
+            }
+            else if j == right_middle.len() || (i < left_middle.len() && lcs[(i + 1) * width + j] >= lcs[i * width + j + 1]) {
+                removed.push(&left_middle[i]);
+                i += 1;
+            }
+            else {
+                added.push(&right_middle[j]);
+                j += 1;
+            }
+        }
+    }
+
+    flush_changes(&mut rows, &mut removed, &mut added);
+
+    rows.extend(left[left.len() - suffix_length..].iter().map(|line| unchanged_row(line)));
+
+    rows
+}
+
+fn unchanged_row(line: &str) -> DiffRow {
+    DiffRow {
+        kind: DiffRowKind::Unchanged,
+        left: Some(line.to_string()),
+        right: Some(line.to_string()),
+    }
+}
+
+fn flush_changes(rows: &mut Vec<DiffRow>, removed: &mut Vec<&String>, added: &mut Vec<&String>) {
+    for index in 0..removed.len().max(added.len()) {
+        let left = removed.get(index).map(|line| line.to_string());
+        let right = added.get(index).map(|line| line.to_string());
+
+        let kind = match (&left, &right) {
+            (Some(_), Some(_)) => DiffRowKind::Changed,
+            (Some(_), None) => DiffRowKind::Removed,
+            _ => DiffRowKind::Added
+        };
+
+        rows.push(DiffRow {
+            kind,
+            left,
+            right,
+        });
+    }
+
+    removed.clear();
+    added.clear();
+}
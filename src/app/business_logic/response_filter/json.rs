@@ -0,0 +1,239 @@
+use jaq_core::load::{Arena, File, Loader};
+use jaq_core::{load, compile, Compiler, Ctx, RcIter};
+use jaq_json::Val;
+use serde_json::Value;
+use serde_json_path::JsonPath;
+
+use crate::app::business_logic::response_filter::ResponseFilterError;
+use crate::app::business_logic::response_filter::ResponseFilterError::{FilterFailed, InvalidFilter, NotJson};
+
+/// JSONPath filters start with `$` and give an array of every match, jq filters start with `.` and give each output on its own
+pub fn filter_json(body: &str, filter: &str) -> Result<String, ResponseFilterError> {
+    let values = select_json(body, filter)?;
+
+    let filtered_body = match filter.starts_with('$') {
+        true if values.is_empty() => String::new(),
+        true => serde_json::to_string_pretty(&Value::Array(values)).unwrap_or_default(),
+        false => values
+            .iter()
+            .map(|value| serde_json::to_string_pretty(value).unwrap_or_default())
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    Ok(filtered_body)
+}
+
+/// Every value matching a JSONPath (RFC 9535) filter, or every output of a jq filter
+pub fn select_json(body: &str, filter: &str) -> Result<Vec<Value>, ResponseFilterError> {
+    match filter.starts_with('$') {
+        true => select_json_path(body, filter),
+        false => select_jq(body, filter)
+    }
+}
+
+fn select_json_path(body: &str, filter: &str) -> Result<Vec<Value>, ResponseFilterError> {
+    let json_path = JsonPath::parse(filter).map_err(|error| InvalidFilter(filter.to_string(), error.to_string()))?;
+
+    let json_body = parse_json_body(body)?;
+
+    Ok(json_path.query(&json_body).all().into_iter().cloned().collect())
+}
+
+/// Run with jq's standard library, without any input besides the body
+fn select_jq(body: &str, filter: &str) -> Result<Vec<Value>, ResponseFilterError> {
+    let arena = Arena::default();
+    let loader = Loader::new(jaq_std::defs().chain(jaq_json::defs()));
+
+    let modules = loader
+        .load(&arena, File { code: filter, path: () })
+        .map_err(|errors| InvalidFilter(filter.to_string(), describe_jq_load_errors(errors)))?;
+
+    let jq_filter = Compiler::default()
+        .with_funs(jaq_std::funs().chain(jaq_json::funs()))
+        .compile(modules)
+        .map_err(|errors| InvalidFilter(filter.to_string(), describe_jq_compile_errors(errors)))?;
+
+    let json_body = parse_json_body(body)?;
+
+    let inputs = RcIter::new(core::iter::empty());
+
+    jq_filter
+        .run((Ctx::new([], &inputs), Val::from(json_body)))
+        .map(|output| output
+            .map(Value::from)
+            .map_err(|error| FilterFailed(filter.to_string(), error.to_string()))
+        )
+        .collect()
+}
+
+fn parse_json_body(body: &str) -> Result<Value, ResponseFilterError> {
+    serde_json::from_str(body).map_err(|error| NotJson(error.to_string()))
+}
+
+fn describe_jq_load_errors(errors: load::Errors<&str, ()>) -> String {
+    let mut descriptions = vec![];
+
+    for (_, error) in errors {
+        match error {
+            load::Error::Io(io_errors) => descriptions.extend(io_errors.into_iter().map(|(path, error)| format!("{path}: {error}"))),
+            load::Error::Lex(lex_errors) => descriptions.extend(lex_errors.into_iter().map(|(expected, found)| describe_unexpected(expected.as_str(), found))),
+            load::Error::Parse(parse_errors) => descriptions.extend(parse_errors.iter().map(|(expected, found)| describe_unexpected(expected.as_str(), found)))
+        }
+    }
+
+    descriptions.join(", ")
+}
+
+fn describe_jq_compile_errors(errors: compile::Errors<&str, ()>) -> String {
+    errors
+        .into_iter()
+        .flat_map(|(_, undefined)| undefined)
+        .map(|(name, undefined)| format!("undefined {} \"{name}\"", undefined.as_str()))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn describe_unexpected(expected: &str, found: &str) -> String {
+    match found.is_empty() {
+        true => format!("expected {expected}, found the end of the filter"),
+        false => format!("expected {expected}, found \"{found}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::app::business_logic::response_filter::json::{filter_json, select_json};
+    use crate::app::business_logic::response_filter::ResponseFilterError;
+
+    const BODY: &str = r#"{
+        "store": {
+            "name": "Books & co",
+            "books": [
+                { "title": "Dune", "author": "Herbert", "price": 8.99, "tags": ["sf"] },
+                { "title": "Emma", "author": "Austen", "price": 12, "available": false },
+                { "title": "Ulysses", "author": "Joyce", "price": 15.5, "available": true }
+            ]
+        },
+        "count": 3
+    }"#;
+
+    #[test]
+    fn select_json_path() {
+        let cases: Vec<(&str, Vec<Value>)> = vec![
+            ("$", vec![serde_json::from_str(BODY).unwrap()]),
+            ("$.count", vec![json!(3)]),
+            ("$.store.name", vec![json!("Books & co")]),
+            ("$['store']['name']", vec![json!("Books & co")]),
+            ("$[\"store\"].books[0].title", vec![json!("Dune")]),
+            ("$.store.books[-1].title", vec![json!("Ulysses")]),
+            ("$.store.books[0,2].title", vec![json!("Dune"), json!("Ulysses")]),
+            ("$.store.books[1:].title", vec![json!("Emma"), json!("Ulysses")]),
+            ("$.store.books[:1].title", vec![json!("Dune")]),
+            ("$.store.books[-2:].author", vec![json!("Austen"), json!("Joyce")]),
+            ("$.store.books[*].price", vec![json!(8.99), json!(12), json!(15.5)]),
+            ("$.store.books.*.title", vec![json!("Dune"), json!("Emma"), json!("Ulysses")]),
+            ("$..author", vec![json!("Herbert"), json!("Austen"), json!("Joyce")]),
+            ("$..tags[0]", vec![json!("sf")]),
+            ("$.store.books[?(@.price < 10)].title", vec![json!("Dune")]),
+            ("$.store.books[?(@.price >= 12)].title", vec![json!("Emma"), json!("Ulysses")]),
+            ("$.store.books[?(@.author == 'Austen')].price", vec![json!(12)]),
+            ("$.store.books[?(@.author != \"Austen\")].title", vec![json!("Dune"), json!("Ulysses")]),
+            // Existence test, as in RFC 9535
+            ("$.store.books[?(@.available)].title", vec![json!("Emma"), json!("Ulysses")]),
+            ("$.store.books[?@.available == true].title", vec![json!("Ulysses")]),
+            ("$.store.books[?(@.available == false)].title", vec![json!("Emma")]),
+            ("$.store.books[?(@.tags)].title", vec![json!("Dune")]),
+            ("$.missing", vec![]),
+            ("$.store.books[10]", vec![]),
+            ("$.count[0]", vec![]),
+        ];
+
+        for (filter, expected) in cases {
+            assert_eq!(select_json(BODY, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn select_jq() {
+        let cases: Vec<(&str, Vec<Value>)> = vec![
+            (".count", vec![json!(3)]),
+            (".store.books[1].title", vec![json!("Emma")]),
+            (".store.books[] | .title", vec![json!("Dune"), json!("Emma"), json!("Ulysses")]),
+            (".store.books[] | select(.price > 10) | .author", vec![json!("Austen"), json!("Joyce")]),
+            (".store.books[] | select(.title == \"Dune\") | .price", vec![json!(8.99)]),
+            (".store.books[] | select(.available) | .title", vec![json!("Ulysses")]),
+            (".store.books | length", vec![json!(3)]),
+            (".store.name | length", vec![json!(10)]),
+            (".store | keys", vec![json!(["books", "name"])]),
+            (".store.books | keys", vec![json!([0, 1, 2])]),
+            (".store | keys | length", vec![json!(2)]),
+            (".store.books[0].tags[0]?", vec![json!("sf")]),
+            (".. | .title? // empty", vec![json!("Dune"), json!("Emma"), json!("Ulysses")]),
+            (".store.books[].price | select(. < 13)", vec![json!(8.99), json!(12)]),
+            (".missing", vec![Value::Null]),
+            (".store.books[] | .tags[]?", vec![json!("sf")]),
+            (".store.books | map(.price) | add", vec![json!(36.49)]),
+        ];
+
+        for (filter, expected) in cases {
+            assert_eq!(select_json(BODY, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn filter_json_output() {
+        let cases = [
+            ("$.store.books[0].title", "[\n  \"Dune\"\n]"),
+            ("$.missing", ""),
+            (".store.books[].title", "\"Dune\"\n\"Emma\"\n\"Ulysses\""),
+            (".store.books[0].tags", "[\n  \"sf\"\n]"),
+        ];
+
+        for (filter, expected) in cases {
+            assert_eq!(filter_json(BODY, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn invalid_filters() {
+        let filters = [
+            "$.store.books[",
+            "$.store.books[0",
+            "$.store.books[abc]",
+            "$.store.books[?(@.price < )]",
+            "$.store.books[?(@.price < 10]",
+            "$['unterminated]",
+            "$.store )",
+            "store",
+            ".store.books[] | unknown",
+            ".store.books[] | select(.price > 10",
+            ".store.books[] | .title)",
+        ];
+
+        for filter in filters {
+            assert!(matches!(select_json(BODY, filter), Err(ResponseFilterError::InvalidFilter(..))), "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn failing_filters() {
+        let filters = [
+            ".count | keys",
+            ".store.name[0]",
+            "error(\"stop\")",
+        ];
+
+        for filter in filters {
+            assert!(matches!(select_json(BODY, filter), Err(ResponseFilterError::FilterFailed(..))), "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn invalid_body() {
+        assert!(matches!(select_json("<xml/>", "$.a"), Err(ResponseFilterError::NotJson(_))));
+        assert!(matches!(select_json("", ".a"), Err(ResponseFilterError::NotJson(_))));
+    }
+}
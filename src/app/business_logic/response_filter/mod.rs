@@ -0,0 +1,142 @@
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::app::business_logic::response_filter::ResponseFilterError::{EmptyFilter, NoMatch};
+
+pub mod json;
+pub mod xml;
+
+#[derive(Error, Debug)]
+pub enum ResponseFilterError {
+    #[error("The filter is empty")]
+    EmptyFilter,
+    #[error("Invalid filter \"{0}\": {1}")]
+    InvalidFilter(String, String),
+    #[error("The response body is not valid JSON\n\t{0}")]
+    NotJson(String),
+    #[error("The response body is not valid XML\n\t{0}")]
+    NotXml(String),
+    #[error("Nothing matches \"{0}\"")]
+    NoMatch(String),
+    #[error("Filter \"{0}\" failed: {1}")]
+    FilterFailed(String, String),
+}
+
+/// Part of a response body kept by a filter
+pub struct FilteredBody {
+    pub content: String,
+    /// Used to highlight the filtered content
+    pub extension: &'static str,
+}
+
+/// Apply a JSONPath (RFC 9535, e.g. `$.items[0].name`) or jq (e.g. `.items[] | .name`) filter to a JSON body,
+/// or an XPath 1.0 (e.g. `//item/@name`) filter to an XML body
+pub fn filter_response_body(body: &str, filter: &str) -> Result<FilteredBody, ResponseFilterError> {
+    let filter = filter.trim();
+
+    if filter.is_empty() {
+        return Err(EmptyFilter);
+    }
+
+    let filtered_body = match is_xpath_filter(body, filter) {
+        true => FilteredBody {
+            content: xml::filter_xml(body, filter)?,
+            extension: "xml",
+        },
+        false => FilteredBody {
+            content: json::filter_json(body, filter)?,
+            extension: "json",
+        }
+    };
+
+    if filtered_body.content.is_empty() {
+        return Err(NoMatch(filter.to_string()));
+    }
+
+    Ok(filtered_body)
+}
+
+/// First non-null value matching the filter, JSON strings being unquoted. jq gives null for missing keys, which is no match here
+pub fn select_response_body_value(body: &str, filter: &str) -> Result<String, ResponseFilterError> {
+    let filter = filter.trim();
+
+    if filter.is_empty() {
+        return Err(EmptyFilter);
+    }
+
+    let value = match is_xpath_filter(body, filter) {
+        true => xml::select_xml(body, filter)?.into_iter().next(),
+        false => json::select_json(body, filter)?
+            .into_iter()
+            .find(|value| !value.is_null())
+            .map(|value| match value {
+                Value::String(string) => string,
+                value => value.to_string()
+            })
+    };
+
+    value.ok_or_else(|| NoMatch(filter.to_string()))
+}
+
+/// Paths tell the language apart, other expressions (e.g. `count(//item)` or `keys`) are given to the one of the body
+fn is_xpath_filter(body: &str, filter: &str) -> bool {
+    match filter.chars().next() {
+        Some('/') => true,
+        Some('$' | '.') => false,
+        _ => body.trim_start().starts_with('<')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::business_logic::response_filter::{filter_response_body, select_response_body_value, ResponseFilterError};
+
+    const JSON_BODY: &str = r#"{ "id": 7, "name": "atac", "tags": ["a", "b"] }"#;
+    const XML_BODY: &str = r#"<user id="7"><name>atac</name></user>"#;
+
+    #[test]
+    fn filter_body() {
+        let cases = [
+            (JSON_BODY, "$.name", "[\n  \"atac\"\n]", "json"),
+            (JSON_BODY, "  .tags[1]  ", "\"b\"", "json"),
+            (XML_BODY, "/user/name", "<name>atac</name>", "xml"),
+            (XML_BODY, " //@id ", "7", "xml"),
+            (XML_BODY, "count(//name)", "1", "xml"),
+            (JSON_BODY, "keys", "[\n  \"id\",\n  \"name\",\n  \"tags\"\n]", "json"),
+        ];
+
+        for (body, filter, content, extension) in cases {
+            let filtered_body = filter_response_body(body, filter).unwrap();
+
+            assert_eq!(filtered_body.content, content, "filter: {filter}");
+            assert_eq!(filtered_body.extension, extension, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn select_value() {
+        let cases = [
+            (JSON_BODY, "$.id", "7"),
+            (JSON_BODY, ".name", "atac"),
+            (JSON_BODY, ".tags", "[\"a\",\"b\"]"),
+            (JSON_BODY, "$.tags[*]", "a"),
+            (XML_BODY, "/user/name/text()", "atac"),
+            (XML_BODY, "/user/@id", "7"),
+        ];
+
+        for (body, filter, expected) in cases {
+            assert_eq!(select_response_body_value(body, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(filter_response_body(JSON_BODY, "  "), Err(ResponseFilterError::EmptyFilter)));
+        assert!(matches!(select_response_body_value(JSON_BODY, ""), Err(ResponseFilterError::EmptyFilter)));
+        assert!(matches!(filter_response_body(JSON_BODY, "$.missing"), Err(ResponseFilterError::NoMatch(_))));
+        assert!(matches!(select_response_body_value(XML_BODY, "//missing"), Err(ResponseFilterError::NoMatch(_))));
+        assert!(matches!(select_response_body_value(JSON_BODY, ".missing"), Err(ResponseFilterError::NoMatch(_))));
+        assert!(matches!(filter_response_body(JSON_BODY, "/user"), Err(ResponseFilterError::NotXml(_))));
+        assert!(matches!(filter_response_body(XML_BODY, ".user"), Err(ResponseFilterError::NotJson(_))));
+    }
+}
@@ -0,0 +1,236 @@
+use sxd_document::dom::{ChildOfElement, Element};
+use sxd_document::parser;
+use sxd_xpath::nodeset::Node;
+use sxd_xpath::{Context, Factory, Value};
+
+use crate::app::business_logic::response_filter::ResponseFilterError;
+use crate::app::business_logic::response_filter::ResponseFilterError::{FilterFailed, InvalidFilter, NotXml};
+
+/// e.g. `//book[@lang='en']/title`, `/catalog/book[2]/@id`, `//title/text()`, `count(//book)`
+pub fn filter_xml(body: &str, filter: &str) -> Result<String, ResponseFilterError> {
+    Ok(select_xml(body, filter)?.join("\n"))
+}
+
+/// Every element (serialized), attribute value or text matching an XPath 1.0 filter, or the value of a number, string or boolean expression.
+/// Namespaced elements are matched with `local-name()`, e.g. `//*[local-name()='item']`, since no prefix can be bound
+pub fn select_xml(body: &str, filter: &str) -> Result<Vec<String>, ResponseFilterError> {
+    let xpath = Factory::new()
+        .build(filter)
+        .map_err(|error| InvalidFilter(filter.to_string(), error.to_string()))?
+        .ok_or_else(|| InvalidFilter(filter.to_string(), String::from("empty expression")))?;
+
+    let package = parser::parse(body).map_err(|error| NotXml(error.to_string()))?;
+    let document = package.as_document();
+
+    let value = xpath
+        .evaluate(&Context::new(), document.root())
+        .map_err(|error| FilterFailed(filter.to_string(), error.to_string()))?;
+
+    let selected = match value {
+        Value::Nodeset(nodes) => nodes
+            .document_order()
+            .into_iter()
+            .map(|node| match node {
+                Node::Element(element) => {
+                    let mut serialized = String::new();
+                    serialize_element(element, 0, &mut serialized);
+                    serialized.trim_end().to_string()
+                },
+                node => node.string_value()
+            })
+            .collect(),
+        value => vec![value.into_string()]
+    };
+
+    Ok(selected)
+}
+
+/// Two spaces indented, texts only children being kept on the same line. Whitespace-only texts, comments and processing instructions are left out
+fn serialize_element(element: Element, depth: usize, output: &mut String) {
+    let indentation = "  ".repeat(depth);
+    let name = qualified_name(element.preferred_prefix(), element.name().local_part());
+
+    output.push_str(&indentation);
+    output.push('<');
+    output.push_str(&name);
+
+    for attribute in element.attributes() {
+        let attribute_name = qualified_name(attribute.preferred_prefix(), attribute.name().local_part());
+        output.push_str(&format!(" {attribute_name}=\"{}\"", escape(attribute.value())));
+    }
+
+    let children: Vec<ChildOfElement> = element
+        .children()
+        .into_iter()
+        .filter(|child| match child {
+            ChildOfElement::Element(_) => true,
+            ChildOfElement::Text(text) => !text.text().trim().is_empty(),
+            ChildOfElement::Comment(_) | ChildOfElement::ProcessingInstruction(_) => false
+        })
+        .collect();
+
+    if children.is_empty() {
+        output.push_str("/>\n");
+        return;
+    }
+
+    output.push('>');
+
+    let is_text_only = children.iter().all(|child| matches!(child, ChildOfElement::Text(_)));
+
+    if is_text_only {
+        let text: String = children.iter().filter_map(|child| child.text()).map(|text| text.text()).collect();
+        output.push_str(&escape(text.trim()));
+    }
+    else {
+        output.push('\n');
+
+        for child in children {
+            match child {
+                ChildOfElement::Element(child_element) => serialize_element(child_element, depth + 1, output),
+                ChildOfElement::Text(text) => {
+                    output.push_str(&"  ".repeat(depth + 1));
+                    output.push_str(&escape(text.text().trim()));
+                    output.push('\n');
+                },
+                _ => {}
+            }
+        }
+
+        output.push_str(&indentation);
+    }
+
+    output.push_str(&format!("</{name}>\n"));
+}
+
+fn qualified_name(prefix: Option<&str>, local_part: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}:{local_part}"),
+        None => local_part.to_string()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::business_logic::response_filter::xml::{filter_xml, select_xml};
+    use crate::app::business_logic::response_filter::ResponseFilterError;
+
+    const BODY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <catalog name="Books &amp; co">
+            <book id="1" lang="en">
+                <title>Dune</title>
+                <price>8.99</price>
+            </book>
+            <book id="2" lang="fr">
+                <title>Madame Bovary</title>
+                <price>12</price>
+            </book>
+            <book id="3" lang="en" available="true">
+                <title><![CDATA[Ulysses & more]]></title>
+                <price>15.5</price>
+            </book>
+            <empty/>
+        </catalog>"#;
+
+    #[test]
+    fn select_xpath() {
+        let cases: Vec<(&str, Vec<&str>)> = vec![
+            ("/catalog/@name", vec!["Books & co"]),
+            ("/catalog/book/title/text()", vec!["Dune", "Madame Bovary", "Ulysses & more"]),
+            ("//title/text()", vec!["Dune", "Madame Bovary", "Ulysses & more"]),
+            ("/catalog/book[2]/@id", vec!["2"]),
+            ("/catalog/book[last()]/@id", vec!["3"]),
+            ("/catalog/book[4]/@id", vec![]),
+            ("//book[@lang='en']/@id", vec!["1", "3"]),
+            ("//book[@lang!='en']/title/text()", vec!["Madame Bovary"]),
+            ("//book[@available]/@id", vec!["3"]),
+            ("//book[title=\"Dune\"]/price/text()", vec!["8.99"]),
+            ("//book[price=12]/@id", vec!["2"]),
+            ("//title[text()='Dune']/text()", vec!["Dune"]),
+            ("//title[.='Dune']/text()", vec!["Dune"]),
+            ("//book[@lang='en'][2]/@id", vec!["3"]),
+            ("/catalog/*/@id", vec!["1", "2", "3"]),
+            ("count(/catalog/book[1]/@*)", vec!["2"]),
+            ("count(//book)", vec!["3"]),
+            ("sum(//price)", vec!["36.49"]),
+            ("boolean(//book[@lang='de'])", vec!["false"]),
+            ("/catalog/book[0]/@id", vec![]),
+            ("/catalog/book[1]/./@lang", vec!["en"]),
+            ("//missing", vec![]),
+            ("/book", vec![]),
+            ("/catalog/empty", vec!["<empty/>"]),
+            ("/catalog/book[1]/title", vec!["<title>Dune</title>"]),
+        ];
+
+        for (filter, expected) in cases {
+            assert_eq!(select_xml(BODY, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn filter_xml_output() {
+        let cases = [
+            ("/catalog/book[1]", "<book id=\"1\" lang=\"en\">\n  <title>Dune</title>\n  <price>8.99</price>\n</book>"),
+            ("//book[@id='3']/title", "<title>Ulysses &amp; more</title>"),
+            ("//book/@id", "1\n2\n3"),
+            ("//missing", ""),
+        ];
+
+        for (filter, expected) in cases {
+            assert_eq!(filter_xml(BODY, filter).unwrap(), expected, "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn invalid_filters() {
+        let filters = [
+            "",
+            "//",
+            "/catalog//",
+            "/catalog/book[",
+            "/catalog/book[@lang='en'",
+            "/catalog/book[=1]",
+            "/catalog/book[@]",
+        ];
+
+        for filter in filters {
+            assert!(matches!(select_xml(BODY, filter), Err(ResponseFilterError::InvalidFilter(..))), "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn failing_filters() {
+        let filters = [
+            "unknown(//book)",
+            "count(1)",
+            "$undefined",
+        ];
+
+        for filter in filters {
+            assert!(matches!(select_xml(BODY, filter), Err(ResponseFilterError::FilterFailed(..))), "filter: {filter}");
+        }
+    }
+
+    #[test]
+    fn invalid_body() {
+        let bodies = [
+            "",
+            "not xml",
+            "<a><b></a>",
+            "<a>",
+            "</a>",
+        ];
+
+        for body in bodies {
+            assert!(matches!(select_xml(body, "/a"), Err(ResponseFilterError::NotXml(_))), "body: {body}");
+        }
+    }
+}
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use tracing::{info, trace, warn};
 
 use crate::app::app::App;
+use crate::app::files::graphql_schema::load_graphql_schemas;
 use crate::cli::args::ARGS;
 use crate::panic_error;
 use crate::models::collection::{Collection, CollectionFileFormat};
@@ -40,6 +41,9 @@ impl App<'_> {
 
         collection.path = path_buf;
         collection.file_format = file_format;
+        collection.create_missing_folders();
+
+        load_graphql_schemas(&collection);
 
         self.collections.push(collection);
 
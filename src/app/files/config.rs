@@ -33,6 +33,10 @@ pub struct Config {
     /// Should wrap response without overflowing in the response area
     pub wrap_responses: Option<bool>,
 
+    #[serde(default)]
+    /// Number of past responses kept per request, 0 disables the response history
+    pub response_history_size: Option<usize>,
+
     #[serde(default)]
     /// Should use either JSON or YAML as preferred collection file format
     pub preferred_collection_file_format: Option<CollectionFileFormat>,
@@ -42,7 +46,7 @@ pub struct Config {
     pub proxy: Option<Proxy>
 }
 
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Proxy {
     pub http_proxy: Option<String>,
     pub https_proxy: Option<String>,
@@ -63,6 +67,10 @@ impl Config {
         });
     }
 
+    pub fn get_response_history_size(&self) -> usize {
+        self.response_history_size.unwrap_or(20)
+    }
+
     pub fn is_image_preview_disabled(&self) -> bool {
         self.disable_images_preview.unwrap_or(false)
     }
@@ -152,6 +160,10 @@ impl App<'_> {
             self.config.wrap_responses = global_config.wrap_responses;
         }
 
+        if self.config.response_history_size.is_none() {
+            self.config.response_history_size = global_config.response_history_size;
+        }
+
         if self.config.preferred_collection_file_format.is_none() {
             self.config.preferred_collection_file_format = global_config.preferred_collection_file_format;
         }
@@ -0,0 +1,109 @@
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use cookie_store::{Cookie, CookieStore};
+use tracing::{trace, warn};
+
+use crate::cli::args::ARGS;
+
+/// Directory inside the app directory, ignored when the collections are loaded
+const COOKIES_DIRECTORY: &str = ".cookies";
+
+/// Jar used when no environment is loaded
+const DEFAULT_COOKIE_JAR: &str = "default";
+
+/// One jar per environment, e.g. .cookies/my_env.json for .env.my_env
+pub fn get_cookie_jar_path(environment_name: Option<&str>) -> Option<PathBuf> {
+    if !ARGS.should_persist_cookies {
+        return None;
+    }
+
+    let directory = ARGS.directory.as_ref()?;
+    let jar_name = environment_name.unwrap_or(DEFAULT_COOKIE_JAR);
+
+    Some(directory.join(COOKIES_DIRECTORY).join(format!("{jar_name}.json")))
+}
+
+/// The jars are stored in plain text, the directory ignores itself so that they are not committed along the collections
+fn create_cookies_directory(directory: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(directory)?;
+
+    let gitignore_path = directory.join(".gitignore");
+
+    if !gitignore_path.exists() {
+        fs::write(gitignore_path, "*\n")?;
+    }
+
+    Ok(())
+}
+
+/// A missing or unreadable jar gives an empty store, expired cookies are dropped while loading
+pub fn load_cookie_jar(path: &Path) -> CookieStore {
+    if !path.exists() {
+        return CookieStore::default();
+    }
+
+    trace!("Loading cookie jar \"{}\"", path.display());
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(error) => {
+            warn!("Could not read cookie jar \"{}\": {error}", path.display());
+            return CookieStore::default();
+        }
+    };
+
+    match cookie_store::serde::json::load(BufReader::new(file)) {
+        Ok(cookie_store) => cookie_store,
+        Err(error) => {
+            warn!("Could not parse cookie jar \"{}\": {error}", path.display());
+            CookieStore::default()
+        }
+    }
+}
+
+/// Session cookies are kept too, so that a login survives a restart, but not the expired ones
+pub fn save_cookie_jar(path: &Path, cookie_store: &CookieStore) {
+    if !ARGS.should_save {
+        warn!("Dry-run, not saving the cookie jar");
+        return;
+    }
+
+    // Avoid creating a file for every environment that never received a cookie
+    if !path.exists() && cookie_store.iter_any().next().is_none() {
+        return;
+    }
+
+    if let Some(parent) = path.parent() && let Err(error) = create_cookies_directory(parent) {
+        warn!("Could not create cookies directory \"{}\": {error}", parent.display());
+        return;
+    }
+
+    // Expired cookies would pile up otherwise
+    let cookies: Vec<&Cookie> = cookie_store.iter_unexpired().collect();
+
+    let cookies_stringed = match serde_json::to_string_pretty(&cookies) {
+        Ok(cookies_stringed) => cookies_stringed,
+        Err(error) => {
+            warn!("Could not save cookie jar \"{}\": {error}", path.display());
+            return;
+        }
+    };
+
+    // Written to a temporary file first, so that an interrupted write never leaves a truncated jar behind
+    let temporary_path = path.with_extension("tmp");
+
+    if let Err(error) = fs::write(&temporary_path, cookies_stringed) {
+        warn!("Could not save cookie jar \"{}\": {error}", path.display());
+        return;
+    }
+
+    if let Err(error) = fs::rename(&temporary_path, path) {
+        warn!("Could not save cookie jar \"{}\": {error}", path.display());
+        return;
+    }
+
+    trace!("Cookie jar saved");
+}
@@ -0,0 +1,109 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use thiserror::Error;
+use tracing::{trace, warn};
+
+use crate::app::files::utils::sanitize_file_stem;
+use crate::cli::args::ARGS;
+use crate::models::collection::Collection;
+use crate::models::protocol::graphql::schema::GraphqlSchema;
+use crate::models::protocol::protocol::Protocol;
+
+#[derive(Error, Debug)]
+pub enum GraphqlSchemaFileError {
+    #[error("Could not create GraphQL schema directory \"{0}\"\n\t{1}")]
+    CouldNotCreateDirectory(String, String),
+    #[error("Could not write GraphQL schema file \"{0}\"\n\t{1}")]
+    CouldNotWriteFile(String, String),
+}
+
+/// Introspection results are cached in a "<collection file stem>.graphql" directory next to the collection file
+pub fn graphql_schema_path(collection_path: &Path, request_name: &str) -> PathBuf {
+    let collection_stem = collection_path.file_stem().unwrap_or_default().to_string_lossy();
+
+    // Request names may contain characters that are not allowed in file names, e.g. "/"
+    let file_stem = sanitize_file_stem(request_name);
+
+    collection_path
+        .with_file_name(format!("{collection_stem}.graphql"))
+        .join(format!("{file_stem}.json"))
+}
+
+/// Save the raw introspection result in the schema cache
+pub fn save_graphql_schema_to_file(collection_path: &Path, request_name: &str, introspection: &Value) -> Result<(), GraphqlSchemaFileError> {
+    if !ARGS.should_save {
+        warn!("Dry-run, not saving the GraphQL schema");
+        return Ok(());
+    }
+
+    let path = graphql_schema_path(collection_path, request_name);
+
+    trace!("Saving GraphQL schema to \"{}\"", path.display());
+
+    if let Some(parent) = path.parent() && let Err(e) = fs::create_dir_all(parent) {
+        return Err(GraphqlSchemaFileError::CouldNotCreateDirectory(parent.display().to_string(), e.to_string()));
+    }
+
+    let introspection_stringed = introspection.to_string();
+
+    if let Err(e) = fs::write(&path, introspection_stringed) {
+        return Err(GraphqlSchemaFileError::CouldNotWriteFile(path.display().to_string(), e.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Follow the request renaming, the cache would be orphaned otherwise
+pub fn rename_graphql_schema_file(collection_path: &Path, old_request_name: &str, new_request_name: &str) {
+    if !ARGS.should_save {
+        return;
+    }
+
+    let old_path = graphql_schema_path(collection_path, old_request_name);
+
+    if !old_path.exists() {
+        return;
+    }
+
+    let new_path = graphql_schema_path(collection_path, new_request_name);
+
+    if let Err(e) = fs::rename(&old_path, &new_path) {
+        warn!("Could not rename GraphQL schema file \"{}\": {e}", old_path.display());
+    }
+}
+
+pub fn delete_graphql_schema_file(collection_path: &Path, request_name: &str) {
+    if !ARGS.should_save {
+        return;
+    }
+
+    let path = graphql_schema_path(collection_path, request_name);
+
+    if path.exists() && let Err(e) = fs::remove_file(&path) {
+        warn!("Could not delete GraphQL schema file \"{}\": {e}", path.display());
+    }
+}
+
+/// Load the cached schema of every GraphQL request of the collection, if any
+pub fn load_graphql_schemas(collection: &Collection) {
+    for request in &collection.requests {
+        let mut request = request.write();
+        let path = graphql_schema_path(&collection.path, &request.name);
+
+        let Protocol::GraphqlRequest(graphql_request) = &mut request.protocol else {
+            continue;
+        };
+
+        let Ok(file_content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        trace!("Loading GraphQL schema \"{}\"", path.display());
+
+        graphql_request.schema = serde_json::from_str::<Value>(&file_content)
+            .ok()
+            .and_then(|introspection| GraphqlSchema::from_introspection(&introspection));
+    }
+}
@@ -0,0 +1,200 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{trace, warn};
+
+use crate::app::files::utils::sanitize_file_stem;
+use crate::cli::args::ARGS;
+use crate::models::collection::Collection;
+use crate::models::history::{DependencyTimestamps, ResponseHistoryEntry};
+
+/// Directory next to the collection files, ignored when the collections are loaded
+const HISTORY_DIRECTORY: &str = ".history";
+
+lazy_static! {
+    /// Held from the loading to the saving of a history directory file, responses may be recorded from several tasks at once
+    static ref HISTORY_DIRECTORY_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// One file per request, its folders being sub-directories, e.g. .history/my_collection/my_folder/my_request.json for my_collection.yaml.
+/// Only the request's own file is rewritten when one of its responses is recorded
+pub fn get_response_history_path(collection: &Collection, folder: &[String], request_name: &str) -> PathBuf {
+    get_response_history_folder_path(collection, folder).join(format!("{}.json", sanitize_file_stem(request_name)))
+}
+
+/// Directory holding the response history files of a folder's requests, e.g. .history/my_collection/my_folder
+pub fn get_response_history_folder_path(collection: &Collection, folder: &[String]) -> PathBuf {
+    let file_stem = collection.path.file_stem().unwrap_or_default();
+    let mut path = collection.path.with_file_name(HISTORY_DIRECTORY).join(file_stem);
+
+    for folder_name in folder {
+        path.push(sanitize_file_stem(folder_name));
+    }
+
+    path
+}
+
+/// Sidecar file of the collection, e.g. .history/my_collection.dependencies.json for my_collection.yaml
+pub fn get_dependency_timestamps_path(collection: &Collection) -> PathBuf {
+    get_history_directory_file_path(collection, "dependencies.json")
+}
+
+fn get_history_directory_file_path(collection: &Collection, extension: &str) -> PathBuf {
+    let file_stem = collection.path.file_stem().unwrap_or_default().to_string_lossy();
+
+    collection.path
+        .with_file_name(HISTORY_DIRECTORY)
+        .join(format!("{file_stem}.{extension}"))
+}
+
+/// A missing or unreadable history file gives an empty history
+pub fn load_response_history(path: &Path) -> Vec<ResponseHistoryEntry> {
+    trace!("Loading response history \"{}\"", path.display());
+
+    load_history_directory_file(path)
+}
+
+/// Loads, modifies and saves the request's history, the modification tells whether there is something to save
+pub fn update_response_history(path: &Path, modify: impl FnOnce(&mut Vec<ResponseHistoryEntry>) -> bool) {
+    let _lock = HISTORY_DIRECTORY_LOCK.lock();
+
+    let mut history = load_response_history(path);
+
+    if !modify(&mut history) {
+        return;
+    }
+
+    if !ARGS.should_save {
+        warn!("Dry-run, not saving the response history");
+        return;
+    }
+
+    save_history_directory_file(path, &history);
+
+    trace!("Response history saved");
+}
+
+pub fn delete_response_history(path: &Path) {
+    let _lock = HISTORY_DIRECTORY_LOCK.lock();
+
+    if !ARGS.should_save || !path.exists() {
+        return;
+    }
+
+    let deletion = match path.is_dir() {
+        true => fs::remove_dir_all(path),
+        false => fs::remove_file(path)
+    };
+
+    if let Err(error) = deletion {
+        warn!("Could not delete response history \"{}\": {error}", path.display());
+    }
+}
+
+/// Follow the renaming or moving of a request (file) or of a folder (directory), the history would be orphaned otherwise
+pub fn move_response_history(old_path: &Path, new_path: &Path) {
+    let _lock = HISTORY_DIRECTORY_LOCK.lock();
+
+    if !ARGS.should_save || !old_path.exists() || old_path == new_path {
+        return;
+    }
+
+    if let Some(parent) = new_path.parent() && !create_history_directory(parent) {
+        return;
+    }
+
+    if let Err(error) = fs::rename(old_path, new_path) {
+        warn!("Could not move response history \"{}\": {error}", old_path.display());
+    }
+}
+
+/// A missing or unreadable file gives no timestamps, the dependencies are then sent again
+pub fn load_dependency_timestamps(path: &Path) -> DependencyTimestamps {
+    trace!("Loading dependency timestamps \"{}\"", path.display());
+
+    load_history_directory_file(path)
+}
+
+pub fn update_dependency_timestamps(path: &Path, modify: impl FnOnce(&mut DependencyTimestamps)) {
+    let _lock = HISTORY_DIRECTORY_LOCK.lock();
+
+    let mut timestamps = load_dependency_timestamps(path);
+    modify(&mut timestamps);
+
+    if !ARGS.should_save {
+        warn!("Dry-run, not saving the dependency timestamps");
+        return;
+    }
+
+    save_history_directory_file(path, &timestamps);
+
+    trace!("Dependency timestamps saved");
+}
+
+fn load_history_directory_file<T: DeserializeOwned + Default>(path: &Path) -> T {
+    if !path.exists() {
+        return T::default();
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => {
+            warn!("Could not read \"{}\": {error}", path.display());
+            return T::default();
+        }
+    };
+
+    match serde_json::from_str(&content) {
+        Ok(value) => value,
+        Err(error) => {
+            warn!("Could not parse \"{}\": {error}", path.display());
+            T::default()
+        }
+    }
+}
+
+/// Written to a temporary file first, so that an interrupted write never leaves a truncated file behind
+fn save_history_directory_file<T: Serialize>(path: &Path, value: &T) {
+    if let Some(parent) = path.parent() && !create_history_directory(parent) {
+        return;
+    }
+
+    let value_stringed = serde_json::to_string(value).expect("Could not serialize history file to JSON");
+
+    let temporary_path = path.with_extension("tmp");
+
+    if let Err(error) = fs::write(&temporary_path, value_stringed) {
+        warn!("Could not save \"{}\": {error}", path.display());
+        return;
+    }
+
+    if let Err(error) = fs::rename(&temporary_path, path) {
+        warn!("Could not save \"{}\": {error}", path.display());
+    }
+}
+
+/// Also ignores the whole history directory from version control, the responses may hold secrets
+fn create_history_directory(path: &Path) -> bool {
+    if let Err(error) = fs::create_dir_all(path) {
+        warn!("Could not create history directory \"{}\": {error}", path.display());
+        return false;
+    }
+
+    let history_directory = path
+        .ancestors()
+        .find(|ancestor| ancestor.file_name().is_some_and(|name| name == HISTORY_DIRECTORY));
+
+    if let Some(history_directory) = history_directory {
+        let gitignore_path = history_directory.join(".gitignore");
+
+        if !gitignore_path.exists() && let Err(error) = fs::write(&gitignore_path, "*\n") {
+            warn!("Could not create \"{}\": {error}", gitignore_path.display());
+        }
+    }
+
+    true
+}
@@ -32,6 +32,9 @@ nest! {
 
             pub move_request_up: KeyCombination,
             pub move_request_down: KeyCombination,
+            pub move_element_to_folder: KeyCombination,
+
+            pub run_collection: KeyCombination,
 
             pub next_environment: KeyCombination,
 
@@ -95,6 +98,8 @@ nest! {
             pub change_method: KeyCombination,
             pub request_settings: KeyCombination,
             pub export_request: KeyCombination,
+            pub response_history: KeyCombination,
+            pub diff_environments: KeyCombination,
 
             pub next_view: KeyCombination,
 
@@ -105,6 +110,9 @@ nest! {
                 pub change_auth_method: KeyCombination,
                 pub change_body_content_type: KeyCombination,
                 pub change_message_type: KeyCombination,
+                pub introspect_graphql_schema: KeyCombination,
+                pub complete_graphql_field: KeyCombination,
+                pub list_grpc_methods: KeyCombination,
             },
 
             pub result_tabs: #[derive(Copy, Clone, Deserialize)] pub struct ResultTabs {
@@ -114,6 +122,7 @@ nest! {
                 pub scroll_right: KeyCombination,
 
                 pub yank_response_part: KeyCombination,
+                pub filter_response_body: KeyCombination,
 
                 /// Will use param_next_tab depending on the selected view
                 pub result_next_tab: KeyCombination,
@@ -165,6 +174,9 @@ impl Default for KeyBindings {
 
                 move_request_up: key!(ctrl-up),
                 move_request_down: key!(ctrl-down),
+                move_element_to_folder: key!(m),
+
+                run_collection: key!(shift-R),
 
                 next_environment: key!(e),
 
@@ -215,6 +227,8 @@ impl Default for KeyBindings {
 
                 request_settings: key!(s),
                 export_request: key!(shift-E),
+                response_history: key!(shift-H),
+                diff_environments: key!(shift-D),
                 
                 next_view: key!(v),
 
@@ -227,6 +241,9 @@ impl Default for KeyBindings {
                     change_auth_method: key!(ctrl-a),
                     change_body_content_type: key!(ctrl-b),
                     change_message_type: key!(ctrl-m),
+                    introspect_graphql_schema: key!(ctrl-g),
+                    complete_graphql_field: key!(ctrl-space),
+                    list_grpc_methods: key!(ctrl-g),
                 },
                 result_tabs: ResultTabs {
                     scroll_up: key!(ctrl-up),
@@ -235,6 +252,7 @@ impl Default for KeyBindings {
                     scroll_right: key!(ctrl-right),
 
                     yank_response_part: key!(y),
+                    filter_response_body: key!(f),
 
                     result_next_tab: key!(shift-backtab),
                 },
@@ -1,5 +1,8 @@
 pub mod collection;
 pub mod environment;
+pub mod graphql_schema;
+pub mod history;
+pub mod cookies;
 pub mod config;
 pub mod key_bindings;
 pub mod theme;
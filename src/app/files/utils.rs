@@ -14,4 +14,14 @@ pub fn expand_tilde(path_buf: PathBuf) -> PathBuf {
         },
         None => panic!("No home directory found when trying to expand \"~\"")
     }
-}
\ No newline at end of file
+}
+/// Replace the characters that are not allowed in file names, e.g. "/", by "_"
+pub fn sanitize_file_stem(name: &str) -> String {
+    name
+        .chars()
+        .map(|character| match character.is_alphanumeric() || matches!(character, '-' | '_' | ' ' | '.') {
+            true => character,
+            false => '_'
+        })
+        .collect()
+}
@@ -53,6 +53,7 @@ impl<'a> App<'a> {
 
         if ARGS.should_parse_directory {
             self.parse_app_directory();
+            self.load_cookie_jar();
         }
 
         if let Some(command) = &ARGS.command {
@@ -10,9 +10,11 @@ use regex::Regex;
 use crate::cli::commands::collection_commands::collection_commands::CollectionCommand;
 use crate::cli::commands::completions::CompletionsCommand;
 use crate::cli::commands::import::ImportCommand;
+use crate::cli::commands::export::ExportCommand;
 use crate::cli::commands::request_commands::request_commands::RequestCommand;
 use crate::app::files::utils::expand_tilde;
 use crate::cli::commands::env::EnvCommand;
+use crate::cli::commands::cookies::CookiesCommand;
 use crate::cli::commands::man::ManCommand;
 use crate::cli::commands::try_command::TryCommand;
 use crate::panic_error;
@@ -46,6 +48,12 @@ pub struct Args {
     #[arg(long, global = true, default_value_t = false, display_order = 99)]
     pub dry_run: bool,
 
+    /// Keep the cookies in memory only, instead of loading and saving the environment cookie jar
+    ///
+    /// The jars are saved in plain text, e.g. .cookies/my_env.json in the app directory, whenever a response sets cookies. The .cookies directory contains a .gitignore so that they are not committed
+    #[arg(long, global = true, default_value_t = false, display_order = 99)]
+    pub no_cookie_persist: bool,
+
     /// Avoid using ANSI format for log file/output
     #[arg(long, global = true, default_value_t = false)]
     pub no_ansi_log: bool,
@@ -74,6 +82,8 @@ atac
       - auth
       - headers
       - body
+      - graphql
+      - grpc
       - scripts
       - send
       - settings
@@ -86,10 +96,23 @@ atac
           - set
           - delete
           - rename
+  - cookies
+      - list
+      - clear
+      - delete
   - import
       - postman
       - curl
       - openapi
+      - insomnia
+      - bruno
+      - har
+      - http
+      - cookies
+  - export
+      - cookies
+      - har
+      - http
  - completions
       - bash, powershell, fish, zsh
  - man
@@ -109,9 +132,15 @@ pub enum Command {
     /// Environment commands
     Env(EnvCommand),
 
-    /// Import a collection, a request or an environment from other file formats (Postman v2.1.0, cURL, OpenAPI)
+    /// Cookie jar commands
+    Cookies(CookiesCommand),
+
+    /// Import a collection, a request, an environment or cookies from other file formats (Postman v2.1.0, cURL, OpenAPI, Insomnia, Bruno, HAR, .http, Netscape cookies.txt)
     Import(ImportCommand),
 
+    /// Export data to other file formats (HAR, .http, Netscape cookies.txt)
+    Export(ExportCommand),
+
     /// Create a completion file
     Completions(CompletionsCommand),
 
@@ -146,6 +175,7 @@ lazy_static! {
             collection_filter: args.filter,
             should_run_tui: args.tui,
             should_save: !args.dry_run,
+            should_persist_cookies: !args.no_cookie_persist,
             should_parse_directory,
             verbosity: args.verbose,
             ansi_log: !args.no_ansi_log
@@ -200,7 +230,32 @@ pub struct GlobalArgs {
     pub collection_filter: Option<Regex>,
     pub should_run_tui: bool,
     pub should_save: bool,
+    pub should_persist_cookies: bool,
     pub should_parse_directory: bool,
     pub verbosity: Verbosity,
     pub ansi_log: bool
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::cli::args::{Args, Command};
+    use crate::cli::commands::request_commands::request_commands::RequestSubcommand;
+
+    /// The body filter of `request send` must not clash with the global collection files filter
+    #[test]
+    fn send_body_filter_and_global_filter() {
+        let args = Args::try_parse_from(["atac", "request", "send", "my_collection/my_request", "--body-filter", "$.items[0]", "--filter", "^my_"]).unwrap();
+
+        assert_eq!(args.filter.map(|filter| filter.to_string()).as_deref(), Some("^my_"));
+
+        match args.command {
+            Some(Command::Request(request_command)) => match request_command.request_subcommand {
+                RequestSubcommand::Send { subcommand, .. } => assert_eq!(subcommand.body_filter.as_deref(), Some("$.items[0]")),
+                _ => panic!("expected request send")
+            },
+            _ => panic!("expected a request command")
+        }
+    }
+}
@@ -1,5 +1,13 @@
+use tracing::info;
+
 use crate::app::app::App;
-use crate::models::collection::Collection;
+use crate::app::business_logic::key_value::print_key_value_vector;
+use crate::cli::cli_logic::request::auth::print_auth;
+use crate::cli::cli_logic::request::settings::{get_setting, modify_setting};
+use crate::cli::commands::request_commands::setting::RequestSettingName;
+use crate::models::collection::{Collection, Folder};
+use crate::models::scripts::ScriptType;
+use crate::models::settings::Setting;
 
 impl App<'_> {
     pub fn list_collections(&mut self, with_request_names: bool) -> anyhow::Result<()> {
@@ -38,6 +46,97 @@ impl App<'_> {
 
         Ok(())
     }
+
+    pub fn cli_print_collection_folders(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        let collection = &self.collections[collection_index];
+
+        print_folders(collection, &collection.folders, &[], 0, false);
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_base_url(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        println!("{}", self.collections[collection_index].defaults.base_url);
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_headers(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        print_key_value_vector(&self.collections[collection_index].defaults.headers, None);
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_header(&mut self, collection_index: usize, row: usize) -> anyhow::Result<()> {
+        println!("{}", self.collections[collection_index].defaults.headers[row].data.1);
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_auth(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        print_auth(&self.collections[collection_index].defaults.auth.read());
+
+        Ok(())
+    }
+
+    pub async fn cli_fetch_collection_oauth2_token(&mut self, collection_index: usize) -> anyhow::Result<()> {
+        let access_token = self.renew_collection_oauth2_token(collection_index).await?;
+
+        println!("{access_token}");
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_script(&mut self, collection_index: usize, script_type: &ScriptType) -> anyhow::Result<()> {
+        let scripts = &self.collections[collection_index].defaults.scripts;
+
+        let script = match script_type {
+            ScriptType::Pre => &scripts.pre_request_script,
+            ScriptType::Post => &scripts.post_request_script,
+        };
+
+        let data = match script {
+            None => &String::from("None"),
+            Some(script) => script
+        };
+
+        println!("{data}");
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_settings(&mut self, collection_name: &str) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+        let settings = self.collections[collection_index].defaults.settings.clone().unwrap_or_default();
+
+        for (setting, state) in settings.to_vec() {
+            println!("{setting}: {state}");
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_print_collection_setting(&mut self, collection_name: &str, setting_name: &RequestSettingName) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+        let settings = self.collections[collection_index].defaults.settings.clone().unwrap_or_default();
+
+        println!("{}", get_setting(&settings, setting_name));
+
+        Ok(())
+    }
+
+    pub fn cli_modify_collection_setting(&mut self, collection_name: &str, setting_name: &RequestSettingName, new_value: &Setting) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        info!("Collection setting \"{}\" set to \"{}\"", setting_name, new_value);
+
+        let settings = self.collections[collection_index].defaults.settings.get_or_insert_with(Default::default);
+        modify_setting(settings, setting_name, new_value)?;
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
 }
 
 fn print_collection(collection: &Collection, shortened: bool, with_request_names: bool) {
@@ -50,9 +149,25 @@ fn print_collection(collection: &Collection, shortened: bool, with_request_names
 
     if with_request_names {
         println!("requests:");
-        for request in &collection.requests {
-            let local_request = request.read();
-            println!("\t{}", local_request.name);
+        print_folders(collection, &collection.folders, &[], 1, true);
+    }
+}
+
+/// Print the folders as a tree, each folder followed by its content
+fn print_folders(collection: &Collection, folders: &[Folder], path: &[String], depth: usize, with_request_names: bool) {
+    let indentation = "\t".repeat(depth);
+
+    for folder in folders {
+        println!("{indentation}{}/", folder.name);
+
+        let folder_path = [path, std::slice::from_ref(&folder.name)].concat();
+        print_folders(collection, &folder.folders, &folder_path, depth + 1, with_request_names);
+    }
+
+    if with_request_names {
+        for request_index in collection.get_folder_requests_indexes(path) {
+            let local_request = collection.requests[request_index].read();
+            println!("{indentation}{}", local_request.name);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+use crate::app::app::App;
+use crate::models::cookie::{cookie_to_row, COOKIES_COLUMNS_NUMBER};
+
+impl App<'_> {
+    pub fn cli_print_cookies(&self) -> anyhow::Result<()> {
+        let cookie_store = self.cookies_popup.cookie_store.read().unwrap();
+
+        for cookie in cookie_store.iter_unexpired() {
+            print_cookie_row(&cookie_to_row(cookie));
+        }
+
+        Ok(())
+    }
+}
+
+pub fn print_cookie_row(cookie_row: &[String; COOKIES_COLUMNS_NUMBER]) {
+    let [domain, name, value, path, expires, http_only, secure, same_site] = cookie_row;
+
+    println!("{domain}{path} {name}={value}");
+    println!("\texpires: {expires}");
+
+    let attributes: Vec<String> = [("http only", http_only), ("secure", secure), ("same site", same_site)]
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(attribute, value)| format!("{attribute}: {value}"))
+        .collect();
+
+    if !attributes.is_empty() {
+        println!("\t{}", attributes.join(", "));
+    }
+}
@@ -0,0 +1,71 @@
+use std::fs;
+
+use anyhow::anyhow;
+use cookie_store::CookieExpiration;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::cli_logic::cookies::print_cookie_row;
+use crate::cli::cli_logic::export::cookies::ExportCookiesError::CouldNotWriteFile;
+use crate::cli::cli_logic::import::cookies::HTTP_ONLY_PREFIX;
+use crate::cli::commands::export::CookiesExport;
+use crate::models::cookie::cookie_to_row;
+
+#[derive(Error, Debug)]
+enum ExportCookiesError {
+    #[error("Could not write cookies file\n\t{0}")]
+    CouldNotWriteFile(String),
+}
+
+impl App<'_> {
+    pub fn export_cookies_file(&mut self, cookies_export: &CookiesExport) -> anyhow::Result<()> {
+        if let Some(env_name) = &cookies_export.env {
+            let env_index = self.find_environment(env_name)?;
+            self.select_environment(env_index);
+        }
+
+        let mut lines = vec![
+            String::from("# Netscape HTTP Cookie File"),
+            String::from("# Exported by ATAC"),
+            String::new()
+        ];
+
+        {
+            let cookie_store = self.cookies_popup.cookie_store.read().unwrap();
+
+            for cookie in cookie_store.iter_unexpired() {
+                let cookie_row = cookie_to_row(cookie);
+                let [domain, name, value, path, _, http_only, secure, _] = &cookie_row;
+
+                // Session cookies have no expiration
+                let expires = match &cookie.expires {
+                    CookieExpiration::AtUtc(datetime) => datetime.unix_timestamp(),
+                    CookieExpiration::SessionEnd => 0
+                };
+
+                let http_only_prefix = if http_only == "true" { HTTP_ONLY_PREFIX } else { "" };
+                let include_subdomains = netscape_flag(domain.starts_with('.'));
+                let secure = netscape_flag(secure == "true");
+
+                lines.push(format!("{http_only_prefix}{domain}\t{include_subdomains}\t{path}\t{secure}\t{expires}\t{name}\t{value}"));
+
+                print_cookie_row(&cookie_row);
+            }
+        }
+
+        lines.push(String::new());
+
+        if let Err(e) = fs::write(&cookies_export.export_path, lines.join("\n")) {
+            return Err(anyhow!(CouldNotWriteFile(e.to_string())));
+        }
+
+        Ok(())
+    }
+}
+
+fn netscape_flag(flag: bool) -> &'static str {
+    match flag {
+        true => "TRUE",
+        false => "FALSE"
+    }
+}
@@ -0,0 +1,322 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use chrono::{SecondsFormat, Utc};
+use reqwest::Url;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::send::get_file_content_with_name;
+use crate::cli::cli_logic::export::har::ExportHarError::{CouldNotParseUrl, CouldNotWriteFile};
+use crate::cli::commands::export::HarExport;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::auth::Auth;
+use crate::models::collection::join_base_url;
+use crate::models::har::{Har, HarContent, HarCreator, HarEntry, HarLog, HarNameValue, HarParam, HarPostData, HarRequest, HarResponse, HarTimings};
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::Request;
+use crate::models::response::{parse_duration, RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+enum ExportHarError {
+    #[error("Could not parse URL \"{0}\"")]
+    CouldNotParseUrl(String),
+    #[error("Could not write HAR file\n\t{0}")]
+    CouldNotWriteFile(String),
+}
+
+impl App<'_> {
+    pub fn export_har_file(&mut self, har_export: &HarExport) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(&har_export.collection_name)?;
+
+        if let Some(env_name) = &har_export.env {
+            let env_index = self.find_environment(env_name)?;
+            self.select_environment(env_index);
+        }
+
+        let collection = &self.collections[collection_index];
+        let base_url = self.replace_env_keys_by_value(&collection.defaults.base_url);
+
+        let mut entries = vec![];
+
+        for request in &collection.requests {
+            let request = collection.defaults.apply_to(&request.read());
+
+            match self.request_to_har_entry(&request, &base_url) {
+                Ok(entry) => {
+                    println!("Exported request \"{}\"", request.name);
+                    entries.push(entry);
+                },
+                Err(error) => println!("Skipped request \"{}\": {error}", request.name)
+            }
+        }
+
+        let har = Har {
+            log: HarLog {
+                version: String::from("1.2"),
+                creator: HarCreator {
+                    name: String::from("ATAC"),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries,
+            },
+        };
+
+        let har_content = serde_json::to_string_pretty(&har).unwrap();
+
+        if let Err(e) = fs::write(&har_export.export_path, har_content) {
+            return Err(anyhow!(CouldNotWriteFile(e.to_string())));
+        }
+
+        Ok(())
+    }
+
+    /// The request as it would be sent, along with its last stored response
+    fn request_to_har_entry(&self, request: &Request, base_url: &str) -> anyhow::Result<HarEntry> {
+        // A GraphQL request is exported as the equivalent JSON POST request
+        let (method, body) = match &request.protocol {
+            Protocol::HttpRequest(http_request) => (http_request.method, http_request.body.clone()),
+            Protocol::GraphqlRequest(graphql_request) => (Method::POST, ContentType::Json(graphql_request.to_json_body())),
+            protocol => return Err(anyhow!("the {} protocol cannot be exported to HAR", protocol))
+        };
+
+        /* URL & QUERY PARAMS */
+
+        let params = self.key_value_vec_to_tuple_vec(&request.params);
+        let url = self.replace_env_keys_by_value(&request.url);
+        let mut url = join_base_url(base_url, &url);
+
+        let path_params = params.iter().filter(|(key, _)| key.starts_with("{") && key.ends_with("}"));
+        let mut query_params: Vec<(String, String)> = params.iter().filter(|(key, _)| !(key.starts_with("{") && key.ends_with("}"))).cloned().collect();
+
+        for (key, value) in path_params {
+            url = url.replace(key, value);
+        }
+
+        if let Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::QueryParam }) = &request.auth {
+            query_params.push((self.replace_env_keys_by_value(key), self.replace_env_keys_by_value(value)));
+        }
+
+        let url = match query_params.is_empty() {
+            true => Url::parse(&url),
+            false => Url::parse_with_params(&url, &query_params)
+        };
+
+        let url = match url {
+            Ok(url) => url,
+            Err(_) => return Err(anyhow!(CouldNotParseUrl(self.replace_env_keys_by_value(&request.url))))
+        };
+
+        let query_string = url.query_pairs()
+            .map(|(key, value)| HarNameValue {
+                name: key.to_string(),
+                value: value.to_string(),
+            })
+            .collect();
+
+        /* HEADERS */
+
+        let mut headers: Vec<HarNameValue> = self.key_value_vec_to_tuple_vec(&request.headers)
+            .into_iter()
+            .map(|(name, value)| HarNameValue {
+                name,
+                value,
+            })
+            .collect();
+
+        if let Some((name, value)) = self.auth_to_header(&request.auth, &url)? {
+            headers.push(HarNameValue {
+                name,
+                value,
+            });
+        }
+
+        /* BODY */
+
+        let post_data = self.body_to_har_post_data(&body)?;
+
+        if let Some(post_data) = &post_data && !headers.iter().any(|header| header.name.eq_ignore_ascii_case("content-type")) {
+            headers.push(HarNameValue {
+                name: String::from("content-type"),
+                value: post_data.mime_type.clone(),
+            });
+        }
+
+        let body_size = post_data.as_ref().map(|post_data| post_data.text.len() as i64).unwrap_or(0);
+
+        let (response, time) = response_to_har(&request.response);
+
+        Ok(HarEntry {
+            comment: Some(request.name.clone()),
+            started_date_time: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            time,
+            request: HarRequest {
+                method: method.to_string(),
+                url: url.to_string(),
+                http_version: String::from("HTTP/1.1"),
+                cookies: vec![],
+                headers,
+                query_string,
+                post_data,
+                headers_size: -1,
+                body_size,
+            },
+            response,
+            cache: Value::Object(Default::default()),
+            timings: HarTimings {
+                send: 0.0,
+                wait: time,
+                receive: 0.0,
+            },
+        })
+    }
+
+    fn body_to_har_post_data(&self, body: &ContentType) -> anyhow::Result<Option<HarPostData>> {
+        let mime_type = body.to_content_type();
+
+        let post_data = match body {
+            ContentType::NoBody => return Ok(None),
+            ContentType::Raw(text) | ContentType::Json(text) | ContentType::Xml(text) | ContentType::Html(text) | ContentType::Javascript(text) => HarPostData {
+                mime_type,
+                params: vec![],
+                text: self.replace_env_keys_by_value(text),
+            },
+            ContentType::File(file_path) => {
+                let file_path = self.replace_env_keys_by_value(file_path);
+
+                let (file_content, _) = match get_file_content_with_name(PathBuf::from(&file_path)) {
+                    Ok(result) => result,
+                    Err(e) => return Err(anyhow!("could not open file \"{file_path}\": {e}"))
+                };
+
+                HarPostData {
+                    mime_type,
+                    params: vec![],
+                    text: String::from_utf8_lossy(&file_content).to_string(),
+                }
+            },
+            ContentType::Form(form) => {
+                let form = self.key_value_vec_to_tuple_vec(form);
+
+                let mut url = Url::parse("http://localhost").unwrap();
+                url.query_pairs_mut().extend_pairs(&form);
+
+                HarPostData {
+                    mime_type,
+                    params: form
+                        .into_iter()
+                        .map(|(name, value)| HarParam {
+                            name,
+                            value: Some(value),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    text: url.query().unwrap_or_default().to_string(),
+                }
+            },
+            // The multipart text depends on the generated boundary, only the params are given
+            ContentType::Multipart(multipart) => HarPostData {
+                mime_type,
+                params: self.key_value_vec_to_tuple_vec(multipart)
+                    .into_iter()
+                    .map(|(name, value)| match value.strip_prefix("!!") {
+                        Some(file_path) => HarParam {
+                            name,
+                            file_name: Some(file_path.to_string()),
+                            ..Default::default()
+                        },
+                        None => HarParam {
+                            name,
+                            value: Some(value),
+                            ..Default::default()
+                        }
+                    })
+                    .collect(),
+                text: String::new(),
+            },
+        };
+
+        Ok(Some(post_data))
+    }
+}
+
+/// Returns the response and its duration in milliseconds, 0 when unknown
+fn response_to_har(response: &RequestResponse) -> (HarResponse, f64) {
+    let (status, status_text) = match &response.status_code {
+        Some(status_code) => {
+            let (status, status_text) = status_code.split_once(' ').unwrap_or((status_code, ""));
+
+            // e.g. CANCELED or TIMEOUT
+            match status.parse::<u16>() {
+                Ok(status) => (status, status_text.to_string()),
+                Err(_) => (0, status_code.clone())
+            }
+        },
+        None => (0, String::new())
+    };
+
+    let mime_type = response.headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default();
+
+    let content = match &response.content {
+        None => HarContent {
+            size: 0,
+            mime_type,
+            text: None,
+            encoding: None,
+        },
+        Some(ResponseContent::Body(body)) => HarContent {
+            size: body.len() as i64,
+            mime_type,
+            text: Some(body.clone()),
+            encoding: None,
+        },
+        Some(ResponseContent::Image(image)) => HarContent {
+            size: image.data.len() as i64,
+            mime_type,
+            text: Some(BASE64_STANDARD.encode(&image.data)),
+            encoding: Some(String::from("base64")),
+        }
+    };
+
+    let time = response.duration
+        .as_deref()
+        .and_then(parse_duration)
+        .map(|duration| duration.as_secs_f64() * 1000.0)
+        .unwrap_or(0.0);
+
+    let redirect_url = response.headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case("location"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default();
+
+    let har_response = HarResponse {
+        status,
+        status_text,
+        http_version: String::from("HTTP/1.1"),
+        cookies: vec![],
+        headers: response.headers
+            .iter()
+            .map(|(name, value)| HarNameValue {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect(),
+        body_size: content.size,
+        content,
+        redirect_url,
+        headers_size: -1,
+    };
+
+    (har_response, time)
+}
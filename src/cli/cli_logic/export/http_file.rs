@@ -0,0 +1,200 @@
+use std::fs;
+
+use anyhow::anyhow;
+use regex::Regex;
+use reqwest::Url;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::cli_logic::export::http_file::ExportHttpFileError::CouldNotWriteFile;
+use crate::cli::commands::export::HttpFileExport;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::auth::Auth;
+use crate::models::collection::join_base_url;
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::{KeyValue, Request};
+use crate::models::settings::Setting;
+
+#[derive(Error, Debug)]
+enum ExportHttpFileError {
+    #[error("Could not write HTTP file\n\t{0}")]
+    CouldNotWriteFile(String),
+}
+
+/// Multipart bodies are written as is, the boundary only has to be absent from the parts
+const MULTIPART_BOUNDARY: &str = "ATACFormBoundary";
+
+impl App<'_> {
+    pub fn export_http_file(&mut self, http_file_export: &HttpFileExport) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(&http_file_export.collection_name)?;
+
+        let mut blocks = vec![];
+
+        if let Some(env_name) = &http_file_export.env {
+            let env_index = self.find_environment(env_name)?;
+            let environment = self.environments[env_index].read();
+
+            let file_variables: Vec<String> = environment.values
+                .iter()
+                .map(|(key, value)| format!("@{key} = {value}\n"))
+                .collect();
+
+            blocks.push(file_variables.concat());
+        }
+
+        let collection = &self.collections[collection_index];
+
+        for request in &collection.requests {
+            let request = collection.defaults.apply_to(&request.read());
+
+            match request_to_http_block(&request, &collection.defaults.base_url) {
+                Ok(block) => {
+                    println!("Exported request \"{}\"", request.name);
+                    blocks.push(convert_template(&block));
+                },
+                Err(error) => println!("Skipped request \"{}\": {error}", request.name)
+            }
+        }
+
+        if let Err(e) = fs::write(&http_file_export.export_path, blocks.join("\n")) {
+            return Err(anyhow!(CouldNotWriteFile(e.to_string())));
+        }
+
+        Ok(())
+    }
+}
+
+/// Variables are left unresolved, both clients replace the {{var}} syntax with their own environments
+fn request_to_http_block(request: &Request, base_url: &str) -> anyhow::Result<String> {
+    // A GraphQL request is exported as the equivalent JSON POST request
+    let (method, body) = match &request.protocol {
+        Protocol::HttpRequest(http_request) => (http_request.method, http_request.body.clone()),
+        Protocol::GraphqlRequest(graphql_request) => (Method::POST, ContentType::Json(graphql_request.to_json_body())),
+        protocol => return Err(anyhow!("the {} protocol cannot be exported to a HTTP file", protocol))
+    };
+
+    let mut output = format!("### {}\n# @name {}\n", request.get_path(), request.name);
+
+    if let Setting::Bool(false) = request.settings.allow_redirects {
+        output += "# @no-redirect\n";
+    }
+
+    if let Setting::Bool(false) = request.settings.store_received_cookies {
+        output += "# @no-cookie-jar\n";
+    }
+
+    /* URL & QUERY PARAMS */
+
+    let mut url_request = request.clone();
+
+    if let Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::QueryParam }) = &request.auth {
+        url_request.params.push(KeyValue {
+            enabled: true,
+            data: (key.clone(), value.clone()),
+        });
+    }
+
+    let mut url = join_base_url(base_url, &url_request.url_with_params_to_string());
+
+    for param in &request.params {
+        let (key, value) = &param.data;
+
+        if key.starts_with('{') && key.ends_with('}') {
+            url = url.replace(key, value);
+        }
+    }
+
+    output += &format!("{method} {url} HTTP/1.1\n");
+
+    /* HEADERS */
+
+    for header in request.headers.iter().filter(|header| header.enabled) {
+        output += &format!("{}: {}\n", header.data.0, header.data.1);
+    }
+
+    /* AUTH */
+
+    // Both clients encode the credentials themselves
+    match &request.auth {
+        Auth::NoAuth => {},
+        Auth::BasicAuth(basic_auth) => output += &format!("Authorization: Basic {} {}\n", basic_auth.username, basic_auth.password),
+        Auth::BearerToken(bearer_token) => output += &format!("Authorization: Bearer {}\n", bearer_token.token),
+        Auth::Digest(digest) => output += &format!("Authorization: Digest {} {}\n", digest.username, digest.password),
+        Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::Header }) => output += &format!("{key}: {value}\n"),
+        Auth::ApiKey(ApiKey { key, value, placement: ApiKeyPlacement::Cookie }) => output += &format!("Cookie: {key}={value}\n"),
+        Auth::ApiKey(ApiKey { placement: ApiKeyPlacement::QueryParam, .. }) => {},
+        auth => println!("\tSkipped the {} auth, it has no .http equivalent", auth)
+    }
+
+    /* BODY */
+
+    let has_content_type = request.headers.iter().any(|header| header.enabled && header.data.0.eq_ignore_ascii_case("content-type"));
+
+    let content_type = match &body {
+        ContentType::Multipart(_) => format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}"),
+        body => body.to_content_type()
+    };
+
+    if !content_type.is_empty() && !has_content_type {
+        output += &format!("Content-Type: {content_type}\n");
+    }
+
+    match &body {
+        ContentType::NoBody => {},
+        ContentType::Raw(text) | ContentType::Json(text) | ContentType::Xml(text) | ContentType::Html(text) | ContentType::Javascript(text) => output += &format!("\n{text}\n"),
+        ContentType::File(file_path) => output += &format!("\n< {file_path}\n"),
+        ContentType::Form(form) => output += &format!("\n{}\n", encode_form(form)),
+        ContentType::Multipart(multipart) => {
+            output += "\n";
+
+            for key_value in multipart.iter().filter(|key_value| key_value.enabled) {
+                let (key, value) = &key_value.data;
+
+                output += &format!("--{MULTIPART_BOUNDARY}\n");
+
+                match value.strip_prefix("!!") {
+                    Some(file_path) => {
+                        let file_name = file_path.rsplit(['/', '\\']).next().unwrap_or(file_path);
+                        output += &format!("Content-Disposition: form-data; name=\"{key}\"; filename=\"{file_name}\"\n\n< {file_path}\n");
+                    },
+                    None => output += &format!("Content-Disposition: form-data; name=\"{key}\"\n\n{value}\n")
+                }
+            }
+
+            output += &format!("--{MULTIPART_BOUNDARY}--\n");
+        }
+    }
+
+    Ok(output)
+}
+
+/// Percent-encoded as it would be sent, the {{var}} placeholders being kept as-is so that they are still resolved
+fn encode_form(form: &[KeyValue]) -> String {
+    let mut url = Url::parse("http://localhost").unwrap();
+
+    url.query_pairs_mut().extend_pairs(
+        form
+            .iter()
+            .filter(|key_value| key_value.enabled)
+            .map(|key_value| (&key_value.data.0, &key_value.data.1))
+    );
+
+    let encoded_form = url.query().unwrap_or_default();
+
+    // e.g. {{TOKEN}} or {{$timestamp}}, the $ being encoded as %24
+    let encoded_placeholder_regex = Regex::new(r"%7B%7B((?:%24)?[\w.-]*)%7D%7D").unwrap();
+
+    encoded_placeholder_regex
+        .replace_all(encoded_form, |captures: &regex::Captures| format!("{{{{{}}}}}", captures[1].replace("%24", "$")))
+        .to_string()
+}
+
+/// Dynamic variables have their own syntax, {{UUIDv7}} has no equivalent
+fn convert_template(text: &str) -> String {
+    text
+        .replace("{{NOW}}", "{{$isoTimestamp}}")
+        .replace("{{TIMESTAMP}}", "{{$timestamp}}")
+        .replace("{{UUIDv4}}", "{{$uuid}}")
+}
@@ -0,0 +1,3 @@
+pub mod cookies;
+pub mod har;
+pub mod http_file;
\ No newline at end of file
@@ -0,0 +1,989 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use regex::Regex;
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::cli::cli_logic::import::bruno::ImportBrunoError::{CollectionAlreadyExists, CouldNotParseBruFile, CouldNotParseBrunoJson, CouldNotReadDirectory, NotABrunoCollection};
+use crate::cli::commands::import::BrunoImport;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::basic::BasicAuth;
+use crate::models::auth::bearer_token::BearerToken;
+use crate::models::auth::digest::{Digest, DigestAlgorithm, DigestCharset, DigestQop};
+use crate::models::auth::oauth2::{OAuth2, OAuth2GrantType};
+use crate::models::collection::{Collection, CollectionDefaults, Folder};
+use crate::models::environment::Environment;
+use crate::models::protocol::graphql::graphql::GraphqlRequest;
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::{DEFAULT_HEADERS, KeyValue, Request};
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::Setting;
+
+#[derive(Error, Debug)]
+enum ImportBrunoError {
+    #[error("Could not read Bruno collection directory \"{0}\"\n\t{1}")]
+    CouldNotReadDirectory(String, String),
+    #[error("\"{0}\" is not a Bruno collection, no bruno.json file found")]
+    NotABrunoCollection(String),
+    #[error("Could not parse bruno.json\n\t{0}")]
+    CouldNotParseBrunoJson(String),
+    #[error("Could not parse Bru file \"{0}\"\n\t{1}")]
+    CouldNotParseBruFile(String, String),
+    #[error("Collection \"{0}\" already exists")]
+    CollectionAlreadyExists(String),
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct BrunoJson {
+    name: String,
+    ignore: Vec<String>,
+}
+
+const BRUNO_METHODS: [&str; 9] = ["get", "post", "put", "delete", "patch", "options", "head", "connect", "trace"];
+
+/// A Bru file is a list of top-level blocks, e.g. `meta { ... }`, `body:json { ... }` or `vars:secret [ ... ]`
+struct BruFile {
+    blocks: Vec<BruBlock>,
+}
+
+struct BruBlock {
+    name: String,
+    /// Raw lines between the opening and the closing bracket
+    lines: Vec<String>,
+}
+
+/// Entry of a dictionary block, disabled entries are prefixed with `~`
+struct BruPair {
+    enabled: bool,
+    key: String,
+    value: String,
+}
+
+impl FromStr for BruFile {
+    type Err = String;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let block_start_regex = Regex::new(r"^([\w:.-]+)\s*([{\[])\s*$").unwrap();
+
+        let mut blocks = vec![];
+        let mut lines = content.lines().map(|line| line.trim_end_matches('\r')).enumerate();
+
+        while let Some((index, line)) = lines.next() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Some(captures) = block_start_regex.captures(line) else {
+                return Err(format!("Unexpected line {}: \"{line}\"", index + 1));
+            };
+
+            let name = captures[1].to_string();
+            let closing_bracket = match &captures[2] {
+                "{" => "}",
+                _ => "]"
+            };
+
+            let mut block_lines = vec![];
+            let mut is_closed = false;
+
+            // Block content is indented, the closing bracket is not
+            for (_, line) in lines.by_ref() {
+                if line == closing_bracket {
+                    is_closed = true;
+                    break;
+                }
+
+                block_lines.push(line.to_string());
+            }
+
+            if !is_closed {
+                return Err(format!("Block \"{name}\" is never closed"));
+            }
+
+            blocks.push(BruBlock {
+                name,
+                lines: block_lines,
+            });
+        }
+
+        Ok(BruFile {
+            blocks
+        })
+    }
+}
+
+impl BruFile {
+    fn block(&self, name: &str) -> Option<&BruBlock> {
+        self.blocks.iter().find(|block| block.name == name)
+    }
+
+    fn dictionary(&self, name: &str) -> Vec<BruPair> {
+        match self.block(name) {
+            None => vec![],
+            Some(block) => parse_dictionary(&block.lines)
+        }
+    }
+
+    /// Value of an enabled dictionary entry, empty when missing
+    fn value(&self, block_name: &str, key: &str) -> String {
+        self.dictionary(block_name)
+            .into_iter()
+            .find(|pair| pair.enabled && pair.key == key)
+            .map(|pair| pair.value)
+            .unwrap_or_default()
+    }
+
+    /// Text blocks such as bodies and scripts, without their 2 spaces indentation
+    fn text(&self, name: &str) -> Option<String> {
+        let block = self.block(name)?;
+
+        let text = block.lines
+            .iter()
+            .map(|line| line.strip_prefix("  ").unwrap_or(line))
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        match text.trim().is_empty() {
+            true => None,
+            false => Some(text.trim_end().to_string())
+        }
+    }
+
+    /// List blocks such as `vars:secret [ token, password ]`
+    fn list(&self, name: &str) -> Vec<(bool, String)> {
+        let Some(block) = self.block(name) else {
+            return vec![];
+        };
+
+        block.lines
+            .iter()
+            .flat_map(|line| line.split(','))
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| match item.strip_prefix('~') {
+                Some(item) => (false, item.to_string()),
+                None => (true, item.to_string())
+            })
+            .collect()
+    }
+}
+
+fn parse_dictionary(lines: &[String]) -> Vec<BruPair> {
+    let mut pairs = vec![];
+    let mut lines = lines.iter();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => (line, "")
+        };
+
+        let (enabled, key) = match key.strip_prefix('~') {
+            Some(key) => (false, key),
+            None => (true, key)
+        };
+
+        // Multiline values are surrounded by ''' lines
+        let value = match value {
+            "'''" => lines
+                .by_ref()
+                .take_while(|line| line.trim() != "'''")
+                .map(|line| line.strip_prefix("    ").unwrap_or(line))
+                .collect::<Vec<&str>>()
+                .join("\n"),
+            value => value.to_string()
+        };
+
+        pairs.push(BruPair {
+            enabled,
+            key: key.trim_matches('"').to_string(),
+            value,
+        });
+    }
+
+    pairs
+}
+
+/// What was found while walking the collection directory and what could not be converted
+struct BrunoImportContext {
+    requests: Vec<Arc<RwLock<Request>>>,
+    unconverted: Vec<String>,
+}
+
+impl BrunoImportContext {
+    fn report(&mut self, element: String, what: String) {
+        println!("\t\t\tCould not convert {what}");
+        self.unconverted.push(format!("{element}: {what}"));
+    }
+}
+
+impl App<'_> {
+    pub fn import_bruno_collection(&mut self, bruno_import: &BrunoImport) -> anyhow::Result<()> {
+        let mut collection_directory = bruno_import.import_path.clone();
+
+        if collection_directory.is_file() && let Some(parent) = collection_directory.parent() {
+            collection_directory = parent.to_path_buf();
+        }
+
+        println!("Parsing Bruno collection");
+
+        let bruno_json_path = collection_directory.join("bruno.json");
+
+        if !bruno_json_path.is_file() {
+            return Err(anyhow!(NotABrunoCollection(collection_directory.display().to_string())));
+        }
+
+        let bruno_json = match fs::read_to_string(&bruno_json_path).map_err(|e| e.to_string()).and_then(|content| serde_json::from_str::<BrunoJson>(&content).map_err(|e| e.to_string())) {
+            Ok(bruno_json) => bruno_json,
+            Err(e) => return Err(anyhow!(CouldNotParseBrunoJson(e)))
+        };
+
+        let collection_name = match bruno_json.name.trim().is_empty() {
+            false => bruno_json.name.trim().to_string(),
+            true => collection_directory.file_name().unwrap_or_default().to_string_lossy().to_string()
+        };
+
+        if self.collections.iter().any(|collection| collection.name == collection_name) {
+            return Err(anyhow!(CollectionAlreadyExists(collection_name)));
+        }
+
+        println!("Collection name: {}", collection_name);
+
+        let mut context = BrunoImportContext {
+            requests: vec![],
+            unconverted: vec![],
+        };
+
+        let file_format = self.config.get_preferred_collection_file_format();
+
+        let mut collection = Collection {
+            name: collection_name.clone(),
+            last_position: Some(self.collections.len()),
+            requests: vec![],
+            folders: vec![],
+            defaults: CollectionDefaults::default(),
+            path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format)),
+            file_format,
+        };
+
+        /* COLLECTION DEFAULTS */
+
+        let collection_bru = read_bru_file(&collection_directory.join("collection.bru"))?;
+
+        if let Some(collection_bru) = &collection_bru {
+            let element = format!("collection \"{collection_name}\"");
+
+            collection.defaults.headers = convert_key_values(&collection_bru.dictionary("headers"));
+            collection.defaults.scripts = convert_scripts(&mut context, &element, collection_bru);
+
+            let auth_mode = collection_bru.value("auth", "mode");
+            *collection.defaults.auth.write() = convert_auth(&mut context, &element, collection_bru, &auth_mode, None);
+
+            report_variables(&mut context, &element, collection_bru);
+        }
+
+        /* FOLDERS & REQUESTS */
+
+        let mut ignored_directories = bruno_json.ignore.clone();
+        ignored_directories.extend([String::from("environments"), String::from("node_modules"), String::from(".git")]);
+
+        let collection_auth = collection.defaults.auth.read().clone();
+        let inherited_auth = match collection_auth {
+            Auth::NoAuth => None,
+            _ => Some(Auth::InheritFromCollection)
+        };
+
+        recursive_import_directory(&mut context, &collection_directory, &ignored_directories, &[], &mut collection.folders, inherited_auth)?;
+
+        collection.requests = context.requests.drain(..).collect();
+
+        /* ENVIRONMENTS */
+
+        let environments = self.parse_bruno_environments(&mut context, &collection_directory.join("environments"))?;
+
+        // Only written once everything is parsed, so that a failed import can be run again
+        self.collections.push(collection);
+
+        let collection_index = self.collections.len() - 1;
+        self.save_collection_to_file(collection_index);
+
+        for environment in environments {
+            self.environments.push(Arc::new(RwLock::new(environment)));
+
+            let environment_index = self.environments.len() - 1;
+            self.save_environment_to_file(environment_index);
+        }
+
+        match context.unconverted.is_empty() {
+            true => println!("Everything was converted"),
+            false => {
+                println!("Could not convert:");
+
+                for unconverted in &context.unconverted {
+                    println!("\t{unconverted}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_bruno_environments(&self, context: &mut BrunoImportContext, environments_directory: &Path) -> anyhow::Result<Vec<Environment>> {
+        let mut environments: Vec<Environment> = vec![];
+
+        if !environments_directory.is_dir() {
+            return Ok(environments);
+        }
+
+        let mut environment_paths: Vec<PathBuf> = list_directory(environments_directory)?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|extension| extension == "bru"))
+            .collect();
+
+        environment_paths.sort();
+
+        for environment_path in environment_paths {
+            let Some(environment_bru) = read_bru_file(&environment_path)? else {
+                continue;
+            };
+
+            let environment_name = environment_path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_lowercase()
+                .replace(" ", "_");
+
+            println!("\tFound environment \"{}\"", environment_name);
+
+            let element = format!("environment \"{environment_name}\"");
+
+            let path = ARGS.directory
+                .as_ref()
+                .unwrap()
+                .join(format!(".env.{environment_name}"));
+
+            let already_exists = self.environments.iter().any(|environment| environment.read().name == environment_name)
+                || environments.iter().any(|environment| environment.name == environment_name);
+
+            if path.exists() || already_exists {
+                context.report(element, String::from("environment, it already exists"));
+                continue;
+            }
+
+            let mut values = IndexMap::new();
+
+            for pair in environment_bru.dictionary("vars") {
+                // Environment files hold a variable per line
+                match (pair.enabled, pair.value.contains('\n')) {
+                    (true, false) => {
+                        values.insert(pair.key, convert_template(&pair.value));
+                    },
+                    (true, true) => context.report(element.clone(), format!("multiline variable \"{}\"", pair.key)),
+                    (false, _) => context.report(element.clone(), format!("disabled variable \"{}\"", pair.key)),
+                }
+            }
+
+            // Secret values are not stored in the collection files
+            for (_, secret) in environment_bru.list("vars:secret") {
+                context.report(element.clone(), format!("secret variable \"{secret}\", its value has to be filled in"));
+                values.insert(secret, String::new());
+            }
+
+            environments.push(Environment {
+                name: environment_name,
+                values,
+                path
+            });
+        }
+
+        Ok(environments)
+    }
+}
+
+/// Sub-directories become folders, sorted like in Bruno by their sequence number then their name
+fn recursive_import_directory(context: &mut BrunoImportContext, directory: &Path, ignored_directories: &[String], path: &[String], folders: &mut Vec<Folder>, inherited_auth: Option<Auth>) -> anyhow::Result<()> {
+    let mut entries: Vec<(f64, String, PathBuf, BruFile)> = vec![];
+    let mut sub_directories: Vec<(f64, String, PathBuf, Option<BruFile>)> = vec![];
+
+    for entry_path in list_directory(directory)? {
+        let file_name = entry_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+
+        if entry_path.is_dir() {
+            if path.is_empty() && ignored_directories.contains(&file_name) || file_name.starts_with('.') {
+                continue;
+            }
+
+            let folder_bru = read_bru_file(&entry_path.join("folder.bru"))?;
+            let sequence = folder_bru.as_ref().map(|folder_bru| folder_bru.value("meta", "seq")).and_then(|seq| seq.parse().ok()).unwrap_or(f64::MAX);
+            let name = folder_bru.as_ref().map(|folder_bru| folder_bru.value("meta", "name")).filter(|name| !name.is_empty()).unwrap_or(file_name);
+
+            sub_directories.push((sequence, name, entry_path, folder_bru));
+        }
+        else if entry_path.extension().is_some_and(|extension| extension == "bru") && file_name != "folder.bru" && file_name != "collection.bru" {
+            let Some(request_bru) = read_bru_file(&entry_path)? else {
+                continue;
+            };
+
+            let sequence = request_bru.value("meta", "seq").parse().unwrap_or(f64::MAX);
+            let name = request_bru.value("meta", "name");
+            let name = match name.is_empty() {
+                true => entry_path.file_stem().unwrap_or_default().to_string_lossy().to_string(),
+                false => name
+            };
+
+            entries.push((sequence, name, entry_path, request_bru));
+        }
+    }
+
+    entries.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+    sub_directories.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(&b.1)));
+
+    for (_, name, _, request_bru) in entries {
+        if let Some(mut request) = parse_request(context, name, &request_bru, inherited_auth.clone()) {
+            request.folder = path.to_vec();
+            context.requests.push(Arc::new(RwLock::new(request)));
+        }
+    }
+
+    for (_, name, sub_directory, folder_bru) in sub_directories {
+        let folder_name = name
+            .replace("/", "-")
+            .replace("\\", "-")
+            .trim()
+            .to_string();
+
+        println!("\tFound folder \"{}\"", folder_name);
+
+        let element = format!("folder \"{folder_name}\"");
+        let mut folder_auth = inherited_auth.clone();
+
+        // Folders only have a name in ATAC, their auth is given to the requests inheriting it
+        if let Some(folder_bru) = &folder_bru {
+            let auth_mode = folder_bru.value("auth", "mode");
+
+            if !auth_mode.is_empty() && auth_mode != "inherit" {
+                folder_auth = Some(convert_auth(context, &element, folder_bru, &auth_mode, inherited_auth.clone()));
+            }
+
+            if !folder_bru.dictionary("headers").is_empty() {
+                context.report(element.clone(), String::from("folder headers"));
+            }
+
+            let has_scripts = ["script:pre-request", "script:post-response", "tests"].iter().any(|block_name| folder_bru.text(block_name).is_some());
+
+            if has_scripts {
+                context.report(element.clone(), String::from("folder scripts"));
+            }
+
+            report_variables(context, &element, folder_bru);
+        }
+
+        let folder_path = [path, std::slice::from_ref(&folder_name)].concat();
+        let mut folder = Folder {
+            name: folder_name,
+            folders: vec![],
+        };
+
+        recursive_import_directory(context, &sub_directory, ignored_directories, &folder_path, &mut folder.folders, folder_auth)?;
+
+        folders.push(folder);
+    }
+
+    Ok(())
+}
+
+fn parse_request(context: &mut BrunoImportContext, name: String, request_bru: &BruFile, inherited_auth: Option<Auth>) -> Option<Request> {
+    println!("\t\tFound request \"{}\"", name);
+
+    let element = format!("request \"{name}\"");
+    let request_type = request_bru.value("meta", "type");
+
+    if !request_type.is_empty() && request_type != "http" && request_type != "graphql" {
+        context.report(element, format!("\"{request_type}\" request type, the request was skipped"));
+        return None;
+    }
+
+    let Some(method_name) = BRUNO_METHODS.iter().find(|method| request_bru.block(method).is_some()) else {
+        context.report(element, String::from("request without method, the request was skipped"));
+        return None;
+    };
+
+    let method = Method::from_str(&method_name.to_uppercase()).unwrap();
+
+    let mut request = Request {
+        name,
+        ..Default::default()
+    };
+
+    /* URL & PATH PARAMS */
+
+    // Bruno path params are written :id, ATAC ones {id}
+    let path_param_regex = Regex::new(r"/:([\w-]+)").unwrap();
+    let url = convert_template(&request_bru.value(method_name, "url"));
+    let url = url.split_once('?').map(|(url, _)| url.to_string()).unwrap_or(url);
+
+    request.url = path_param_regex.replace_all(&url, "/{$1}").to_string();
+
+    /* QUERY PARAMS */
+
+    // Bruno files written before the params:query block used a single "query" block
+    let mut query_params = request_bru.dictionary("params:query");
+    query_params.extend(request_bru.dictionary("query"));
+
+    for pair in &query_params {
+        request.params.push(KeyValue {
+            enabled: pair.enabled,
+            data: (convert_template(&pair.key), convert_template(&pair.value)),
+        });
+    }
+
+    for pair in request_bru.dictionary("params:path") {
+        request.params.push(KeyValue {
+            enabled: true,
+            data: (format!("{{{}}}", pair.key), convert_template(&pair.value)),
+        });
+    }
+
+    /* HEADERS */
+
+    request.headers = DEFAULT_HEADERS.clone();
+    request.headers.extend(convert_key_values(&request_bru.dictionary("headers")));
+
+    /* AUTH */
+
+    let auth_mode = request_bru.value(method_name, "auth");
+    request.auth = convert_auth(context, &element, request_bru, &auth_mode, inherited_auth);
+
+    /* PROTOCOL & BODY */
+
+    let body_mode = request_bru.value(method_name, "body");
+
+    request.protocol = match body_mode.as_str() {
+        "graphql" => Protocol::GraphqlRequest(GraphqlRequest {
+            query: request_bru.text("body:graphql").unwrap_or_default(),
+            variables: request_bru.text("body:graphql:vars").map(|variables| convert_template(&variables)).unwrap_or_default(),
+            operation_name: None,
+            schema: None,
+        }),
+        _ => {
+            let body = convert_body(context, &element, request_bru, &body_mode);
+            let has_content_type = request.headers.iter().any(|header| header.data.0.eq_ignore_ascii_case("content-type"));
+
+            if !has_content_type && !matches!(body, ContentType::NoBody | ContentType::Multipart(_)) {
+                request.modify_or_create_header("content-type", &body.to_content_type());
+            }
+
+            Protocol::HttpRequest(HttpRequest {
+                method,
+                body,
+            })
+        }
+    };
+
+    /* CAPTURES */
+
+    for pair in request_bru.dictionary("vars:post-response") {
+        match convert_capture_rule(&pair.value) {
+            Some(rule) => request.captures.push(KeyValue {
+                enabled: pair.enabled,
+                data: (pair.key, rule),
+            }),
+            None => context.report(element.clone(), format!("post-response variable \"{}\" ({})", pair.key, pair.value))
+        }
+    }
+
+    for pair in request_bru.dictionary("vars:pre-request") {
+        context.report(element.clone(), format!("pre-request variable \"{}\"", pair.key));
+    }
+
+    /* SCRIPTS & TESTS */
+
+    request.scripts = convert_scripts(context, &element, request_bru);
+
+    /* SETTINGS */
+
+    match request_bru.value("settings", "followRedirects").as_str() {
+        "true" => request.settings.allow_redirects = Setting::Bool(true),
+        "false" => request.settings.allow_redirects = Setting::Bool(false),
+        _ => {}
+    }
+
+    if let Ok(timeout) = request_bru.value("settings", "timeout").parse::<u32>() && timeout > 0 {
+        request.settings.timeout = Setting::U32(timeout);
+    }
+
+    /* OTHERS */
+
+    if request_bru.text("docs").is_some() {
+        context.report(element, String::from("docs"));
+    }
+
+    Some(request)
+}
+
+fn convert_key_values(pairs: &[BruPair]) -> Vec<KeyValue> {
+    pairs
+        .iter()
+        .map(|pair| KeyValue {
+            enabled: pair.enabled,
+            data: (convert_template(&pair.key), convert_template(&pair.value)),
+        })
+        .collect()
+}
+
+fn convert_body(context: &mut BrunoImportContext, element: &str, request_bru: &BruFile, body_mode: &str) -> ContentType {
+    let text = |block_name: &str| convert_template(&request_bru.text(block_name).unwrap_or_default());
+
+    match body_mode {
+        "" | "none" => ContentType::NoBody,
+        "json" => ContentType::Json(text("body:json")),
+        "xml" => ContentType::Xml(text("body:xml")),
+        "text" => ContentType::Raw(text("body:text")),
+        "sparql" => ContentType::Raw(text("body:sparql")),
+        "formUrlEncoded" => ContentType::Form(convert_key_values(&request_bru.dictionary("body:form-urlencoded"))),
+        "multipartForm" => {
+            let mut key_values = convert_key_values(&request_bru.dictionary("body:multipart-form"));
+
+            for key_value in key_values.iter_mut() {
+                if let Some(file_paths) = parse_file_value(&key_value.data.1) {
+                    if file_paths.len() > 1 {
+                        context.report(element.to_string(), format!("multiple files for the \"{}\" form field, only the first one was kept", key_value.data.0));
+                    }
+
+                    key_value.data.1 = format!("!!{}", file_paths[0]);
+                }
+            }
+
+            ContentType::Multipart(key_values)
+        },
+        "file" => {
+            let selected_file = request_bru.dictionary("body:file")
+                .into_iter()
+                .find(|pair| pair.enabled)
+                .and_then(|pair| parse_file_value(&pair.value));
+
+            match selected_file {
+                Some(file_paths) => ContentType::File(file_paths[0].clone()),
+                None => ContentType::NoBody
+            }
+        },
+        body_mode => {
+            context.report(element.to_string(), format!("\"{body_mode}\" body"));
+            ContentType::NoBody
+        }
+    }
+}
+
+/// e.g. `@file(a.png|b.png) @contentType(image/png)`
+fn parse_file_value(value: &str) -> Option<Vec<String>> {
+    let file_regex = Regex::new(r"@file\(([^)]*)\)").unwrap();
+    let captures = file_regex.captures(value)?;
+
+    let file_paths: Vec<String> = captures[1]
+        .split('|')
+        .map(|file_path| file_path.trim().to_string())
+        .filter(|file_path| !file_path.is_empty())
+        .collect();
+
+    match file_paths.is_empty() {
+        true => None,
+        false => Some(file_paths)
+    }
+}
+
+/// The auth mode is given by the method block of a request, or by the auth block of a collection or folder
+fn convert_auth(context: &mut BrunoImportContext, element: &str, bru_file: &BruFile, auth_mode: &str, inherited_auth: Option<Auth>) -> Auth {
+    let field = |block_name: &str, key: &str| convert_template(&bru_file.value(block_name, key));
+
+    match auth_mode {
+        "" | "none" => Auth::NoAuth,
+        "inherit" => inherited_auth.unwrap_or(Auth::InheritFromCollection),
+        "basic" => Auth::BasicAuth(BasicAuth {
+            username: field("auth:basic", "username"),
+            password: field("auth:basic", "password"),
+        }),
+        "bearer" => Auth::BearerToken(BearerToken {
+            token: field("auth:bearer", "token"),
+        }),
+        "digest" => Auth::Digest(Digest {
+            username: field("auth:digest", "username"),
+            password: field("auth:digest", "password"),
+            domains: String::new(),
+            realm: String::new(),
+            nonce: String::new(),
+            opaque: String::new(),
+            stale: false,
+            algorithm: DigestAlgorithm::default(),
+            qop: DigestQop::default(),
+            user_hash: false,
+            charset: DigestCharset::default(),
+            nc: 0,
+        }),
+        "apikey" => Auth::ApiKey(ApiKey {
+            key: field("auth:apikey", "key"),
+            value: field("auth:apikey", "value"),
+            placement: match field("auth:apikey", "placement").as_str() {
+                "queryparams" => ApiKeyPlacement::QueryParam,
+                _ => ApiKeyPlacement::Header
+            },
+        }),
+        "awsv4" => {
+            let profile_name = field("auth:awsv4", "profileName");
+
+            if !profile_name.is_empty() {
+                context.report(element.to_string(), format!("AWS profile \"{profile_name}\""));
+            }
+
+            Auth::AwsSigV4(AwsSigV4 {
+                access_key_id: field("auth:awsv4", "accessKeyId"),
+                secret_access_key: field("auth:awsv4", "secretAccessKey"),
+                region: field("auth:awsv4", "region"),
+                service: field("auth:awsv4", "service"),
+                session_token: field("auth:awsv4", "sessionToken"),
+            })
+        },
+        "oauth2" => {
+            let grant_type = field("auth:oauth2", "grant_type");
+
+            let oauth2_grant_type = match grant_type.as_str() {
+                "client_credentials" => OAuth2GrantType::ClientCredentials,
+                "password" => OAuth2GrantType::Password,
+                "authorization_code" => OAuth2GrantType::AuthorizationCode,
+                _ => {
+                    context.report(element.to_string(), format!("OAuth2 \"{grant_type}\" grant"));
+                    return Auth::NoAuth;
+                }
+            };
+
+            Auth::OAuth2(OAuth2 {
+                grant_type: oauth2_grant_type,
+                token_url: field("auth:oauth2", "access_token_url"),
+                client_id: field("auth:oauth2", "client_id"),
+                client_secret: field("auth:oauth2", "client_secret"),
+                scope: field("auth:oauth2", "scope"),
+                username: field("auth:oauth2", "username"),
+                password: field("auth:oauth2", "password"),
+                authorization_url: field("auth:oauth2", "authorization_url"),
+                redirect_url: field("auth:oauth2", "callback_url"),
+                ..Default::default()
+            })
+        },
+        auth_mode => {
+            context.report(element.to_string(), format!("\"{auth_mode}\" auth"));
+            Auth::NoAuth
+        }
+    }
+}
+
+/// Bruno scripts and tests use the req, res and bru objects that ATAC does not have, they are kept commented out to be ported, while the assertions become tests
+fn convert_scripts(context: &mut BrunoImportContext, element: &str, bru_file: &BruFile) -> RequestScripts {
+    let pre_request_script = bru_file.text("script:pre-request");
+    let post_request_scripts: Vec<String> = [bru_file.text("script:post-response"), bru_file.text("tests")]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let comment_out = |script: &str| {
+        let lines: Vec<String> = script.lines().map(|line| format!("// {line}").trim_end().to_string()).collect();
+        format!("// Imported from Bruno, to be ported to the ATAC scripting API\n{}", lines.join("\n"))
+    };
+
+    if pre_request_script.is_some() {
+        context.report(element.to_string(), String::from("pre-request script, it was imported commented out"));
+    }
+
+    if !post_request_scripts.is_empty() {
+        context.report(element.to_string(), String::from("post-response script or tests, they were imported commented out"));
+    }
+
+    let mut post_request_script: Vec<String> = match post_request_scripts.is_empty() {
+        true => vec![],
+        false => vec![comment_out(&post_request_scripts.join("\n\n"))],
+    };
+
+    let mut tests: Vec<String> = vec![];
+
+    for pair in bru_file.dictionary("assert") {
+        match convert_assertion(&pair.key, &pair.value) {
+            Some(test) if pair.enabled => tests.push(test),
+            Some(test) => tests.push(format!("// {test}")),
+            None => context.report(element.to_string(), format!("assertion \"{}: {}\"", pair.key, pair.value))
+        }
+    }
+
+    if !tests.is_empty() {
+        post_request_script.push(format!("// Imported from Bruno assertions\n{}", tests.join("\n")));
+    }
+
+    RequestScripts {
+        pre_request_script: pre_request_script.map(|script| comment_out(&script)),
+        post_request_script: match post_request_script.is_empty() {
+            true => None,
+            false => Some(post_request_script.join("\n\n")),
+        },
+    }
+}
+
+/// Bruno assertions become tests, e.g. "res.status: eq 200" becomes test("res.status: eq 200", () => expect(...).toEqual(200))
+fn convert_assertion(expression: &str, assertion: &str) -> Option<String> {
+    let actual = convert_response_expression(expression)?;
+
+    let assertion = assertion.trim();
+    let (operator, value) = assertion.split_once(char::is_whitespace).unwrap_or((assertion, ""));
+    let value = value.trim();
+
+    let expectation = match (operator, value.is_empty()) {
+        ("eq", false) => format!("expect({actual}).toEqual({})", convert_assertion_value(value)?),
+        ("neq", false) => format!("expect({actual}).not.toEqual({})", convert_assertion_value(value)?),
+        ("gt", false) => format!("expect({actual}).toBeGreaterThan({})", convert_assertion_value(value)?),
+        ("gte", false) => format!("expect({actual}).toBeGreaterThanOrEqual({})", convert_assertion_value(value)?),
+        ("lt", false) => format!("expect({actual}).toBeLessThan({})", convert_assertion_value(value)?),
+        ("lte", false) => format!("expect({actual}).toBeLessThanOrEqual({})", convert_assertion_value(value)?),
+        ("contains", false) => format!("expect({actual}).toContain({})", convert_assertion_value(value)?),
+        ("notContains", false) => format!("expect({actual}).not.toContain({})", convert_assertion_value(value)?),
+        ("matches", false) => format!("expect({actual}).toMatch({})", convert_assertion_value(value)?),
+        ("notMatches", false) => format!("expect({actual}).not.toMatch({})", convert_assertion_value(value)?),
+        ("length", false) => format!("expect({actual}).toHaveLength({})", convert_assertion_value(value)?),
+        ("isEmpty", true) => format!("expect({actual}).toHaveLength(0)"),
+        ("isNotEmpty", true) => format!("expect({actual}).not.toHaveLength(0)"),
+        ("isNull", true) => format!("expect({actual}).toBeNull()"),
+        ("isUndefined", true) => format!("expect({actual}).toBeUndefined()"),
+        ("isDefined", true) => format!("expect({actual}).toBeDefined()"),
+        ("isTruthy", true) => format!("expect({actual}).toBeTruthy()"),
+        ("isFalsy", true) => format!("expect({actual}).toBeFalsy()"),
+        ("isNumber", true) => format!("expect(typeof {actual}).toBe(\"number\")"),
+        ("isString", true) => format!("expect(typeof {actual}).toBe(\"string\")"),
+        ("isBoolean", true) => format!("expect(typeof {actual}).toBe(\"boolean\")"),
+        ("isArray", true) => format!("expect(Array.isArray({actual})).toBe(true)"),
+        _ => return None
+    };
+
+    let name = serde_json::to_string(&format!("{expression}: {assertion}")).unwrap();
+
+    Some(format!("test({name}, () => {expectation});"))
+}
+
+/// Same response expressions as the capture rules, read from the script response object
+fn convert_response_expression(expression: &str) -> Option<String> {
+    let capture_rule = convert_capture_rule(expression)?;
+
+    if capture_rule == "status" {
+        return Some(String::from("parseInt(response.status_code)"));
+    }
+
+    if let Some(header) = capture_rule.strip_prefix("header ") {
+        let header = serde_json::to_string(&header.to_lowercase()).unwrap();
+        return Some(format!("response.headers.find(([name]) => name.toLowerCase() === {header})?.[1]"));
+    }
+
+    let path = capture_rule.strip_prefix("body $")?;
+
+    Some(format!("JSON.parse(response.content){path}"))
+}
+
+/// Numbers, booleans, null and quoted strings are kept as they are, anything else is a string
+fn convert_assertion_value(value: &str) -> Option<String> {
+    // Variables are not available to the scripts
+    if value.contains("{{") {
+        return None;
+    }
+
+    let is_literal = matches!(value, "true" | "false" | "null")
+        || value.parse::<f64>().is_ok()
+        || serde_json::from_str::<String>(value).is_ok();
+
+    match is_literal {
+        true => Some(value.to_string()),
+        false => Some(serde_json::to_string(value.trim_matches('\'')).unwrap())
+    }
+}
+
+fn report_variables(context: &mut BrunoImportContext, element: &str, bru_file: &BruFile) {
+    for block_name in ["vars:pre-request", "vars:post-response"] {
+        for pair in bru_file.dictionary(block_name) {
+            context.report(element.to_string(), format!("variable \"{}\"", pair.key));
+        }
+    }
+}
+
+/// Simple response expressions become capture rules, e.g. res.body.token becomes "body $.token"
+fn convert_capture_rule(expression: &str) -> Option<String> {
+    let expression = expression.trim();
+
+    if expression == "res.status" || expression == "res.getStatus()" {
+        return Some(String::from("status"));
+    }
+
+    let header_regex = Regex::new(r#"^res\.(?:headers\[["']([^"']+)["']]|headers\.([\w-]+)|getHeader\(["']([^"']+)["']\))$"#).unwrap();
+
+    if let Some(captures) = header_regex.captures(expression) {
+        let header = captures.get(1).or(captures.get(2)).or(captures.get(3)).unwrap().as_str();
+        return Some(format!("header {header}"));
+    }
+
+    let body_regex = Regex::new(r"^res\.(?:body|getBody\(\))((?:\.[A-Za-z_$][\w$]*|\[\d+])*)$").unwrap();
+
+    if let Some(captures) = body_regex.captures(expression) {
+        return Some(format!("body ${}", &captures[1]));
+    }
+
+    None
+}
+
+/// Bruno variables are also written {{name}}, only the dynamic ones differ
+fn convert_template(text: &str) -> String {
+    let process_env_regex = Regex::new(r"\{\{\s*process\.env\.(\w+)\s*}}").unwrap();
+
+    let text = process_env_regex.replace_all(text, "{{$1}}");
+
+    text
+        .replace("{{$timestamp}}", "{{TIMESTAMP}}")
+        .replace("{{$isoTimestamp}}", "{{NOW}}")
+        .replace("{{$guid}}", "{{UUIDv4}}")
+        .replace("{{$randomUUID}}", "{{UUIDv4}}")
+}
+
+fn read_bru_file(path: &Path) -> anyhow::Result<Option<BruFile>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let bru_file = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| BruFile::from_str(&content));
+
+    match bru_file {
+        Ok(bru_file) => Ok(Some(bru_file)),
+        Err(e) => Err(anyhow!(CouldNotParseBruFile(path.display().to_string(), e)))
+    }
+}
+
+fn list_directory(directory: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(e) => return Err(anyhow!(CouldNotReadDirectory(directory.display().to_string(), e.to_string())))
+    };
+
+    Ok(entries.flatten().map(|entry| entry.path()).collect())
+}
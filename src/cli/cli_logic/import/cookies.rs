@@ -0,0 +1,125 @@
+use std::fs;
+
+use anyhow::anyhow;
+use chrono::{DateTime, SecondsFormat};
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::cli_logic::cookies::print_cookie_row;
+use crate::cli::cli_logic::import::cookies::ImportCookiesError::{CouldNotParseLine, CouldNotReadFile};
+use crate::cli::commands::import::CookiesImport;
+use crate::models::cookie::COOKIES_COLUMNS_NUMBER;
+
+#[derive(Error, Debug)]
+enum ImportCookiesError {
+    #[error("Could not read cookies file\n\t{0}")]
+    CouldNotReadFile(String),
+    #[error("Could not parse cookies file line {0}\n\t{1}")]
+    CouldNotParseLine(usize, String),
+}
+
+/// Lines starting with this prefix are not comments but HttpOnly cookies
+pub const HTTP_ONLY_PREFIX: &str = "#HttpOnly_";
+
+impl App<'_> {
+    pub fn import_cookies_file(&mut self, cookies_import: &CookiesImport) -> anyhow::Result<()> {
+        let path_buf = &cookies_import.import_path;
+
+        if let Some(env_name) = &cookies_import.env {
+            let env_index = self.find_environment(env_name)?;
+            self.select_environment(env_index);
+        }
+
+        println!("Parsing Netscape cookies file");
+
+        let file_content = match fs::read_to_string(path_buf) {
+            Ok(content) => content,
+            Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string())))
+        };
+
+        let mut cookie_rows = vec![];
+
+        for (index, line) in file_content.lines().enumerate() {
+            match parse_netscape_cookie_line(line) {
+                Ok(Some(cookie_row)) => cookie_rows.push(cookie_row),
+                Ok(None) => {},
+                Err(error) => return Err(anyhow!(CouldNotParseLine(index + 1, error)))
+            }
+        }
+
+        let skipped_cookies = self.import_cookies(&cookie_rows);
+
+        for (index, cookie_row) in cookie_rows.iter().enumerate() {
+            match skipped_cookies.iter().find(|(skipped_index, _)| *skipped_index == index) {
+                None => print_cookie_row(cookie_row),
+                Some((_, error)) => println!("Skipped cookie \"{}\": {error}", cookie_row[1])
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// domain, include subdomains, path, secure, expiration timestamp, name, value.
+/// Returns None for comments and empty lines
+fn parse_netscape_cookie_line(line: &str) -> Result<Option<[String; COOKIES_COLUMNS_NUMBER]>, String> {
+    let line = line.trim_end_matches('\r');
+
+    let (line, http_only) = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+        Some(line) => (line, true),
+        None => (line, false)
+    };
+
+    if line.trim().is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let fields: Vec<&str> = line.splitn(7, '\t').collect();
+
+    let [domain, include_subdomains, path, secure, expires, name, value] = fields[..] else {
+        return Err(format!("Expected 7 tab-separated fields, found {}", fields.len()));
+    };
+
+    let host = domain.trim_start_matches('.');
+
+    let domain = match parse_netscape_flag(include_subdomains)? {
+        true => format!(".{host}"),
+        false => host.to_string()
+    };
+
+    let expires = match expires.parse::<i64>() {
+        Ok(0) => String::from("session"),
+        Ok(timestamp) => match DateTime::from_timestamp(timestamp, 0) {
+            Some(datetime) => datetime.to_rfc3339_opts(SecondsFormat::Secs, true),
+            None => return Err(format!("Invalid expiration timestamp \"{expires}\""))
+        },
+        Err(_) => return Err(format!("Invalid expiration timestamp \"{expires}\""))
+    };
+
+    Ok(Some([
+        domain,
+        name.to_string(),
+        value.to_string(),
+        path.to_string(),
+        expires,
+        cookie_flag_to_row(http_only),
+        cookie_flag_to_row(parse_netscape_flag(secure)?),
+        String::new()
+    ]))
+}
+
+/// Same as a cookie that was set without the attribute
+fn cookie_flag_to_row(flag: bool) -> String {
+    match flag {
+        true => String::from("true"),
+        false => String::new()
+    }
+}
+
+fn parse_netscape_flag(flag: &str) -> Result<bool, String> {
+    match flag.to_uppercase().as_str() {
+        "TRUE" => Ok(true),
+        "FALSE" => Ok(false),
+        _ => Err(format!("Invalid flag \"{flag}\", expected TRUE or FALSE"))
+    }
+}
@@ -20,7 +20,7 @@ use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{extract_www_authenticate_digest_data, Digest, DigestAlgorithm, DigestCharset, DigestQop};
-use crate::models::collection::Collection;
+use crate::models::collection::{Collection, CollectionDefaults};
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::body::ContentType::NoBody;
 use crate::models::protocol::http::http::HttpRequest;
@@ -66,6 +66,8 @@ impl App<'_> {
                     name: collection_name.clone(),
                     last_position: Some(self.collections.len() - 1),
                     requests: vec![],
+                    folders: vec![],
+                    defaults: CollectionDefaults::default(),
                     path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name.clone(), file_format.to_string())),
                     file_format,
                 };
@@ -90,6 +92,7 @@ impl App<'_> {
 
         // Add the parsed request to the collection
         collection.requests.extend(requests);
+        collection.create_missing_folders();
 
         self.save_collection_to_file(collection_index);
         
@@ -118,6 +121,14 @@ fn parse_requests_recursively(path: &PathBuf, recursive: bool, max_depth: u16) -
         let file_name = entry.file_name().to_str().unwrap().to_string();
         let request = parse_request(&entry.path().to_path_buf(), file_name)?;
 
+        // Sub-directories become the request folders
+        if let Some(Ok(relative_directory)) = entry.path().parent().map(|parent| parent.strip_prefix(path)) {
+            request.write().folder = relative_directory
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect();
+        }
+
         requests.push(request);
     }
 
@@ -0,0 +1,305 @@
+use std::fs;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use reqwest::Url;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::cli::cli_logic::import::har::ImportHarError::{CouldNotParseHar, CouldNotReadFile};
+use crate::cli::commands::import::HarImport;
+use crate::models::collection::{Collection, CollectionDefaults};
+use crate::models::har::{Har, HarEntry, HarParam, HarPostData};
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::{KeyValue, Request};
+use crate::models::response::{RequestResponse, ResponseContent};
+
+#[derive(Error, Debug)]
+enum ImportHarError {
+    #[error("Could not read HAR file\n\t{0}")]
+    CouldNotReadFile(String),
+    #[error("Could not parse HAR file \"{0}\"\n\t{1}")]
+    CouldNotParseHar(String, String),
+}
+
+/// Headers computed again when the request is sent
+const SKIPPED_HEADERS: [&str; 3] = ["host", "content-length", "connection"];
+
+impl App<'_> {
+    pub fn import_har_file(&mut self, har_import: &HarImport) -> anyhow::Result<()> {
+        let path_buf = &har_import.import_path;
+        let collection_name = &har_import.collection_name;
+
+        println!("Parsing HAR file");
+
+        let file_content = match fs::read_to_string(path_buf) {
+            Ok(content) => content,
+            Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string())))
+        };
+
+        let har = match serde_json::from_str::<Har>(&file_content) {
+            Ok(har) => har,
+            Err(e) => return Err(anyhow!(CouldNotParseHar(path_buf.display().to_string(), e.to_string())))
+        };
+
+        println!("Collection name: {}", collection_name);
+
+        let collection_index = match self.collections.iter().position(|collection| &collection.name == collection_name) {
+            Some(collection_index) => collection_index,
+            None => {
+                println!("Collection does not exist. Creating it...");
+
+                let file_format = self.config.get_preferred_collection_file_format();
+
+                let collection = Collection {
+                    name: collection_name.clone(),
+                    last_position: Some(self.collections.len()),
+                    requests: vec![],
+                    folders: vec![],
+                    defaults: CollectionDefaults::default(),
+                    path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format)),
+                    file_format,
+                };
+
+                self.collections.push(collection);
+
+                self.collections.len() - 1
+            }
+        };
+
+        let collection = &mut self.collections[collection_index];
+
+        let mut request_names: Vec<String> = collection.requests
+            .iter()
+            .map(|request| request.read().name.clone())
+            .collect();
+
+        for (index, entry) in har.log.entries.iter().enumerate() {
+            match parse_entry(entry) {
+                Ok(mut request) => {
+                    request.name = unique_request_name(&request_names, &request.name);
+
+                    println!("\tFound request \"{}\"", request.name);
+
+                    request_names.push(request.name.clone());
+                    collection.requests.push(Arc::new(RwLock::new(request)));
+                },
+                Err(error) => println!("\tSkipped entry {}: {error}", index + 1)
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
+
+fn parse_entry(entry: &HarEntry) -> Result<Request, String> {
+    let har_request = &entry.request;
+
+    let method = match Method::from_str(&har_request.method.to_uppercase()) {
+        Ok(method) => method,
+        Err(_) => return Err(format!("Unknown method \"{}\"", har_request.method))
+    };
+
+    let mut url = match Url::parse(&har_request.url) {
+        Ok(url) => url,
+        Err(e) => return Err(format!("Could not parse URL \"{}\": {e}", har_request.url))
+    };
+
+    /* QUERY PARAMS */
+
+    let params: Vec<KeyValue> = match har_request.query_string.is_empty() {
+        false => har_request.query_string
+            .iter()
+            .map(|param| KeyValue {
+                enabled: true,
+                data: (param.name.clone(), param.value.clone()),
+            })
+            .collect(),
+        // Some tools only keep the query params in the URL
+        true => url.query_pairs()
+            .map(|(key, value)| KeyValue {
+                enabled: true,
+                data: (key.to_string(), value.to_string()),
+            })
+            .collect()
+    };
+
+    url.set_query(None);
+    url.set_fragment(None);
+
+    /* BODY */
+
+    let body = match &har_request.post_data {
+        None => ContentType::NoBody,
+        Some(post_data) => convert_post_data(post_data)
+    };
+
+    /* HEADERS */
+
+    let is_multipart = matches!(body, ContentType::Multipart(_));
+
+    let headers = har_request.headers
+        .iter()
+        // HTTP/2 pseudo-headers, e.g. :authority or :path
+        .filter(|header| !header.name.starts_with(':'))
+        .filter(|header| !SKIPPED_HEADERS.contains(&header.name.to_lowercase().as_str()))
+        // The boundary is generated when sending
+        .filter(|header| !(is_multipart && header.name.eq_ignore_ascii_case("content-type")))
+        .map(|header| KeyValue {
+            enabled: true,
+            data: (header.name.clone(), header.value.clone()),
+        })
+        .collect();
+
+    /* NAME */
+
+    let name = match &entry.comment {
+        Some(comment) if !comment.trim().is_empty() => comment.trim().to_string(),
+        _ => request_name_from_path(&method, url.host_str().unwrap_or_default(), url.path())
+    };
+
+    Ok(Request {
+        name,
+        url: url.to_string(),
+        params,
+        headers,
+        protocol: Protocol::HttpRequest(HttpRequest {
+            method,
+            body,
+        }),
+        response: convert_response(entry),
+        ..Default::default()
+    })
+}
+
+fn convert_post_data(post_data: &HarPostData) -> ContentType {
+    let mime_type = post_data.mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    let text = post_data.text.clone();
+
+    match mime_type.as_str() {
+        "application/x-www-form-urlencoded" => ContentType::Form(match post_data.params.is_empty() {
+            false => har_params_to_key_values(&post_data.params),
+            true => parse_url_encoded(&text)
+                .into_iter()
+                .map(|(key, value)| KeyValue {
+                    enabled: true,
+                    data: (key, value),
+                })
+                .collect()
+        }),
+        // Without its params, the multipart body can only be sent as is
+        "multipart/form-data" if !post_data.params.is_empty() => ContentType::Multipart(har_params_to_key_values(&post_data.params)),
+        _ if text.is_empty() => ContentType::NoBody,
+        "text/html" => ContentType::Html(text),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(text),
+        mime_type if mime_type.ends_with("json") => ContentType::Json(text),
+        mime_type if mime_type.ends_with("xml") => ContentType::Xml(text),
+        _ => ContentType::Raw(text)
+    }
+}
+
+/// e.g. a=1&b=hello%20world
+fn parse_url_encoded(text: &str) -> Vec<(String, String)> {
+    let mut url = Url::parse("http://localhost").unwrap();
+    url.set_query(Some(text));
+
+    url.query_pairs()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+/// Files are referenced by their name, the browser does not record their path
+fn har_params_to_key_values(params: &[HarParam]) -> Vec<KeyValue> {
+    params
+        .iter()
+        .map(|param| {
+            let value = match &param.file_name {
+                Some(file_name) => format!("!!{file_name}"),
+                None => param.value.clone().unwrap_or_default()
+            };
+
+            KeyValue {
+                enabled: true,
+                data: (param.name.clone(), value),
+            }
+        })
+        .collect()
+}
+
+fn convert_response(entry: &HarEntry) -> RequestResponse {
+    let har_response = &entry.response;
+
+    if har_response.status == 0 {
+        return RequestResponse::default();
+    }
+
+    let content = match (&har_response.content.text, har_response.content.encoding.as_deref()) {
+        (Some(text), None) => Some(ResponseContent::Body(text.clone())),
+        _ => None
+    };
+
+    let duration = match entry.time > 0.0 {
+        true => Some(format!("{:?}", Duration::from_secs_f64(entry.time / 1000.0))),
+        false => None
+    };
+
+    RequestResponse {
+        duration,
+        status_code: Some(format!("{} {}", har_response.status, har_response.status_text).trim().to_string()),
+        content,
+        cookies: None,
+        headers: har_response.headers
+            .iter()
+            .map(|header| (header.name.to_lowercase(), header.value.clone()))
+            .collect(),
+    }
+}
+
+/// e.g. "GET api-users-42", only made of characters accepted by the CLI
+pub fn request_name_from_path(method: &Method, host: &str, path: &str) -> String {
+    let path = path.trim_matches('/');
+
+    let element = match path.is_empty() {
+        true => host,
+        false => path
+    };
+
+    let element: String = element
+        .chars()
+        .map(|character| match character {
+            '/' => '-',
+            character if character.is_ascii_alphanumeric() || character == '-' || character == '_' => character,
+            _ => '_'
+        })
+        .collect();
+
+    format!("{method} {element}")
+}
+
+/// Entries often share the same method and path
+pub fn unique_request_name(request_names: &[String], name: &str) -> String {
+    let mut unique_name = name.to_string();
+    let mut index = 2;
+
+    while request_names.contains(&unique_name) {
+        unique_name = format!("{name} {index}");
+        index += 1;
+    }
+
+    unique_name
+}
@@ -0,0 +1,531 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use regex::Regex;
+use reqwest::Url;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::cli::cli_logic::import::har::{request_name_from_path, unique_request_name};
+use crate::cli::cli_logic::import::http_file::ImportHttpFileError::CouldNotReadFile;
+use crate::cli::commands::import::HttpFileImport;
+use crate::models::auth::auth::Auth;
+use crate::models::auth::basic::BasicAuth;
+use crate::models::auth::bearer_token::BearerToken;
+use crate::models::auth::digest::{Digest, DigestAlgorithm, DigestCharset, DigestQop};
+use crate::models::collection::{Collection, CollectionDefaults};
+use crate::models::environment::Environment;
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::request::{KeyValue, Request};
+use crate::models::settings::Setting;
+
+#[derive(Error, Debug)]
+enum ImportHttpFileError {
+    #[error("Could not read HTTP file\n\t{0}")]
+    CouldNotReadFile(String),
+}
+
+/// Requests are separated by lines starting with ###, the text following it is the request name in JetBrains clients
+const REQUEST_SEPARATOR: &str = "###";
+
+/// Request of a .http/.rest file, as written by the JetBrains HTTP client and the VS Code REST Client
+#[derive(Default)]
+struct HttpFileRequest {
+    name: Option<String>,
+    annotations: Vec<String>,
+    method: Option<String>,
+    target: String,
+    headers: Vec<(String, String)>,
+    body_lines: Vec<String>,
+    /// Response handler scripts and response references, not supported
+    skipped_lines: usize,
+}
+
+impl App<'_> {
+    pub fn import_http_file(&mut self, http_file_import: &HttpFileImport) -> anyhow::Result<()> {
+        let path_buf = &http_file_import.import_path;
+        let collection_name = &http_file_import.collection_name;
+
+        println!("Parsing HTTP file");
+
+        let file_content = match fs::read_to_string(path_buf) {
+            Ok(content) => content,
+            Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string())))
+        };
+
+        // Body includes such as < ./body.json are relative to the file
+        let file_directory = path_buf
+            .canonicalize()
+            .ok()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+            .unwrap_or_default();
+
+        println!("Collection name: {}", collection_name);
+
+        let collection_index = match self.collections.iter().position(|collection| &collection.name == collection_name) {
+            Some(collection_index) => collection_index,
+            None => {
+                println!("Collection does not exist. Creating it...");
+
+                let file_format = self.config.get_preferred_collection_file_format();
+
+                let collection = Collection {
+                    name: collection_name.clone(),
+                    last_position: Some(self.collections.len()),
+                    requests: vec![],
+                    folders: vec![],
+                    defaults: CollectionDefaults::default(),
+                    path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format)),
+                    file_format,
+                };
+
+                self.collections.push(collection);
+
+                self.collections.len() - 1
+            }
+        };
+
+        let (http_file_requests, file_variables) = parse_http_file(&file_content);
+
+        let collection = &mut self.collections[collection_index];
+
+        let mut request_names: Vec<String> = collection.requests
+            .iter()
+            .map(|request| request.read().name.clone())
+            .collect();
+
+        for (index, http_file_request) in http_file_requests.iter().enumerate() {
+            match convert_request(http_file_request, &file_directory) {
+                Ok(mut request) => {
+                    request.name = unique_request_name(&request_names, &request.name);
+
+                    println!("\tFound request \"{}\"", request.name);
+
+                    if http_file_request.skipped_lines > 0 {
+                        println!("\t\tSkipped the response handlers and references, they are not supported");
+                    }
+
+                    request_names.push(request.name.clone());
+                    collection.requests.push(Arc::new(RwLock::new(request)));
+                },
+                Err(error) => println!("\tSkipped request {}: {error}", index + 1)
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        if !file_variables.is_empty() {
+            self.import_http_file_variables(collection_name, file_variables);
+        }
+
+        Ok(())
+    }
+
+    /// Variables defined in the file with @name = value are kept in an environment named after the collection
+    fn import_http_file_variables(&mut self, collection_name: &str, values: IndexMap<String, String>) {
+        let environment_name = collection_name.to_lowercase().replace(" ", "_");
+
+        println!("\tFound environment \"{}\"", environment_name);
+
+        let path = ARGS.directory
+            .as_ref()
+            .unwrap()
+            .join(format!(".env.{environment_name}"));
+
+        if path.exists() || self.environments.iter().any(|environment| environment.read().name == environment_name) {
+            println!("\t\tSkipped the file variables, the environment already exists");
+            return;
+        }
+
+        let environment = Environment {
+            name: environment_name,
+            values,
+            path
+        };
+
+        self.environments.push(Arc::new(RwLock::new(environment)));
+
+        let environment_index = self.environments.len() - 1;
+        self.save_environment_to_file(environment_index);
+    }
+}
+
+/// Returns the requests along with the file variables
+fn parse_http_file(file_content: &str) -> (Vec<HttpFileRequest>, IndexMap<String, String>) {
+    let file_variable_regex = Regex::new(r"^@([\w.-]+)\s*=\s*(.*)$").unwrap();
+    let annotation_regex = Regex::new(r"^(?:#|//)\s*@([\w-]+)(?:\s*=?\s*(.*))?$").unwrap();
+    let request_line_regex = Regex::new(r"^(?:([A-Z]+)\s+)?(\S.*?)(?:\s+HTTP/[\d.]+)?$").unwrap();
+
+    let mut requests = vec![];
+    let mut file_variables = IndexMap::new();
+
+    let mut blocks: Vec<(Option<String>, Vec<&str>)> = vec![(None, vec![])];
+
+    for line in file_content.lines().map(|line| line.trim_end_matches('\r')) {
+        match line.strip_prefix(REQUEST_SEPARATOR) {
+            Some(title) => {
+                let title = title.trim_start_matches('#').trim();
+                blocks.push((Some(title.to_string()).filter(|title| !title.is_empty()), vec![]));
+            },
+            None => blocks.last_mut().unwrap().1.push(line)
+        }
+    }
+
+    for (title, lines) in blocks {
+        let mut request = HttpFileRequest {
+            name: title,
+            ..Default::default()
+        };
+
+        let mut lines = lines.into_iter().peekable();
+
+        /* COMMENTS, ANNOTATIONS & VARIABLES */
+
+        while let Some(line) = lines.next() {
+            let trimmed_line = line.trim();
+
+            if trimmed_line.is_empty() {
+                continue;
+            }
+
+            if let Some(captures) = annotation_regex.captures(trimmed_line) {
+                let value = captures.get(2).map(|value| value.as_str().trim().to_string()).unwrap_or_default();
+
+                match &captures[1] {
+                    "name" if !value.is_empty() => request.name = Some(value),
+                    annotation => request.annotations.push(annotation.to_string())
+                }
+
+                continue;
+            }
+
+            if trimmed_line.starts_with('#') || trimmed_line.starts_with("//") {
+                continue;
+            }
+
+            if let Some(captures) = file_variable_regex.captures(trimmed_line) {
+                file_variables.insert(captures[1].to_string(), convert_template(captures[2].trim()));
+                continue;
+            }
+
+            /* REQUEST LINE */
+
+            let captures = request_line_regex.captures(trimmed_line).unwrap();
+
+            request.method = captures.get(1).map(|method| method.as_str().to_string());
+            request.target = captures[2].to_string();
+
+            // Long query strings can be split on several indented lines starting with ? or &
+            while let Some(next_line) = lines.peek() && next_line.starts_with(char::is_whitespace) && (next_line.trim().starts_with('?') || next_line.trim().starts_with('&')) {
+                request.target += next_line.trim();
+                lines.next();
+            }
+
+            request.target = request_line_regex
+                .captures(&request.target)
+                .map(|captures| captures[2].to_string())
+                .unwrap_or(request.target);
+
+            break;
+        }
+
+        if request.target.is_empty() {
+            continue;
+        }
+
+        /* HEADERS */
+
+        for line in lines.by_ref() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                break;
+            }
+
+            if line.starts_with('#') || line.starts_with("//") {
+                continue;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                request.headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        /* BODY */
+
+        let mut is_in_handler_script = false;
+
+        for line in lines {
+            if is_in_handler_script {
+                request.skipped_lines += 1;
+                is_in_handler_script = !line.contains("%}");
+                continue;
+            }
+
+            // Response handlers, e.g. > {% client.global.set(...) %} or > ./handler.js, and response references, e.g. <> ./response.json
+            if line.starts_with("> ") || line.starts_with(">> ") || line.starts_with("<> ") {
+                request.skipped_lines += 1;
+                is_in_handler_script = line.contains("{%") && !line.contains("%}");
+                continue;
+            }
+
+            request.body_lines.push(line.to_string());
+        }
+
+        while request.body_lines.last().is_some_and(|line| line.trim().is_empty()) {
+            request.body_lines.pop();
+        }
+
+        requests.push(request);
+    }
+
+    (requests, file_variables)
+}
+
+fn convert_request(http_file_request: &HttpFileRequest, file_directory: &Path) -> Result<Request, String> {
+    let method = match &http_file_request.method {
+        None => Method::GET,
+        Some(method) => match Method::from_str(method) {
+            Ok(method) => method,
+            Err(_) => return Err(format!("Unknown method \"{method}\""))
+        }
+    };
+
+    let mut headers: Vec<(String, String)> = http_file_request.headers
+        .iter()
+        .map(|(name, value)| (name.clone(), convert_template(value)))
+        .collect();
+
+    /* URL */
+
+    let mut url = convert_template(&http_file_request.target);
+
+    // Origin-form, e.g. GET /users HTTP/1.1 along with a Host header, as written by the ATAC HTTP export
+    if url.starts_with('/') && let Some(index) = headers.iter().position(|(name, _)| name.eq_ignore_ascii_case("host")) {
+        let (_, host) = headers.remove(index);
+        url = format!("http://{host}{url}");
+    }
+
+    let mut request = Request {
+        ..Default::default()
+    };
+
+    request.update_url_and_params(url);
+
+    /* AUTH */
+
+    if let Some(index) = headers.iter().position(|(name, _)| name.eq_ignore_ascii_case("authorization")) && let Some(auth) = authorization_to_auth(&headers[index].1) {
+        request.auth = auth;
+        headers.remove(index);
+    }
+
+    /* BODY */
+
+    let content_type = headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+        .map(|(_, value)| value.clone())
+        .unwrap_or_default();
+
+    let body = convert_body(&http_file_request.body_lines, &content_type, file_directory);
+
+    // The boundary is generated when sending
+    if matches!(body, ContentType::Multipart(_)) {
+        headers.retain(|(name, _)| !name.eq_ignore_ascii_case("content-type"));
+    }
+
+    request.headers = headers
+        .into_iter()
+        .map(|(name, value)| KeyValue {
+            enabled: true,
+            data: (name, value),
+        })
+        .collect();
+
+    request.protocol = Protocol::HttpRequest(HttpRequest {
+        method,
+        body,
+    });
+
+    /* SETTINGS */
+
+    for annotation in &http_file_request.annotations {
+        match annotation.as_str() {
+            "no-redirect" => request.settings.allow_redirects = Setting::Bool(false),
+            "no-cookie-jar" => request.settings.store_received_cookies = Setting::Bool(false),
+            _ => {}
+        }
+    }
+
+    /* NAME */
+
+    request.name = match &http_file_request.name {
+        Some(name) => name.clone(),
+        None => {
+            let url_without_scheme = request.url.split_once("://").map(|(_, url)| url).unwrap_or(&request.url);
+            let (host, path) = url_without_scheme.split_once('/').unwrap_or((url_without_scheme, ""));
+
+            request_name_from_path(&method, host, path)
+        }
+    };
+
+    Ok(request)
+}
+
+/// Both clients accept unencoded credentials, e.g. Basic {{username}} {{password}}.
+/// The password is everything after the username, so that it can contain spaces
+fn authorization_to_auth(authorization: &str) -> Option<Auth> {
+    let (scheme, credentials) = authorization.trim().split_once(char::is_whitespace)?;
+    let credentials = credentials.trim_start();
+
+    let username_password = credentials
+        .split_once(char::is_whitespace)
+        .map(|(username, password)| (username, password.trim_start()));
+
+    match (scheme, username_password) {
+        (scheme, Some((username, password))) if scheme.eq_ignore_ascii_case("basic") => Some(Auth::BasicAuth(BasicAuth {
+            username: username.to_string(),
+            password: password.to_string(),
+        })),
+        (scheme, None) if scheme.eq_ignore_ascii_case("bearer") && !credentials.is_empty() => Some(Auth::BearerToken(BearerToken {
+            token: credentials.to_string(),
+        })),
+        (scheme, Some((username, password))) if scheme.eq_ignore_ascii_case("digest") => Some(Auth::Digest(Digest {
+            username: username.to_string(),
+            password: password.to_string(),
+            domains: String::new(),
+            realm: String::new(),
+            nonce: String::new(),
+            opaque: String::new(),
+            stale: false,
+            algorithm: DigestAlgorithm::default(),
+            qop: DigestQop::default(),
+            user_hash: false,
+            charset: DigestCharset::default(),
+            nc: 0,
+        })),
+        _ => None
+    }
+}
+
+fn convert_body(body_lines: &[String], content_type: &str, file_directory: &Path) -> ContentType {
+    let text = convert_template(&body_lines.join("\n"));
+
+    if text.trim().is_empty() {
+        return ContentType::NoBody;
+    }
+
+    // The whole body is read from a file, e.g. < ./body.json
+    if body_lines.len() == 1 && let Some(file_path) = parse_file_include(&text, file_directory) {
+        return ContentType::File(file_path);
+    }
+
+    let mime_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase();
+
+    match mime_type.as_str() {
+        "application/x-www-form-urlencoded" => ContentType::Form(parse_url_encoded(&text.replace(['\n', '\r'], ""))),
+        "multipart/form-data" => match parse_multipart(&text, content_type, file_directory) {
+            Some(multipart) => ContentType::Multipart(multipart),
+            None => ContentType::Raw(text)
+        },
+        "text/html" => ContentType::Html(text),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(text),
+        mime_type if mime_type.ends_with("json") => ContentType::Json(text),
+        mime_type if mime_type.ends_with("xml") => ContentType::Xml(text),
+        _ => ContentType::Raw(text)
+    }
+}
+
+/// `< ./file` or `<@ ./file`, relative paths are resolved from the HTTP file directory
+fn parse_file_include(line: &str, file_directory: &Path) -> Option<String> {
+    let file_path = line
+        .trim()
+        .strip_prefix("<@")
+        .or_else(|| line.trim().strip_prefix('<'))?
+        .trim();
+
+    if file_path.is_empty() {
+        return None;
+    }
+
+    match file_path.starts_with('/') || file_path.starts_with("{{") {
+        true => Some(file_path.to_string()),
+        false => Some(file_directory.join(file_path.trim_start_matches("./")).display().to_string())
+    }
+}
+
+/// e.g. a=1&b={{value}}
+fn parse_url_encoded(text: &str) -> Vec<KeyValue> {
+    let mut url = Url::parse("http://localhost").unwrap();
+    url.set_query(Some(text));
+
+    url.query_pairs()
+        .map(|(key, value)| KeyValue {
+            enabled: true,
+            data: (key.to_string(), value.to_string()),
+        })
+        .collect()
+}
+
+/// Parts are either a value or a file include, e.g. < ./image.png
+fn parse_multipart(text: &str, content_type: &str, file_directory: &Path) -> Option<Vec<KeyValue>> {
+    let boundary_regex = Regex::new(r#"boundary="?([^";]+)"?"#).unwrap();
+    let name_regex = Regex::new(r#"(?i)content-disposition:.*?\bname="([^"]*)""#).unwrap();
+
+    let boundary = boundary_regex.captures(content_type)?[1].to_string();
+    let delimiter = format!("--{boundary}");
+
+    let mut key_values = vec![];
+
+    for part in text.split(&delimiter).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+
+        let part = part.trim_start_matches(['\r', '\n']);
+        let (part_headers, part_content) = part.split_once("\n\n").unwrap_or((part, ""));
+        let name = name_regex.captures(part_headers)?[1].to_string();
+        let part_content = part_content.trim_end_matches(['\r', '\n']);
+
+        let value = match parse_file_include(part_content, file_directory) {
+            Some(file_path) if !part_content.contains('\n') => format!("!!{file_path}"),
+            _ => part_content.to_string()
+        };
+
+        key_values.push(KeyValue {
+            enabled: true,
+            data: (name, value),
+        });
+    }
+
+    Some(key_values)
+}
+
+/// {{var}} variables are kept as is, only the dynamic ones differ
+fn convert_template(text: &str) -> String {
+    let process_env_regex = Regex::new(r"\{\{\s*\$(?:processEnv|dotenv)\s+(\w+)\s*}}").unwrap();
+
+    let text = process_env_regex.replace_all(text, "{{$1}}");
+
+    text
+        .replace("{{$timestamp}}", "{{TIMESTAMP}}")
+        .replace("{{$isoTimestamp}}", "{{NOW}}")
+        .replace("{{$datetime iso8601}}", "{{NOW}}")
+        .replace("{{$guid}}", "{{UUIDv4}}")
+        .replace("{{$uuid}}", "{{UUIDv4}}")
+        .replace("{{$random.uuid}}", "{{UUIDv4}}")
+}
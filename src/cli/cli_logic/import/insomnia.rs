@@ -0,0 +1,752 @@
+use std::collections::HashSet;
+use std::fs;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::cli::cli_logic::import::insomnia::ImportInsomniaError::{CollectionAlreadyExists, CouldNotParseExport, CouldNotReadFile, UnsupportedExportFormat};
+use crate::cli::commands::import::InsomniaImport;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
+use crate::models::auth::auth::Auth;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::basic::BasicAuth;
+use crate::models::auth::bearer_token::BearerToken;
+use crate::models::auth::digest::{Digest, DigestAlgorithm, DigestCharset, DigestQop};
+use crate::models::auth::oauth2::{OAuth2, OAuth2GrantType};
+use crate::models::collection::{Collection, CollectionDefaults, Folder};
+use crate::models::environment::Environment;
+use crate::models::protocol::graphql::graphql::GraphqlRequest;
+use crate::models::protocol::http::body::ContentType;
+use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
+use crate::models::protocol::ws::ws::WsRequest;
+use crate::models::request::{DEFAULT_HEADERS, KeyValue, Request};
+use crate::models::settings::Setting;
+
+#[derive(Error, Debug)]
+enum ImportInsomniaError {
+    #[error("Could not read Insomnia export file\n\t{0}")]
+    CouldNotReadFile(String),
+    #[error("Could not parse Insomnia export \"{0}\"\n\t{1}")]
+    CouldNotParseExport(String, String),
+    #[error("Unsupported Insomnia export format {0}, only the v4 format is supported")]
+    UnsupportedExportFormat(u64),
+    #[error("Collection \"{0}\" already exists")]
+    CollectionAlreadyExists(String),
+}
+
+#[derive(Deserialize)]
+struct InsomniaExport {
+    #[serde(rename = "__export_format")]
+    export_format: u64,
+    resources: Vec<InsomniaResource>,
+}
+
+/// Every resource type shares the same flat structure, only the relevant fields are filled
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct InsomniaResource {
+    #[serde(rename = "_id")]
+    id: String,
+    #[serde(rename = "_type")]
+    resource_type: String,
+    parent_id: Option<String>,
+    #[serde(deserialize_with = "lenient_string")]
+    name: String,
+    #[serde(deserialize_with = "lenient_string")]
+    description: String,
+    meta_sort_key: Option<f64>,
+
+    /* Requests */
+
+    #[serde(deserialize_with = "lenient_string")]
+    url: String,
+    #[serde(deserialize_with = "lenient_string")]
+    method: String,
+    body: InsomniaBody,
+    parameters: Vec<InsomniaPair>,
+    headers: Vec<InsomniaPair>,
+    authentication: Value,
+    #[serde(deserialize_with = "lenient_string")]
+    setting_follow_redirects: String,
+    setting_store_cookies: Option<bool>,
+    setting_send_cookies: Option<bool>,
+    #[serde(deserialize_with = "lenient_string")]
+    pre_request_script: String,
+    #[serde(deserialize_with = "lenient_string")]
+    after_response_script: String,
+
+    /* Environments */
+
+    data: Value,
+    /// Variables of a request group
+    environment: Value,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct InsomniaBody {
+    #[serde(deserialize_with = "lenient_string")]
+    mime_type: String,
+    #[serde(deserialize_with = "lenient_string")]
+    text: String,
+    #[serde(deserialize_with = "lenient_string")]
+    file_name: String,
+    params: Vec<InsomniaPair>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase")]
+struct InsomniaPair {
+    #[serde(deserialize_with = "lenient_string")]
+    name: String,
+    #[serde(deserialize_with = "lenient_string")]
+    value: String,
+    disabled: bool,
+    #[serde(rename = "type", deserialize_with = "lenient_string")]
+    pair_type: String,
+    #[serde(deserialize_with = "lenient_string")]
+    file_name: String,
+}
+
+/// YAML exports may contain numbers, booleans or nulls where a string is expected
+fn lenient_string<'de, D>(deserializer: D) -> Result<String, D::Error> where D: Deserializer<'de> {
+    Ok(value_to_string(&Value::deserialize(deserializer)?))
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        value => value.to_string()
+    }
+}
+
+/// Resources to convert, plus what was converted and what could not be
+struct InsomniaImportContext {
+    resources: Vec<InsomniaResource>,
+    visited_ids: HashSet<String>,
+    unconverted: Vec<String>,
+}
+
+impl InsomniaImportContext {
+    fn find(&self, id: &str) -> Option<&InsomniaResource> {
+        self.resources.iter().find(|resource| resource.id == id)
+    }
+
+    /// Children in the order shown by Insomnia
+    fn children(&self, parent_id: &str, resource_type: Option<&str>) -> Vec<&InsomniaResource> {
+        let mut children: Vec<&InsomniaResource> = self.resources
+            .iter()
+            .filter(|resource| resource.parent_id.as_deref() == Some(parent_id))
+            .filter(|resource| resource_type.is_none_or(|resource_type| resource.resource_type == resource_type))
+            .collect();
+
+        children.sort_by(|a, b| a.meta_sort_key.unwrap_or_default().total_cmp(&b.meta_sort_key.unwrap_or_default()));
+
+        children
+    }
+
+    fn report(&mut self, element: String, what: String) {
+        println!("\t\t\tCould not convert {what}");
+        self.unconverted.push(format!("{element}: {what}"));
+    }
+
+    /// Insomnia variables such as {{ _.base_url }} or {{ base_url }} become {{base_url}}, template tags are kept but reported
+    fn convert_template(&mut self, element: &str, text: &str) -> String {
+        let variable_regex = Regex::new(r"\{\{\s*(?:_\.)?([\w.-]+)\s*}}").unwrap();
+        let tag_regex = Regex::new(r"\{%.*?%}").unwrap();
+
+        let converted = variable_regex
+            .replace_all(text, |captures: &regex::Captures| format!("{{{{{}}}}}", sanitize_key(&captures[1])))
+            .to_string();
+
+        let tags: Vec<String> = tag_regex.find_iter(&converted).map(|tag| tag.as_str().to_string()).collect();
+
+        for tag in tags {
+            self.report(element.to_string(), format!("template tag \"{tag}\""));
+        }
+
+        converted
+    }
+}
+
+impl App<'_> {
+    pub fn import_insomnia_export(&mut self, insomnia_import: &InsomniaImport) -> anyhow::Result<()> {
+        let path_buf = &insomnia_import.import_path;
+
+        println!("Parsing Insomnia export");
+
+        let file_content = match fs::read_to_string(path_buf) {
+            Ok(content) => content,
+            Err(e) => return Err(anyhow!(CouldNotReadFile(e.to_string())))
+        };
+
+        // JSON is also valid YAML
+        let insomnia_export = match serde_yaml::from_str::<InsomniaExport>(&file_content) {
+            Ok(insomnia_export) => insomnia_export,
+            Err(e) => return Err(anyhow!(CouldNotParseExport(path_buf.display().to_string(), e.to_string())))
+        };
+
+        if insomnia_export.export_format != 4 {
+            return Err(anyhow!(UnsupportedExportFormat(insomnia_export.export_format)));
+        }
+
+        let mut context = InsomniaImportContext {
+            resources: insomnia_export.resources,
+            visited_ids: HashSet::new(),
+            unconverted: vec![],
+        };
+
+        // Workspaces become collections, so do the request groups exported without their workspace
+        let root_ids: Vec<String> = context.resources
+            .iter()
+            .filter(|resource| match resource.resource_type.as_str() {
+                "workspace" => true,
+                "request_group" => resource.parent_id.as_deref().and_then(|parent_id| context.find(parent_id)).is_none(),
+                _ => false
+            })
+            .map(|resource| resource.id.clone())
+            .collect();
+
+        let mut collection_names: Vec<String> = vec![];
+
+        for root_id in &root_ids {
+            let collection_name = context.find(root_id).unwrap().name.clone();
+
+            if collection_names.contains(&collection_name) || self.collections.iter().any(|collection| collection.name == collection_name) {
+                return Err(anyhow!(CollectionAlreadyExists(collection_name)));
+            }
+
+            collection_names.push(collection_name);
+        }
+
+        let file_format = self.config.get_preferred_collection_file_format();
+
+        for (root_id, collection_name) in root_ids.iter().zip(collection_names) {
+            println!("Collection name: {}", collection_name);
+
+            let mut collection = Collection {
+                name: collection_name.clone(),
+                last_position: Some(self.collections.len()),
+                requests: vec![],
+                folders: vec![],
+                defaults: CollectionDefaults::default(),
+                path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format)),
+                file_format,
+            };
+
+            context.visited_ids.insert(root_id.clone());
+
+            let root = context.find(root_id).unwrap();
+
+            if root.resource_type == "request_group" {
+                report_request_group_leftovers(&mut context, root_id);
+            }
+
+            recursive_import_resources(&mut context, root_id, &[], &mut collection.folders, &mut collection.requests);
+
+            self.collections.push(collection);
+
+            let collection_index = self.collections.len() - 1;
+            self.save_collection_to_file(collection_index);
+
+            for (environment_name, values) in convert_environments(&mut context, root_id) {
+                self.import_insomnia_environment(&mut context, environment_name, values);
+            }
+        }
+
+        let skipped_resources: Vec<(String, String)> = context.resources
+            .iter()
+            .filter(|resource| !context.visited_ids.contains(&resource.id))
+            .map(|resource| (resource.resource_type.clone(), resource.name.clone()))
+            .collect();
+
+        for (resource_type, name) in skipped_resources {
+            context.unconverted.push(format!("{resource_type} \"{name}\": not supported"));
+        }
+
+        match context.unconverted.is_empty() {
+            true => println!("Everything was converted"),
+            false => {
+                println!("Could not convert:");
+
+                for unconverted in &context.unconverted {
+                    println!("\t{unconverted}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn import_insomnia_environment(&mut self, context: &mut InsomniaImportContext, environment_name: String, values: IndexMap<String, String>) {
+        let environment_name = environment_name.to_lowercase().replace(" ", "_");
+
+        println!("\tFound environment \"{}\"", environment_name);
+
+        let path = ARGS.directory
+            .as_ref()
+            .unwrap()
+            .join(format!(".env.{environment_name}"));
+
+        if path.exists() || self.environments.iter().any(|environment| environment.read().name == environment_name) {
+            context.report(format!("environment \"{environment_name}\""), String::from("environment, it already exists"));
+            return;
+        }
+
+        let environment = Environment {
+            name: environment_name,
+            values,
+            path
+        };
+
+        self.environments.push(Arc::new(RwLock::new(environment)));
+
+        let environment_index = self.environments.len() - 1;
+        self.save_environment_to_file(environment_index);
+    }
+}
+
+/// Request groups become collection folders, keeping their nesting
+fn recursive_import_resources(context: &mut InsomniaImportContext, parent_id: &str, path: &[String], folders: &mut Vec<Folder>, requests: &mut Vec<Arc<RwLock<Request>>>) {
+    let children_ids: Vec<String> = context.children(parent_id, None)
+        .iter()
+        .map(|child| child.id.clone())
+        .collect();
+
+    for child_id in children_ids {
+        let child = context.find(&child_id).unwrap();
+
+        match child.resource_type.as_str() {
+            "request_group" => {
+                let folder_name = child.name
+                    .replace("/", "-")
+                    .replace("\\", "-")
+                    .trim()
+                    .to_string();
+
+                println!("\tFound folder \"{}\"", folder_name);
+
+                context.visited_ids.insert(child_id.clone());
+                report_request_group_leftovers(context, &child_id);
+
+                let folder_path = [path, std::slice::from_ref(&folder_name)].concat();
+                let mut folder = Folder {
+                    name: folder_name,
+                    folders: vec![],
+                };
+
+                recursive_import_resources(context, &child_id, &folder_path, &mut folder.folders, requests);
+
+                folders.push(folder);
+            },
+            "request" | "websocket_request" => {
+                context.visited_ids.insert(child_id.clone());
+
+                if let Some(mut request) = parse_request(context, &child_id) {
+                    request.folder = path.to_vec();
+                    requests.push(Arc::new(RwLock::new(request)));
+                }
+            },
+            // Environments are converted afterward, anything else is reported as not supported
+            _ => {}
+        }
+    }
+}
+
+/// Folders only have a name in ATAC
+fn report_request_group_leftovers(context: &mut InsomniaImportContext, request_group_id: &str) {
+    let request_group = context.find(request_group_id).unwrap();
+    let element = format!("folder \"{}\"", request_group.name);
+
+    let has_variables = request_group.environment.as_object().is_some_and(|variables| !variables.is_empty());
+    let has_headers = !request_group.headers.is_empty();
+    let has_scripts = !request_group.pre_request_script.trim().is_empty() || !request_group.after_response_script.trim().is_empty();
+
+    if has_variables {
+        context.report(element.clone(), String::from("folder environment variables"));
+    }
+
+    if has_headers {
+        context.report(element.clone(), String::from("folder headers"));
+    }
+
+    if has_scripts {
+        context.report(element, String::from("folder scripts"));
+    }
+}
+
+fn parse_request(context: &mut InsomniaImportContext, request_id: &str) -> Option<Request> {
+    let resource = context.find(request_id).unwrap();
+
+    let is_websocket = resource.resource_type == "websocket_request";
+    let element = format!("request \"{}\"", resource.name);
+    let name = resource.name.clone();
+    let url = resource.url.clone();
+    let method = resource.method.to_uppercase();
+    let parameters: Vec<(bool, String, String)> = resource.parameters.iter().map(|pair| (!pair.disabled, pair.name.clone(), pair.value.clone())).collect();
+    let headers: Vec<(bool, String, String)> = resource.headers.iter().map(|pair| (!pair.disabled, pair.name.clone(), pair.value.clone())).collect();
+    let description = resource.description.trim().to_string();
+    let follow_redirects = resource.setting_follow_redirects.clone();
+    let store_cookies = resource.setting_store_cookies;
+    let send_cookies = resource.setting_send_cookies;
+    let has_scripts = !resource.pre_request_script.trim().is_empty() || !resource.after_response_script.trim().is_empty();
+
+    println!("\t\tFound request \"{}\"", name);
+
+    let mut request = Request {
+        name,
+        ..Default::default()
+    };
+
+    /* URL */
+
+    request.url = context.convert_template(&element, &url);
+
+    /* QUERY PARAMS */
+
+    for (enabled, key, value) in parameters {
+        request.params.push(KeyValue {
+            enabled,
+            data: (context.convert_template(&element, &key), context.convert_template(&element, &value)),
+        });
+    }
+
+    /* HEADERS */
+
+    request.headers = DEFAULT_HEADERS.clone();
+
+    for (enabled, key, value) in headers {
+        request.headers.push(KeyValue {
+            enabled,
+            data: (context.convert_template(&element, &key), context.convert_template(&element, &value)),
+        });
+    }
+
+    /* AUTH */
+
+    let authentication = find_authentication(context, request_id);
+    request.auth = convert_auth(context, &element, &authentication);
+
+    /* PROTOCOL & BODY */
+
+    request.protocol = match is_websocket {
+        true => Protocol::WsRequest(WsRequest::default()),
+        false => match convert_body(context, request_id, &element) {
+            InsomniaBodyConversion::Graphql(graphql_request) => Protocol::GraphqlRequest(graphql_request),
+            InsomniaBodyConversion::Http(body) => {
+                let method = match Method::from_str(&method) {
+                    Ok(method) => method,
+                    Err(_) => {
+                        context.report(element, format!("method \"{method}\", the request was skipped"));
+                        return None;
+                    }
+                };
+
+                let has_content_type = request.headers.iter().any(|header| header.data.0.eq_ignore_ascii_case("content-type"));
+
+                // Keep the exact content type given in Insomnia, e.g. application/vnd.api+json
+                if !has_content_type && !matches!(body, ContentType::NoBody | ContentType::Multipart(_)) {
+                    request.modify_or_create_header("content-type", &body.to_content_type());
+                }
+
+                Protocol::HttpRequest(HttpRequest {
+                    method,
+                    body,
+                })
+            }
+        }
+    };
+
+    /* SETTINGS */
+
+    match follow_redirects.as_str() {
+        "on" => request.settings.allow_redirects = Setting::Bool(true),
+        "off" => request.settings.allow_redirects = Setting::Bool(false),
+        _ => {}
+    }
+
+    if store_cookies == Some(false) {
+        request.settings.store_received_cookies = Setting::Bool(false);
+    }
+
+    if send_cookies == Some(false) {
+        context.report(element.clone(), String::from("\"send cookies\" setting"));
+    }
+
+    /* OTHERS */
+
+    if has_scripts {
+        context.report(element.clone(), String::from("scripts, the Insomnia scripting API differs"));
+    }
+
+    if !description.is_empty() {
+        context.report(element, String::from("description"));
+    }
+
+    Some(request)
+}
+
+/// An empty authentication is inherited from the closest request group that has one
+fn find_authentication(context: &InsomniaImportContext, request_id: &str) -> Value {
+    let mut resource = context.find(request_id);
+
+    while let Some(current_resource) = resource {
+        let has_authentication = current_resource.authentication.get("type").is_some();
+
+        if has_authentication {
+            return current_resource.authentication.clone();
+        }
+
+        resource = current_resource.parent_id
+            .as_deref()
+            .and_then(|parent_id| context.find(parent_id))
+            .filter(|parent| parent.resource_type == "request_group");
+    }
+
+    Value::Null
+}
+
+fn convert_auth(context: &mut InsomniaImportContext, element: &str, authentication: &Value) -> Auth {
+    let auth_type = value_to_string(&authentication["type"]);
+
+    if authentication["disabled"].as_bool() == Some(true) {
+        return Auth::NoAuth;
+    }
+
+    let mut field = |key: &str| context.convert_template(element, &value_to_string(&authentication[key]));
+
+    match auth_type.as_str() {
+        "" | "none" => Auth::NoAuth,
+        "basic" => Auth::BasicAuth(BasicAuth {
+            username: field("username"),
+            password: field("password"),
+        }),
+        "bearer" => {
+            let prefix = field("prefix");
+
+            if !prefix.is_empty() && !prefix.eq_ignore_ascii_case("Bearer") {
+                context.report(element.to_string(), format!("bearer token prefix \"{prefix}\""));
+            }
+
+            Auth::BearerToken(BearerToken {
+                token: context.convert_template(element, &value_to_string(&authentication["token"])),
+            })
+        },
+        "digest" => Auth::Digest(Digest {
+            username: field("username"),
+            password: field("password"),
+            domains: String::new(),
+            realm: String::new(),
+            nonce: String::new(),
+            opaque: String::new(),
+            stale: false,
+            algorithm: DigestAlgorithm::default(),
+            qop: DigestQop::default(),
+            user_hash: false,
+            charset: DigestCharset::default(),
+            nc: 0,
+        }),
+        "apikey" => Auth::ApiKey(ApiKey {
+            key: field("key"),
+            value: field("value"),
+            placement: match value_to_string(&authentication["addTo"]).as_str() {
+                "queryParams" => ApiKeyPlacement::QueryParam,
+                "cookie" => ApiKeyPlacement::Cookie,
+                _ => ApiKeyPlacement::Header
+            },
+        }),
+        "iam" => Auth::AwsSigV4(AwsSigV4 {
+            access_key_id: field("accessKeyId"),
+            secret_access_key: field("secretAccessKey"),
+            region: field("region"),
+            service: field("service"),
+            session_token: field("sessionToken"),
+        }),
+        "oauth2" => {
+            let grant_type = match value_to_string(&authentication["grantType"]).as_str() {
+                "client_credentials" => Some(OAuth2GrantType::ClientCredentials),
+                "password" => Some(OAuth2GrantType::Password),
+                "refresh_token" => Some(OAuth2GrantType::RefreshToken),
+                "authorization_code" => Some(OAuth2GrantType::AuthorizationCode),
+                _ => None
+            };
+
+            match grant_type {
+                Some(grant_type) => Auth::OAuth2(OAuth2 {
+                    grant_type,
+                    token_url: field("accessTokenUrl"),
+                    client_id: field("clientId"),
+                    client_secret: field("clientSecret"),
+                    scope: field("scope"),
+                    username: field("username"),
+                    password: field("password"),
+                    authorization_url: field("authorizationUrl"),
+                    redirect_url: field("redirectUrl"),
+                    refresh_token: field("refreshToken"),
+                    ..Default::default()
+                }),
+                None => {
+                    let grant_type = value_to_string(&authentication["grantType"]);
+                    context.report(element.to_string(), format!("OAuth2 \"{grant_type}\" grant"));
+                    Auth::NoAuth
+                }
+            }
+        },
+        auth_type => {
+            context.report(element.to_string(), format!("\"{auth_type}\" auth"));
+            Auth::NoAuth
+        }
+    }
+}
+
+enum InsomniaBodyConversion {
+    Http(ContentType),
+    Graphql(GraphqlRequest),
+}
+
+fn convert_body(context: &mut InsomniaImportContext, request_id: &str, element: &str) -> InsomniaBodyConversion {
+    let body = &context.find(request_id).unwrap().body;
+
+    let mime_type = body.mime_type.to_lowercase();
+    let text = body.text.clone();
+    let file_name = body.file_name.clone();
+    let params: Vec<(bool, String, String, bool)> = body.params
+        .iter()
+        .map(|param| match param.pair_type.as_str() {
+            "file" => (!param.disabled, param.name.clone(), param.file_name.clone(), true),
+            _ => (!param.disabled, param.name.clone(), param.value.clone(), false)
+        })
+        .collect();
+
+    let text = context.convert_template(element, &text);
+
+    let key_values = |context: &mut InsomniaImportContext| -> Vec<KeyValue> {
+        params
+            .iter()
+            .map(|(enabled, key, value, is_file)| {
+                let value = context.convert_template(element, value);
+
+                KeyValue {
+                    enabled: *enabled,
+                    data: (key.clone(), if *is_file { format!("!!{value}") } else { value }),
+                }
+            })
+            .collect()
+    };
+
+    let body = match mime_type.as_str() {
+        "" if text.is_empty() => ContentType::NoBody,
+        "" => ContentType::Raw(text),
+        "application/graphql" => return match serde_json::from_str::<Value>(&text) {
+            Ok(graphql_body) => InsomniaBodyConversion::Graphql(GraphqlRequest {
+                query: value_to_string(&graphql_body["query"]),
+                variables: match &graphql_body["variables"] {
+                    Value::Object(variables) if !variables.is_empty() => serde_json::to_string_pretty(variables).unwrap(),
+                    _ => String::new()
+                },
+                operation_name: graphql_body["operationName"].as_str().map(String::from),
+                schema: None,
+            }),
+            Err(_) => InsomniaBodyConversion::Http(ContentType::Json(text))
+        },
+        "application/x-www-form-urlencoded" => ContentType::Form(key_values(context)),
+        "multipart/form-data" => ContentType::Multipart(key_values(context)),
+        "application/octet-stream" => ContentType::File(file_name),
+        mime_type if mime_type.ends_with("json") => ContentType::Json(text),
+        mime_type if mime_type.ends_with("xml") => ContentType::Xml(text),
+        "text/html" => ContentType::Html(text),
+        "application/javascript" | "text/javascript" => ContentType::Javascript(text),
+        _ => ContentType::Raw(text)
+    };
+
+    InsomniaBodyConversion::Http(body)
+}
+
+/// Sub-environments are merged with the base environment, the base environment alone is named after the collection
+fn convert_environments(context: &mut InsomniaImportContext, root_id: &str) -> Vec<(String, IndexMap<String, String>)> {
+    let root_name = context.find(root_id).unwrap().name.clone();
+
+    let base_environments: Vec<(String, String, Value)> = context.children(root_id, Some("environment"))
+        .iter()
+        .map(|environment| (environment.id.clone(), environment.name.clone(), environment.data.clone()))
+        .collect();
+
+    let mut environments = vec![];
+
+    for (base_id, base_name, base_data) in base_environments {
+        context.visited_ids.insert(base_id.clone());
+
+        let element = format!("environment \"{base_name}\"");
+        let mut base_values = IndexMap::new();
+        flatten_environment_data(context, &element, "", &base_data, &mut base_values);
+
+        let sub_environments: Vec<(String, String, Value)> = context.children(&base_id, Some("environment"))
+            .iter()
+            .map(|environment| (environment.id.clone(), environment.name.clone(), environment.data.clone()))
+            .collect();
+
+        if sub_environments.is_empty() {
+            if !base_values.is_empty() {
+                environments.push((root_name.clone(), base_values));
+            }
+
+            continue;
+        }
+
+        for (sub_id, sub_name, sub_data) in sub_environments {
+            context.visited_ids.insert(sub_id);
+
+            let element = format!("environment \"{sub_name}\"");
+            let mut values = base_values.clone();
+            flatten_environment_data(context, &element, "", &sub_data, &mut values);
+
+            environments.push((sub_name, values));
+        }
+    }
+
+    environments
+}
+
+/// Nested objects such as {"api": {"url": "..."}} become API_URL-like keys, here api_url, matching the converted {{ _.api.url }}
+fn flatten_environment_data(context: &mut InsomniaImportContext, element: &str, prefix: &str, data: &Value, values: &mut IndexMap<String, String>) {
+    let Value::Object(object) = data else {
+        return;
+    };
+
+    for (key, value) in object {
+        let key = match prefix.is_empty() {
+            true => sanitize_key(key),
+            false => format!("{prefix}_{}", sanitize_key(key))
+        };
+
+        match value {
+            Value::Object(_) => flatten_environment_data(context, element, &key, value, values),
+            value => {
+                let value = context.convert_template(element, &value_to_string(value));
+                values.insert(key, value);
+            }
+        }
+    }
+}
+
+/// ATAC environment keys only contain word characters
+fn sanitize_key(key: &str) -> String {
+    key.chars()
+        .map(|character| match character.is_alphanumeric() || character == '_' {
+            true => character,
+            false => '_'
+        })
+        .collect()
+}
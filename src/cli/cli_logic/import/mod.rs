@@ -1,4 +1,9 @@
 pub mod postman_collection;
 pub mod postman_env;
 pub mod curl;
-pub mod openapi;
\ No newline at end of file
+pub mod openapi;
+pub mod insomnia;
+pub mod bruno;
+pub mod har;
+pub mod http_file;
+pub mod cookies;
\ No newline at end of file
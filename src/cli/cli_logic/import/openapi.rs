@@ -17,8 +17,9 @@ use crate::cli::commands::import::OpenApiImport;
 use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
 use crate::models::protocol::http::body::ContentType;
-use crate::models::collection::Collection;
+use crate::models::collection::{Collection, CollectionDefaults, Folder};
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
@@ -42,6 +43,10 @@ impl App<'_> {
     pub fn import_openapi_collection(&mut self, openapi_import: &OpenApiImport) -> anyhow::Result<()> {
         let path_buf = &openapi_import.import_path;
 
+        if openapi_import.max_depth.is_some() {
+            println!("The --max-depth argument is deprecated and has no effect, every folder is imported");
+        }
+
         println!("Parsing OpenAPI specification");
 
         // Read the file content
@@ -95,6 +100,8 @@ impl App<'_> {
             name: collection_name.clone(),
             last_position: Some(self.collections.len() - 1),
             requests: Vec::new(),
+            folders: Vec::new(),
+            defaults: CollectionDefaults::default(),
             path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
             file_format,
         };
@@ -126,6 +133,18 @@ impl App<'_> {
 
         println!("\tFound {} requests in OpenAPI spec", collection.requests.len());
 
+        // Tags become folders, in the order in which the spec declares them
+        for tag in &spec.tags {
+            if collection.requests.iter().any(|request| request.read().folder.first() == Some(&tag_to_folder_name(&tag.name))) {
+                collection.folders.push(Folder {
+                    name: tag_to_folder_name(&tag.name),
+                    folders: vec![],
+                });
+            }
+        }
+
+        collection.create_missing_folders();
+
         // Add the collection to app's collections
         self.collections.push(collection);
 
@@ -192,8 +211,15 @@ fn process_path_operations(collection: &mut Collection, path_item: &PathItem, pa
 fn create_request(name: String, method: Method, path: &str, base_url: &str, operation: &Operation, spec: &OpenAPI) -> anyhow::Result<Request> {
     println!("\tFound request \"{}\"", name);
 
+    // Operations are grouped by their first tag
+    let folder = match operation.tags.first() {
+        Some(tag) => vec![tag_to_folder_name(tag)],
+        None => vec![]
+    };
+
     let mut request = Request {
         name,
+        folder,
         url: format!("{}{}", base_url, path),
         protocol: Protocol::HttpRequest(HttpRequest {
             method,
@@ -220,6 +246,10 @@ fn create_request(name: String, method: Method, path: &str, base_url: &str, oper
     Ok(request)
 }
 
+fn tag_to_folder_name(tag: &str) -> String {
+    tag.replace("/", "-").trim().to_string()
+}
+
 fn process_parameters(request: &mut Request, operation: &Operation, path: &str, spec: &OpenAPI) -> anyhow::Result<()> {
     // Process path parameters
     let path_params: Vec<KeyValue> = path
@@ -517,18 +547,15 @@ fn process_security(request: &mut Request, security_requirements: &Vec<SecurityR
                         ReferenceOr::Item(scheme) => {
                             match &scheme {
                                 SecurityScheme::APIKey { name, location, .. } => {
-                                    match location {
-                                        APIKeyLocation::Header => request.modify_or_create_header(
-                                            &name.clone(),
-                                            "API_KEY"
-                                        ),
-                                        APIKeyLocation::Query => request.params.push(KeyValue {
-                                            enabled: true,
-                                            data: (name.clone(), "API_KEY".to_string()),
-                                        }),
-                                        // Not supported
-                                        APIKeyLocation::Cookie => {}
-                                    }
+                                    request.auth = Auth::ApiKey(ApiKey {
+                                        key: name.clone(),
+                                        value: "API_KEY".to_string(),
+                                        placement: match location {
+                                            APIKeyLocation::Header => ApiKeyPlacement::Header,
+                                            APIKeyLocation::Query => ApiKeyPlacement::QueryParam,
+                                            APIKeyLocation::Cookie => ApiKeyPlacement::Cookie,
+                                        },
+                                    });
                                 },
                                 SecurityScheme::HTTP { scheme, .. } => {
                                     request.auth = match scheme.as_str() {
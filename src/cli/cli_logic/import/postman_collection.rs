@@ -1,3 +1,5 @@
+use std::fs;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -5,8 +7,9 @@ use anyhow::anyhow;
 use clap::ValueEnum;
 use parking_lot::RwLock;
 use rayon::prelude::*;
+use serde_json::Value;
 
-use parse_postman_collection::v2_1_0::{AuthType, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass, RequestUnion, Url};
+use parse_postman_collection::v2_1_0::{AuthAttribute, AuthType, PostmanCollection_v2_1_0, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass, RequestUnion, Url};
 use thiserror::Error;
 
 use crate::app::app::App;
@@ -16,10 +19,11 @@ use crate::cli::commands::import::PostmanImport;
 use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
 use crate::models::auth::digest::{Digest, DigestAlgorithm, DigestCharset, DigestError, DigestQop};
 use crate::models::auth::jwt::{JwtAlgorithm, JwtSecretType, JwtToken};
 use crate::models::protocol::http::body::ContentType;
-use crate::models::collection::{Collection, CollectionFileFormat};
+use crate::models::collection::{Collection, CollectionDefaults, Folder};
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
@@ -41,11 +45,14 @@ enum ImportPostmanError {
 impl App<'_> {
     pub fn import_postman_collection(&mut self, postman_import: &PostmanImport) -> anyhow::Result<()> {
         let path_buf = &postman_import.import_path;
-        let max_depth = postman_import.max_depth.unwrap_or(99);
+
+        if postman_import.max_depth.is_some() {
+            println!("The --max-depth argument is deprecated and has no effect, every folder is imported");
+        }
 
         println!("Parsing Postman collection");
 
-        let mut postman_collection = match parse_postman_collection::from_path(path_buf) {
+        let postman_collection = match read_postman_collection(path_buf) {
             Ok(postman_collection) => postman_collection,
             Err(e) => {
                 return Err(anyhow!(CouldNotParseCollection(path_buf.display().to_string(), e.to_string())));
@@ -64,130 +71,96 @@ impl App<'_> {
 
         let file_format = self.config.get_preferred_collection_file_format();
 
-        let mut collections: Vec<Collection> = vec![
-            Collection {
-                name: collection_name.clone(),
-                last_position: Some(self.collections.len() - 1),
-                requests: vec![],
-                path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
-                file_format,
-            }
-        ];
-        
-        let mut depth_level: u16 = 0;
-
-        if max_depth == 0 {
-            for item in postman_collection.item.iter_mut() {
-                collections[0].requests.extend(recursive_get_requests(item)?);
-            }
-        }
-        else {
-            for mut item in postman_collection.item {
-                if item.name.is_none() {
-                    continue;
-                }
-
-                // If this is a folder
-                if is_folder(&item) {
-                    let mut temp_nesting_prefix = String::new();
-                    let new_collections: Vec<Collection> = vec![];
-
-                    let file_format = self.config.get_preferred_collection_file_format();
-
-                    recursive_has_requests(&mut item, &mut collections, &mut temp_nesting_prefix, &mut depth_level, max_depth, file_format)?;
-
-                    collections.extend(new_collections);
-                } else {
-                    collections[0].requests.push(Arc::new(RwLock::new(parse_request(item)?)));
-                }
-            }
-        }
-        
-        // Prevent from having an empty collection
-        if collections.len() > 1 && collections[0].requests.len() == 0 {
-            collections.remove(0);
-        }
+        let mut collection = Collection {
+            name: collection_name.clone(),
+            last_position: Some(self.collections.len() - 1),
+            requests: vec![],
+            folders: vec![],
+            defaults: CollectionDefaults::default(),
+            path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
+            file_format,
+        };
 
-        let collections_length = collections.len();
+        recursive_import_items(postman_collection.item, &[], &mut collection.folders, &mut collection.requests)?;
 
-        self.collections.extend(collections);
+        self.collections.push(collection);
 
-        for collection_index in 0..collections_length {
-            self.save_collection_to_file(collection_index);
-        }
+        let collection_index = self.collections.len() - 1;
+        self.save_collection_to_file(collection_index);
 
         Ok(())
     }
 }
 
-fn recursive_has_requests(item: &mut Items, collections: &mut Vec<Collection>, mut nesting_prefix: &mut String, mut depth_level: &mut u16, max_depth: u16, file_format: CollectionFileFormat) -> anyhow::Result<Option<Arc<RwLock<Request>>>> {
-    return if is_folder(&item) {
-        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+fn read_postman_collection(path: &PathBuf) -> anyhow::Result<PostmanCollection_v2_1_0> {
+    let collection_content = fs::read_to_string(path)?;
+    let mut collection_json: Value = serde_json::from_str(&collection_content)?;
 
-        let mut folder_name = item.clone().name.unwrap();
-        folder_name = folder_name.replace("/", "-");
-        folder_name = folder_name.replace("\\", "-");
-        folder_name = folder_name.trim().to_string();
-        
-        let collection_name = format!("{nesting_prefix}{folder_name}");
+    carry_api_key_auths(&mut collection_json);
 
-        *depth_level += 1;
-
-        if *depth_level == max_depth {
-            println!("\tMet max depth level");
-            requests = recursive_get_requests(item)?;
-        }
-        else {
-            nesting_prefix.push_str(&format!("{folder_name} "));
+    Ok(serde_json::from_value(collection_json)?)
+}
 
-            let mut has_sub_folders = false;
+/// The Postman collection parser does not know the "apikey" auth type.
+/// Such auths are turned into "noauth" ones, carrying the API key attributes in their free-form "noauth" field.
+fn carry_api_key_auths(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            if object.get("type").and_then(Value::as_str) == Some("apikey") {
+                let api_key_attributes = object.remove("apikey").unwrap_or(Value::Array(vec![]));
 
-            for mut sub_item in item.item.clone().unwrap() {
-                if let Some(request) = recursive_has_requests(&mut sub_item, collections, &mut nesting_prefix, &mut depth_level, max_depth, file_format)? {
-                    requests.push(request);
-                } else {
-                    has_sub_folders = true;
-                }
+                object.insert(String::from("type"), Value::String(String::from("noauth")));
+                object.insert(String::from("noauth"), serde_json::json!({ "apikey": api_key_attributes }));
             }
 
-            if has_sub_folders {
-                nesting_prefix.clear();
+            for child in object.values_mut() {
+                carry_api_key_auths(child);
             }
-        }
+        },
+        Value::Array(array) => {
+            for child in array {
+                carry_api_key_auths(child);
+            }
+        },
+        _ => {}
+    }
+}
 
-        if requests.len() > 0 {
-            println!("\tFound collection \"{}\"", collection_name);
+/// Postman folders become collection folders, keeping their nesting
+fn recursive_import_items(items: Vec<Items>, path: &[String], folders: &mut Vec<Folder>, requests: &mut Vec<Arc<RwLock<Request>>>) -> anyhow::Result<()> {
+    for item in items {
+        if item.name.is_none() {
+            continue;
+        }
 
-            let collection = Collection {
-                name: collection_name.clone(),
-                last_position: Some(collections.len() - 1),
-                requests,
-                path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
-                file_format,
-            };
+        if is_folder(&item) {
+            let folder_name = item.name.clone().unwrap()
+                .replace("/", "-")
+                .replace("\\", "-")
+                .trim()
+                .to_string();
 
-            collections.push(collection);
-            *depth_level -= 1;
-        }
+            println!("\tFound folder \"{}\"", folder_name);
 
-        Ok(None)
-    } else {
-        Ok(Some(Arc::new(RwLock::new(parse_request(item.clone())?))))
-    }
-}
+            let folder_path = [path, std::slice::from_ref(&folder_name)].concat();
+            let mut folder = Folder {
+                name: folder_name,
+                folders: vec![],
+            };
 
-fn recursive_get_requests(item: &mut Items) -> anyhow::Result<Vec<Arc<RwLock<Request>>>> {
-    return if let Some(items) = &mut item.item {
-        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+            recursive_import_items(item.item.unwrap(), &folder_path, &mut folder.folders, requests)?;
 
-        for item in items {
-            requests.extend(recursive_get_requests(item)?);
+            folders.push(folder);
         }
+        else {
+            let mut request = parse_request(item)?;
+            request.folder = path.to_vec();
 
-        Ok(requests)
-    } else {
-        Ok(vec![Arc::new(RwLock::new(parse_request(item.clone())?))])
+            requests.push(Arc::new(RwLock::new(request)));
+        }
     }
+
+    Ok(())
 }
 
 fn is_folder(folder: &Items) -> bool {
@@ -515,7 +488,36 @@ fn retrieve_auth(request_class: &RequestClass) -> Option<anyhow::Result<Auth>> {
         },
         AuthType::Awsv4 => Some(Ok(Auth::NoAuth)),
         AuthType::Hawk => Some(Ok(Auth::NoAuth)),
-        AuthType::Noauth => Some(Ok(Auth::NoAuth)),
+        AuthType::Noauth => {
+            let api_key_attributes = auth.noauth
+                .and_then(|noauth| noauth.get("apikey").cloned())
+                .and_then(|api_key_attributes| serde_json::from_value::<Vec<AuthAttribute>>(api_key_attributes).ok());
+
+            let api_key_attributes = match api_key_attributes {
+                None => return Some(Ok(Auth::NoAuth)),
+                Some(api_key_attributes) => api_key_attributes
+            };
+
+            let mut key = String::new();
+            let mut value = String::new();
+            let mut placement = ApiKeyPlacement::default();
+
+            for api_key_attribute in api_key_attributes {
+                let attribute_value = api_key_attribute.value.unwrap_or_default().as_str()?.to_string();
+
+                match api_key_attribute.key.as_str() {
+                    "key" => key = attribute_value,
+                    "value" => value = attribute_value,
+                    "in" => match attribute_value.as_str() {
+                        "query" => placement = ApiKeyPlacement::QueryParam,
+                        _ => placement = ApiKeyPlacement::Header
+                    },
+                    _ => {}
+                }
+            }
+
+            Some(Ok(Auth::ApiKey(ApiKey { key, value, placement })))
+        },
         AuthType::Ntlm => Some(Ok(Auth::NoAuth)),
         AuthType::Oauth1 => Some(Ok(Auth::NoAuth)),
         AuthType::Oauth2 => Some(Ok(Auth::NoAuth)),
@@ -1,7 +1,9 @@
 mod collection;
 mod request;
 mod environment;
+mod cookies;
 mod import;
+mod export;
 pub(super) mod try_request;
 pub(super) mod completions;
 pub(super) mod man;
\ No newline at end of file
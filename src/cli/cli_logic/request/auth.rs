@@ -4,6 +4,8 @@ use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::api_key::ApiKey;
 
 impl App<'_> {
     pub fn cli_print_request_auth(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
@@ -12,32 +14,58 @@ impl App<'_> {
         {
             let selected_request = local_selected_request.read();
 
-            print!("{}\n\t", selected_request.auth);
-
-            match &selected_request.auth {
-                Auth::NoAuth => {}
-                Auth::BasicAuth(BasicAuth { username, password }) => println!("username: {username}\n\tpassword: {password}"),
-                Auth::BearerToken(BearerToken { token: bearer_token }) => println!("token: {bearer_token}"),
-                Auth::JwtToken(JwtToken { algorithm, secret_type, secret, payload }) => println!("algorithm: {algorithm}\n\tsecret_type: {secret_type}\n\tsecret: {secret}\n\tpayload: {payload}"),
-                Auth::Digest(Digest {
-                    username,
-                    password,
-                    domains,
-                    realm,
-                    nonce,
-                    opaque,
-                    stale,
-                    algorithm,
-                    qop,
-                    user_hash,
-                    charset,
-                    ..
-                }) => println!("username: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}", stale.to_string(), user_hash.to_string()),
-            }
+            print_auth(&selected_request.auth);
         }
 
+        Ok(())
+    }
+
+    pub async fn cli_fetch_request_oauth2_token(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let access_token = self.renew_request_oauth2_token(collection_index, request_index).await?;
+
+        println!("{access_token}");
 
         Ok(())
     }
 }
 
+pub fn print_auth(auth: &Auth) {
+    print!("{}\n\t", auth);
+
+    match auth {
+        Auth::NoAuth | Auth::InheritFromCollection => {}
+        Auth::BasicAuth(BasicAuth { username, password }) => println!("username: {username}\n\tpassword: {password}"),
+        Auth::BearerToken(BearerToken { token: bearer_token }) => println!("token: {bearer_token}"),
+        Auth::JwtToken(JwtToken { algorithm, secret_type, secret, payload }) => println!("algorithm: {algorithm}\n\tsecret_type: {secret_type}\n\tsecret: {secret}\n\tpayload: {payload}"),
+        Auth::Digest(Digest {
+            username,
+            password,
+            domains,
+            realm,
+            nonce,
+            opaque,
+            stale,
+            algorithm,
+            qop,
+            user_hash,
+            charset,
+            ..
+        }) => println!("username: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}", stale.to_string(), user_hash.to_string()),
+        Auth::OAuth2(oauth2) => println!(
+            "grant_type: {}\n\ttoken_url: {}\n\tclient_id: {}\n\tclient_secret: {}\n\tscope: {}\n\tusername: {}\n\tpassword: {}\n\tauthorization_url: {}\n\tredirect_url: {}\n\trefresh_token: {}\n\taccess_token: {}",
+            oauth2.grant_type,
+            oauth2.token_url,
+            oauth2.client_id,
+            oauth2.client_secret,
+            oauth2.scope,
+            oauth2.username,
+            oauth2.password,
+            oauth2.authorization_url,
+            oauth2.get_redirect_url(),
+            oauth2.refresh_token,
+            oauth2.access_token_status()
+        ),
+        Auth::AwsSigV4(AwsSigV4 { access_key_id, secret_access_key, region, service, session_token }) => println!("access_key_id: {access_key_id}\n\tsecret_access_key: {secret_access_key}\n\tregion: {region}\n\tservice: {service}\n\tsession_token: {session_token}"),
+        Auth::ApiKey(ApiKey { key, value, placement }) => println!("key: {key}\n\tvalue: {value}\n\tplacement: {placement}"),
+    }
+}
\ No newline at end of file
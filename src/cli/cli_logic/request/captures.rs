@@ -0,0 +1,29 @@
+use crate::app::app::App;
+use crate::app::business_logic::key_value::print_key_value_vector;
+
+impl App<'_> {
+    pub fn cli_print_captures(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+            print_key_value_vector(&selected_request.captures, None);
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_print_capture(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+
+            let rule = &selected_request.captures[row].data.1;
+
+            println!("{rule}")
+        }
+
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@ use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::api_key::ApiKey;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
 
@@ -46,6 +48,23 @@ impl App<'_> {
                 charset,
                 ..
              }) => println!("auth: Digest\n\tusername: {username}\n\tpassword: {password}\n\tdomains: {domains}\n\trealm: {realm}\n\tnonce: {nonce}\n\topaque: {opaque}\n\tstale: {}\n\talgorithm: {algorithm}\n\tqop: {qop}\n\tuser_hash: {}\n\tcharset: {charset}", stale.to_string(), user_hash.to_string()),
+            Auth::OAuth2(oauth2) => println!(
+                "auth: OAuth2\n\tgrant_type: {}\n\ttoken_url: {}\n\tclient_id: {}\n\tclient_secret: {}\n\tscope: {}\n\tusername: {}\n\tpassword: {}\n\tauthorization_url: {}\n\tredirect_url: {}\n\trefresh_token: {}\n\taccess_token: {}",
+                oauth2.grant_type,
+                oauth2.token_url,
+                oauth2.client_id,
+                oauth2.client_secret,
+                oauth2.scope,
+                oauth2.username,
+                oauth2.password,
+                oauth2.authorization_url,
+                oauth2.get_redirect_url(),
+                oauth2.refresh_token,
+                oauth2.access_token_status()
+            ),
+            Auth::AwsSigV4(AwsSigV4 { access_key_id, secret_access_key, region, service, session_token }) => println!("auth: AWS SigV4\n\taccess_key_id: {access_key_id}\n\tsecret_access_key: {secret_access_key}\n\tregion: {region}\n\tservice: {service}\n\tsession_token: {session_token}"),
+            Auth::ApiKey(ApiKey { key, value, placement }) => println!("auth: API Key\n\tkey: {key}\n\tvalue: {value}\n\tplacement: {placement}"),
+            Auth::InheritFromCollection => println!("auth: Inherit from collection"),
         }
 
         if let Protocol::HttpRequest(http_request) = &request.protocol {
@@ -61,6 +80,28 @@ impl App<'_> {
                 }
             }
         }
+
+        if let Protocol::GraphqlRequest(graphql_request) = &request.protocol {
+            if let Some(operation_name) = &graphql_request.operation_name {
+                println!("operation name: {operation_name}");
+            }
+
+            println!("query:\n{}", graphql_request.query);
+
+            if !graphql_request.variables.is_empty() {
+                println!("variables:\n{}", graphql_request.variables);
+            }
+        }
+
+        if let Protocol::GrpcRequest(grpc_request) = &request.protocol {
+            match grpc_request.proto_files.is_empty() {
+                true => println!("proto files: none (server reflection)"),
+                false => println!("proto files:\n\t{}", grpc_request.proto_files.join("\n\t"))
+            }
+
+            println!("method: {}", grpc_request.to_path());
+            println!("message:\n{}", grpc_request.message);
+        }
         
         match (request.scripts.pre_request_script.is_some(), request.scripts.post_request_script.is_some()) {
             (false, false) => {}
@@ -0,0 +1,79 @@
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::app::business_logic::response_diff::{DiffRowKind, ResponseDiff};
+use crate::app::business_logic::response_diff::ResponseDiffError::ResponsesDiffer;
+
+impl App<'_> {
+    pub fn cli_diff_response_history_entries(&mut self, collection_index: usize, request_index: usize, left_entry_index: usize, right_entry_index: usize, ignored_headers: &[String]) -> anyhow::Result<()> {
+        let left_entry = self.get_response_history_entry(collection_index, request_index, left_entry_index)?;
+        let right_entry = self.get_response_history_entry(collection_index, request_index, right_entry_index)?;
+
+        let diff = ResponseDiff::new(
+            format!("#{left_entry_index} {}", left_entry.summary()),
+            &left_entry.to_response(),
+            format!("#{right_entry_index} {}", right_entry.summary()),
+            &right_entry.to_response(),
+            ignored_headers
+        );
+
+        print_response_diff(&diff)
+    }
+
+    pub async fn cli_diff_environments_responses(&mut self, collection_index: usize, request_index: usize, left_env_name: &str, right_env_name: &str, ignored_headers: &[String]) -> anyhow::Result<()> {
+        let left_env_index = self.find_environment(left_env_name)?;
+        let right_env_index = self.find_environment(right_env_name)?;
+
+        let left_response = self.request_preparer_with_environment(left_env_index).prepare_environment_request(collection_index, request_index).await?.send().await?;
+        let right_response = self.request_preparer_with_environment(right_env_index).prepare_environment_request(collection_index, request_index).await?.send().await?;
+
+        let diff = ResponseDiff::new(left_env_name.to_string(), &left_response, right_env_name.to_string(), &right_response, ignored_headers);
+
+        print_response_diff(&diff)
+    }
+}
+
+/// Unified format, fails when the responses differ so that it can be used as a check
+fn print_response_diff(diff: &ResponseDiff) -> anyhow::Result<()> {
+    println!("--- {}", diff.left_title);
+    println!("+++ {}", diff.right_title);
+
+    let mut removed: Vec<&str> = vec![];
+    let mut added: Vec<&str> = vec![];
+
+    for row in &diff.rows {
+        if matches!(row.kind, DiffRowKind::Section | DiffRowKind::Unchanged) {
+            print_changes(&mut removed, &mut added);
+        }
+
+        match row.kind {
+            DiffRowKind::Section => println!("@@ {} @@", row.left.as_deref().unwrap_or_default()),
+            DiffRowKind::Unchanged => println!(" {}", row.left.as_deref().unwrap_or_default()),
+            DiffRowKind::Changed | DiffRowKind::Removed | DiffRowKind::Added => {
+                removed.extend(row.left.as_deref());
+                added.extend(row.right.as_deref());
+            }
+        }
+    }
+
+    print_changes(&mut removed, &mut added);
+
+    let differences_count = diff.differences_count();
+
+    if differences_count > 0 {
+        return Err(anyhow!(ResponsesDiffer(differences_count)));
+    }
+
+    Ok(())
+}
+
+/// Consecutive changes are printed as all their removed lines, then all their added lines
+fn print_changes(removed: &mut Vec<&str>, added: &mut Vec<&str>) {
+    for line in removed.drain(..) {
+        println!("-{line}");
+    }
+
+    for line in added.drain(..) {
+        println!("+{line}");
+    }
+}
@@ -0,0 +1,42 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::graphql::GraphqlPart;
+
+impl App<'_> {
+    pub fn cli_print_request_graphql(&mut self, collection_index: usize, request_index: usize, part: &GraphqlPart) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+            let graphql_request = selected_request.get_graphql_request()?;
+
+            let data = match part {
+                GraphqlPart::Query => &graphql_request.query,
+                GraphqlPart::Variables => &graphql_request.variables,
+                GraphqlPart::OperationName => match &graphql_request.operation_name {
+                    None => &String::from("None"),
+                    Some(operation_name) => operation_name
+                }
+            };
+
+            println!("{data}")
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_modify_request_graphql(&mut self, collection_index: usize, request_index: usize, part: &GraphqlPart, value: Option<String>) -> anyhow::Result<()> {
+        match part {
+            GraphqlPart::Query => self.modify_request_graphql_query(collection_index, request_index, value.unwrap_or_default()),
+            GraphqlPart::Variables => self.modify_request_graphql_variables(collection_index, request_index, value.unwrap_or_default()),
+            GraphqlPart::OperationName => self.modify_request_graphql_operation_name(collection_index, request_index, value),
+        }
+    }
+
+    pub async fn cli_introspect_graphql_schema(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let names_count = self.introspect_graphql_schema(collection_index, request_index).await?;
+
+        println!("Schema cached, {names_count} type and field names found");
+
+        Ok(())
+    }
+}
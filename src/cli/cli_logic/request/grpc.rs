@@ -0,0 +1,48 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::grpc::GrpcPart;
+use crate::models::protocol::grpc::grpc::parse_proto_files;
+
+impl App<'_> {
+    pub fn cli_print_request_grpc(&mut self, collection_index: usize, request_index: usize, part: &GrpcPart) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+            let grpc_request = selected_request.get_grpc_request()?;
+
+            let data = match part {
+                GrpcPart::ProtoFiles => &grpc_request.proto_files.join("\n"),
+                GrpcPart::Service => &grpc_request.service,
+                GrpcPart::Method => &grpc_request.method,
+                GrpcPart::Message => &grpc_request.message,
+            };
+
+            println!("{data}")
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_modify_request_grpc(&mut self, collection_index: usize, request_index: usize, part: &GrpcPart, value: Option<String>) -> anyhow::Result<()> {
+        match part {
+            GrpcPart::ProtoFiles => self.modify_request_grpc_proto_files(collection_index, request_index, parse_proto_files(&value.unwrap_or_default())),
+            GrpcPart::Service => self.modify_request_grpc_service(collection_index, request_index, value.unwrap_or_default()),
+            GrpcPart::Method => self.modify_request_grpc_method(collection_index, request_index, value.unwrap_or_default()),
+            GrpcPart::Message => self.modify_request_grpc_message(collection_index, request_index, value.unwrap_or_default()),
+        }
+    }
+
+    pub async fn cli_list_grpc_methods(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        self.list_grpc_methods(collection_index, request_index).await?;
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let selected_request = local_selected_request.read();
+        let grpc_request = selected_request.get_grpc_request()?;
+
+        for method in &grpc_request.methods {
+            println!("{}/{} ({})", method.service, method.name, method.kind);
+        }
+
+        Ok(())
+    }
+}
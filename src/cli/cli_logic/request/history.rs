@@ -0,0 +1,42 @@
+use crate::app::app::App;
+
+impl App<'_> {
+    pub fn cli_print_response_history(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let history = self.get_response_history(collection_index, request_index);
+
+        for (index, entry) in history.iter().rev().enumerate() {
+            println!("{index}\t{}", entry.summary());
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_print_response_history_entry(&mut self, collection_index: usize, request_index: usize, entry_index: usize) -> anyhow::Result<()> {
+        let entry = self.get_response_history_entry(collection_index, request_index, entry_index)?;
+
+        println!("date: {}", entry.timestamp.format("%d/%m/%Y %H:%M:%S"));
+        println!("url: {}", entry.url);
+
+        if let Some(status_code) = &entry.status_code {
+            println!("status code: {status_code}");
+        }
+
+        if let Some(duration) = &entry.duration {
+            println!("duration: {duration}");
+        }
+
+        if !entry.headers.is_empty() {
+            println!("headers:");
+
+            for (header, value) in &entry.headers {
+                println!("\t{header}: {value}");
+            }
+        }
+
+        if let Some(body) = &entry.body {
+            println!("body:\n{body}");
+        }
+
+        Ok(())
+    }
+}
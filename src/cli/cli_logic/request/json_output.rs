@@ -0,0 +1,89 @@
+use base64::Engine;
+use base64::prelude::BASE64_STANDARD;
+use serde_json::{json, Map, Value};
+
+use crate::models::protocol::ws::ws::Message;
+use crate::models::request::Request;
+use crate::models::response::{RequestResponse, ResponseContent};
+
+/// One JSON object describing the request outcome, printed on a single line so that several of them form NDJSON
+pub fn request_to_json(request: &Request, response: Option<&RequestResponse>, error: Option<String>) -> Value {
+    let status_code = response
+        .and_then(|response| response.status_code.as_deref())
+        .and_then(|status_code| status_code.split_whitespace().next())
+        .and_then(|status_code| status_code.parse::<u16>().ok());
+
+    let (body, body_encoding) = match response.and_then(|response| response.content.as_ref()) {
+        None => (Value::Null, None),
+        Some(ResponseContent::Body(body)) => match serde_json::from_str::<Value>(body) {
+            Ok(json_body) => (json_body, Some("json")),
+            Err(_) => (Value::String(body.clone()), Some("text"))
+        },
+        Some(ResponseContent::Image(image)) => (Value::String(BASE64_STANDARD.encode(&image.data)), Some("base64"))
+    };
+
+    let events: Vec<Value> = request.events
+        .iter()
+        .map(|event| json!({
+            "timestamp": event.timestamp.to_rfc3339(),
+            "event": event.event,
+            "id": event.id,
+            "data": event.data,
+        }))
+        .collect();
+
+    json!({
+        "name": request.name,
+        "status": response.and_then(|response| response.status_code.clone()),
+        "status_code": status_code,
+        "duration": response.and_then(|response| response.duration.clone()),
+        "headers": response.map(|response| headers_to_json(&response.headers)),
+        "cookies": response.and_then(|response| response.cookies.as_deref()).map(cookies_to_json),
+        "body": body,
+        "body_encoding": body_encoding,
+        "events": events,
+        "console_output": {
+            "pre_request": request.console_output.pre_request_output,
+            "post_request": request.console_output.post_request_output,
+        },
+        "tests": request.test_results,
+        "error": error,
+    })
+}
+
+pub fn ws_message_to_json(message: &Message) -> Value {
+    json!({
+        "timestamp": message.timestamp.to_rfc3339(),
+        "sender": message.sender.to_string(),
+        "type": message.content.to_string(),
+        "content": message.content.to_content(),
+    })
+}
+
+/// Headers received several times, such as set-cookie, are grouped in an array
+fn headers_to_json(headers: &[(String, String)]) -> Value {
+    let mut json_headers = Map::new();
+
+    for (header, value) in headers {
+        match json_headers.get_mut(header) {
+            None => {
+                json_headers.insert(header.clone(), Value::String(value.clone()));
+            },
+            Some(Value::Array(values)) => values.push(Value::String(value.clone())),
+            Some(previous_value) => *previous_value = json!([previous_value.take(), value])
+        }
+    }
+
+    Value::Object(json_headers)
+}
+
+/// The response cookies are formatted as "name: value" lines
+fn cookies_to_json(cookies: &str) -> Value {
+    let json_cookies: Map<String, Value> = cookies
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+        .map(|(name, value)| (name.to_string(), Value::String(value.to_string())))
+        .collect();
+
+    Value::Object(json_cookies)
+}
@@ -1,11 +1,17 @@
 pub(super) mod new;
 mod describe;
 mod url;
-mod auth;
+pub(super) mod auth;
 mod scripts;
+mod graphql;
+mod grpc;
 mod send;
-mod settings;
+mod json_output;
+mod history;
+mod diff;
+pub(super) mod settings;
 mod query_params;
 mod header;
+mod captures;
 mod export;
 mod http;
\ No newline at end of file
@@ -1,13 +1,20 @@
 use anyhow::anyhow;
 use clap::ValueEnum;
+use reqwest::header::CONTENT_TYPE;
 use tokio_util::sync::CancellationToken;
 use crate::app::app::App;
+use crate::app::business_logic::folder::FolderError::FolderNotFound;
+use crate::app::business_logic::folder::parse_folder_path;
+use crate::app::business_logic::request::dependency::parse_dependency_cache_lifetime;
 use crate::cli::commands::request_commands::new::{AuthArgs, BodyArgs, NewRequestCommand};
 use crate::models::auth::auth::Auth;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::{extract_www_authenticate_digest_data, Digest};
 use crate::models::auth::jwt::{JwtAlgorithm, JwtSecretType, JwtToken};
+use crate::models::auth::oauth2::{OAuth2, OAuth2GrantType};
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::api_key::{ApiKey, ApiKeyPlacement};
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
@@ -18,9 +25,19 @@ use crate::models::settings::{RequestSettings, Setting};
 use crate::panic_error;
 
 impl App<'_> {
-    pub fn cli_new_request(&mut self, collection_slash_request: (String, String), new_request_command: NewRequestCommand) -> anyhow::Result<()> {
+    pub fn cli_new_request(&mut self, collection_slash_request: (String, String), folder: Option<&str>, new_request_command: NewRequestCommand) -> anyhow::Result<()> {
         let collection_index = self.find_collection(&collection_slash_request.0)?;
-        let new_request = create_request_from_new_request_command(collection_slash_request.1.trim().to_string(), new_request_command)?;
+        let mut new_request = create_request_from_new_request_command(collection_slash_request.1.trim().to_string(), new_request_command)?;
+
+        if let Some(folder) = folder {
+            let folder = parse_folder_path(folder);
+
+            if !folder.is_empty() && self.collections[collection_index].find_folder(&folder).is_none() {
+                return Err(anyhow!(FolderNotFound(folder.join("/"))));
+            }
+
+            new_request.folder = folder;
+        }
         
         self.new_request(collection_index, new_request)?;
         
@@ -57,19 +74,86 @@ pub fn create_request_from_new_request_command(request_name: String, new_request
                 _ => return Err(anyhow!("Setting a body with a websocket request body is incompatible"))
             }
         }
+        Protocol::GraphqlRequest(graphql_request) => {
+            match new_request_command.method {
+                Method::GET | Method::POST => {}
+                _ => return Err(anyhow!("Setting a method with a GraphQL request is incompatible"))
+            }
+
+            match body {
+                ContentType::NoBody => {}
+                _ => return Err(anyhow!("Setting a body with a GraphQL request is incompatible, use the --graphql-* arguments instead"))
+            }
+
+            graphql_request.query = new_request_command.graphql.graphql_query.clone().unwrap_or_default();
+            graphql_request.variables = new_request_command.graphql.graphql_variables.clone().unwrap_or_default();
+            graphql_request.operation_name = new_request_command.graphql.graphql_operation_name.clone();
+        }
+        Protocol::GrpcRequest(grpc_request) => {
+            match new_request_command.method {
+                Method::GET | Method::POST => {}
+                _ => return Err(anyhow!("Setting a method with a gRPC request is incompatible"))
+            }
+
+            match body {
+                ContentType::NoBody => {}
+                _ => return Err(anyhow!("Setting a body with a gRPC request is incompatible, use the --grpc-message argument instead"))
+            }
+
+            grpc_request.proto_files = new_request_command.grpc.proto_file.clone();
+            grpc_request.service = new_request_command.grpc.grpc_service.clone().unwrap_or_default();
+            grpc_request.method = new_request_command.grpc.grpc_method.clone().unwrap_or_default();
+
+            if let Some(message) = &new_request_command.grpc.grpc_message {
+                grpc_request.message = message.clone();
+            }
+        }
+    };
+
+    let has_graphql_args = new_request_command.graphql.graphql_query.is_some()
+        || new_request_command.graphql.graphql_variables.is_some()
+        || new_request_command.graphql.graphql_operation_name.is_some();
+
+    if has_graphql_args && !matches!(protocol, Protocol::GraphqlRequest(_)) {
+        return Err(anyhow!("Setting GraphQL arguments is only compatible with the graphql protocol"));
+    }
+
+    let has_grpc_args = !new_request_command.grpc.proto_file.is_empty()
+        || new_request_command.grpc.grpc_service.is_some()
+        || new_request_command.grpc.grpc_method.is_some()
+        || new_request_command.grpc.grpc_message.is_some();
+
+    if has_grpc_args && !matches!(protocol, Protocol::GrpcRequest(_)) {
+        return Err(anyhow!("Setting gRPC arguments is only compatible with the grpc protocol"));
+    }
+
+    if let Some(dependency_cache_lifetime) = &new_request_command.dependency_cache_lifetime {
+        parse_dependency_cache_lifetime(dependency_cache_lifetime)?;
+    }
+
+    let protocol_headers = match &protocol {
+        Protocol::GraphqlRequest(_) => vec![
+            KeyValue {
+                enabled: true,
+                data: (CONTENT_TYPE.to_string(), String::from("application/json")),
+            }
+        ],
+        Protocol::HttpRequest(_) | Protocol::WsRequest(_) | Protocol::GrpcRequest(_) => vec![]
     };
 
     let mut request = Request {
         name: request_name,
+        folder: vec![],
         url: String::new(),
         protocol,
         params,
         auth,
-        headers: vec![base_headers, headers].concat(),
+        headers: [base_headers, protocol_headers, headers].concat(),
         scripts: RequestScripts {
             pre_request_script: new_request_command.pre_request_script,
             post_request_script: new_request_command.post_request_script,
         },
+        captures: vec![],
         settings: RequestSettings {
             use_config_proxy: Setting::Bool(!new_request_command.no_proxy),
             allow_redirects: Setting::Bool(!new_request_command.no_redirects),
@@ -78,10 +162,21 @@ pub fn create_request_from_new_request_command(request_name: String, new_request
             pretty_print_response_content: Setting::Bool(!new_request_command.no_pretty),
             accept_invalid_certs: Setting::Bool(new_request_command.accept_invalid_certs),
             accept_invalid_hostnames: Setting::Bool(new_request_command.accept_invalid_hostnames),
+            force_event_stream: Setting::Bool(new_request_command.force_event_stream),
+            client_certificate: Setting::String(new_request_command.client_cert.unwrap_or_default()),
+            client_key: Setting::String(new_request_command.client_key.unwrap_or_default()),
+            client_certificate_password: Setting::String(new_request_command.client_cert_password.unwrap_or_default()),
+            ca_certificates: Setting::String(new_request_command.ca_certs.unwrap_or_default()),
+            depends_on: Setting::String(new_request_command.depends_on.unwrap_or_default()),
+            dependency_cache_lifetime: Setting::String(new_request_command.dependency_cache_lifetime.unwrap_or_default()),
+            overridden: vec![],
         },
         response: RequestResponse::default(),
+        events: vec![],
         console_output: ConsoleOutput::default(),
+        test_results: vec![],
         is_pending: false,
+        is_streaming: false,
         cancellation_token: CancellationToken::new(),
     };
 
@@ -151,6 +246,44 @@ fn get_auth_from_auth_args(auth_args: AuthArgs) -> anyhow::Result<Auth> {
             Err(error) => panic_error(error)
         };
     }
+    else if !auth_args.auth_oauth2.is_empty() {
+        return Ok(Auth::OAuth2(
+            OAuth2 {
+                grant_type: OAuth2GrantType::from_str(&auth_args.auth_oauth2[0], true).map_err(|e| anyhow!(e))?,
+                token_url: auth_args.auth_oauth2[1].clone(),
+                client_id: auth_args.auth_oauth2[2].clone(),
+                ..OAuth2::default()
+            }
+        ));
+    }
+    else if !auth_args.auth_aws_sigv4.is_empty() {
+        return Ok(Auth::AwsSigV4(
+            AwsSigV4 {
+                access_key_id: auth_args.auth_aws_sigv4[0].clone(),
+                secret_access_key: auth_args.auth_aws_sigv4[1].clone(),
+                region: auth_args.auth_aws_sigv4[2].clone(),
+                service: auth_args.auth_aws_sigv4[3].clone(),
+                session_token: String::new(),
+            }
+        ));
+    }
+    else if !auth_args.auth_api_key.is_empty() {
+        let placement = match auth_args.auth_api_key.get(2) {
+            None => ApiKeyPlacement::default(),
+            Some(placement) => ApiKeyPlacement::from_str(placement, true).map_err(|e| anyhow!(e))?
+        };
+
+        return Ok(Auth::ApiKey(
+            ApiKey {
+                key: auth_args.auth_api_key[0].clone(),
+                value: auth_args.auth_api_key[1].clone(),
+                placement,
+            }
+        ));
+    }
+    else if auth_args.auth_inherit {
+        return Ok(Auth::InheritFromCollection);
+    }
     else {
         return Ok(Auth::NoAuth);
     }
@@ -1,8 +1,9 @@
 use std::io::stdout;
 use crate::app::app::App;
-use crate::cli::commands::request_commands::send::SendCommand;
+use crate::cli::cli_logic::request::json_output::{request_to_json, ws_message_to_json};
+use crate::cli::commands::request_commands::send::{OutputFormat, SendCommand};
 use crate::models::request::Request;
-use crate::models::response::ResponseContent;
+use crate::models::response::{RequestResponse, ResponseContent};
 use anyhow::anyhow;
 use parking_lot::RwLock;
 use ratatui::backend::Backend;
@@ -12,13 +13,21 @@ use ratatui::{Terminal, TerminalOptions, Viewport};
 use ratatui_image::picker::Picker;
 use ratatui_image::{Resize, ResizeEncodeRender};
 use std::sync::Arc;
+use std::time::Duration;
 use chrono::Local;
 use futures_util::SinkExt;
 use tokio::io;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tracing::info;
+use crate::app::business_logic::request::grpc::send::send_grpc_request;
+use crate::app::business_logic::response_filter::filter_response_body;
+use crate::app::business_logic::request::scripts::ScriptTestsError::TestsFailed;
 use crate::app::business_logic::request::http::send::send_http_request;
 use crate::app::business_logic::request::ws::send::send_ws_request;
+use crate::app::business_logic::collection_runner::{CollectionRun, CollectionRunOptions, CollectionRunResult};
+use crate::app::business_logic::collection_runner::CollectionRunnerError::CollectionRunFailed;
+use crate::cli::commands::collection_commands::runner::RunnerCommand;
+use crate::models::collection::CollectionDefaults;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::message_type::MessageType;
 use crate::models::protocol::ws::ws::{Message, Sender};
@@ -27,72 +36,172 @@ impl App<'_> {
     pub async fn cli_send_request(&mut self, collection_index: usize, request_index: usize, send_command: &SendCommand) -> anyhow::Result<()> {
         let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
-        self.local_send_request(&send_command, local_request).await?;
+        self.local_send_request(&send_command, local_request.clone(), Some((collection_index, request_index))).await?;
 
         if self.config.should_save_requests_response() {
             self.save_collection_to_file(collection_index);
         }
 
+        let failed_tests_count = local_request.read().failed_tests_count();
+
+        if failed_tests_count > 0 {
+            return Err(anyhow!(TestsFailed(failed_tests_count)));
+        }
+
         Ok(())
     }
 
-    pub async fn cli_send_collection(&mut self, collection_name: &str, send_command: &SendCommand) -> anyhow::Result<()> {
+    pub async fn cli_send_collection(&mut self, collection_name: &str, send_command: &SendCommand, runner_command: &RunnerCommand) -> anyhow::Result<()> {
         let collection_index = self.find_collection(collection_name)?;
-        let collection = &self.collections[collection_index];
 
-        let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
+        let options = CollectionRunOptions {
+            iterations: runner_command.iterations,
+            data_file: runner_command.data.clone(),
+            delay: Duration::from_millis(runner_command.delay),
+            bail: runner_command.bail,
+            fail_on_status: runner_command.fail_on_status,
+        };
 
-        for request in &collection.requests {
-            let local_request = request.clone();
-            requests.push(local_request);
-        }
+        let mut run = self.new_collection_run(collection_index, &options)?;
+
+        while !run.is_finished() {
+            if !run.results.is_empty() && !run.delay.is_zero() {
+                tokio::time::sleep(run.delay).await;
+            }
+
+            let (iteration, request_index) = run.current();
+            let local_request = self.collections[collection_index].requests[request_index].clone();
+            let request_name = local_request.read().name.clone();
+
+            self.iteration_values = run.iteration_values(iteration);
+            let response = self.local_send_request(send_command, local_request.clone(), Some((collection_index, request_index))).await;
+
+            let mut result = match response {
+                Ok(response) => {
+                    let request = local_request.read();
+                    CollectionRunResult::from_response(iteration, request_name, &request.protocol, &response, request.test_results.clone())
+                },
+                Err(error) => CollectionRunResult::from_error(iteration, request_name, error.to_string())
+            };
+
+            {
+                let request = local_request.read();
 
-        for request in requests {
-            self.local_send_request(&send_command, request).await?;
+                result.url = Some(self.get_request_url(&request, &self.collections[collection_index].defaults));
+                result.console_output = match (&request.console_output.pre_request_output, &request.console_output.post_request_output) {
+                    (None, None) => None,
+                    (Some(pre_request_console_output), None) => Some(pre_request_console_output.clone()),
+                    (None, Some(post_request_console_output)) => Some(post_request_console_output.clone()),
+                    (Some(pre_request_console_output), Some(post_request_console_output)) => Some(format!("{pre_request_console_output}\n{post_request_console_output}"))
+                };
+            }
+
+            self.iteration_values.clear();
+
+            run.push_result(result);
 
             if self.config.should_save_requests_response() {
                 self.save_collection_to_file(collection_index);
             }
         }
 
+        // The JSON output of each request must not be mixed with text
+        if send_command.output == OutputFormat::Text {
+            print_collection_run_summary(&run);
+        }
+
+        for report in &runner_command.report {
+            report.write(&run, &self.collections[collection_index].name)?;
+        }
+
+        if run.failed_count() > 0 {
+            return Err(anyhow!(CollectionRunFailed(run.failed_count())));
+        }
+
         Ok(())
     }
 
-    pub async fn local_send_request(&mut self, send_command: &SendCommand, local_request: Arc<RwLock<Request>>) -> anyhow::Result<()> {
+    pub async fn local_send_request(&mut self, send_command: &SendCommand, local_request: Arc<RwLock<Request>>, request_index: Option<(usize, usize)>) -> anyhow::Result<RequestResponse> {
         let mut request = local_request.write();
 
+        let collection_defaults = match request_index {
+            Some((collection_index, _)) => self.collections[collection_index].defaults.clone(),
+            None => CollectionDefaults::default()
+        };
+
          if let Some(env_name )= &send_command.env {
             let env_index = self.find_environment(env_name)?;
-             self.selected_environment = env_index;
+             self.select_environment(env_index);
         };
         
-        if send_command.request_name {
+        let is_json_output = send_command.output == OutputFormat::Json;
+
+        if send_command.request_name && !is_json_output {
             println!("{}", request.name);
         }
         
-        let prepared_request = match self.prepare_request(&mut request).await {
+        let request_preparer = self.request_preparer();
+
+        let mut prepared_request = match request_preparer.prepare_request(request_index, &mut request, &collection_defaults).await {
             Ok(prepared_request) => prepared_request,
             Err(error) => {
-                if send_command.console {
-                    if let Some(pre_request_output) = &request.console_output.pre_request_output {
-                        println!("{}", pre_request_output);
-                    }
+                if is_json_output {
+                    println!("{}", request_to_json(&request, None, Some(error.to_string())));
+                }
+                else if send_command.console && let Some(pre_request_output) = &request.console_output.pre_request_output {
+                    println!("{}", pre_request_output);
                 }
 
                 return Err(anyhow!(error));
             }
         };
 
+        let response_history_recorder = request_index.and_then(|(collection_index, _)| request_preparer.new_response_history_recorder(collection_index, &request, &mut prepared_request));
         let protocol = request.protocol.clone();
 
         drop(request);
 
         let local_env = self.get_selected_env_as_local();
         let response = match protocol {
-            Protocol::HttpRequest(_) => send_http_request(prepared_request, local_request.clone(), &local_env).await?,
-            Protocol::WsRequest(_) => send_ws_request(prepared_request, local_request.clone(), &local_env, self.received_response.clone()).await?,
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => send_http_request(prepared_request, local_request.clone(), &collection_defaults, &local_env, self.received_response.clone()).await,
+            Protocol::WsRequest(_) => send_ws_request(prepared_request, local_request.clone(), &collection_defaults, &local_env, self.received_response.clone()).await,
+            Protocol::GrpcRequest(_) => send_grpc_request(prepared_request, local_request.clone(), &collection_defaults, &local_env).await,
+        };
+
+        let mut response = match response {
+            Ok(response) => {
+                if let Some(response_history_recorder) = &response_history_recorder {
+                    response_history_recorder.record(&response).await;
+                }
+
+                response
+            },
+            Err(error) => {
+                if is_json_output {
+                    println!("{}", request_to_json(&local_request.read(), None, Some(error.to_string())));
+                }
+
+                return Err(anyhow!(error));
+            }
         };
 
+        if let Some(filter) = &send_command.body_filter && let Some(ResponseContent::Body(body)) = &response.content {
+            match filter_response_body(body, filter) {
+                Ok(filtered_body) => response.content = Some(ResponseContent::Body(filtered_body.content)),
+                Err(error) => {
+                    if is_json_output {
+                        println!("{}", request_to_json(&local_request.read(), None, Some(error.to_string())));
+                    }
+
+                    return Err(anyhow!(error));
+                }
+            }
+        }
+
+        if is_json_output {
+            return print_json_response(local_request, &protocol, response).await;
+        }
+
         let request = local_request.read();
 
         if send_command.status_code {
@@ -100,11 +209,11 @@ impl App<'_> {
         }
 
         if send_command.duration {
-            println!("{}", response.duration.unwrap());
+            println!("{}", response.duration.as_ref().unwrap());
         }
 
         if send_command.cookies {
-            println!("{}", response.cookies.unwrap());
+            println!("{}", response.cookies.as_ref().unwrap());
         }
 
         if send_command.headers {
@@ -122,12 +231,21 @@ impl App<'_> {
             println!("{}", console_output);
         }
 
+        if send_command.tests {
+            for test_result in &request.test_results {
+                match &test_result.error {
+                    None => println!("PASS {}", test_result.name),
+                    Some(error) => println!("FAIL {}: {error}", test_result.name)
+                }
+            }
+        }
+
         if !send_command.hide_content {
-            match response.content {
+            match &response.content {
                 None => {},
                 Some(content) => match content {
                     ResponseContent::Body(body) => println!("{}", body),
-                    ResponseContent::Image(image) => match image.image {
+                    ResponseContent::Image(image) => match &image.image {
                         None => {
                             println!("{:?}", image.data)
                         }
@@ -155,7 +273,7 @@ impl App<'_> {
                                 false => Picker::from_query_stdio().unwrap_or(Picker::halfblocks())
                             };
 
-                            let mut stateful_protocol = picker.new_resize_protocol(dynamic_image);
+                            let mut stateful_protocol = picker.new_resize_protocol(dynamic_image.clone());
 
                             terminal.draw(|frame|
                                 stateful_protocol.resize_encode_render(
@@ -175,6 +293,39 @@ impl App<'_> {
         }
         drop(request);
 
+        if let Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) = &protocol {
+            let mut last_length = 0;
+
+            loop {
+                {
+                    let request = local_request.read();
+
+                    for event in &request.events[last_length..] {
+                        let id = match &event.id {
+                            None => String::new(),
+                            Some(id) => format!(" (id: {id})")
+                        };
+
+                        println!(
+                            "=== {} - New {} event{} ===\n{}",
+                            event.timestamp.format("%H:%M:%S %d/%m/%Y"),
+                            event.event,
+                            id,
+                            event.data
+                        );
+                    }
+
+                    last_length = request.events.len();
+
+                    if !request.is_streaming {
+                        break;
+                    }
+                }
+
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        }
+
         if let Protocol::WsRequest(_) = &protocol {
             let mut last_length = 0;
             let local_local_request = local_request.clone();
@@ -243,6 +394,70 @@ impl App<'_> {
             }
         }
 
-        Ok(())
+        Ok(response)
+    }
+}
+
+/// Print the response as a single JSON object, once the event stream has ended. WebSocket messages are then printed one per line
+async fn print_json_response(local_request: Arc<RwLock<Request>>, protocol: &Protocol, response: RequestResponse) -> anyhow::Result<RequestResponse> {
+    if let Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) = protocol {
+        while local_request.read().is_streaming {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    println!("{}", request_to_json(&local_request.read(), Some(&response), None));
+
+    if let Protocol::WsRequest(_) = protocol {
+        let mut last_length = 0;
+
+        loop {
+            {
+                let request = local_request.read();
+                let ws_request = request.get_ws_request()?;
+
+                for message in &ws_request.messages[last_length..] {
+                    println!("{}", ws_message_to_json(message));
+                }
+
+                last_length = ws_request.messages.len();
+
+                if !ws_request.is_connected {
+                    break;
+                }
+            }
+
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
     }
+
+    Ok(response)
+}
+
+fn print_collection_run_summary(run: &CollectionRun) {
+    println!("=== Collection run: {} iteration(s) of {} request(s) ===", run.iterations, run.requests_indexes.len());
+
+    for request_name in &run.skipped_ws_requests {
+        println!("SKIP {request_name}: WebSocket requests cannot be part of a collection run");
+    }
+
+    for result in &run.results {
+        let state = match run.is_passed(result) {
+            true => "PASS",
+            false => "FAIL"
+        };
+
+        let duration = match &result.duration {
+            None => String::new(),
+            Some(duration) => format!(" ({duration})")
+        };
+
+        println!("{state} #{} {}: {}{duration}", result.iteration + 1, result.request_name, result.outcome());
+
+        for test_result in result.test_results.iter().filter(|test_result| !test_result.passed) {
+            println!("\tFAIL {}: {}", test_result.name, test_result.error.as_deref().unwrap_or_default());
+        }
+    }
+
+    println!("=== {} ===", run.summary());
 }
\ No newline at end of file
@@ -2,8 +2,9 @@ use anyhow::anyhow;
 use tracing::{info};
 
 use crate::app::app::App;
+use crate::app::business_logic::request::dependency::parse_dependency_cache_lifetime;
 use crate::cli::commands::request_commands::setting::RequestSettingName;
-use crate::models::settings::Setting;
+use crate::models::settings::{RequestSettings, Setting};
 
 impl App<'_> {
     pub fn cli_print_request_settings(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
@@ -19,7 +20,7 @@ impl App<'_> {
 
         Ok(())
     }
-    
+
     pub fn cli_modify_request_setting(&mut self, collection_index: usize, request_index: usize, setting_name: &RequestSettingName, new_value: &Setting) -> anyhow::Result<()> {
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
@@ -28,43 +29,84 @@ impl App<'_> {
 
             info!("Setting \"{}\" set to \"{}\"", setting_name, new_value);
 
-            match new_value {
-                Setting::Bool(bool) => match setting_name {
-                    RequestSettingName::Proxy => selected_request.settings.use_config_proxy = Setting::Bool(*bool),
-                    RequestSettingName::Redirects => selected_request.settings.allow_redirects = Setting::Bool(*bool),
-                    RequestSettingName::Cookies => selected_request.settings.store_received_cookies = Setting::Bool(*bool),
-                    RequestSettingName::Pretty => selected_request.settings.pretty_print_response_content = Setting::Bool(*bool),
-                    _ => return Err(anyhow!(format!("The setting \"{}\" only takes positive int values", setting_name)))
-                },
-                Setting::U32(u32) => match setting_name {
-                    RequestSettingName::Timeout => selected_request.settings.timeout = Setting::U32(*u32),
-                    _ => return Err(anyhow!(format!("The setting \"{}\" only takes boolean values", setting_name)))
-                }
-            }
+            modify_setting(&mut selected_request.settings, setting_name, new_value)?;
+            selected_request.settings.set_overridden(setting_name.field_name());
         }
 
         self.save_collection_to_file(collection_index);
 
         Ok(())
     }
-    
+
     pub fn cli_print_request_setting(&mut self, collection_index: usize, request_index: usize, setting_name: &RequestSettingName) -> anyhow::Result<()> {
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
         {
             let selected_request = local_selected_request.read();
-            
-            let setting = match setting_name {
-                RequestSettingName::Proxy => &selected_request.settings.use_config_proxy,
-                RequestSettingName::Timeout => &selected_request.settings.timeout,
-                RequestSettingName::Redirects => &selected_request.settings.allow_redirects,
-                RequestSettingName::Cookies => &selected_request.settings.store_received_cookies,
-                RequestSettingName::Pretty => &selected_request.settings.pretty_print_response_content,
-            };
-            
-            println!("{setting}")
+
+            println!("{}", get_setting(&selected_request.settings, setting_name))
         }
-        
+
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+pub fn modify_setting(settings: &mut RequestSettings, setting_name: &RequestSettingName, new_value: &Setting) -> anyhow::Result<()> {
+    // Paths, passwords and durations are kept as typed, even when they look like a boolean or a number
+    if setting_name.is_text() {
+        let new_value = Setting::String(new_value.to_string());
+
+        match setting_name {
+            RequestSettingName::ClientCert => settings.client_certificate = new_value,
+            RequestSettingName::ClientKey => settings.client_key = new_value,
+            RequestSettingName::ClientCertPassword => settings.client_certificate_password = new_value,
+            RequestSettingName::CaCerts => settings.ca_certificates = new_value,
+            RequestSettingName::DependsOn => settings.depends_on = new_value,
+            RequestSettingName::DependencyCacheLifetime => {
+                parse_dependency_cache_lifetime(new_value.as_string())?;
+                settings.dependency_cache_lifetime = new_value
+            },
+            _ => unreachable!()
+        }
+
+        return Ok(());
+    }
+
+    match new_value {
+        Setting::Bool(bool) => match setting_name {
+            RequestSettingName::Proxy => settings.use_config_proxy = Setting::Bool(*bool),
+            RequestSettingName::Redirects => settings.allow_redirects = Setting::Bool(*bool),
+            RequestSettingName::Cookies => settings.store_received_cookies = Setting::Bool(*bool),
+            RequestSettingName::Pretty => settings.pretty_print_response_content = Setting::Bool(*bool),
+            RequestSettingName::EventStream => settings.force_event_stream = Setting::Bool(*bool),
+            _ => return Err(anyhow!(format!("The setting \"{}\" only takes positive int values", setting_name)))
+        },
+        Setting::U32(u32) => match setting_name {
+            RequestSettingName::Timeout => settings.timeout = Setting::U32(*u32),
+            _ => return Err(anyhow!(format!("The setting \"{}\" only takes boolean values", setting_name)))
+        },
+        Setting::String(_) => match setting_name {
+            RequestSettingName::Timeout => return Err(anyhow!(format!("The setting \"{}\" only takes positive int values", setting_name))),
+            _ => return Err(anyhow!(format!("The setting \"{}\" only takes boolean values", setting_name)))
+        }
+    }
+
+    Ok(())
+}
+
+pub fn get_setting<'a>(settings: &'a RequestSettings, setting_name: &RequestSettingName) -> &'a Setting {
+    match setting_name {
+        RequestSettingName::Proxy => &settings.use_config_proxy,
+        RequestSettingName::Timeout => &settings.timeout,
+        RequestSettingName::Redirects => &settings.allow_redirects,
+        RequestSettingName::Cookies => &settings.store_received_cookies,
+        RequestSettingName::Pretty => &settings.pretty_print_response_content,
+        RequestSettingName::EventStream => &settings.force_event_stream,
+        RequestSettingName::ClientCert => &settings.client_certificate,
+        RequestSettingName::ClientKey => &settings.client_key,
+        RequestSettingName::ClientCertPassword => &settings.client_certificate_password,
+        RequestSettingName::CaCerts => &settings.ca_certificates,
+        RequestSettingName::DependsOn => &settings.depends_on,
+        RequestSettingName::DependencyCacheLifetime => &settings.dependency_cache_lifetime,
+    }
+}
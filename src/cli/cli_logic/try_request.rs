@@ -1,6 +1,8 @@
 use std::sync::Arc;
+use anyhow::anyhow;
 use parking_lot::RwLock;
 use crate::app::app::App;
+use crate::app::business_logic::request::scripts::ScriptTestsError::TestsFailed;
 use crate::cli::cli_logic::request::new::create_request_from_new_request_command;
 use crate::cli::commands::request_commands::new::NewRequestCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
@@ -10,7 +12,13 @@ impl App<'_> {
         let new_request = create_request_from_new_request_command(String::new(), new_request_command.clone())?;
         let local_request = Arc::new(RwLock::new(new_request));
         
-        self.local_send_request(&send_command, local_request).await?;
+        self.local_send_request(&send_command, local_request.clone(), None).await?;
+
+        let failed_tests_count = local_request.read().failed_tests_count();
+
+        if failed_tests_count > 0 {
+            return Err(anyhow!(TestsFailed(failed_tests_count)));
+        }
 
         Ok(())
     }
@@ -1,5 +1,12 @@
 use clap::Subcommand;
+use crate::cli::commands::collection_commands::folder::FolderCommand;
+use crate::cli::commands::collection_commands::runner::RunnerCommand;
+use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::auth::AuthCommand;
+use crate::cli::commands::request_commands::scripts::ScriptsCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
+use crate::cli::commands::request_commands::setting::SettingsCommand;
+use crate::cli::commands::request_commands::url::UrlCommand;
 
 #[derive(clap::Args, Debug, Clone)]
 pub struct CollectionCommand {
@@ -52,12 +59,69 @@ pub enum CollectionSubcommand {
         new_collection_name: String
     },
     
-    /// Send all the collection's requests
+    /// List, create, delete, rename or move the collection's folders
+    Folder {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: FolderCommand
+    },
+
+    /// Send all the collection's requests, possibly several times with a data file, then print a pass/fail summary
     Send {
         /// e.g. my_collection, "my collection"
         collection_name: String,
 
+        #[clap(flatten)]
+        runner: RunnerCommand,
+
         #[clap(flatten)]
         subcommand: SendCommand
     },
+
+    /// Get or set the base URL prepended to the collection's request URLs that have no scheme
+    BaseUrl {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: UrlCommand
+    },
+
+    /// Add, get, set or delete a header sent along all the collection's requests
+    Header {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: KeyValueCommand
+    },
+
+    /// Get or set the auth method of the collection's requests that inherit it
+    Auth {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: AuthCommand
+    },
+
+    /// Get or set a script run before the request's own one
+    Scripts {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: ScriptsCommand
+    },
+
+    /// Get or set a setting inherited by the collection's requests that do not override it
+    Settings {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: SettingsCommand
+    },
 }
\ No newline at end of file
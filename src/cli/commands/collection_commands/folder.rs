@@ -0,0 +1,33 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum FolderCommand {
+    /// Print the collection's folder tree
+    List,
+    /// Create a folder, its parent folders must already exist
+    New {
+        /// e.g. my_folder, my_folder/my_sub_folder
+        folder_path: String
+    },
+    /// Delete a folder along with its sub-folders and requests
+    Delete {
+        /// e.g. my_folder, my_folder/my_sub_folder
+        folder_path: String
+    },
+    /// Rename a folder
+    Rename {
+        /// e.g. my_folder, my_folder/my_sub_folder
+        folder_path: String,
+
+        /// New folder name
+        new_folder_name: String
+    },
+    /// Move a folder and its content into another folder
+    Move {
+        /// e.g. my_folder, my_folder/my_sub_folder
+        folder_path: String,
+
+        /// Destination folder, leave empty to move it to the collection root
+        new_parent_path: Option<String>
+    }
+}
@@ -1 +1,3 @@
-pub mod collection_commands;
\ No newline at end of file
+pub mod collection_commands;
+pub mod folder;
+pub mod runner;
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use crate::app::business_logic::collection_report::CollectionReport;
+use crate::cli::utils::arguments_validators::report_validator;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct RunnerCommand {
+    /// Number of times the requests are sent, defaults to the number of data file rows or to 1
+    #[arg(long, value_name = "NUMBER")]
+    pub iterations: Option<u32>,
+
+    /// CSV (with a header line) or JSON (array of objects) file, each row values replace the {{KEY}} of its iteration
+    #[arg(long, value_name = "FILE")]
+    pub data: Option<PathBuf>,
+
+    /// Milliseconds waited between two requests
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 0)]
+    pub delay: u64,
+
+    /// Stop at the first failed request instead of continuing
+    #[arg(long, default_value_t = false)]
+    pub bail: bool,
+
+    /// Also fail the requests whose status is a client or server error, or not OK for gRPC, instead of only the send errors and failed tests
+    #[arg(long, default_value_t = false)]
+    pub fail_on_status: bool,
+
+    /// Write a report of the run, e.g. junit=report.xml or json=report.json. Can be repeated
+    #[arg(long, value_name = "FORMAT=PATH", value_parser = report_validator)]
+    pub report: Vec<CollectionReport>,
+}
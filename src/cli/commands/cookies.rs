@@ -0,0 +1,34 @@
+use clap::Subcommand;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CookiesCommand {
+    #[command(subcommand)]
+    pub cookies_subcommand: CookiesSubcommand,
+
+    /// Use the cookie jar of an environment, e.g. my_env (from the file .env.my_env)
+    #[arg(long, global = true)]
+    pub env: Option<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CookiesSubcommand {
+    /// List the unexpired cookies of the jar
+    List,
+
+    /// Remove every cookie from the jar
+    Clear,
+
+    /// Delete the cookies matching a name
+    Delete {
+        /// e.g. session_id
+        cookie_name: String,
+
+        /// Only delete the cookie set for this domain
+        #[arg(long)]
+        domain: Option<String>,
+
+        /// Only delete the cookie set for this path
+        #[arg(long)]
+        path: Option<String>,
+    }
+}
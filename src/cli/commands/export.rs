@@ -0,0 +1,60 @@
+use std::path::PathBuf;
+use clap::Subcommand;
+use nestify::nest;
+
+nest! {
+    #[derive(clap::Args, Debug, Clone)]
+    pub struct ExportCommand {
+        /// The type of data to export
+        #[command(subcommand)]
+        pub export_type: #[derive(Subcommand, Debug, Clone)] pub enum ExportType {
+            /// Export the cookie jar to a Netscape cookies.txt file (curl, browser extensions)
+            Cookies(CookiesExport),
+
+            /// Export a collection's requests, along with their last stored response, to a HAR file
+            Har(HarExport),
+
+            /// Export a collection's requests to a JetBrains/VS Code .http file, variables are kept as is
+            Http(HttpFileExport)
+        }
+    }
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CookiesExport {
+    /// Path of the file to write
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub export_path: PathBuf,
+
+    /// Export the cookie jar of an environment, e.g. my_env (from the file .env.my_env)
+    #[arg(long)]
+    pub env: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HarExport {
+    /// Name of the collection to export
+    pub collection_name: String,
+
+    /// Path of the file to write
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub export_path: PathBuf,
+
+    /// Resolve the variables with an environment, e.g. my_env (from the file .env.my_env)
+    #[arg(long)]
+    pub env: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HttpFileExport {
+    /// Name of the collection to export
+    pub collection_name: String,
+
+    /// Path of the file to write
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub export_path: PathBuf,
+
+    /// Write the values of an environment as file variables, e.g. my_env (from the file .env.my_env)
+    #[arg(long)]
+    pub env: Option<String>,
+}
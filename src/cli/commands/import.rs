@@ -19,7 +19,22 @@ nest! {
 
             /// Import an Open Api file
             #[clap(alias = "openapi")]
-            OpenApi(OpenApiImport)
+            OpenApi(OpenApiImport),
+
+            /// Import an Insomnia v4 export (JSON or YAML), environments included
+            Insomnia(InsomniaImport),
+
+            /// Import a Bruno collection directory, environments included
+            Bruno(BrunoImport),
+
+            /// Import the requests of a HAR file (browser devtools, proxies) into a collection
+            Har(HarImport),
+
+            /// Import the requests of a JetBrains/VS Code .http or .rest file into a collection
+            Http(HttpFileImport),
+
+            /// Import a Netscape cookies.txt file (curl, browser extensions) into the cookie jar
+            Cookies(CookiesImport)
         }
     }
 }
@@ -30,8 +45,8 @@ pub struct PostmanImport {
     #[clap(value_hint = clap::ValueHint::FilePath)]
     pub import_path: PathBuf,
 
-    /// Max depth at which import should stop creating nested collections and only get the deeper requests
-    #[arg(long)]
+    /// Deprecated, has no effect: every folder is now imported, whatever its depth
+    #[arg(long, hide = true)]
     pub max_depth: Option<u16>,
 }
 
@@ -66,7 +81,7 @@ pub struct CurlImport {
     #[arg(short, long, conflicts_with = "request_name")]
     pub recursive: bool,
 
-    /// Max depth at which import should stop creating nested collections and only get the deeper requests
+    /// Max directory depth at which the search for deeper files stops, sub-directories become folders
     #[arg(long, requires = "recursive", conflicts_with = "request_name")]
     pub max_depth: Option<u16>,
 }
@@ -77,7 +92,52 @@ pub struct OpenApiImport {
     #[clap(value_hint = clap::ValueHint::FilePath)]
     pub import_path: PathBuf,
 
-    /// Max depth at which import should stop creating nested collections and only get the deeper requests
-    #[arg(long)]
+    /// Deprecated, has no effect: every folder is now imported, whatever its depth
+    #[arg(long, hide = true)]
     pub max_depth: Option<u16>,
-}
\ No newline at end of file
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct InsomniaImport {
+    /// Path to the file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct BrunoImport {
+    /// Path to the collection directory, containing the bruno.json file
+    #[clap(value_hint = clap::ValueHint::DirPath)]
+    pub import_path: PathBuf,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HarImport {
+    /// Path to the file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+
+    /// Collection name to save the requests to
+    pub collection_name: String,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct HttpFileImport {
+    /// Path to the file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+
+    /// Collection name to save the requests to
+    pub collection_name: String,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CookiesImport {
+    /// Path to the file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+
+    /// Import into the cookie jar of an environment, e.g. my_env (from the file .env.my_env)
+    #[arg(long)]
+    pub env: Option<String>,
+}
@@ -2,7 +2,9 @@ pub mod collection_commands;
 pub mod request_commands;
 pub mod try_command;
 pub mod env;
+pub mod cookies;
 pub mod import;
+pub mod export;
 pub mod completions;
 pub mod man;
 pub mod key;
@@ -9,5 +9,19 @@ pub enum AuthCommand {
     Set {
         #[command(subcommand)]
         auth_method: Auth
+    },
+    /// Manage the cached OAuth2 access token
+    #[command(name = "oauth2")]
+    OAuth2 {
+        #[command(subcommand)]
+        subcommand: OAuth2Command
     }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum OAuth2Command {
+    /// Fetch a new access token, even if the cached one is still valid, then print it
+    Fetch,
+    /// Forget the cached access token
+    Clear
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum GraphqlCommand {
+    /// Print the current GraphQL query, variables or operation name
+    Get {
+        /// Query, variables or operation name
+        part: GraphqlPart
+    },
+    /// Set the GraphQL query, variables or operation name
+    Set {
+        /// Query, variables or operation name
+        part: GraphqlPart,
+
+        /// Value to set, leave empty to clear it
+        value: Option<String>
+    },
+    /// Fetch the schema with an introspection query and cache it next to the collection
+    Introspect,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum GraphqlPart {
+    Query,
+    Variables,
+    OperationName,
+}
@@ -0,0 +1,28 @@
+use clap::{Subcommand, ValueEnum};
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum GrpcCommand {
+    /// Print the current gRPC proto files, service, method or message
+    Get {
+        /// Proto files, service, method or message
+        part: GrpcPart
+    },
+    /// Set the gRPC proto files, service, method or message
+    Set {
+        /// Proto files, service, method or message
+        part: GrpcPart,
+
+        /// Value to set, proto files are comma-separated, leave empty to clear it
+        value: Option<String>
+    },
+    /// List the services and methods, from the proto files or the server reflection
+    Methods,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum GrpcPart {
+    ProtoFiles,
+    Service,
+    Method,
+    Message,
+}
@@ -5,5 +5,7 @@ pub mod new;
 pub mod auth;
 pub mod body;
 pub mod scripts;
+pub mod graphql;
+pub mod grpc;
 pub mod send;
 pub mod setting;
@@ -36,6 +36,12 @@ pub struct NewRequestCommand {
     #[command(flatten)]
     pub body: BodyArgs,
 
+    #[command(flatten)]
+    pub graphql: GraphqlArgs,
+
+    #[command(flatten)]
+    pub grpc: GrpcArgs,
+
     /// Set a pre-request script
     #[arg(long, display_order = 17)]
     pub pre_request_script: Option<String>,
@@ -70,7 +76,35 @@ pub struct NewRequestCommand {
 
     /// Accept invalid hostnames
     #[arg(long, default_value_t = false, display_order = 25)]
-    pub accept_invalid_hostnames: bool
+    pub accept_invalid_hostnames: bool,
+
+    /// Always parse the response as a server-sent events stream
+    #[arg(long, default_value_t = false, display_order = 26)]
+    pub force_event_stream: bool,
+
+    /// Client certificate file path, PEM or PKCS#12 (.p12, .pfx)
+    #[arg(long, display_order = 27)]
+    pub client_cert: Option<String>,
+
+    /// Client private key file path, when not contained in the PEM client certificate
+    #[arg(long, display_order = 28)]
+    pub client_key: Option<String>,
+
+    /// PKCS#12 client certificate password
+    #[arg(long, display_order = 29)]
+    pub client_cert_password: Option<String>,
+
+    /// Additional trusted CA bundle file paths, comma separated
+    #[arg(long, display_order = 30)]
+    pub ca_certs: Option<String>,
+
+    /// Request sent beforehand when its captured values are missing or expired, e.g. my_collection/login
+    #[arg(long, display_order = 31)]
+    pub depends_on: Option<String>,
+
+    /// How long the dependency's last response is trusted, e.g. 300, 15m or 1h
+    #[arg(long, display_order = 32, requires = "depends_on")]
+    pub dependency_cache_lifetime: Option<String>
 }
 
 #[derive(clap::Args, Debug, Clone)]
@@ -91,6 +125,22 @@ pub struct AuthArgs {
     /// Set a digest auth method
     #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 3, value_names = ["USERNAME", "PASSWORD", "WWW_AUTHENTICATE_HEADER"], display_order = 6)]
     pub auth_digest: Vec<String>,
+
+    /// Set an OAuth2 auth method, the other OAuth2 parameters can be set with "request auth set oauth2"
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 3, value_names = ["GRANT_TYPE", "TOKEN_URL", "CLIENT_ID"], display_order = 6)]
+    pub auth_oauth2: Vec<String>,
+
+    /// Set an AWS SigV4 auth method, the session token can be set with "request auth set aws-sigv4"
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 4, value_names = ["ACCESS_KEY_ID", "SECRET_ACCESS_KEY", "REGION", "SERVICE"], display_order = 6)]
+    pub auth_aws_sigv4: Vec<String>,
+
+    /// Set an API key auth method, the placement is one of header (default), query-param or cookie
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 2..=3, value_names = ["KEY", "VALUE", "PLACEMENT"], display_order = 6)]
+    pub auth_api_key: Vec<String>,
+
+    /// Use the auth method of the request's collection
+    #[arg(long, group = "auth", display_order = 6)]
+    pub auth_inherit: bool,
 }
 
 #[derive(clap::Args, Debug, Clone)]
@@ -131,3 +181,44 @@ pub struct BodyArgs {
     pub body_javascript: Option<String>,
 }
 
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct GraphqlArgs {
+    /// Set the GraphQL query
+    /// (only with the graphql protocol)
+    #[arg(long, value_name = "QUERY", display_order = 16)]
+    pub graphql_query: Option<String>,
+
+    /// Set the GraphQL variables, as a JSON object
+    /// (only with the graphql protocol)
+    #[arg(long, value_name = "JSON", display_order = 16)]
+    pub graphql_variables: Option<String>,
+
+    /// Set the GraphQL operation name
+    /// (only with the graphql protocol)
+    #[arg(long, value_name = "NAME", display_order = 16)]
+    pub graphql_operation_name: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct GrpcArgs {
+    /// Add a .proto file describing the service, server reflection is used if there are none
+    /// (can be used multiple times, only with the grpc protocol)
+    #[arg(long, action = clap::ArgAction::Append, value_name = "PATH", value_hint = clap::ValueHint::FilePath, display_order = 16)]
+    pub proto_file: Vec<String>,
+
+    /// Set the fully qualified gRPC service name, e.g. helloworld.Greeter
+    /// (only with the grpc protocol)
+    #[arg(long, value_name = "SERVICE", display_order = 16)]
+    pub grpc_service: Option<String>,
+
+    /// Set the gRPC method name
+    /// (only with the grpc protocol)
+    #[arg(long, value_name = "METHOD", display_order = 16)]
+    pub grpc_method: Option<String>,
+
+    /// Set the gRPC request message, as JSON
+    /// (only with the grpc protocol)
+    #[arg(long, value_name = "JSON", display_order = 16)]
+    pub grpc_message: Option<String>,
+}
@@ -3,6 +3,8 @@ use clap::Subcommand;
 use crate::cli::commands::key_value::KeyValueCommand;
 use crate::cli::commands::request_commands::auth::AuthCommand;
 use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::graphql::GraphqlCommand;
+use crate::cli::commands::request_commands::grpc::GrpcCommand;
 use crate::cli::commands::request_commands::method::MethodCommand;
 use crate::cli::commands::request_commands::new::NewRequestCommand;
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
@@ -33,6 +35,10 @@ pub enum RequestSubcommand {
         #[arg(value_parser = collection_slash_request_validator)]
         collection_slash_request: (String, String),
 
+        /// Folder to create the request in, e.g. my_folder/my_sub_folder
+        #[arg(long)]
+        folder: Option<String>,
+
         #[clap(flatten)]
         subcommand: NewRequestCommand
     },
@@ -54,6 +60,16 @@ pub enum RequestSubcommand {
         new_request_name: String
     },
     
+    /// Move a request into a folder
+    Move {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        /// Destination folder e.g. my_folder/my_sub_folder, leave empty to move it to the collection root
+        folder_path: Option<String>
+    },
+    
     /// Get or set a request URL
     Url {
         /// e.g. my_collection/my_request
@@ -104,6 +120,17 @@ pub enum RequestSubcommand {
         subcommand: KeyValueCommand
     },
 
+    /// Get, set, add, delete, rename or toggle a capture, storing a response value in an environment variable.
+    /// e.g. add TOKEN "body $.access_token", add SESSION "header set-cookie", add CODE status
+    Captures {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: KeyValueCommand
+    },
+
     /// Get or set a request body
     Body {
         /// e.g. my_collection/my_request
@@ -114,6 +141,26 @@ pub enum RequestSubcommand {
         subcommand: BodySubcommand
     },
 
+    /// Get or set a GraphQL query, variables and operation name, or introspect its schema
+    Graphql {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: GraphqlCommand
+    },
+
+    /// Get or set a gRPC request's proto files, service, method and message, or list its methods
+    Grpc {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: GrpcCommand
+    },
+
     /// Get or set pre- and post-request scripts
     Scripts {
         /// e.g. my_collection/my_request
@@ -134,6 +181,45 @@ pub enum RequestSubcommand {
         subcommand: SendCommand
     },
 
+    /// List the past responses of a request, or show one of them
+    History {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        /// Show the full response of an entry, 0 being the most recent one
+        #[arg(long, conflicts_with = "clear")]
+        entry: Option<usize>,
+
+        /// Delete the request response history
+        #[arg(long)]
+        clear: bool,
+    },
+
+    /// Compare two responses of a request, from its history or by sending it with two environments
+    Diff {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        /// Response history entries to compare, 0 being the most recent one. Defaults to the last two responses
+        #[arg(long, num_args = 2, value_names = ["ENTRY_A", "ENTRY_B"], conflicts_with = "envs")]
+        entries: Option<Vec<usize>>,
+
+        /// Send the request with each environment and compare both responses
+        #[arg(long, num_args = 2, value_names = ["ENV_A", "ENV_B"])]
+        envs: Option<Vec<String>>,
+
+        /// Header not to compare, in addition to the volatile ones such as date or x-request-id
+        /// (can be used multiple times)
+        #[arg(long, action = clap::ArgAction::Append, value_name = "HEADER")]
+        ignore_header: Vec<String>,
+
+        /// Also compare the volatile headers, such as date or x-request-id
+        #[arg(long, default_value_t = false)]
+        all_headers: bool,
+    },
+
     /// Get or set a request setting
     Settings {
         /// e.g. my_collection/my_request
@@ -1,3 +1,5 @@
+use clap::ValueEnum;
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct SendCommand {
     /// Hide response content
@@ -24,11 +26,32 @@ pub struct SendCommand {
     #[arg(long, default_value_t = false)]
     pub console: bool,
 
+    /// Show the results of the post-request script tests
+    #[arg(long, default_value_t = false)]
+    pub tests: bool,
+
     /// Show the request name
     #[arg(long, default_value_t = false)]
     pub request_name: bool,
 
+    /// Output format, json prints one JSON object per request
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub output: OutputFormat,
+
+    /// Only print the part of the body matching a JSONPath ($.items[0]), jq (.items[] | .name) or XPath (//item/@name) filter
+    /// (named --body-filter since the global --filter already filters the collection files)
+    // clap rejects a subcommand argument reusing the long name of a global one, hence not --filter
+    #[arg(long, value_name = "FILTER")]
+    pub body_filter: Option<String>,
+
     /// Name of the environment to use, e.g. my_env (from file .env.my_env)
     #[arg(long, value_name = "ENV_NAME", display_order = 98)]
     pub env: Option<String>
-}
\ No newline at end of file
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
@@ -33,5 +33,43 @@ pub enum RequestSettingName {
     /// Store received cookies
     Cookies,
     /// Pretty print response content
-    Pretty
+    Pretty,
+    /// Force event stream
+    EventStream,
+    /// Client certificate file path, PEM or PKCS#12 (.p12, .pfx)
+    ClientCert,
+    /// Client private key file path, when not contained in the PEM client certificate
+    ClientKey,
+    /// PKCS#12 client certificate password
+    ClientCertPassword,
+    /// Additional trusted CA bundle file paths, comma separated
+    CaCerts,
+    /// Request sent beforehand when its captured values are missing or expired, e.g. my_collection/login
+    DependsOn,
+    /// How long the dependency's last response is trusted, e.g. 300, 15m or 1h
+    DependencyCacheLifetime
+}
+
+impl RequestSettingName {
+    pub fn is_text(&self) -> bool {
+        matches!(self, RequestSettingName::ClientCert | RequestSettingName::ClientKey | RequestSettingName::ClientCertPassword | RequestSettingName::CaCerts | RequestSettingName::DependsOn | RequestSettingName::DependencyCacheLifetime)
+    }
+
+    /// Name of the setting in the collection files
+    pub fn field_name(&self) -> &'static str {
+        match self {
+            RequestSettingName::Proxy => "use_config_proxy",
+            RequestSettingName::Redirects => "allow_redirects",
+            RequestSettingName::Timeout => "timeout",
+            RequestSettingName::Cookies => "store_received_cookies",
+            RequestSettingName::Pretty => "pretty_print_response_content",
+            RequestSettingName::EventStream => "force_event_stream",
+            RequestSettingName::ClientCert => "client_certificate",
+            RequestSettingName::ClientKey => "client_key",
+            RequestSettingName::ClientCertPassword => "client_certificate_password",
+            RequestSettingName::CaCerts => "ca_certificates",
+            RequestSettingName::DependsOn => "depends_on",
+            RequestSettingName::DependencyCacheLifetime => "dependency_cache_lifetime",
+        }
+    }
 }
\ No newline at end of file
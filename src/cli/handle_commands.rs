@@ -1,15 +1,22 @@
 use crate::app::app::App;
+use crate::app::business_logic::folder::parse_folder_path;
+use crate::app::business_logic::response_diff::ResponseDiff;
 use crate::cli::args::Command;
 use crate::cli::args::Command::*;
 use crate::cli::cli_logic::completions::generate_completions;
 use crate::cli::cli_logic::man::generate_man_page;
 use crate::cli::commands::collection_commands::collection_commands::{CollectionCommand, CollectionSubcommand};
+use crate::cli::commands::collection_commands::folder::FolderCommand;
 use crate::cli::commands::env::{EnvCommand, EnvSubcommand};
+use crate::cli::commands::cookies::{CookiesCommand, CookiesSubcommand};
 use crate::cli::commands::key::KeyCommand;
 use crate::cli::commands::import::ImportType;
+use crate::cli::commands::export::ExportType;
 use crate::cli::commands::key_value::KeyValueCommand;
-use crate::cli::commands::request_commands::auth::{AuthCommand};
+use crate::cli::commands::request_commands::auth::{AuthCommand, OAuth2Command};
 use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::graphql::GraphqlCommand;
+use crate::cli::commands::request_commands::grpc::GrpcCommand;
 use crate::cli::commands::request_commands::method::MethodCommand;
 use crate::cli::commands::request_commands::request_commands::{RequestCommand, RequestSubcommand};
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
@@ -29,17 +36,32 @@ impl App<'_> {
                 ImportType::PostmanEnv(postman_env_import) => self.import_postman_environment(postman_env_import),
                 ImportType::Curl(curl_import) => self.import_curl_file(curl_import),
                 ImportType::OpenApi(openapi_import) => self.import_openapi_collection(openapi_import),
+                ImportType::Insomnia(insomnia_import) => self.import_insomnia_export(insomnia_import),
+                ImportType::Bruno(bruno_import) => self.import_bruno_collection(bruno_import),
+                ImportType::Har(har_import) => self.import_har_file(har_import),
+                ImportType::Http(http_file_import) => self.import_http_file(http_file_import),
+                ImportType::Cookies(cookies_import) => self.import_cookies_file(cookies_import),
+            },
+
+            Export(export_command) => match &export_command.export_type {
+                ExportType::Cookies(cookies_export) => self.export_cookies_file(cookies_export),
+                ExportType::Har(har_export) => self.export_har_file(har_export),
+                ExportType::Http(http_file_export) => self.export_http_file(http_file_export),
             },
 
             Try(try_command) => self.try_request(&try_command.new_request_command, &try_command.send_command).await,
 
             Env(env_command) => self.handle_env_commands(env_command),
 
+            Cookies(cookies_command) => self.handle_cookies_command(cookies_command),
+
             Completions(completions_command) => generate_completions(completions_command),
             
             Man(_) => generate_man_page()
         };
 
+        self.save_cookie_jar();
+
         if let Err(error) = result {
             panic_error(error.to_string());
         }
@@ -48,7 +70,7 @@ impl App<'_> {
     async fn handle_collection_command(&mut self, collection_command: &CollectionCommand) -> anyhow::Result<()> {
         if let Some(environment_name) = &collection_command.env {
             let environment_index = self.find_environment(&environment_name)?;
-            self.selected_environment = environment_index;
+            self.select_environment(environment_index);
         }
 
         match &collection_command.collection_subcommand {
@@ -57,16 +79,93 @@ impl App<'_> {
             CollectionSubcommand::New { collection_name } => self.new_collection(collection_name.clone()),
             CollectionSubcommand::Delete { collection_name } => self.cli_delete_collection(collection_name),
             CollectionSubcommand::Rename { collection_name, new_collection_name } => self.cli_rename_collection(collection_name, new_collection_name.clone()),
-            CollectionSubcommand::Send { collection_name, subcommand } => self.cli_send_collection(collection_name, subcommand).await,
+            CollectionSubcommand::Folder { collection_name, subcommand } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                match subcommand {
+                    FolderCommand::List => self.cli_print_collection_folders(collection_index),
+                    FolderCommand::New { folder_path } => {
+                        let mut parent_path = parse_folder_path(folder_path);
+                        let folder_name = parent_path.pop().unwrap_or_default();
+
+                        self.new_folder(collection_index, &parent_path, folder_name)
+                    },
+                    FolderCommand::Delete { folder_path } => self.delete_folder(collection_index, &parse_folder_path(folder_path)),
+                    FolderCommand::Rename { folder_path, new_folder_name } => self.rename_folder(collection_index, &parse_folder_path(folder_path), new_folder_name.clone()),
+                    FolderCommand::Move { folder_path, new_parent_path } => self.move_folder(collection_index, &parse_folder_path(folder_path), &parse_folder_path(new_parent_path.as_deref().unwrap_or_default())),
+                }
+            },
+            CollectionSubcommand::Send { collection_name, runner, subcommand } => self.cli_send_collection(collection_name, subcommand, runner).await,
+            CollectionSubcommand::BaseUrl { collection_name, subcommand } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                match subcommand {
+                    UrlCommand::Get => self.cli_print_collection_base_url(collection_index),
+                    UrlCommand::Set { new_url } => self.modify_collection_base_url(collection_index, new_url.clone())
+                }
+            },
+            CollectionSubcommand::Header { collection_name, subcommand } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                let key = match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        // Specific case
+                        KeyCommand::Add { key, value } => return self.create_new_collection_header(collection_index, key.clone(), value.clone()),
+                        // Otherwise, get the key
+                        KeyCommand::Get { key } | KeyCommand::Set { key, .. } | KeyCommand::Delete { key } | KeyCommand::Rename { key, .. } => key
+                    },
+                    KeyValueCommand::Toggle { key, .. } => key,
+                    KeyValueCommand::All => return self.cli_print_collection_headers(collection_index)
+                };
+
+                let header_index = self.find_collection_header(collection_index, key)?;
+
+                match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        KeyCommand::Get { .. } => self.cli_print_collection_header(collection_index, header_index),
+                        KeyCommand::Set { value, .. } => self.modify_collection_header(collection_index, value.clone(), 1, header_index),
+                        KeyCommand::Delete { .. } => self.delete_collection_header(collection_index, header_index),
+                        KeyCommand::Rename { new_key, .. } => self.modify_collection_header(collection_index, new_key.clone(), 0, header_index),
+                        _ => unreachable!()
+                    },
+                    KeyValueCommand::Toggle { state, .. } => self.toggle_collection_header(collection_index, *state, header_index),
+                    _ => unreachable!()
+                }
+            },
+            CollectionSubcommand::Auth { collection_name, subcommand } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                match subcommand {
+                    AuthCommand::Get => self.cli_print_collection_auth(collection_index),
+                    AuthCommand::Set { auth_method } => self.modify_collection_auth(collection_index, auth_method.to_owned()),
+                    AuthCommand::OAuth2 { subcommand } => match subcommand {
+                        OAuth2Command::Fetch => self.cli_fetch_collection_oauth2_token(collection_index).await,
+                        OAuth2Command::Clear => self.clear_collection_oauth2_token(collection_index),
+                    }
+                }
+            },
+            CollectionSubcommand::Scripts { collection_name, subcommand } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                match subcommand {
+                    ScriptsCommand::Get { script_type } => self.cli_print_collection_script(collection_index, script_type),
+                    ScriptsCommand::Set { script_type, script } => self.modify_collection_script(collection_index, script_type, script.clone())
+                }
+            },
+            CollectionSubcommand::Settings { collection_name, subcommand } => match subcommand {
+                SettingsCommand::All => self.cli_print_collection_settings(collection_name),
+                SettingsCommand::Get { setting_name } => self.cli_print_collection_setting(collection_name, setting_name),
+                SettingsCommand::Set { setting_name, new_value } => self.cli_modify_collection_setting(collection_name, setting_name, new_value),
+            },
         }
     }
 
     async fn handle_request_command(&mut self, request_command: &RequestCommand) -> anyhow::Result<()> {
         // Since all the request commands need the collection_slash_request argument, it's preferable to parse it from here
         let (collection_index, request_index) = match &request_command.request_subcommand {
-            RequestSubcommand::Info { collection_slash_request } | RequestSubcommand::Delete { collection_slash_request }| RequestSubcommand::Rename { collection_slash_request, .. } | RequestSubcommand::Url { collection_slash_request, .. } | RequestSubcommand::Method { collection_slash_request, .. } | RequestSubcommand::Params { collection_slash_request, .. } | RequestSubcommand::Auth { collection_slash_request, .. } | RequestSubcommand::Header { collection_slash_request, .. } | RequestSubcommand::Body { collection_slash_request, .. } | RequestSubcommand::Scripts { collection_slash_request, .. } | RequestSubcommand::Send { collection_slash_request, .. } | RequestSubcommand::Settings { collection_slash_request, .. } | RequestSubcommand::Export { collection_slash_request, .. } => self.find_collection_slash_request(&collection_slash_request.0, &collection_slash_request.1)?,
+            RequestSubcommand::Info { collection_slash_request } | RequestSubcommand::Delete { collection_slash_request }| RequestSubcommand::Rename { collection_slash_request, .. } | RequestSubcommand::Move { collection_slash_request, .. } | RequestSubcommand::Url { collection_slash_request, .. } | RequestSubcommand::Method { collection_slash_request, .. } | RequestSubcommand::Params { collection_slash_request, .. } | RequestSubcommand::Auth { collection_slash_request, .. } | RequestSubcommand::Header { collection_slash_request, .. } | RequestSubcommand::Captures { collection_slash_request, .. } | RequestSubcommand::Body { collection_slash_request, .. } | RequestSubcommand::Graphql { collection_slash_request, .. } | RequestSubcommand::Grpc { collection_slash_request, .. } | RequestSubcommand::Scripts { collection_slash_request, .. } | RequestSubcommand::Send { collection_slash_request, .. } | RequestSubcommand::History { collection_slash_request, .. } | RequestSubcommand::Diff { collection_slash_request, .. } | RequestSubcommand::Settings { collection_slash_request, .. } | RequestSubcommand::Export { collection_slash_request, .. } => self.find_collection_slash_request(&collection_slash_request.0, &collection_slash_request.1)?,
             // Specific case
-            RequestSubcommand::New { collection_slash_request, subcommand } => return self.cli_new_request(collection_slash_request.clone(), subcommand.clone()),
+            RequestSubcommand::New { collection_slash_request, folder, subcommand } => return self.cli_new_request(collection_slash_request.clone(), folder.as_deref(), subcommand.clone()),
         };
 
         match &request_command.request_subcommand {
@@ -74,6 +173,7 @@ impl App<'_> {
             RequestSubcommand::Delete { .. } => self.delete_request(collection_index, request_index),
             RequestSubcommand::Rename { new_request_name, .. } => self.rename_request(collection_index, request_index, new_request_name.clone()),
             RequestSubcommand::New { .. } => unreachable!(),
+            RequestSubcommand::Move { folder_path, .. } => self.move_request_to_folder(collection_index, request_index, &parse_folder_path(folder_path.as_deref().unwrap_or_default())),
             RequestSubcommand::Url { subcommand, .. } => match subcommand {
                 UrlCommand::Get => self.cli_print_request_url(collection_index, request_index),
                 UrlCommand::Set { new_url } => self.modify_request_url(collection_index, request_index, new_url.clone())
@@ -110,7 +210,11 @@ impl App<'_> {
             },
             RequestSubcommand::Auth { subcommand, .. } => match subcommand {
                 AuthCommand::Get => self.cli_print_request_auth(collection_index, request_index),
-                AuthCommand::Set { auth_method } => self.modify_request_auth(collection_index, request_index, auth_method.to_owned())
+                AuthCommand::Set { auth_method } => self.modify_request_auth(collection_index, request_index, auth_method.to_owned()),
+                AuthCommand::OAuth2 { subcommand } => match subcommand {
+                    OAuth2Command::Fetch => self.cli_fetch_request_oauth2_token(collection_index, request_index).await,
+                    OAuth2Command::Clear => self.clear_request_oauth2_token(collection_index, request_index),
+                }
             },
             RequestSubcommand::Header { subcommand, .. } => {
                 let key = match subcommand {
@@ -138,6 +242,32 @@ impl App<'_> {
                     _ => unreachable!()
                 }
             },
+            RequestSubcommand::Captures { subcommand, .. } => {
+                let key = match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        // Specific case
+                        KeyCommand::Add { key, value } => return self.create_new_capture(collection_index, request_index, key.clone(), value.clone()),
+                        // Otherwise, get the key
+                        KeyCommand::Get { key } | KeyCommand::Set { key, .. } | KeyCommand::Delete { key } | KeyCommand::Rename { key, .. } => key
+                    },
+                    KeyValueCommand::Toggle { key, .. } => key,
+                    KeyValueCommand::All => return self.cli_print_captures(collection_index, request_index)
+                };
+
+                let capture_index = self.find_capture(collection_index, request_index, key)?;
+
+                match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        KeyCommand::Get { .. } => self.cli_print_capture(collection_index, request_index, capture_index),
+                        KeyCommand::Set { value, .. } => self.modify_request_capture(collection_index, request_index, value.clone(), 1, capture_index),
+                        KeyCommand::Delete { .. } => self.delete_capture(collection_index, request_index, capture_index),
+                        KeyCommand::Rename { new_key, .. } => self.modify_request_capture(collection_index, request_index, new_key.clone(), 0, capture_index),
+                        _ => unreachable!()
+                    },
+                    KeyValueCommand::Toggle { state, .. } => self.toggle_capture(collection_index, request_index, *state, capture_index),
+                    _ => unreachable!()
+                }
+            },
             RequestSubcommand::Body { subcommand, .. } => match subcommand {
                 BodySubcommand::Get => self.cli_print_request_body(collection_index, request_index),
                 BodySubcommand::Set { content_type } => self.modify_request_content_type(collection_index, request_index, content_type.to_content_type()),
@@ -168,11 +298,42 @@ impl App<'_> {
                     }
                 }
             },
+            RequestSubcommand::Graphql { subcommand, .. } => match subcommand {
+                GraphqlCommand::Get { part } => self.cli_print_request_graphql(collection_index, request_index, part),
+                GraphqlCommand::Set { part, value } => self.cli_modify_request_graphql(collection_index, request_index, part, value.clone()),
+                GraphqlCommand::Introspect => self.cli_introspect_graphql_schema(collection_index, request_index).await,
+            },
+            RequestSubcommand::Grpc { subcommand, .. } => match subcommand {
+                GrpcCommand::Get { part } => self.cli_print_request_grpc(collection_index, request_index, part),
+                GrpcCommand::Set { part, value } => self.cli_modify_request_grpc(collection_index, request_index, part, value.clone()),
+                GrpcCommand::Methods => self.cli_list_grpc_methods(collection_index, request_index).await,
+            },
             RequestSubcommand::Scripts { subcommand, .. } => match subcommand {
                 ScriptsCommand::Get { script_type } => self.cli_print_request_script(collection_index, request_index, script_type),
                 ScriptsCommand::Set { script_type, script } => self.modify_request_script(collection_index, request_index, script_type, script.clone())
             },
             RequestSubcommand::Send { subcommand, .. } => self.cli_send_request(collection_index, request_index, subcommand).await,
+            RequestSubcommand::History { entry, clear, .. } => match (entry, clear) {
+                (_, true) => self.clear_response_history(collection_index, request_index),
+                (Some(entry), false) => self.cli_print_response_history_entry(collection_index, request_index, *entry),
+                (None, false) => self.cli_print_response_history(collection_index, request_index)
+            },
+            RequestSubcommand::Diff { entries, envs, ignore_header, all_headers, .. } => {
+                let mut ignored_headers = match all_headers {
+                    true => vec![],
+                    false => ResponseDiff::default_ignored_headers()
+                };
+
+                ignored_headers.extend(ignore_header.iter().cloned());
+
+                match envs {
+                    Some(envs) => self.cli_diff_environments_responses(collection_index, request_index, &envs[0], &envs[1], &ignored_headers).await,
+                    None => {
+                        let entries = entries.clone().unwrap_or(vec![1, 0]);
+                        self.cli_diff_response_history_entries(collection_index, request_index, entries[0], entries[1], &ignored_headers)
+                    }
+                }
+            },
             RequestSubcommand::Settings { subcommand, .. } => match subcommand {
                 SettingsCommand::All => self.cli_print_request_settings(collection_index, request_index),
                 SettingsCommand::Get { setting_name } => self.cli_print_request_setting(collection_index, request_index, setting_name),
@@ -198,4 +359,17 @@ impl App<'_> {
             }
         }
     }
+
+    fn handle_cookies_command(&mut self, cookies_command: &CookiesCommand) -> anyhow::Result<()> {
+        if let Some(environment_name) = &cookies_command.env {
+            let environment_index = self.find_environment(environment_name)?;
+            self.select_environment(environment_index);
+        }
+
+        match &cookies_command.cookies_subcommand {
+            CookiesSubcommand::List => self.cli_print_cookies(),
+            CookiesSubcommand::Clear => self.clear_cookies(),
+            CookiesSubcommand::Delete { cookie_name, domain, path } => self.delete_cookies(cookie_name, domain.as_deref(), path.as_deref())
+        }
+    }
 }
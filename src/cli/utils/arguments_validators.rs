@@ -1,5 +1,8 @@
-use clap::Error;
+use std::path::PathBuf;
+use clap::{Error, ValueEnum};
 use clap::error::ErrorKind::InvalidValue;
+use crate::app::business_logic::collection_report::{CollectionReport, ReportFormat};
+use crate::app::files::utils::expand_tilde;
 use crate::models::request::KeyValue;
 
 const ELEMENT_NAME_REGEX: &str = "[a-zA-Z0-9-_ ]+";
@@ -32,4 +35,21 @@ pub fn key_value_array_validator(arg: &str) -> Result<KeyValue, Error> {
         enabled: true,
         data: (pair.0.to_string(), pair.1.to_string()),
     })
-}
\ No newline at end of file
+}
+
+pub fn report_validator(arg: &str) -> Result<CollectionReport, Error> {
+    let (format, path) = match arg.split_once("=") {
+        Some((format, path)) if !path.is_empty() => (format, path),
+        _ => return Err(Error::new(InvalidValue))
+    };
+
+    let format = match ReportFormat::from_str(format, true) {
+        Ok(format) => format,
+        Err(_) => return Err(Error::new(InvalidValue))
+    };
+
+    Ok(CollectionReport {
+        format,
+        path: expand_tilde(PathBuf::from(path)),
+    })
+}
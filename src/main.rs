@@ -60,6 +60,6 @@ async fn run_tui<'a>(app: &mut App<'a>) -> anyhow::Result<()> {
 }
 
 pub fn panic_error<T>(message: T) -> ! where T: Display {
-    println!("{error}:\n\t{message}", error = "Error".red().bold());
+    eprintln!("{error}:\n\t{message}", error = "Error".red().bold());
     exit(1);
 }
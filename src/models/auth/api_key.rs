@@ -0,0 +1,45 @@
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+#[derive(Args, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub key: String,
+    pub value: String,
+
+    /// Where the API key is sent
+    #[arg(long, value_enum, default_value_t)]
+    #[serde(default)]
+    pub placement: ApiKeyPlacement,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, ValueEnum, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyPlacement {
+    #[default]
+    #[strum(to_string = "Header")]
+    /// Sent as a request header
+    Header,
+    #[strum(to_string = "Query param")]
+    /// Sent as a query parameter
+    QueryParam,
+    #[strum(to_string = "Cookie")]
+    /// Sent as a cookie
+    Cookie,
+}
+
+pub fn previous_api_key_placement(placement: &ApiKeyPlacement) -> ApiKeyPlacement {
+    match placement {
+        ApiKeyPlacement::Header => ApiKeyPlacement::Cookie,
+        ApiKeyPlacement::QueryParam => ApiKeyPlacement::Header,
+        ApiKeyPlacement::Cookie => ApiKeyPlacement::QueryParam,
+    }
+}
+
+pub fn next_api_key_placement(placement: &ApiKeyPlacement) -> ApiKeyPlacement {
+    match placement {
+        ApiKeyPlacement::Header => ApiKeyPlacement::QueryParam,
+        ApiKeyPlacement::QueryParam => ApiKeyPlacement::Cookie,
+        ApiKeyPlacement::Cookie => ApiKeyPlacement::Header,
+    }
+}
@@ -5,6 +5,9 @@ use clap::Subcommand;
 use serde::{Deserialize, Serialize};
 use strum::Display;
 use crate::models::auth::digest::Digest;
+use crate::models::auth::oauth2::OAuth2;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::api_key::ApiKey;
 
 #[derive(Subcommand, Clone, Default, Debug, Display, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -30,9 +33,29 @@ pub enum Auth {
     JwtToken(JwtToken),
 
     #[strum(to_string = "Digest")]
-    #[clap(visible_alias = "digest")]
     /// Digest auth method
     Digest(Digest),
+
+    #[strum(to_string = "OAuth2")]
+    #[serde(rename = "oauth2")]
+    #[clap(name = "oauth2")]
+    /// OAuth 2.0 auth method, the access token is fetched and refreshed automatically
+    OAuth2(OAuth2),
+
+    #[strum(to_string = "AWS SigV4")]
+    #[serde(rename = "aws_sigv4")]
+    #[clap(name = "aws-sigv4")]
+    /// AWS Signature Version 4 auth method, the request is signed right before being sent
+    AwsSigV4(AwsSigV4),
+
+    #[strum(to_string = "API Key")]
+    /// API key auth method, sent as a header, a query param or a cookie
+    ApiKey(ApiKey),
+
+    #[strum(to_string = "Inherit from collection")]
+    #[clap(visible_alias = "inherit")]
+    /// Use the auth method of the request's collection
+    InheritFromCollection,
 }
 
 impl Auth {
@@ -63,6 +86,41 @@ impl Auth {
             _ => unreachable!()
         }
     }
+
+    pub fn get_oauth2(&self) -> &OAuth2 {
+        match self {
+            Auth::OAuth2(oauth2) => oauth2,
+            _ => unreachable!()
+        }
+    }
+
+    pub fn get_oauth2_mut(&mut self) -> &mut OAuth2 {
+        match self {
+            Auth::OAuth2(oauth2) => oauth2,
+            _ => unreachable!()
+        }
+    }
+
+    pub fn get_aws_sigv4_mut(&mut self) -> &mut AwsSigV4 {
+        match self {
+            Auth::AwsSigV4(aws_sigv4) => aws_sigv4,
+            _ => unreachable!()
+        }
+    }
+
+    pub fn get_api_key(&self) -> &ApiKey {
+        match self {
+            Auth::ApiKey(api_key) => api_key,
+            _ => unreachable!()
+        }
+    }
+
+    pub fn get_api_key_mut(&mut self) -> &mut ApiKey {
+        match self {
+            Auth::ApiKey(api_key) => api_key,
+            _ => unreachable!()
+        }
+    }
 }
 
 pub fn next_auth(auth: &Auth) -> Auth {
@@ -71,7 +129,11 @@ pub fn next_auth(auth: &Auth) -> Auth {
         Auth::BasicAuth(_) => Auth::BearerToken(BearerToken::default()),
         Auth::BearerToken(_) => Auth::JwtToken(JwtToken::default()),
         Auth::JwtToken(_) => Auth::Digest(Digest::default()),
-        Auth::Digest(_) => Auth::NoAuth
+        Auth::Digest(_) => Auth::OAuth2(OAuth2::default()),
+        Auth::OAuth2(_) => Auth::AwsSigV4(AwsSigV4::default()),
+        Auth::AwsSigV4(_) => Auth::ApiKey(ApiKey::default()),
+        Auth::ApiKey(_) => Auth::InheritFromCollection,
+        Auth::InheritFromCollection => Auth::NoAuth
     }
 }
 
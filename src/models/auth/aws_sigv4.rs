@@ -0,0 +1,311 @@
+use chrono::{DateTime, Utc};
+use clap::Args;
+use hmac::{Hmac, Mac};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::{HeaderName, HeaderValue, AUTHORIZATION, HOST};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use thiserror::Error;
+use tracing::{debug, trace};
+
+const AWS_SIGV4_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// Payload hash used when the body is streamed (multipart) and cannot be hashed beforehand
+const AWS_SIGV4_UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Every byte except the unreserved characters of RFC 3986 must be percent-encoded
+const AWS_SIGV4_URI_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Headers that proxies may add, remove or rewrite on the way, they must stay out of the signature
+const AWS_SIGV4_UNSIGNED_HEADERS: [&str; 11] = [
+    "connection",
+    "expect",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+    "user-agent",
+    "x-amzn-trace-id",
+];
+
+#[derive(Args, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct AwsSigV4 {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// AWS region, e.g. us-east-1
+    pub region: String,
+    /// AWS service, e.g. execute-api, s3
+    pub service: String,
+
+    /// Session token, only for temporary credentials
+    #[arg(long, default_value_t)]
+    #[serde(default)]
+    pub session_token: String,
+}
+
+#[derive(Error, Debug)]
+pub enum AwsSigV4Error {
+    #[error("AWS SIGV4 {0} IS MISSING")]
+    MissingField(&'static str),
+
+    #[error("COULD NOT BUILD THE REQUEST TO SIGN")]
+    InvalidRequest,
+
+    #[error("INVALID AWS SIGV4 HEADER VALUE")]
+    InvalidHeaderValue,
+}
+
+/// Sign a fully built request with AWS Signature Version 4.
+/// Adds the x-amz-date, x-amz-content-sha256 (S3 only), optional x-amz-security-token and Authorization headers.
+pub fn sign_aws_sigv4_request(request: &mut reqwest::Request, access_key_id: &str, secret_access_key: &str, session_token: &str, region: &str, service: &str, date_time: DateTime<Utc>) -> Result<(), AwsSigV4Error> {
+    debug!("Signing request with AWS SigV4");
+
+    for (field_name, field) in [("ACCESS KEY ID", access_key_id), ("SECRET ACCESS KEY", secret_access_key), ("REGION", region), ("SERVICE", service)] {
+        if field.trim().is_empty() {
+            return Err(AwsSigV4Error::MissingField(field_name));
+        }
+    }
+
+    let amz_date = date_time.format("%Y%m%dT%H%M%SZ").to_string();
+    let date_stamp = date_time.format("%Y%m%d").to_string();
+
+    let payload_hash = match request.body() {
+        None => sha256_hex(b""),
+        Some(body) => match body.as_bytes() {
+            Some(bytes) => sha256_hex(bytes),
+            None => String::from(AWS_SIGV4_UNSIGNED_PAYLOAD)
+        }
+    };
+
+    let headers = request.headers_mut();
+    headers.remove(AUTHORIZATION);
+    headers.insert(HeaderName::from_static("x-amz-date"), to_header_value(&amz_date)?);
+
+    // Only S3 requires the payload hash as a header, like the AWS SDKs do
+    if service == "s3" {
+        headers.insert(HeaderName::from_static("x-amz-content-sha256"), to_header_value(&payload_hash)?);
+    }
+
+    if !session_token.is_empty() {
+        headers.insert(HeaderName::from_static("x-amz-security-token"), to_header_value(session_token)?);
+    }
+
+    /* CANONICAL REQUEST */
+
+    let canonical_query = canonical_query(request.url());
+
+    // The query is sent as signed, since servers do not agree on decoding "+" as a space
+    if !canonical_query.is_empty() {
+        request.url_mut().set_query(Some(&canonical_query));
+    }
+
+    let url = request.url();
+
+    // S3 is the only service whose path segments are not encoded twice
+    let canonical_uri = canonical_uri(url.path(), service != "s3");
+
+    let mut canonical_headers: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    // The Host header is only added by the client when sending, but it has to be signed
+    if !request.headers().contains_key(HOST) {
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(AwsSigV4Error::InvalidRequest)
+        };
+
+        canonical_headers.insert(HOST.to_string(), vec![host]);
+    }
+
+    for (header_name, header_value) in request.headers() {
+        if AWS_SIGV4_UNSIGNED_HEADERS.contains(&header_name.as_str()) {
+            continue;
+        }
+
+        let header_value = header_value.to_str().map_err(|_| AwsSigV4Error::InvalidHeaderValue)?;
+
+        canonical_headers
+            .entry(header_name.as_str().to_lowercase())
+            .or_default()
+            .push(header_value.split_whitespace().collect::<Vec<&str>>().join(" "));
+    }
+
+    let signed_headers = canonical_headers.keys().cloned().collect::<Vec<String>>().join(";");
+    let canonical_headers = canonical_headers
+        .iter()
+        .map(|(header_name, header_values)| format!("{}:{}\n", header_name, header_values.join(",")))
+        .collect::<String>();
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method().as_str(),
+        canonical_uri,
+        canonical_query,
+        canonical_headers,
+        signed_headers,
+        payload_hash
+    );
+
+    trace!("AWS SigV4 canonical request:\n{canonical_request}");
+
+    /* SIGNATURE */
+
+    let credential_scope = format!("{date_stamp}/{region}/{service}/aws4_request");
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        AWS_SIGV4_ALGORITHM,
+        amz_date,
+        credential_scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    let date_key = hmac_sha256(format!("AWS4{secret_access_key}").as_bytes(), date_stamp.as_bytes());
+    let region_key = hmac_sha256(&date_key, region.as_bytes());
+    let service_key = hmac_sha256(&region_key, service.as_bytes());
+    let signing_key = hmac_sha256(&service_key, b"aws4_request");
+    let signature = to_hex(&hmac_sha256(&signing_key, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "{AWS_SIGV4_ALGORITHM} Credential={access_key_id}/{credential_scope}, SignedHeaders={signed_headers}, Signature={signature}"
+    );
+
+    request.headers_mut().insert(AUTHORIZATION, to_header_value(&authorization)?);
+
+    Ok(())
+}
+
+fn canonical_uri(path: &str, double_encode: bool) -> String {
+    if path.is_empty() {
+        return String::from("/");
+    }
+
+    path
+        .split('/')
+        .map(|segment| {
+            let decoded_segment = percent_decode_str(segment).decode_utf8_lossy();
+            let encoded_segment = utf8_percent_encode(&decoded_segment, AWS_SIGV4_URI_ENCODE_SET).to_string();
+
+            match double_encode {
+                true => utf8_percent_encode(&encoded_segment, AWS_SIGV4_URI_ENCODE_SET).to_string(),
+                false => encoded_segment
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+fn canonical_query(url: &reqwest::Url) -> String {
+    let mut query_pairs = url
+        .query_pairs()
+        .map(|(key, value)| (
+            utf8_percent_encode(&key, AWS_SIGV4_URI_ENCODE_SET).to_string(),
+            utf8_percent_encode(&value, AWS_SIGV4_URI_ENCODE_SET).to_string()
+        ))
+        .collect::<Vec<(String, String)>>();
+
+    query_pairs.sort();
+
+    query_pairs
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+fn to_header_value(value: &str) -> Result<HeaderValue, AwsSigV4Error> {
+    HeaderValue::from_str(value).map_err(|_| AwsSigV4Error::InvalidHeaderValue)
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take a key of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    to_hex(&Sha256::digest(data))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
+    use reqwest::{Method, Url};
+
+    use crate::models::auth::aws_sigv4::{canonical_uri, sign_aws_sigv4_request};
+
+    /// Credentials and date of the AWS Signature Version 4 test suite
+    const ACCESS_KEY_ID: &str = "AKIDEXAMPLE";
+    const SECRET_ACCESS_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+    fn test_suite_date_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2015, 8, 30, 12, 36, 0).unwrap()
+    }
+
+    fn sign(mut request: reqwest::Request) -> String {
+        sign_aws_sigv4_request(&mut request, ACCESS_KEY_ID, SECRET_ACCESS_KEY, "", "us-east-1", "service", test_suite_date_time()).unwrap();
+
+        request.headers()[AUTHORIZATION].to_str().unwrap().to_string()
+    }
+
+    fn test_suite_request(method: Method, url: &str) -> reqwest::Request {
+        reqwest::Request::new(method, Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn aws_test_suite_vectors() {
+        let credential = "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request";
+
+        let get_vanilla = test_suite_request(Method::GET, "https://example.amazonaws.com/");
+        assert_eq!(sign(get_vanilla), format!("{credential}, SignedHeaders=host;x-amz-date, Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"));
+
+        let get_vanilla_query_order_key_case = test_suite_request(Method::GET, "https://example.amazonaws.com/?Param2=value2&Param1=value1");
+        assert_eq!(sign(get_vanilla_query_order_key_case), format!("{credential}, SignedHeaders=host;x-amz-date, Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"));
+
+        let get_vanilla_query_order_key = test_suite_request(Method::GET, "https://example.amazonaws.com/?Param1=value2&Param1=Value1");
+        assert_eq!(sign(get_vanilla_query_order_key), format!("{credential}, SignedHeaders=host;x-amz-date, Signature=eedbc4e291e521cf13422ffca22be7d2eb8146eecf653089df300a15b2382bd1"));
+
+        let get_vanilla_query_order_value = test_suite_request(Method::GET, "https://example.amazonaws.com/?Param1=value2&Param1=value1");
+        assert_eq!(sign(get_vanilla_query_order_value), format!("{credential}, SignedHeaders=host;x-amz-date, Signature=5772eed61e12b33fae39ee5e7012498b51d56abc0abb7c60486157bd471c4694"));
+
+        let post_vanilla = test_suite_request(Method::POST, "https://example.amazonaws.com/");
+        assert_eq!(sign(post_vanilla), format!("{credential}, SignedHeaders=host;x-amz-date, Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"));
+
+        let mut post_x_www_form_urlencoded = test_suite_request(Method::POST, "https://example.amazonaws.com/");
+        post_x_www_form_urlencoded.headers_mut().insert(CONTENT_TYPE, "application/x-www-form-urlencoded".parse().unwrap());
+        *post_x_www_form_urlencoded.body_mut() = Some(reqwest::Body::from("Param1=value1"));
+        assert_eq!(sign(post_x_www_form_urlencoded), format!("{credential}, SignedHeaders=content-type;host;x-amz-date, Signature=ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"));
+    }
+
+    #[test]
+    fn s3_payload_hash_header() {
+        let mut request = test_suite_request(Method::GET, "https://example.amazonaws.com/");
+        sign_aws_sigv4_request(&mut request, ACCESS_KEY_ID, SECRET_ACCESS_KEY, "", "us-east-1", "s3", test_suite_date_time()).unwrap();
+
+        assert_eq!(request.headers()["x-amz-content-sha256"], "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert!(request.headers()[AUTHORIZATION].to_str().unwrap().contains("SignedHeaders=host;x-amz-content-sha256;x-amz-date,"));
+    }
+
+    #[test]
+    fn canonical_uri_encoding() {
+        assert_eq!(canonical_uri("", true), "/");
+        assert_eq!(canonical_uri("/documents and settings/", false), "/documents%20and%20settings/");
+        assert_eq!(canonical_uri("/example%20space/", false), "/example%20space/");
+        // Every service but S3 encodes the already encoded path once more
+        assert_eq!(canonical_uri("/example%20space/", true), "/example%2520space/");
+        assert_eq!(canonical_uri("/%E1%88%B4", true), "/%25E1%2588%25B4");
+        assert_eq!(canonical_uri("/a-b_c.d~e", true), "/a-b_c.d~e");
+    }
+}
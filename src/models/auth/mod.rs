@@ -2,4 +2,7 @@ pub mod auth;
 pub mod basic;
 pub mod bearer_token;
 pub mod jwt;
-pub mod digest;
\ No newline at end of file
+pub mod digest;
+pub mod oauth2;
+pub mod aws_sigv4;
+pub mod api_key;
\ No newline at end of file
@@ -0,0 +1,147 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Local, TimeDelta};
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use strum::Display;
+use uuid::Uuid;
+
+/// Redirect URL used by the authorization code grant when none is given
+pub const DEFAULT_OAUTH2_REDIRECT_URL: &str = "http://127.0.0.1:8910/callback";
+
+/// A token expiring in less than this delay is renewed before sending the request
+const OAUTH2_TOKEN_EXPIRY_MARGIN: TimeDelta = TimeDelta::seconds(30);
+
+#[derive(Args, Default, Clone, Debug, Serialize, Deserialize)]
+pub struct OAuth2 {
+    pub grant_type: OAuth2GrantType,
+    pub token_url: String,
+    pub client_id: String,
+
+    #[arg(long, default_value_t)]
+    pub client_secret: String,
+    /// Space separated scopes
+    #[arg(long, default_value_t)]
+    pub scope: String,
+
+    /// Password grant username
+    #[arg(long, default_value_t)]
+    pub username: String,
+    /// Password grant password
+    #[arg(long, default_value_t)]
+    pub password: String,
+
+    /// Authorization code grant authorization endpoint
+    #[arg(long, default_value_t)]
+    pub authorization_url: String,
+    /// Authorization code grant redirect URL, a one-shot listener catches the redirect on its host and port
+    #[arg(long, default_value_t)]
+    pub redirect_url: String,
+
+    /// Used by the refresh token grant, may be an environment variable
+    #[arg(long, default_value_t)]
+    pub refresh_token: String,
+
+    // Cached token part, only kept in memory so that live tokens do not end up in the collection files
+    #[arg(skip)]
+    #[serde(skip)]
+    pub access_token: String,
+    #[arg(skip)]
+    #[serde(skip)]
+    pub expires_at: Option<DateTime<Local>>,
+    /// Last refresh token issued by the server, used instead of the configured one
+    #[arg(skip)]
+    #[serde(skip)]
+    pub issued_refresh_token: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, ValueEnum, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth2GrantType {
+    #[default]
+    #[strum(to_string = "Client credentials")]
+    /// Client credentials grant
+    ClientCredentials,
+    #[strum(to_string = "Password")]
+    /// Resource owner password credentials grant
+    Password,
+    #[strum(to_string = "Refresh token")]
+    /// Refresh token grant
+    RefreshToken,
+    #[strum(to_string = "Authorization code (PKCE)")]
+    /// Authorization code grant with PKCE
+    AuthorizationCode,
+}
+
+impl OAuth2 {
+    /// True if there is a cached access token that is not about to expire
+    pub fn has_valid_access_token(&self) -> bool {
+        if self.access_token.is_empty() {
+            return false;
+        }
+
+        match self.expires_at {
+            None => true,
+            Some(expires_at) => expires_at - OAUTH2_TOKEN_EXPIRY_MARGIN > Local::now()
+        }
+    }
+
+    pub fn clear_access_token(&mut self) {
+        self.access_token.clear();
+        self.expires_at = None;
+    }
+
+    pub fn get_redirect_url(&self) -> &str {
+        match self.redirect_url.trim().is_empty() {
+            true => DEFAULT_OAUTH2_REDIRECT_URL,
+            false => self.redirect_url.trim()
+        }
+    }
+
+    /// Short human-readable state of the cached token
+    pub fn access_token_status(&self) -> String {
+        if self.access_token.is_empty() {
+            return String::from("No token, one will be fetched when sending the request");
+        }
+
+        match self.expires_at {
+            None => String::from("Token cached, no expiry"),
+            Some(expires_at) => {
+                let remaining = expires_at - Local::now();
+
+                match remaining > TimeDelta::zero() {
+                    true => format!("Token cached, expires in {}m{:02}s", remaining.num_minutes(), remaining.num_seconds() % 60),
+                    false => String::from("Token expired, it will be renewed when sending the request")
+                }
+            }
+        }
+    }
+}
+
+pub fn previous_oauth2_grant_type(grant_type: &OAuth2GrantType) -> OAuth2GrantType {
+    match grant_type {
+        OAuth2GrantType::ClientCredentials => OAuth2GrantType::AuthorizationCode,
+        OAuth2GrantType::Password => OAuth2GrantType::ClientCredentials,
+        OAuth2GrantType::RefreshToken => OAuth2GrantType::Password,
+        OAuth2GrantType::AuthorizationCode => OAuth2GrantType::RefreshToken,
+    }
+}
+
+pub fn next_oauth2_grant_type(grant_type: &OAuth2GrantType) -> OAuth2GrantType {
+    match grant_type {
+        OAuth2GrantType::ClientCredentials => OAuth2GrantType::Password,
+        OAuth2GrantType::Password => OAuth2GrantType::RefreshToken,
+        OAuth2GrantType::RefreshToken => OAuth2GrantType::AuthorizationCode,
+        OAuth2GrantType::AuthorizationCode => OAuth2GrantType::ClientCredentials,
+    }
+}
+
+/// Generate a PKCE code verifier and its S256 code challenge (RFC 7636)
+pub fn generate_pkce_pair() -> (String, String) {
+    // 64 characters, within the 43 to 128 characters range required by the specification
+    let code_verifier = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+    let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+
+    (code_verifier, code_challenge)
+}
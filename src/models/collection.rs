@@ -8,7 +8,10 @@ use strum::Display;
 use tui_tree_widget::TreeItem;
 use rayon::prelude::*;
 use crate::app::files::theme::THEME;
-use crate::models::request::Request;
+use crate::models::auth::auth::Auth;
+use crate::models::request::{KeyValue, Request};
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::RequestSettings;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Collection {
@@ -16,6 +19,13 @@ pub struct Collection {
     pub last_position: Option<usize>,
     pub requests: Vec<Arc<RwLock<Request>>>,
 
+    /// Folder tree of the collection, requests reference their folder by its path
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folders: Vec<Folder>,
+
+    #[serde(flatten)]
+    pub defaults: CollectionDefaults,
+
     #[serde(skip)]
     pub path: PathBuf,
 
@@ -23,6 +33,49 @@ pub struct Collection {
     pub file_format: CollectionFileFormat
 }
 
+/// Values inherited by all the collection's requests, each request can override them
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CollectionDefaults {
+    /// Prepended to the request URLs that have no scheme
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub base_url: String,
+
+    /// Sent along every request, unless the request has an enabled header with the same name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<KeyValue>,
+
+    /// Used by the requests whose auth method is "Inherit from collection".
+    /// Shared so that the OAuth2 token and the digest nonce count are cached for all of them
+    #[serde(default, skip_serializing_if = "is_no_auth")]
+    pub auth: Arc<RwLock<Auth>>,
+
+    /// Run before the request's own scripts
+    #[serde(default, skip_serializing_if = "RequestScripts::is_empty")]
+    pub scripts: RequestScripts,
+
+    /// A request setting left to its default value takes the collection's one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<RequestSettings>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Folder {
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folders: Vec<Folder>,
+}
+
+/// Element of the collection tree pointed at by a tree widget selection
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollectionTreeElement {
+    Collection,
+    /// Folder path, made of the folder names
+    Folder(Vec<String>),
+    /// Request index in the collection
+    Request(usize),
+}
+
 #[derive(Debug, Default, Copy, Clone, Display, Serialize, Deserialize)]
 pub enum CollectionFileFormat {
     #[default]
@@ -43,14 +96,263 @@ impl Collection {
             Span::from(format!(" ({})", self.requests.len()))
         ]);
 
-        let items: Vec<TreeItem<usize>> = self.requests
+        let items = self.folder_tree_items(&self.folders, &[]);
+
+        TreeItem::new(identifier, line, items).unwrap()
+    }
+
+    /// Children of a folder level: the sub-folders first, then the requests.
+    /// Identifiers are the position among the children, so that the tree widget path can be resolved back
+    fn folder_tree_items<'a>(&self, folders: &[Folder], path: &[String]) -> Vec<TreeItem<'a, usize>> {
+        let mut items: Vec<TreeItem<usize>> = folders
+            .iter()
+            .enumerate()
+            .map(|(folder_index, folder)| {
+                let folder_path = [path, std::slice::from_ref(&folder.name)].concat();
+
+                let line = Line::from(vec![
+                    Span::raw(folder.name.clone()).fg(THEME.read().ui.font_color),
+                    Span::from(format!(" ({})", self.count_folder_requests(&folder_path)))
+                ]);
+
+                let children = self.folder_tree_items(&folder.folders, &folder_path);
+
+                TreeItem::new(folder_index, line, children).unwrap()
+            })
+            .collect();
+
+        let request_items: Vec<TreeItem<usize>> = self.get_folder_requests_indexes(path)
             .par_iter()
             .enumerate()
-            .map(|(request_index, request)| {
-                request.read().to_tree_item(request_index)
+            .map(|(position, request_index)| {
+                self.requests[*request_index].read().to_tree_item(folders.len() + position)
             })
             .collect();
 
-        TreeItem::new(identifier, line, items).unwrap()
+        items.extend(request_items);
+
+        items
+    }
+
+    /// Indexes of the requests directly contained in the folder
+    pub fn get_folder_requests_indexes(&self, path: &[String]) -> Vec<usize> {
+        self.requests
+            .iter()
+            .enumerate()
+            .filter(|(_, request)| request.read().folder == path)
+            .map(|(request_index, _)| request_index)
+            .collect()
+    }
+
+    /// Indexes of all the requests, in the order they are displayed in the collection tree
+    pub fn get_requests_indexes_in_tree_order(&self) -> Vec<usize> {
+        let mut requests_indexes = vec![];
+        self.push_folder_requests_indexes(&self.folders, &[], &mut requests_indexes);
+
+        requests_indexes
+    }
+
+    fn push_folder_requests_indexes(&self, folders: &[Folder], path: &[String], requests_indexes: &mut Vec<usize>) {
+        for folder in folders {
+            let folder_path = [path, std::slice::from_ref(&folder.name)].concat();
+            self.push_folder_requests_indexes(&folder.folders, &folder_path, requests_indexes);
+        }
+
+        requests_indexes.extend(self.get_folder_requests_indexes(path));
+    }
+
+    /// Number of requests contained in the folder and its sub-folders
+    pub fn count_folder_requests(&self, path: &[String]) -> usize {
+        self.requests
+            .iter()
+            .filter(|request| request.read().folder.starts_with(path))
+            .count()
+    }
+
+    pub fn find_folder(&self, path: &[String]) -> Option<&Folder> {
+        let (first, rest) = path.split_first()?;
+        let mut folder = self.folders.iter().find(|folder| &folder.name == first)?;
+
+        for name in rest {
+            folder = folder.folders.iter().find(|folder| &folder.name == name)?;
+        }
+
+        Some(folder)
+    }
+
+    /// Sub-folders of the given folder path, the collection root folders if the path is empty
+    pub fn find_folders_mut(&mut self, path: &[String]) -> Option<&mut Vec<Folder>> {
+        let mut folders = &mut self.folders;
+
+        for name in path {
+            folders = &mut folders.iter_mut().find(|folder| &folder.name == name)?.folders;
+        }
+
+        Some(folders)
     }
+
+    /// Declare the folders referenced by requests but absent from the folder tree, e.g. after a manual edit
+    pub fn create_missing_folders(&mut self) {
+        let request_folders: Vec<Vec<String>> = self.requests
+            .iter()
+            .map(|request| request.read().folder.clone())
+            .collect();
+
+        for request_folder in request_folders {
+            let mut folders = &mut self.folders;
+
+            for name in request_folder {
+                let folder_index = match folders.iter().position(|folder| folder.name == name) {
+                    Some(folder_index) => folder_index,
+                    None => {
+                        folders.push(Folder {
+                            name,
+                            folders: vec![],
+                        });
+                        folders.len() - 1
+                    }
+                };
+
+                folders = &mut folders[folder_index].folders;
+            }
+        }
+    }
+
+    /// Resolve the tree widget selection, without the collection identifier
+    pub fn resolve_tree_path(&self, tree_path: &[usize]) -> Option<CollectionTreeElement> {
+        let mut folders = &self.folders;
+        let mut path: Vec<String> = vec![];
+
+        for (depth, position) in tree_path.iter().enumerate() {
+            if *position < folders.len() {
+                let folder = &folders[*position];
+                path.push(folder.name.clone());
+                folders = &folder.folders;
+                continue;
+            }
+
+            // A request cannot have children
+            if depth != tree_path.len() - 1 {
+                return None;
+            }
+
+            let request_index = *self.get_folder_requests_indexes(&path).get(position - folders.len())?;
+
+            return Some(CollectionTreeElement::Request(request_index));
+        }
+
+        match path.is_empty() {
+            true => Some(CollectionTreeElement::Collection),
+            false => Some(CollectionTreeElement::Folder(path))
+        }
+    }
+
+    /// Tree widget path of a request, without the collection identifier
+    pub fn tree_path_of_request(&self, request_index: usize) -> Vec<usize> {
+        let folder = self.requests[request_index].read().folder.clone();
+        let mut tree_path = self.tree_path_of_folder(&folder);
+
+        let folders_len = match self.find_folder(&folder) {
+            Some(folder) => folder.folders.len(),
+            None => self.folders.len()
+        };
+
+        let position = self.get_folder_requests_indexes(&folder)
+            .iter()
+            .position(|index| *index == request_index)
+            .unwrap_or(0);
+
+        tree_path.push(folders_len + position);
+
+        tree_path
+    }
+
+    /// Tree widget path of a folder, without the collection identifier
+    pub fn tree_path_of_folder(&self, path: &[String]) -> Vec<usize> {
+        let mut folders = &self.folders;
+        let mut tree_path = vec![];
+
+        for name in path {
+            match folders.iter().position(|folder| &folder.name == name) {
+                Some(position) => {
+                    tree_path.push(position);
+                    folders = &folders[position].folders;
+                },
+                None => break
+            }
+        }
+
+        tree_path
+    }
+}
+
+impl CollectionDefaults {
+    pub fn inherit_settings(&self, request_settings: &RequestSettings) -> RequestSettings {
+        match &self.settings {
+            None => request_settings.clone(),
+            Some(collection_settings) => request_settings.inherit_from(collection_settings)
+        }
+    }
+
+    /// Values shown when editing the request settings, the inherited ones included.
+    /// The CA certificates are added up when inherited, only the request's own ones are shown
+    pub fn editable_settings(&self, request_settings: &RequestSettings) -> RequestSettings {
+        let mut settings = self.inherit_settings(request_settings);
+        settings.ca_certificates = request_settings.ca_certificates.clone();
+
+        settings
+    }
+
+    /// The collection's enabled headers that the request does not redefine come first
+    pub fn inherit_headers(&self, request_headers: &[KeyValue]) -> Vec<KeyValue> {
+        let mut headers: Vec<KeyValue> = self.headers
+            .iter()
+            .filter(|header| header.enabled)
+            .filter(|header| !request_headers.iter().any(|request_header| request_header.enabled && request_header.data.0.eq_ignore_ascii_case(&header.data.0)))
+            .cloned()
+            .collect();
+
+        headers.extend_from_slice(request_headers);
+
+        headers
+    }
+
+    pub fn inherit_auth(&self, request_auth: &Auth) -> Auth {
+        match request_auth {
+            Auth::InheritFromCollection => self.auth.read().clone(),
+            _ => request_auth.clone()
+        }
+    }
+
+    /// Copy of the request with the collection's headers, auth and settings applied.
+    /// The base URL is left out since it must be joined once the environment values are replaced
+    pub fn apply_to(&self, request: &Request) -> Request {
+        let mut request = request.clone();
+
+        request.headers = self.inherit_headers(&request.headers);
+        request.auth = self.inherit_auth(&request.auth);
+        request.settings = self.inherit_settings(&request.settings);
+
+        request
+    }
+}
+
+/// Join the base URL and the request URL, unless the request URL already has a scheme
+pub fn join_base_url(base_url: &str, url: &str) -> String {
+    if base_url.is_empty() || url.contains("://") {
+        return url.to_string();
+    }
+
+    let base_url = base_url.trim_end_matches('/');
+
+    if url.is_empty() || url.starts_with('?') || url.starts_with('#') {
+        format!("{base_url}{url}")
+    }
+    else {
+        format!("{base_url}/{}", url.trim_start_matches('/'))
+    }
+}
+
+fn is_no_auth(auth: &Arc<RwLock<Auth>>) -> bool {
+    matches!(*auth.read(), Auth::NoAuth)
 }
\ No newline at end of file
@@ -0,0 +1,108 @@
+use chrono::{DateTime, SecondsFormat};
+use cookie_store::{Cookie, CookieDomain, CookieExpiration};
+use reqwest::Url;
+
+use crate::app::business_logic::cookies::CookieError;
+use crate::app::business_logic::cookies::CookieError::{InvalidCookie, InvalidCookieDomain, InvalidCookieExpiration, InvalidCookieFlag, InvalidCookiePath, InvalidCookieSameSite};
+
+/// A cookie is displayed, exported and edited as a row of URL, name, value, path, expires, HTTP only, secure and SameSite
+pub const COOKIES_COLUMNS_NUMBER: usize = 8;
+
+pub fn cookie_to_row(cookie: &Cookie) -> [String; COOKIES_COLUMNS_NUMBER]{
+    [
+        // A leading dot means that the cookie is also sent to the subdomains
+        match &cookie.domain {
+            CookieDomain::Suffix(domain) => format!(".{domain}"),
+            domain => String::from(domain)
+        },
+        cookie.name().to_string(),
+        cookie.value().to_string(),
+        String::from(&cookie.path),
+        match &cookie.expires {
+            CookieExpiration::AtUtc(datetime) => match DateTime::from_timestamp(datetime.unix_timestamp(), 0) {
+                None => String::new(),
+                Some(datetime) => datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+            },
+            CookieExpiration::SessionEnd => String::from("session")
+        },
+        match cookie.http_only() {
+            None => String::new(),
+            Some(http_only) => http_only.to_string()
+        },
+        match cookie.secure() {
+            None => String::new(),
+            Some(secure) => secure.to_string()
+        },
+        match cookie.same_site() {
+            None => String::new(),
+            Some(same_site) => same_site.to_string()
+        }
+    ]
+}
+
+/// (domain, path, name) under which the cookie store keeps the cookie of this row
+pub fn cookie_row_key(row: &[String; COOKIES_COLUMNS_NUMBER]) -> (String, String, String) {
+    (
+        row[0].trim_start_matches('.').to_string(),
+        row[3].clone(),
+        row[1].clone()
+    )
+}
+
+/// Rebuild a cookie from its table row, as if the domain had set it
+pub fn row_to_cookie(row: &[String; COOKIES_COLUMNS_NUMBER]) -> Result<(Cookie<'static>, Url), CookieError> {
+    let [domain, name, value, path, expires, http_only, secure, same_site] = row;
+
+    let host = domain.trim_start_matches('.');
+
+    if host.is_empty() {
+        return Err(InvalidCookieDomain(domain.clone()));
+    }
+
+    if !path.starts_with('/') {
+        return Err(InvalidCookiePath(path.clone()));
+    }
+
+    let mut attributes = vec![format!("{name}={value}"), format!("Path={path}")];
+
+    if domain.starts_with('.') {
+        attributes.push(format!("Domain={host}"));
+    }
+
+    match expires.as_str() {
+        "" | "session" => {},
+        expires => match DateTime::parse_from_rfc3339(expires) {
+            Ok(datetime) => attributes.push(format!("Expires={}", datetime.to_utc().format("%a, %d %b %Y %H:%M:%S GMT"))),
+            Err(_) => return Err(InvalidCookieExpiration(expires.to_string()))
+        }
+    }
+
+    if parse_cookie_flag(http_only)? {
+        attributes.push(String::from("HttpOnly"));
+    }
+
+    if parse_cookie_flag(secure)? {
+        attributes.push(String::from("Secure"));
+    }
+
+    match same_site.to_lowercase().as_str() {
+        "" => {},
+        "strict" | "lax" | "none" => attributes.push(format!("SameSite={same_site}")),
+        _ => return Err(InvalidCookieSameSite(same_site.clone()))
+    }
+
+    let url = Url::parse(&format!("http://{host}{path}")).map_err(|_| InvalidCookieDomain(domain.clone()))?;
+
+    let cookie = Cookie::parse(attributes.join("; "), &url)
+        .map_err(|error| InvalidCookie(error.to_string()))?
+        .into_owned();
+
+    Ok((cookie, url))
+}
+
+fn parse_cookie_flag(flag: &str) -> Result<bool, CookieError> {
+    match flag {
+        "" => Ok(false),
+        flag => flag.parse::<bool>().map_err(|_| InvalidCookieFlag(flag.to_string()))
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// HTTP Archive 1.2, as written by the browser devtools, see http://www.softwareishard.com/blog/har-12-spec/
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Har {
+    pub log: HarLog,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HarLog {
+    pub version: String,
+    pub creator: HarCreator,
+    pub entries: Vec<HarEntry>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HarCreator {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarEntry {
+    /// Request name when exported by ATAC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub started_date_time: String,
+    /// Total elapsed time in milliseconds
+    pub time: f64,
+    pub request: HarRequest,
+    pub response: HarResponse,
+    pub cache: Value,
+    pub timings: HarTimings,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    pub http_version: String,
+    pub cookies: Vec<Value>,
+    pub headers: Vec<HarNameValue>,
+    pub query_string: Vec<HarNameValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_data: Option<HarPostData>,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarPostData {
+    pub mime_type: String,
+    /// Only filled for url-encoded and multipart bodies
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<HarParam>,
+    pub text: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarParam {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarResponse {
+    /// 0 when no response was received
+    pub status: u16,
+    pub status_text: String,
+    pub http_version: String,
+    pub cookies: Vec<Value>,
+    pub headers: Vec<HarNameValue>,
+    pub content: HarContent,
+    #[serde(rename = "redirectURL")]
+    pub redirect_url: String,
+    pub headers_size: i64,
+    pub body_size: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct HarContent {
+    pub size: i64,
+    pub mime_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// "base64" for binary contents
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HarTimings {
+    pub send: f64,
+    pub wait: f64,
+    pub receive: f64,
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Local};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::models::response::{RequestResponse, ResponseContent};
+
+/// When each request of a collection was last sent as a dependency, keyed by request path.
+/// Kept apart from the response history since the latter may be disabled
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyTimestamps {
+    pub requests: IndexMap<String, DateTime<Local>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseHistoryEntry {
+    pub timestamp: DateTime<Local>,
+    /// Resolved URL the request was sent to
+    pub url: String,
+    pub status_code: Option<String>,
+    pub duration: Option<String>,
+    pub headers: Vec<(String, String)>,
+    /// Images are not kept
+    pub body: Option<String>,
+}
+
+impl ResponseHistoryEntry {
+    pub fn new(url: String, response: &RequestResponse) -> Self {
+        let body = match &response.content {
+            Some(ResponseContent::Body(body)) => Some(body.clone()),
+            Some(ResponseContent::Image(_)) | None => None
+        };
+
+        ResponseHistoryEntry {
+            timestamp: Local::now(),
+            url,
+            status_code: response.status_code.clone(),
+            duration: response.duration.clone(),
+            headers: response.headers.clone(),
+            body,
+        }
+    }
+
+    pub fn to_response(&self) -> RequestResponse {
+        RequestResponse {
+            duration: self.duration.clone(),
+            status_code: self.status_code.clone(),
+            content: self.body.clone().map(ResponseContent::Body),
+            cookies: None,
+            headers: self.headers.clone(),
+        }
+    }
+
+    /// One line summary, e.g. "18/10/2026 11:00:00 200 OK (12ms) http://localhost/"
+    pub fn summary(&self) -> String {
+        format!(
+            "{} {} ({}) {}",
+            self.timestamp.format("%d/%m/%Y %H:%M:%S"),
+            self.status_code.as_deref().unwrap_or("NO RESPONSE"),
+            self.duration.as_deref().unwrap_or("-"),
+            self.url
+        )
+    }
+}
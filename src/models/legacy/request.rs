@@ -40,19 +40,24 @@ impl From<RequestV0_20_2> for Request {
     fn from(request: RequestV0_20_2) -> Self {
         Self {
             name: request.name,
+            folder: vec![],
             url: request.url,
             params: request.params,
             headers: request.headers,
             auth: request.auth,
             scripts: request.scripts,
+            captures: vec![],
             settings: request.settings,
             protocol: Protocol::HttpRequest(HttpRequest {
                 method: request.method,
                 body: request.body,
             }),
             response: request.response,
+            events: vec![],
             console_output: request.console_output,
+            test_results: vec![],
             is_pending: request.is_pending,
+            is_streaming: false,
             cancellation_token: request.cancellation_token,
         }
     }
@@ -6,5 +6,8 @@ pub mod settings;
 pub mod response;
 pub mod scripts;
 pub mod export;
+pub mod har;
+pub mod history;
+pub mod cookie;
 pub mod protocol;
 pub(super) mod legacy;
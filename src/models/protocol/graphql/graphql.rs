@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use crate::models::protocol::graphql::schema::GraphqlSchema;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct GraphqlRequest {
+    pub query: String,
+
+    /// JSON object, left empty when the operation takes no variables
+    #[serde(default)]
+    pub variables: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<String>,
+
+    /// Loaded from the schema cache, see [crate::app::files::graphql_schema]
+    #[serde(skip)]
+    pub schema: Option<GraphqlSchema>,
+}
+
+impl GraphqlRequest {
+    /// JSON payload as sent over HTTP, variables are inserted as-is so that environment keys are preserved
+    pub fn to_json_body(&self) -> String {
+        let variables = match self.variables.trim().is_empty() {
+            true => "null",
+            false => self.variables.trim()
+        };
+
+        let operation_name = match &self.operation_name {
+            None => String::new(),
+            Some(operation_name) => format!(",\n  \"operationName\": {}", serde_json::to_string(operation_name).unwrap())
+        };
+
+        format!(
+            "{{\n  \"query\": {},\n  \"variables\": {}{}\n}}",
+            serde_json::to_string(&self.query).unwrap(),
+            variables,
+            operation_name
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use serde_json::Value;
+
+pub const INTROSPECTION_QUERY: &str = r#"query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    subscriptionType { name }
+    types {
+      kind
+      name
+      fields(includeDeprecated: true) {
+        name
+        args { name }
+      }
+      inputFields { name }
+      enumValues(includeDeprecated: true) { name }
+    }
+  }
+}"#;
+
+/// Names extracted from an introspection result, used for completion
+#[derive(Default, Debug, Clone)]
+pub struct GraphqlSchema {
+    pub type_names: Vec<String>,
+    pub field_names: Vec<String>,
+}
+
+impl GraphqlSchema {
+    /// Parse the JSON result of the introspection query, with or without its "data" wrapper
+    pub fn from_introspection(introspection: &Value) -> Option<GraphqlSchema> {
+        let schema = match introspection.get("data") {
+            Some(data) => data.get("__schema")?,
+            None => introspection.get("__schema")?
+        };
+
+        let types = schema.get("types")?.as_array()?;
+
+        let mut type_names: Vec<String> = vec![];
+        let mut field_names: Vec<String> = vec![];
+
+        for graphql_type in types {
+            let type_name = match graphql_type.get("name").and_then(Value::as_str) {
+                Some(type_name) => type_name,
+                None => continue
+            };
+
+            // Skip the introspection types themselves
+            if type_name.starts_with("__") {
+                continue;
+            }
+
+            type_names.push(type_name.to_string());
+
+            for key in ["fields", "inputFields", "enumValues"] {
+                let Some(elements) = graphql_type.get(key).and_then(Value::as_array) else {
+                    continue;
+                };
+
+                for element in elements {
+                    if let Some(name) = element.get("name").and_then(Value::as_str) {
+                        field_names.push(name.to_string());
+                    }
+
+                    if let Some(args) = element.get("args").and_then(Value::as_array) {
+                        for arg in args {
+                            if let Some(name) = arg.get("name").and_then(Value::as_str) {
+                                field_names.push(name.to_string());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        type_names.sort();
+        type_names.dedup();
+        field_names.sort();
+        field_names.dedup();
+
+        Some(GraphqlSchema {
+            type_names,
+            field_names,
+        })
+    }
+
+    /// Every known name starting with the given prefix, fields first
+    pub fn complete(&self, prefix: &str) -> Vec<&str> {
+        if prefix.is_empty() {
+            return vec![];
+        }
+
+        self.field_names
+            .iter()
+            .chain(self.type_names.iter())
+            .filter(|name| name.starts_with(prefix) && name.as_str() != prefix)
+            .map(String::as_str)
+            .collect()
+    }
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrpcRequest {
+    /// Paths to the .proto files describing the service, server reflection is used when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proto_files: Vec<String>,
+
+    /// Fully qualified service name, e.g. "helloworld.Greeter"
+    pub service: String,
+
+    pub method: String,
+
+    /// Request message as JSON, a JSON array sends one message per element to client streaming methods
+    #[serde(default)]
+    pub message: String,
+
+    /// Filled when listing the services, see [crate::app::business_logic::request::grpc::descriptors]
+    #[serde(skip)]
+    pub methods: Vec<GrpcMethod>,
+}
+
+impl Default for GrpcRequest {
+    fn default() -> Self {
+        GrpcRequest {
+            proto_files: vec![],
+            service: String::new(),
+            method: String::new(),
+            message: String::from("{}"),
+            methods: vec![],
+        }
+    }
+}
+
+impl GrpcRequest {
+    /// HTTP/2 path the call is sent to, e.g. "/helloworld.Greeter/SayHello"
+    pub fn to_path(&self) -> String {
+        format!("/{}/{}", self.service.trim(), self.method.trim())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrpcMethod {
+    pub service: String,
+    pub name: String,
+    pub kind: GrpcMethodKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum GrpcMethodKind {
+    #[strum(to_string = "unary")]
+    Unary,
+    #[strum(to_string = "server streaming")]
+    ServerStreaming,
+    #[strum(to_string = "client streaming")]
+    ClientStreaming,
+    #[strum(to_string = "bidirectional streaming")]
+    BidiStreaming,
+}
+
+impl GrpcMethodKind {
+    pub fn from_streaming(client_streaming: bool, server_streaming: bool) -> GrpcMethodKind {
+        match (client_streaming, server_streaming) {
+            (false, false) => GrpcMethodKind::Unary,
+            (false, true) => GrpcMethodKind::ServerStreaming,
+            (true, false) => GrpcMethodKind::ClientStreaming,
+            (true, true) => GrpcMethodKind::BidiStreaming,
+        }
+    }
+}
+
+/// Comma-separated list of .proto file paths
+pub fn parse_proto_files(proto_files: &str) -> Vec<String> {
+    proto_files
+        .split(',')
+        .map(|proto_file| proto_file.trim().to_string())
+        .filter(|proto_file| !proto_file.is_empty())
+        .collect()
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ServerSentEvent {
+    pub timestamp: DateTime<Local>,
+    /// Event type, "message" when the server did not send any
+    pub event: String,
+    pub id: Option<String>,
+    pub data: String,
+    pub retry: Option<u64>,
+}
+
+/// Incremental text/event-stream parser, fed with the raw response chunks as they arrive
+#[derive(Default, Debug)]
+pub struct EventStreamParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Vec<String>,
+    retry: Option<u64>,
+    /// The last event ID persists across events, as per the specification
+    last_event_id: Option<String>,
+}
+
+impl EventStreamParser {
+    /// Parse a chunk and return every event completed by it
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<ServerSentEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = vec![];
+
+        while let Some(line_end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=line_end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches('\n').trim_end_matches('\r');
+
+            if let Some(event) = self.parse_line(line) {
+                events.push(event);
+            }
+        }
+
+        events
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<ServerSentEvent> {
+        // An empty line dispatches the event
+        if line.is_empty() {
+            return self.dispatch();
+        }
+
+        // Comment
+        if line.starts_with(':') {
+            return None;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            None => (line, ""),
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value))
+        };
+
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => self.data.push(value.to_string()),
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+            "retry" => if let Ok(retry) = value.parse::<u64>() {
+                self.retry = Some(retry);
+            },
+            _ => {}
+        }
+
+        None
+    }
+
+    fn dispatch(&mut self) -> Option<ServerSentEvent> {
+        let event = self.event.take();
+        let retry = self.retry.take();
+
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let data = self.data.join("\n");
+        self.data.clear();
+
+        Some(ServerSentEvent {
+            timestamp: Local::now(),
+            event: event.filter(|event| !event.is_empty()).unwrap_or(String::from("message")),
+            id: self.last_event_id.clone(),
+            data,
+            retry,
+        })
+    }
+}
+
+pub fn is_event_stream(headers: &[(String, String)]) -> bool {
+    headers
+        .iter()
+        .any(|(header, value)| header == "content-type" && value.starts_with("text/event-stream"))
+}
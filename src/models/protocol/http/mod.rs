@@ -1,3 +1,4 @@
 pub mod http;
 pub mod body;
-pub mod method;
\ No newline at end of file
+pub mod method;
+pub mod event_stream;
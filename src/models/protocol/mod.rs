@@ -1,3 +1,5 @@
 pub mod http;
 pub mod ws;
+pub mod graphql;
+pub mod grpc;
 pub mod protocol;
\ No newline at end of file
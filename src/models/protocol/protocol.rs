@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 use thiserror::Error;
+use crate::models::protocol::graphql::graphql::GraphqlRequest;
+use crate::models::protocol::grpc::grpc::GrpcRequest;
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::ws::ws::WsRequest;
 
@@ -9,7 +11,11 @@ pub enum ProtocolTypeError {
     #[error("The request is not an HTTP request")]
     NotAnHttpRequest,
     #[error("The request is not an websocket request")]
-    NotAWsRequest
+    NotAWsRequest,
+    #[error("The request is not a GraphQL request")]
+    NotAGraphqlRequest,
+    #[error("The request is not a gRPC request")]
+    NotAGrpcRequest
 }
 
 #[derive(Debug, Clone, EnumString, Display, Serialize, Deserialize)]
@@ -21,7 +27,15 @@ pub enum Protocol {
 
     #[serde(rename = "websocket", alias = "websocket", alias = "WEBSOCKET")]
     #[strum(to_string = "websocket")]
-    WsRequest(WsRequest)
+    WsRequest(WsRequest),
+
+    #[serde(rename = "graphql", alias = "graphql", alias = "GRAPHQL")]
+    #[strum(to_string = "GraphQL", serialize = "graphql")]
+    GraphqlRequest(GraphqlRequest),
+
+    #[serde(rename = "grpc", alias = "grpc", alias = "GRPC")]
+    #[strum(to_string = "gRPC", serialize = "grpc")]
+    GrpcRequest(GrpcRequest)
 }
 
 impl Default for Protocol {
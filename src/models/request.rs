@@ -15,23 +15,37 @@ use crate::app::files::config::SKIP_SAVE_REQUESTS_RESPONSE;
 use crate::app::files::theme::THEME;
 use crate::models::auth::auth::Auth;
 use crate::models::legacy::request::RequestV0_20_2;
+use crate::models::protocol::graphql::graphql::GraphqlRequest;
+use crate::models::protocol::grpc::grpc::GrpcRequest;
+use crate::models::protocol::http::event_stream::ServerSentEvent;
 use crate::models::protocol::http::http::HttpRequest;
+use crate::models::protocol::http::method::Method;
 use crate::models::protocol::protocol::Protocol;
-use crate::models::protocol::protocol::ProtocolTypeError::{NotAWsRequest, NotAnHttpRequest};
+use crate::models::protocol::protocol::ProtocolTypeError::{NotAGraphqlRequest, NotAGrpcRequest, NotAWsRequest, NotAnHttpRequest};
 use crate::models::protocol::ws::ws::WsRequest;
 use crate::models::response::RequestResponse;
-use crate::models::scripts::RequestScripts;
+use crate::models::scripts::{RequestScripts, TestResult};
 use crate::models::settings::RequestSettings;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[versioning(previous_version = RequestV0_20_2)]
 pub struct Request {
     pub name: String,
+
+    /// Path of the collection folder containing the request, empty when at the collection root
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub folder: Vec<String>,
+
     pub url: String,
     pub params: Vec<KeyValue>,
     pub headers: Vec<KeyValue>,
     pub auth: Auth,
     pub scripts: RequestScripts,
+
+    /// Response values copied into the selected environment after the request is sent, e.g. TOKEN from "body $.access_token"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub captures: Vec<KeyValue>,
+
     pub settings: RequestSettings,
 
     pub protocol: Protocol,
@@ -39,12 +53,23 @@ pub struct Request {
     #[serde(skip_serializing_if = "should_skip_requests_response", default = "RequestResponse::default")]
     pub response: RequestResponse,
 
+    /// Server-sent events received while the response is streamed
+    #[serde(skip)]
+    pub events: Vec<ServerSentEvent>,
+
     #[serde(skip)]
     pub console_output: ConsoleOutput,
 
+    /// Results of the tests run by the post-request scripts
+    #[serde(skip)]
+    pub test_results: Vec<TestResult>,
+
     #[serde(skip)]
     pub is_pending: bool,
 
+    #[serde(skip)]
+    pub is_streaming: bool,
+
     #[serde(skip)]
     pub cancellation_token: CancellationToken,
 }
@@ -112,31 +137,88 @@ impl Request {
     pub fn get_http_request(&self) -> anyhow::Result<&HttpRequest> {
         match &self.protocol {
             Protocol::HttpRequest(request) => Ok(request),
-            Protocol::WsRequest(_) => Err(anyhow!(NotAnHttpRequest))
+            Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAnHttpRequest))
         }
     }
 
     pub fn get_http_request_mut(&mut self) -> anyhow::Result<&mut HttpRequest> {
         match &mut self.protocol {
             Protocol::HttpRequest(request) => Ok(request),
-            Protocol::WsRequest(_) => Err(anyhow!(NotAnHttpRequest))
+            Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAnHttpRequest))
         }
     }
 
     pub fn get_ws_request(&self) -> anyhow::Result<&WsRequest> {
         match &self.protocol {
-            Protocol::HttpRequest(_) => Err(anyhow!(NotAWsRequest)),
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAWsRequest)),
             Protocol::WsRequest(request) => Ok(request)
         }
     }
 
     pub fn get_ws_request_mut(&mut self) -> anyhow::Result<&mut WsRequest> {
         match &mut self.protocol {
-            Protocol::HttpRequest(_) => Err(anyhow!(NotAWsRequest)),
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAWsRequest)),
             Protocol::WsRequest(request) => Ok(request)
         }
     }
 
+    pub fn get_graphql_request(&self) -> anyhow::Result<&GraphqlRequest> {
+        match &self.protocol {
+            Protocol::HttpRequest(_) | Protocol::WsRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAGraphqlRequest)),
+            Protocol::GraphqlRequest(request) => Ok(request)
+        }
+    }
+
+    pub fn get_graphql_request_mut(&mut self) -> anyhow::Result<&mut GraphqlRequest> {
+        match &mut self.protocol {
+            Protocol::HttpRequest(_) | Protocol::WsRequest(_) | Protocol::GrpcRequest(_) => Err(anyhow!(NotAGraphqlRequest)),
+            Protocol::GraphqlRequest(request) => Ok(request)
+        }
+    }
+
+    pub fn get_grpc_request(&self) -> anyhow::Result<&GrpcRequest> {
+        match &self.protocol {
+            Protocol::HttpRequest(_) | Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) => Err(anyhow!(NotAGrpcRequest)),
+            Protocol::GrpcRequest(request) => Ok(request)
+        }
+    }
+
+    pub fn get_grpc_request_mut(&mut self) -> anyhow::Result<&mut GrpcRequest> {
+        match &mut self.protocol {
+            Protocol::HttpRequest(_) | Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) => Err(anyhow!(NotAGrpcRequest)),
+            Protocol::GrpcRequest(request) => Ok(request)
+        }
+    }
+
+    /// Folders and name of the request, e.g. my_folder/my_request
+    pub fn get_path(&self) -> String {
+        match self.folder.is_empty() {
+            true => self.name.clone(),
+            false => format!("{}/{}", self.folder.join("/"), self.name)
+        }
+    }
+
+    /// Keep what preparing a copy of the request changed: the script output, the cleared tests and the auth state
+    pub fn keep_prepared_state(&mut self, prepared_request: &Request) {
+        self.console_output.pre_request_output = prepared_request.console_output.pre_request_output.clone();
+        self.test_results = prepared_request.test_results.clone();
+
+        // The auth may have been modified in the meantime
+        match (&mut self.auth, &prepared_request.auth) {
+            (Auth::OAuth2(oauth2), Auth::OAuth2(prepared_oauth2)) => {
+                oauth2.access_token = prepared_oauth2.access_token.clone();
+                oauth2.expires_at = prepared_oauth2.expires_at;
+                oauth2.issued_refresh_token = prepared_oauth2.issued_refresh_token.clone();
+            },
+            (Auth::Digest(digest), Auth::Digest(prepared_digest)) => digest.nc = prepared_digest.nc,
+            _ => {}
+        }
+    }
+
+    pub fn failed_tests_count(&self) -> usize {
+        self.test_results.iter().filter(|test_result| !test_result.passed).count()
+    }
+
     pub fn to_tree_item<'a>(&self, identifier: usize) -> TreeItem<'a, usize> {
         let mut line_elements: Vec<Span> = vec![];
 
@@ -155,7 +237,16 @@ impl Request {
                     .style(Modifier::BOLD)
                     .fg(Color::White)
                     .bg(color)
-            }
+            },
+            Protocol::GraphqlRequest(_) => Span::from("GQL")
+                .style(Modifier::BOLD)
+                .fg(Color::White)
+                .bg(Method::POST.get_color()),
+            // gRPC calls are always sent as HTTP/2 POST
+            Protocol::GrpcRequest(_) => Span::from("gRPC")
+                .style(Modifier::BOLD)
+                .fg(Color::White)
+                .bg(Method::POST.get_color())
         };
 
         line_elements.push(prefix);
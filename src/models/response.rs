@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use image::DynamicImage;
 use serde::{Deserialize, Serialize};
 
@@ -23,3 +25,41 @@ pub struct ImageResponse {
     #[serde(skip)]
     pub image: Option<DynamicImage>
 }
+
+/// Parse a response duration, formatted with Debug, e.g. "1.5s", "32.1ms", "850µs" or "850us"
+pub fn parse_duration(duration: &str) -> Option<Duration> {
+    let units = [("ns", 1e-9), ("µs", 1e-6), ("us", 1e-6), ("ms", 1e-3), ("s", 1.0)];
+
+    units
+        .iter()
+        .find_map(|(unit, factor)| duration.strip_suffix(unit).and_then(|value| value.parse::<f64>().ok()).map(|value| value * factor))
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::models::response::parse_duration;
+
+    #[test]
+    fn parse_debug_durations() {
+        let cases = [
+            ("1.5s", Some(Duration::from_millis(1500))),
+            ("32ms", Some(Duration::from_millis(32))),
+            ("850µs", Some(Duration::from_micros(850))),
+            ("850us", Some(Duration::from_micros(850))),
+            ("120ns", Some(Duration::from_nanos(120))),
+            ("0ns", Some(Duration::ZERO)),
+            ("", None),
+            ("12", None),
+            ("ms", None),
+            ("-1s", None),
+            ("fast", None),
+        ];
+
+        for (duration, expected) in cases {
+            assert_eq!(parse_duration(duration), expected, "duration: {duration}");
+        }
+    }
+}
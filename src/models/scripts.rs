@@ -8,8 +8,23 @@ pub struct RequestScripts {
     pub post_request_script: Option<String>,
 }
 
+impl RequestScripts {
+    pub fn is_empty(&self) -> bool {
+        self.pre_request_script.is_none() && self.post_request_script.is_none()
+    }
+}
+
 #[derive(ValueEnum, Debug, Clone, Display)]
 pub enum ScriptType {
     Pre,
     Post
-}
\ No newline at end of file
+}
+
+/// Outcome of a `test(name, fn)` call made by a post-request script
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    /// Message of the failed assertion or of the error thrown by the test
+    pub error: Option<String>,
+}
@@ -1,37 +1,97 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RequestSettings {
+    #[serde(deserialize_with = "deserialize_bool_setting")]
     pub use_config_proxy: Setting,
+    #[serde(deserialize_with = "deserialize_bool_setting")]
     pub allow_redirects: Setting,
+    #[serde(deserialize_with = "deserialize_u32_setting")]
     pub timeout: Setting,
+    #[serde(deserialize_with = "deserialize_bool_setting")]
     pub store_received_cookies: Setting,
+    #[serde(deserialize_with = "deserialize_bool_setting")]
     pub pretty_print_response_content: Setting,
+    #[serde(deserialize_with = "deserialize_bool_setting")]
     pub accept_invalid_certs: Setting,
-    pub accept_invalid_hostnames: Setting
+    #[serde(deserialize_with = "deserialize_bool_setting")]
+    pub accept_invalid_hostnames: Setting,
+    #[serde(deserialize_with = "deserialize_bool_setting")]
+    pub force_event_stream: Setting,
+    /// PEM or PKCS#12 (.p12, .pfx) client certificate file path
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub client_certificate: Setting,
+    /// PEM private key file path, when not already contained in the client certificate file
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub client_key: Setting,
+    /// PKCS#12 client certificate password
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub client_certificate_password: Setting,
+    /// Comma separated PEM CA bundle file paths, trusted on top of the system ones
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub ca_certificates: Setting,
+    /// "collection/request" sent beforehand when its captured values are missing or expired, e.g. a login request
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub depends_on: Setting,
+    /// How long the dependency's last response is trusted, e.g. 300, 15m or 1h. Never expires when empty
+    #[serde(deserialize_with = "deserialize_text_setting")]
+    pub dependency_cache_lifetime: Setting,
+    /// Settings explicitly set on the request, they are not inherited from the collection even when equal to their default value
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub overridden: Vec<String>
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Setting {
     Bool(bool),
-    U32(u32)
+    U32(u32),
+    String(String)
+}
+
+/// A mistyped value, e.g. "30s" for the timeout, is rejected when the file is loaded rather than when the request is sent
+fn deserialize_bool_setting<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Setting, D::Error> {
+    match Setting::deserialize(deserializer)? {
+        Setting::Bool(bool) => Ok(Setting::Bool(bool)),
+        setting => Err(D::Error::custom(format!("expected a boolean setting, found \"{setting}\""))),
+    }
+}
+
+fn deserialize_u32_setting<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Setting, D::Error> {
+    match Setting::deserialize(deserializer)? {
+        Setting::U32(u32) => Ok(Setting::U32(u32)),
+        setting => Err(D::Error::custom(format!("expected a positive int setting, found \"{setting}\""))),
+    }
+}
+
+/// Text settings may look like a boolean or a number in the files, e.g. a "1234" password or a "300" cache lifetime
+fn deserialize_text_setting<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Setting, D::Error> {
+    Setting::deserialize(deserializer).map(|setting| Setting::String(setting.to_string()))
 }
 
 impl Setting {
     pub fn as_bool(&self) -> bool {
         match self {
             Setting::Bool(bool) => *bool,
-            Setting::U32(_) => unreachable!()
+            Setting::U32(_) | Setting::String(_) => unreachable!()
         }
     }
 
     pub fn as_u32(&self) -> u32 {
         match self {
-            Setting::Bool(_) => unreachable!(),
-            Setting::U32(u32) => *u32
+            Setting::U32(u32) => *u32,
+            Setting::Bool(_) | Setting::String(_) => unreachable!()
+        }
+    }
+
+    pub fn as_string(&self) -> &String {
+        match self {
+            Setting::String(string) => string,
+            Setting::Bool(_) | Setting::U32(_) => unreachable!()
         }
     }
 }
@@ -44,7 +104,7 @@ impl FromStr for Setting {
             Ok(bool) => Ok(Setting::Bool(bool)),
             Err(_) => match u32::from_str(input) {
                 Ok(u32) => Ok(Setting::U32(u32)),
-                Err(_) => Err(String::from("Value should either be a boolean or a positive int"))
+                Err(_) => Ok(Setting::String(input.to_string()))
             }
         }
     }
@@ -54,7 +114,8 @@ impl Display for Setting {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let str = match self {
             Setting::Bool(bool) => bool.to_string(),
-            Setting::U32(uint) => uint.to_string()
+            Setting::U32(uint) => uint.to_string(),
+            Setting::String(string) => string.clone()
         };
         write!(f, "{}", str)
     }
@@ -70,11 +131,70 @@ impl Default for RequestSettings {
             pretty_print_response_content: Setting::Bool(true),
             accept_invalid_certs: Setting::Bool(false),
             accept_invalid_hostnames: Setting::Bool(false),
+            force_event_stream: Setting::Bool(false),
+            client_certificate: Setting::String(String::new()),
+            client_key: Setting::String(String::new()),
+            client_certificate_password: Setting::String(String::new()),
+            ca_certificates: Setting::String(String::new()),
+            depends_on: Setting::String(String::new()),
+            dependency_cache_lifetime: Setting::String(String::new()),
+            overridden: vec![],
         }
     }
 }
 
 impl RequestSettings {
+    /// Settings left to their default value and not overridden take the collection's one.
+    /// The client certificate, key and password are inherited together, CA certificates are added up
+    pub fn inherit_from(&self, collection_settings: &RequestSettings) -> RequestSettings {
+        let default_settings = RequestSettings::default();
+
+        let inherit = |setting_name: &str, request_setting: &Setting, collection_setting: &Setting, default_setting: &Setting| match request_setting == default_setting && !self.is_overridden(setting_name) {
+            true => collection_setting.clone(),
+            false => request_setting.clone()
+        };
+
+        let client_certificate_settings = match self.client_certificate.as_string().is_empty() && !self.is_overridden("client_certificate") {
+            true => collection_settings,
+            false => self
+        };
+
+        let ca_certificates = [collection_settings.ca_certificates.as_string(), self.ca_certificates.as_string()]
+            .into_iter()
+            .filter(|ca_certificates| !ca_certificates.is_empty())
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        RequestSettings {
+            use_config_proxy: inherit("use_config_proxy", &self.use_config_proxy, &collection_settings.use_config_proxy, &default_settings.use_config_proxy),
+            allow_redirects: inherit("allow_redirects", &self.allow_redirects, &collection_settings.allow_redirects, &default_settings.allow_redirects),
+            timeout: inherit("timeout", &self.timeout, &collection_settings.timeout, &default_settings.timeout),
+            store_received_cookies: inherit("store_received_cookies", &self.store_received_cookies, &collection_settings.store_received_cookies, &default_settings.store_received_cookies),
+            pretty_print_response_content: inherit("pretty_print_response_content", &self.pretty_print_response_content, &collection_settings.pretty_print_response_content, &default_settings.pretty_print_response_content),
+            accept_invalid_certs: inherit("accept_invalid_certs", &self.accept_invalid_certs, &collection_settings.accept_invalid_certs, &default_settings.accept_invalid_certs),
+            accept_invalid_hostnames: inherit("accept_invalid_hostnames", &self.accept_invalid_hostnames, &collection_settings.accept_invalid_hostnames, &default_settings.accept_invalid_hostnames),
+            force_event_stream: inherit("force_event_stream", &self.force_event_stream, &collection_settings.force_event_stream, &default_settings.force_event_stream),
+            client_certificate: client_certificate_settings.client_certificate.clone(),
+            client_key: client_certificate_settings.client_key.clone(),
+            client_certificate_password: client_certificate_settings.client_certificate_password.clone(),
+            ca_certificates: Setting::String(ca_certificates),
+            depends_on: inherit("depends_on", &self.depends_on, &collection_settings.depends_on, &default_settings.depends_on),
+            dependency_cache_lifetime: inherit("dependency_cache_lifetime", &self.dependency_cache_lifetime, &collection_settings.dependency_cache_lifetime, &default_settings.dependency_cache_lifetime),
+            overridden: self.overridden.clone(),
+        }
+    }
+
+    pub fn is_overridden(&self, setting_name: &str) -> bool {
+        self.overridden.iter().any(|overridden| overridden == setting_name)
+    }
+
+    pub fn set_overridden(&mut self, setting_name: &str) {
+        if !self.is_overridden(setting_name) {
+            self.overridden.push(setting_name.to_string());
+        }
+    }
+
     pub fn to_vec(&self) -> Vec<(String, Setting)> {
         vec![
             (String::from("Use config proxy"), self.use_config_proxy.clone()),
@@ -84,21 +204,75 @@ impl RequestSettings {
             (String::from("Pretty print response content"), self.pretty_print_response_content.clone()),
             (String::from("Accept invalid certs"), self.accept_invalid_certs.clone()),
             (String::from("Accept invalid hostnames"), self.accept_invalid_hostnames.clone()),
+            (String::from("Force event stream"), self.force_event_stream.clone()),
+            (String::from("Client certificate"), self.client_certificate.clone()),
+            (String::from("Client key"), self.client_key.clone()),
+            (String::from("Client certificate password"), self.client_certificate_password.clone()),
+            (String::from("CA certificates"), self.ca_certificates.clone()),
+            (String::from("Depends on"), self.depends_on.clone()),
+            (String::from("Dependency cache lifetime"), self.dependency_cache_lifetime.clone()),
         ]
     }
 
-    pub fn update_from_vec(&mut self, vec: &Vec<(String, Setting)>) {
+    /// Only the settings modified from their shown value are set, they are then marked as overridden.
+    /// The client certificate, key and password are set together since they are inherited together
+    pub fn update_from_vec(&mut self, vec: &[(String, Setting)], shown: &[(String, Setting)]) {
+        let is_modified = |setting_name: &str| vec
+            .iter()
+            .zip(shown)
+            .any(|((name, value), (_, shown_value))| name == setting_name && value != shown_value);
+
+        let client_certificate_modified = ["Client certificate", "Client key", "Client certificate password"]
+            .into_iter()
+            .any(is_modified);
+
         for (setting_name, setting_value) in vec {
-            match setting_name.as_str() {
-                "Use config proxy" => self.use_config_proxy = setting_value.clone(),
-                "Allow redirects" => self.allow_redirects = setting_value.clone(),
-                "Timeout (ms)" => self.timeout = setting_value.clone(),
-                "Store received cookies" => self.store_received_cookies = setting_value.clone(),
-                "Pretty print response content" => self.pretty_print_response_content = setting_value.clone(),
-                "Accept invalid certs" => self.accept_invalid_certs = setting_value.clone(),
-                "Accept invalid hostnames" => self.accept_invalid_hostnames = setting_value.clone(),
-                _ => {}
+            let is_client_certificate = matches!(setting_name.as_str(), "Client certificate" | "Client key" | "Client certificate password");
+            let should_set = is_modified(setting_name) || (is_client_certificate && client_certificate_modified);
+
+            if !should_set {
+                continue;
             }
+
+            let (field_name, setting) = match setting_name.as_str() {
+                "Use config proxy" => ("use_config_proxy", &mut self.use_config_proxy),
+                "Allow redirects" => ("allow_redirects", &mut self.allow_redirects),
+                "Timeout (ms)" => ("timeout", &mut self.timeout),
+                "Store received cookies" => ("store_received_cookies", &mut self.store_received_cookies),
+                "Pretty print response content" => ("pretty_print_response_content", &mut self.pretty_print_response_content),
+                "Accept invalid certs" => ("accept_invalid_certs", &mut self.accept_invalid_certs),
+                "Accept invalid hostnames" => ("accept_invalid_hostnames", &mut self.accept_invalid_hostnames),
+                "Force event stream" => ("force_event_stream", &mut self.force_event_stream),
+                "Client certificate" => ("client_certificate", &mut self.client_certificate),
+                "Client key" => ("client_key", &mut self.client_key),
+                "Client certificate password" => ("client_certificate_password", &mut self.client_certificate_password),
+                "CA certificates" => ("ca_certificates", &mut self.ca_certificates),
+                "Depends on" => ("depends_on", &mut self.depends_on),
+                "Dependency cache lifetime" => ("dependency_cache_lifetime", &mut self.dependency_cache_lifetime),
+                _ => continue
+            };
+
+            *setting = setting_value.clone();
+            self.set_overridden(field_name);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::models::settings::{RequestSettings, Setting};
+
+    #[test]
+    fn deserialize_typed_settings() {
+        let settings: RequestSettings = serde_json::from_str(r#"{ "timeout": 500, "allow_redirects": false, "client_certificate_password": 1234 }"#).unwrap();
+
+        assert_eq!(settings.timeout, Setting::U32(500));
+        assert_eq!(settings.allow_redirects, Setting::Bool(false));
+        assert_eq!(settings.client_certificate_password, Setting::String(String::from("1234")));
+        assert_eq!(settings.use_config_proxy, Setting::Bool(true));
+
+        assert!(serde_json::from_str::<RequestSettings>(r#"{ "timeout": "30s" }"#).is_err());
+        assert!(serde_json::from_str::<RequestSettings>(r#"{ "timeout": true }"#).is_err());
+        assert!(serde_json::from_str::<RequestSettings>(r#"{ "allow_redirects": "yes" }"#).is_err());
+    }
+}
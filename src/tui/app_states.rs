@@ -16,6 +16,7 @@ use crate::tui::event_key_bindings::EventKeyBinding;
 use crate::tui::events::AppEvent;
 use crate::tui::events::AppEvent::*;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
+use crate::tui::ui::result_tabs::RequestResultTabs;
 use crate::tui::ui::views::RequestView;
 
 #[derive(Copy, Clone, PartialEq, Default, Display)]
@@ -37,8 +38,7 @@ pub enum AppState {
     #[strum(to_string = "Displaying cookies")]
     DisplayingCookies,
 
-    #[strum(to_string = "Editing cookies")]
-    #[allow(dead_code)]
+    #[strum(to_string = "Editing cookie")]
     EditingCookies,
 
     /* Logs */
@@ -69,6 +69,27 @@ pub enum AppState {
     #[strum(to_string = "Renaming request")]
     RenamingRequest,
 
+    #[strum(to_string = "Creating new folder")]
+    CreatingNewFolder,
+
+    #[strum(to_string = "Deleting folder")]
+    DeletingFolder,
+
+    #[strum(to_string = "Renaming folder")]
+    RenamingFolder,
+
+    #[strum(to_string = "Moving to folder")]
+    MovingElementToFolder,
+
+    #[strum(to_string = "Choosing collection run options")]
+    ChoosingCollectionRunOptions,
+
+    #[strum(to_string = "Editing collection run data file")]
+    EditingCollectionRunDataFile,
+
+    #[strum(to_string = "Running collection")]
+    RunningCollection,
+
     /* Request */
 
     #[strum(to_string = "Request menu")]
@@ -112,10 +133,61 @@ pub enum AppState {
 
     #[strum(to_string = "Editing request digest opaque")]
     EditingRequestAuthDigestOpaque,
+
+    #[strum(to_string = "Editing request OAuth2 token URL")]
+    EditingRequestAuthOAuth2TokenUrl,
+
+    #[strum(to_string = "Editing request OAuth2 authorization URL")]
+    EditingRequestAuthOAuth2AuthorizationUrl,
+
+    #[strum(to_string = "Editing request OAuth2 redirect URL")]
+    EditingRequestAuthOAuth2RedirectUrl,
+
+    #[strum(to_string = "Editing request OAuth2 client ID")]
+    EditingRequestAuthOAuth2ClientId,
+
+    #[strum(to_string = "Editing request OAuth2 client secret")]
+    EditingRequestAuthOAuth2ClientSecret,
+
+    #[strum(to_string = "Editing request OAuth2 scope")]
+    EditingRequestAuthOAuth2Scope,
+
+    #[strum(to_string = "Editing request OAuth2 username")]
+    EditingRequestAuthOAuth2Username,
+
+    #[strum(to_string = "Editing request OAuth2 password")]
+    EditingRequestAuthOAuth2Password,
+
+    #[strum(to_string = "Editing request OAuth2 refresh token")]
+    EditingRequestAuthOAuth2RefreshToken,
+
+    #[strum(to_string = "Editing request AWS SigV4 access key ID")]
+    EditingRequestAuthAwsSigV4AccessKeyId,
+
+    #[strum(to_string = "Editing request AWS SigV4 secret access key")]
+    EditingRequestAuthAwsSigV4SecretAccessKey,
+
+    #[strum(to_string = "Editing request AWS SigV4 region")]
+    EditingRequestAuthAwsSigV4Region,
+
+    #[strum(to_string = "Editing request AWS SigV4 service")]
+    EditingRequestAuthAwsSigV4Service,
+
+    #[strum(to_string = "Editing request AWS SigV4 session token")]
+    EditingRequestAuthAwsSigV4SessionToken,
+
+    #[strum(to_string = "Editing request API key key")]
+    EditingRequestAuthApiKeyKey,
+
+    #[strum(to_string = "Editing request API key value")]
+    EditingRequestAuthApiKeyValue,
     
     #[strum(to_string = "Editing request header")]
     EditingRequestHeader,
 
+    #[strum(to_string = "Editing request capture")]
+    EditingRequestCapture,
+
     #[strum(to_string = "Editing request body (Form)")]
     EditingRequestBodyTable,
 
@@ -128,6 +200,27 @@ pub enum AppState {
     #[strum(to_string = "Editing request message")]
     EditingRequestMessage,
 
+    #[strum(to_string = "Editing request GraphQL query")]
+    EditingRequestGraphqlQuery,
+
+    #[strum(to_string = "Editing request GraphQL variables")]
+    EditingRequestGraphqlVariables,
+
+    #[strum(to_string = "Editing request GraphQL operation name")]
+    EditingRequestGraphqlOperationName,
+
+    #[strum(to_string = "Editing request gRPC proto files")]
+    EditingRequestGrpcProtoFiles,
+
+    #[strum(to_string = "Editing request gRPC service")]
+    EditingRequestGrpcService,
+
+    #[strum(to_string = "Editing request gRPC method")]
+    EditingRequestGrpcMethod,
+
+    #[strum(to_string = "Editing request gRPC message")]
+    EditingRequestGrpcMessage,
+
     #[strum(to_string = "Editing pre-request script")]
     EditingPreRequestScript,
 
@@ -137,11 +230,23 @@ pub enum AppState {
     #[strum(to_string = "Editing request settings")]
     EditingRequestSettings,
 
+    #[strum(to_string = "Editing request setting value")]
+    EditingRequestSettingValue,
+
     #[strum(to_string = "Choosing request export format")]
     ChoosingRequestExportFormat,
 
     #[strum(to_string = "Displaying request export")]
-    DisplayingRequestExport
+    DisplayingRequestExport,
+
+    #[strum(to_string = "Displaying response history")]
+    DisplayingResponseHistory,
+
+    #[strum(to_string = "Editing response body filter")]
+    EditingResponseBodyFilter,
+
+    #[strum(to_string = "Choosing environment to compare")]
+    ChoosingDiffEnvironment
 }
 
 pub fn next_app_state(app_state: &AppState) -> AppState {
@@ -158,7 +263,14 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
         DeletingCollection => DeletingRequest,
         DeletingRequest => RenamingCollection,
         RenamingCollection => RenamingRequest,
-        RenamingRequest => SelectedRequest,
+        RenamingRequest => CreatingNewFolder,
+        CreatingNewFolder => DeletingFolder,
+        DeletingFolder => RenamingFolder,
+        RenamingFolder => MovingElementToFolder,
+        MovingElementToFolder => ChoosingCollectionRunOptions,
+        ChoosingCollectionRunOptions => EditingCollectionRunDataFile,
+        EditingCollectionRunDataFile => RunningCollection,
+        RunningCollection => SelectedRequest,
         SelectedRequest => EditingRequestUrl,
         EditingRequestUrl => EditingRequestParam,
         EditingRequestParam => EditingRequestAuthBasicUsername,
@@ -172,17 +284,45 @@ pub fn next_app_state(app_state: &AppState) -> AppState {
         EditingRequestAuthDigestDomains => EditingRequestAuthDigestRealm,
         EditingRequestAuthDigestRealm => EditingRequestAuthDigestNonce,
         EditingRequestAuthDigestNonce => EditingRequestAuthDigestOpaque,
-        EditingRequestAuthDigestOpaque => EditingRequestHeader,
+        EditingRequestAuthDigestOpaque => EditingRequestAuthOAuth2TokenUrl,
+        EditingRequestAuthOAuth2TokenUrl => EditingRequestAuthOAuth2AuthorizationUrl,
+        EditingRequestAuthOAuth2AuthorizationUrl => EditingRequestAuthOAuth2RedirectUrl,
+        EditingRequestAuthOAuth2RedirectUrl => EditingRequestAuthOAuth2ClientId,
+        EditingRequestAuthOAuth2ClientId => EditingRequestAuthOAuth2ClientSecret,
+        EditingRequestAuthOAuth2ClientSecret => EditingRequestAuthOAuth2Scope,
+        EditingRequestAuthOAuth2Scope => EditingRequestAuthOAuth2Username,
+        EditingRequestAuthOAuth2Username => EditingRequestAuthOAuth2Password,
+        EditingRequestAuthOAuth2Password => EditingRequestAuthOAuth2RefreshToken,
+        EditingRequestAuthOAuth2RefreshToken => EditingRequestAuthAwsSigV4AccessKeyId,
+        EditingRequestAuthAwsSigV4AccessKeyId => EditingRequestAuthAwsSigV4SecretAccessKey,
+        EditingRequestAuthAwsSigV4SecretAccessKey => EditingRequestAuthAwsSigV4Region,
+        EditingRequestAuthAwsSigV4Region => EditingRequestAuthAwsSigV4Service,
+        EditingRequestAuthAwsSigV4Service => EditingRequestAuthAwsSigV4SessionToken,
+        EditingRequestAuthAwsSigV4SessionToken => EditingRequestAuthApiKeyKey,
+        EditingRequestAuthApiKeyKey => EditingRequestAuthApiKeyValue,
+        EditingRequestAuthApiKeyValue => EditingRequestHeader,
         EditingRequestHeader => EditingRequestBodyTable,
         EditingRequestBodyTable => EditingRequestBodyFile,
         EditingRequestBodyFile => EditingRequestBodyString,
         EditingRequestBodyString => EditingRequestMessage,
-        EditingRequestMessage => EditingPreRequestScript,
+        EditingRequestMessage => EditingRequestGraphqlQuery,
+        EditingRequestGraphqlQuery => EditingRequestGraphqlVariables,
+        EditingRequestGraphqlVariables => EditingRequestGraphqlOperationName,
+        EditingRequestGraphqlOperationName => EditingRequestGrpcProtoFiles,
+        EditingRequestGrpcProtoFiles => EditingRequestGrpcService,
+        EditingRequestGrpcService => EditingRequestGrpcMethod,
+        EditingRequestGrpcMethod => EditingRequestGrpcMessage,
+        EditingRequestGrpcMessage => EditingRequestCapture,
+        EditingRequestCapture => EditingPreRequestScript,
         EditingPreRequestScript => EditingPostRequestScript,
         EditingPostRequestScript => EditingRequestSettings,
-        EditingRequestSettings => ChoosingRequestExportFormat,
+        EditingRequestSettings => EditingRequestSettingValue,
+        EditingRequestSettingValue => ChoosingRequestExportFormat,
         ChoosingRequestExportFormat => DisplayingRequestExport,
-        DisplayingRequestExport => Normal
+        DisplayingRequestExport => DisplayingResponseHistory,
+        DisplayingResponseHistory => EditingResponseBodyFilter,
+        EditingResponseBodyFilter => ChoosingDiffEnvironment,
+        ChoosingDiffEnvironment => Normal
     }
 }
 
@@ -201,7 +341,14 @@ pub fn previous_app_state(app_state: &AppState) -> AppState {
         DeletingRequest => DeletingCollection,
         RenamingCollection => DeletingRequest,
         RenamingRequest => RenamingCollection,
-        SelectedRequest => RenamingRequest,
+        CreatingNewFolder => RenamingRequest,
+        DeletingFolder => CreatingNewFolder,
+        RenamingFolder => DeletingFolder,
+        MovingElementToFolder => RenamingFolder,
+        ChoosingCollectionRunOptions => MovingElementToFolder,
+        EditingCollectionRunDataFile => ChoosingCollectionRunOptions,
+        RunningCollection => EditingCollectionRunDataFile,
+        SelectedRequest => RunningCollection,
         EditingRequestUrl => SelectedRequest,
         EditingRequestParam => EditingRequestUrl,
         EditingRequestAuthBasicUsername => EditingRequestParam,
@@ -215,21 +362,49 @@ pub fn previous_app_state(app_state: &AppState) -> AppState {
         EditingRequestAuthDigestRealm => EditingRequestAuthDigestDomains,
         EditingRequestAuthDigestNonce => EditingRequestAuthDigestRealm,
         EditingRequestAuthDigestOpaque => EditingRequestAuthDigestNonce,
-        EditingRequestHeader => EditingRequestAuthDigestOpaque,
+        EditingRequestAuthOAuth2TokenUrl => EditingRequestAuthDigestOpaque,
+        EditingRequestAuthOAuth2AuthorizationUrl => EditingRequestAuthOAuth2TokenUrl,
+        EditingRequestAuthOAuth2RedirectUrl => EditingRequestAuthOAuth2AuthorizationUrl,
+        EditingRequestAuthOAuth2ClientId => EditingRequestAuthOAuth2RedirectUrl,
+        EditingRequestAuthOAuth2ClientSecret => EditingRequestAuthOAuth2ClientId,
+        EditingRequestAuthOAuth2Scope => EditingRequestAuthOAuth2ClientSecret,
+        EditingRequestAuthOAuth2Username => EditingRequestAuthOAuth2Scope,
+        EditingRequestAuthOAuth2Password => EditingRequestAuthOAuth2Username,
+        EditingRequestAuthOAuth2RefreshToken => EditingRequestAuthOAuth2Password,
+        EditingRequestAuthAwsSigV4AccessKeyId => EditingRequestAuthOAuth2RefreshToken,
+        EditingRequestAuthAwsSigV4SecretAccessKey => EditingRequestAuthAwsSigV4AccessKeyId,
+        EditingRequestAuthAwsSigV4Region => EditingRequestAuthAwsSigV4SecretAccessKey,
+        EditingRequestAuthAwsSigV4Service => EditingRequestAuthAwsSigV4Region,
+        EditingRequestAuthAwsSigV4SessionToken => EditingRequestAuthAwsSigV4Service,
+        EditingRequestAuthApiKeyKey => EditingRequestAuthAwsSigV4SessionToken,
+        EditingRequestAuthApiKeyValue => EditingRequestAuthApiKeyKey,
+        EditingRequestHeader => EditingRequestAuthApiKeyValue,
         EditingRequestBodyTable => EditingRequestHeader,
         EditingRequestBodyFile => EditingRequestBodyTable,
         EditingRequestBodyString => EditingRequestBodyFile,
         EditingRequestMessage => EditingRequestBodyString,
-        EditingPreRequestScript => EditingRequestMessage,
+        EditingRequestGraphqlQuery => EditingRequestMessage,
+        EditingRequestGraphqlVariables => EditingRequestGraphqlQuery,
+        EditingRequestGraphqlOperationName => EditingRequestGraphqlVariables,
+        EditingRequestGrpcProtoFiles => EditingRequestGraphqlOperationName,
+        EditingRequestGrpcService => EditingRequestGrpcProtoFiles,
+        EditingRequestGrpcMethod => EditingRequestGrpcService,
+        EditingRequestGrpcMessage => EditingRequestGrpcMethod,
+        EditingRequestCapture => EditingRequestGrpcMessage,
+        EditingPreRequestScript => EditingRequestCapture,
         EditingPostRequestScript => EditingPreRequestScript,
         EditingRequestSettings => EditingPostRequestScript,
-        ChoosingRequestExportFormat => EditingRequestSettings,
-        DisplayingRequestExport => ChoosingRequestExportFormat
+        EditingRequestSettingValue => EditingRequestSettings,
+        ChoosingRequestExportFormat => EditingRequestSettingValue,
+        DisplayingRequestExport => ChoosingRequestExportFormat,
+        DisplayingResponseHistory => DisplayingRequestExport,
+        EditingResponseBodyFilter => DisplayingResponseHistory,
+        ChoosingDiffEnvironment => EditingResponseBodyFilter
     }
 }
 
 impl AppState {
-    pub fn get_available_events(&self, request_view: RequestView, request_param_tab: RequestParamsTabs, protocol: Option<Protocol>, is_there_any_env: bool) -> Vec<AppEvent> {
+    pub fn get_available_events(&self, request_view: RequestView, request_param_tab: RequestParamsTabs, request_result_tab: RequestResultTabs, protocol: Option<Protocol>, is_there_any_env: bool) -> Vec<AppEvent> {
         let key_bindings = KEY_BINDINGS.read();
 
         match self {
@@ -251,8 +426,11 @@ impl AppState {
                     RenameElement(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.rename_element], "Rename element", None)),
                     DuplicateElement(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.duplicate_element], "Duplicate element", None)),
 
-                    MoveElementUp(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_up], "Move request up", None)),
-                    MoveElementDown(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_down], "Move request down", None)),
+                    MoveElementUp(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_up], "Move element up", None)),
+                    MoveElementDown(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_down], "Move element down", None)),
+                    MoveElementToFolder(EventKeyBinding::new(vec![key_bindings.main_menu.move_element_to_folder], "Move element to folder", None)),
+
+                    RunCollection(EventKeyBinding::new(vec![key_bindings.main_menu.run_collection], "Run collection", None)),
                 ];
 
                 if is_there_any_env {
@@ -295,17 +473,25 @@ impl AppState {
             ].concat(),
             DisplayingCookies => vec![
                 GoBackToLastState(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+                EditCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit cookie", None)),
 
                 CookiesMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
                 CookiesMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
                 CookiesMoveLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move left", Some("Left"))),
                 CookiesMoveRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move right", Some("Right"))),
 
+                CreateCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "Create cookie", Some("Create"))),
                 DeleteCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete cookie", Some("Delete"))),
+                DuplicateCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.duplicate_element], "Duplicate cookie", Some("Duplicate"))),
             ],
-            EditingCookies => vec![
-                Documentation(EventKeyBinding::new(vec![*EMPTY_KEY], "Not implemented yet", None))
-            ],
+            EditingCookies => [
+                vec![
+                    ModifyCookie(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditCookie(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditCookie(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, true)
+            ].concat(),
             DisplayingLogs => vec![
                 GoBackToLastState(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
                 ScrollLogsUp(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_up], "Scroll logs up", Some("Up"))),
@@ -376,12 +562,66 @@ impl AppState {
                 ],
                 generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
             ].concat(),
+            CreatingNewFolder => [
+                vec![
+                    CreateNewFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelCreateNewFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventCreateNewFolder(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            DeletingFolder => vec![
+                GoBackToLastState(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Cancel", Some("Cancel"))),
+
+                DeletingFolderMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move selection left", Some("Left"))),
+                DeletingFolderMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move selection right", Some("Right"))),
+
+                DeleteFolder(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select choice", Some("Select"))),
+            ],
+            RenamingFolder => [
+                vec![
+                    RenameFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelRenameFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventRenameFolder(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            MovingElementToFolder => [
+                vec![
+                    ConfirmMoveElementToFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelMoveElementToFolder(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventMoveElementToFolder(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            ChoosingCollectionRunOptions => vec![
+                GoBackToLastState(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Cancel", Some("Cancel"))),
+
+                CollectionRunOptionsMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                CollectionRunOptionsMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+                CollectionRunOptionsToggleLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Toggle or edit option", Some("Toggle left"))),
+                CollectionRunOptionsToggleRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Toggle or edit option", Some("Toggle right"))),
+
+                StartCollectionRun(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Start run", Some("Run"))),
+            ],
+            EditingCollectionRunDataFile => [
+                vec![
+                    ModifyCollectionRunDataFile(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelModifyCollectionRunDataFile(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventModifyCollectionRunDataFile(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, true)
+            ].concat(),
+            RunningCollection => vec![
+                StopCollectionRun(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Stop run or quit", Some("Stop/Quit"))),
+            ],
             SelectedRequest => {
                 // Depending on the current request view, some keys may need to be deactivated
                 let (params_events_allowed, result_events_allowed) = match request_view {
                     RequestView::Normal => (true, true),
                     RequestView::OnlyResult => (false, true),
-                    RequestView::OnlyParams => (true, false)
+                    RequestView::OnlyParams => (true, false),
+                    RequestView::Diff => (false, false)
                 };
 
                 let mut base_events: Vec<AppEvent> = vec![
@@ -403,6 +643,7 @@ impl AppState {
                     let env_events = vec![
                         NextEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.next_environment], "Next environment", None)),
                         DisplayEnvEditor(EventKeyBinding::new(vec![key_bindings.main_menu.display_env_editor], "Environment editor", None)),
+                        DiffEnvironments(EventKeyBinding::new(vec![key_bindings.request_selected.diff_environments], "Compare the responses of two environments", None)),
                     ];
                     
                     base_events.extend(env_events);
@@ -412,6 +653,7 @@ impl AppState {
                     DisplayCookies(EventKeyBinding::new(vec![key_bindings.main_menu.display_cookies], "Display cookies", None)),
                     DisplayLogs(EventKeyBinding::new(vec![key_bindings.main_menu.display_logs], "Display logs", None)),
                     ExportRequest(EventKeyBinding::new(vec![key_bindings.request_selected.export_request], "Export request", None)),
+                    DisplayResponseHistory(EventKeyBinding::new(vec![key_bindings.request_selected.response_history], "Response history", None)),
                 ];
                 
                 base_events.extend(other_events);
@@ -435,6 +677,12 @@ impl AppState {
                             ],
                             Protocol::WsRequest(_) => vec![
                                 ModifyRequestMessageType(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.change_message_type], "Modify message type", None)),
+                            ],
+                            Protocol::GraphqlRequest(_) => vec![
+                                IntrospectGraphqlSchema(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.introspect_graphql_schema], "Introspect GraphQL schema", None)),
+                            ],
+                            Protocol::GrpcRequest(_) => vec![
+                                ListGrpcMethods(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.list_grpc_methods], "List gRPC methods", None)),
                             ]
                         };
 
@@ -493,6 +741,29 @@ impl AppState {
                         RequestParamsTabs::Message => vec![
                             EditRequestMessage(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit message", None)),
                         ],
+                        RequestParamsTabs::Graphql => vec![
+                            EditRequestGraphql(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit GraphQL element", Some("Edit"))),
+                            RequestGraphqlMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                            RequestGraphqlMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+                        ],
+                        RequestParamsTabs::Grpc => vec![
+                            EditRequestGrpc(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit gRPC element", Some("Edit"))),
+                            RequestGrpcMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                            RequestGrpcMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+                        ],
+                        RequestParamsTabs::Captures => vec![
+                            EditRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit capture", None)),
+
+                            RequestCapturesMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", None)),
+                            RequestCapturesMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", None)),
+                            RequestCapturesMoveLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move left", None)),
+                            RequestCapturesMoveRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move right", None)),
+
+                            CreateRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "Create capture", None)),
+                            DeleteRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete capture", None)),
+                            ToggleRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.toggle_element], "Toggle capture", None)),
+                            DuplicateRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.duplicate_element], "Duplicate capture", None)),
+                        ],
                         RequestParamsTabs::Scripts => vec![
                             EditRequestScript(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit request script", Some("Edit"))),
                             RequestScriptMove(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
@@ -502,7 +773,7 @@ impl AppState {
 
                     base_param_tabs_events.extend(param_tabs_events);
                 }
-                else {
+                else if result_events_allowed {
                     base_events.push(
                         NextResultTab(EventKeyBinding::new(vec![key_bindings.request_selected.param_next_tab], "Next result tab", Some("Next tab"))),
                     );
@@ -518,6 +789,12 @@ impl AppState {
                         CopyResponsePart(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.yank_response_part], "Yank response part", Some("Yank"))),
                     ];
 
+                    if request_result_tab == RequestResultTabs::Body {
+                        base_result_tabs_events.push(
+                            EditResponseBodyFilter(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.filter_response_body], "Filter result body", Some("Filter"))),
+                        );
+                    }
+
                     if params_events_allowed {
                         base_events.push(
                             NextResultTab(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.result_next_tab], "Next result tab", None)),
@@ -525,6 +802,15 @@ impl AppState {
                     }
                 }
 
+                if request_view == RequestView::Diff {
+                    base_result_tabs_events = vec![
+                        ScrollResponseDiffUp(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_up], "Scroll diff up", None)),
+                        ScrollResponseDiffDown(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_down], "Scroll diff down", None)),
+                        ScrollResponseDiffLeft(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_left], "Scroll diff left", None)),
+                        ScrollResponseDiffRight(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_right], "Scroll diff right", None)),
+                    ];
+                }
+
                 base_events.extend(base_param_tabs_events);
                 base_events.extend(base_result_tabs_events);
 
@@ -634,6 +920,134 @@ impl AppState {
                 ],
                 generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
             ].concat(),
+            EditingRequestAuthOAuth2TokenUrl => [
+                vec![
+                    ModifyRequestAuthOAuth2TokenUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2TokenUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2TokenUrl(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2AuthorizationUrl => [
+                vec![
+                    ModifyRequestAuthOAuth2AuthorizationUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2AuthorizationUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2AuthorizationUrl(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2RedirectUrl => [
+                vec![
+                    ModifyRequestAuthOAuth2RedirectUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2RedirectUrl(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2RedirectUrl(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2ClientId => [
+                vec![
+                    ModifyRequestAuthOAuth2ClientId(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2ClientId(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2ClientId(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2ClientSecret => [
+                vec![
+                    ModifyRequestAuthOAuth2ClientSecret(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2ClientSecret(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2ClientSecret(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2Scope => [
+                vec![
+                    ModifyRequestAuthOAuth2Scope(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2Scope(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2Scope(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2Username => [
+                vec![
+                    ModifyRequestAuthOAuth2Username(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2Username(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2Username(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2Password => [
+                vec![
+                    ModifyRequestAuthOAuth2Password(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2Password(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2Password(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthOAuth2RefreshToken => [
+                vec![
+                    ModifyRequestAuthOAuth2RefreshToken(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthOAuth2RefreshToken(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthOAuth2RefreshToken(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthAwsSigV4AccessKeyId => [
+                vec![
+                    ModifyRequestAuthAwsSigV4AccessKeyId(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthAwsSigV4SecretAccessKey => [
+                vec![
+                    ModifyRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthAwsSigV4Region => [
+                vec![
+                    ModifyRequestAuthAwsSigV4Region(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthAwsSigV4Region(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthAwsSigV4Region(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthAwsSigV4Service => [
+                vec![
+                    ModifyRequestAuthAwsSigV4Service(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthAwsSigV4Service(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthAwsSigV4Service(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthAwsSigV4SessionToken => [
+                vec![
+                    ModifyRequestAuthAwsSigV4SessionToken(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthAwsSigV4SessionToken(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthAwsSigV4SessionToken(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthApiKeyKey => [
+                vec![
+                    ModifyRequestAuthApiKeyKey(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthApiKeyKey(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthApiKeyKey(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestAuthApiKeyValue => [
+                vec![
+                    ModifyRequestAuthApiKeyValue(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestAuthApiKeyValue(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestAuthApiKeyValue(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
             EditingRequestHeader => [
                 vec![
                     ModifyRequestHeader(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
@@ -642,6 +1056,14 @@ impl AppState {
                 ],
                 generate_text_input_documentation(key_bindings.generic.text_input.mode, true, true)
             ].concat(),
+            EditingRequestCapture => [
+                vec![
+                    ModifyRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestCapture(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestCapture(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, true)
+            ].concat(),
             EditingRequestBodyTable => [
                 vec![
                     ModifyRequestBodyTable(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
@@ -674,6 +1096,63 @@ impl AppState {
                 ],
                 generate_text_input_documentation(key_bindings.generic.text_input.mode, false, false)
             ].concat(),
+            EditingRequestGraphqlQuery => [
+                vec![
+                    ModifyRequestGraphqlQuery(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_area], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGraphqlQuery(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    CompleteGraphqlField(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.complete_graphql_field], "Complete field", Some("Complete"))),
+                    KeyEventEditRequestGraphqlQuery(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, false, false)
+            ].concat(),
+            EditingRequestGraphqlVariables => [
+                vec![
+                    ModifyRequestGraphqlVariables(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_area], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGraphqlVariables(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGraphqlVariables(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, false, false)
+            ].concat(),
+            EditingRequestGraphqlOperationName => [
+                vec![
+                    ModifyRequestGraphqlOperationName(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGraphqlOperationName(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGraphqlOperationName(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestGrpcProtoFiles => [
+                vec![
+                    ModifyRequestGrpcProtoFiles(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGrpcProtoFiles(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGrpcProtoFiles(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestGrpcService => [
+                vec![
+                    ModifyRequestGrpcService(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGrpcService(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGrpcService(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestGrpcMethod => [
+                vec![
+                    ModifyRequestGrpcMethod(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGrpcMethod(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGrpcMethod(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat(),
+            EditingRequestGrpcMessage => [
+                vec![
+                    ModifyRequestGrpcMessage(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_area], "Confirm", Some("Confirm"))),
+                    CancelEditRequestGrpcMessage(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditRequestGrpcMessage(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, false, false)
+            ].concat(),
             EditingPreRequestScript => [
                 vec![
                     ModifyRequestPreRequestScript(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_area], "Confirm", Some("Confirm"))),
@@ -695,11 +1174,19 @@ impl AppState {
 
                 RequestSettingsMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
                 RequestSettingsMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
-                RequestSettingsToggleSettingLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Toggle setting", Some("Toggle left"))),
-                RequestSettingsToggleSettingRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Toggle setting", Some("Toggle right"))),
+                RequestSettingsToggleSettingLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Toggle or edit setting", Some("Toggle left"))),
+                RequestSettingsToggleSettingRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Toggle or edit setting", Some("Toggle right"))),
 
                 ModifyRequestSettings(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Confirm", Some("Confirm"))),
             ],
+            EditingRequestSettingValue => [
+                vec![
+                    ModifyRequestSettingValue(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelModifyRequestSettingValue(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventModifyRequestSettingValue(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, true)
+            ].concat(),
             ChoosingRequestExportFormat => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
 
@@ -717,7 +1204,32 @@ impl AppState {
                 ScrollRequestExportRight(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_right], "Scroll request export right", None)),
 
                 CopyRequestExport(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.yank_response_part], "Yank request export", Some("Yank"))),
-            ]
+            ],
+            DisplayingResponseHistory => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                ResponseHistoryMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                ResponseHistoryMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                SelectResponseHistoryEntry(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Display response", Some("Display"))),
+                CompareResponseHistoryEntry(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.toggle_element], "Select two responses to compare", Some("Compare"))),
+            ],
+            ChoosingDiffEnvironment => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                DiffEnvironmentMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move selection left", Some("Left"))),
+                DiffEnvironmentMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move selection right", Some("Right"))),
+
+                SelectDiffEnvironment(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Compare with the selected environment", Some("Compare"))),
+            ],
+            EditingResponseBodyFilter => [
+                vec![
+                    ModifyResponseBodyFilter(EventKeyBinding::new(vec![key_bindings.generic.text_input.save_and_quit_single_line], "Confirm", Some("Confirm"))),
+                    CancelEditResponseBodyFilter(EventKeyBinding::new(vec![key_bindings.generic.text_input.quit_without_saving], "Cancel", Some("Cancel"))),
+                    KeyEventEditResponseBodyFilter(EventKeyBinding::new(vec![], "Any input", None)),
+                ],
+                generate_text_input_documentation(key_bindings.generic.text_input.mode, true, false)
+            ].concat()
         }
     }
 }
@@ -877,14 +1389,14 @@ impl App<'_> {
             None => None
         };
         
-        *AVAILABLE_EVENTS.write() = self.state.get_available_events(self.request_view, self.request_param_tab, protocol, is_there_any_env);
+        *AVAILABLE_EVENTS.write() = self.state.get_available_events(self.request_view, self.request_param_tab, self.request_result_tab, protocol, is_there_any_env);
     }
 
     pub fn get_state_line(&self) -> Line<'_> {
         match self.state {
             Normal |
             ChoosingElementToCreate |
-            CreatingNewCollection | CreatingNewRequest |
+            CreatingNewCollection | CreatingNewRequest | CreatingNewFolder | MovingElementToFolder |
             DisplayingCookies | EditingCookies |
             DisplayingLogs => Line::from(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color),
 
@@ -900,8 +1412,8 @@ impl App<'_> {
             },
 
             DeletingRequest | RenamingRequest => {
-                let selected_request_index = &self.collections_tree.state.selected();
-                let selected_request = &self.collections[selected_request_index[0]].requests[selected_request_index[1]].read();
+                let (collection_index, request_index) = self.get_tree_selected_request().unwrap();
+                let selected_request = &self.collections[collection_index].requests[request_index].read();
 
                 Line::from(vec![
                     Span::raw("Request > ").fg(THEME.read().ui.secondary_foreground_color),
@@ -910,6 +1422,26 @@ impl App<'_> {
                 ])
             },
 
+            ChoosingCollectionRunOptions | EditingCollectionRunDataFile | RunningCollection => {
+                let collection_name = &self.collections[self.collection_runner_popup.collection_index].name;
+
+                Line::from(vec![
+                    Span::raw("Collection > ").fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{} > ", collection_name)).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                ])
+            },
+
+            DeletingFolder | RenamingFolder => {
+                let (_, folder_path) = self.get_tree_selected_folder().unwrap();
+
+                Line::from(vec![
+                    Span::raw("Folder > ").fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{} > ", folder_path.join("/"))).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                ])
+            },
+
             DisplayingEnvEditor | EditingEnvVariable => {
                 let local_env = self.get_selected_env_as_local().unwrap();
                 let env = local_env.read();
@@ -927,12 +1459,19 @@ impl App<'_> {
             EditingRequestAuthBearerToken |
             EditingRequestAuthJwtSecret | EditingRequestAuthJwtPayload |
             EditingRequestAuthDigestUsername | EditingRequestAuthDigestPassword | EditingRequestAuthDigestDomains | EditingRequestAuthDigestRealm | EditingRequestAuthDigestNonce | EditingRequestAuthDigestOpaque |
-            EditingRequestHeader |
+            EditingRequestAuthOAuth2TokenUrl | EditingRequestAuthOAuth2AuthorizationUrl | EditingRequestAuthOAuth2RedirectUrl | EditingRequestAuthOAuth2ClientId | EditingRequestAuthOAuth2ClientSecret | EditingRequestAuthOAuth2Scope | EditingRequestAuthOAuth2Username | EditingRequestAuthOAuth2Password | EditingRequestAuthOAuth2RefreshToken |
+            EditingRequestAuthAwsSigV4AccessKeyId | EditingRequestAuthAwsSigV4SecretAccessKey | EditingRequestAuthAwsSigV4Region | EditingRequestAuthAwsSigV4Service | EditingRequestAuthAwsSigV4SessionToken |
+            EditingRequestAuthApiKeyKey | EditingRequestAuthApiKeyValue |
+            EditingRequestHeader | EditingRequestCapture |
             EditingRequestBodyTable | EditingRequestBodyFile | EditingRequestBodyString |
             EditingRequestMessage |
+            EditingRequestGraphqlQuery | EditingRequestGraphqlVariables | EditingRequestGraphqlOperationName |
+            EditingRequestGrpcProtoFiles | EditingRequestGrpcService | EditingRequestGrpcMethod | EditingRequestGrpcMessage |
             EditingPreRequestScript | EditingPostRequestScript |
-            EditingRequestSettings |
-            ChoosingRequestExportFormat | DisplayingRequestExport
+            EditingRequestSettings | EditingRequestSettingValue |
+            ChoosingRequestExportFormat | DisplayingRequestExport |
+            DisplayingResponseHistory | EditingResponseBodyFilter |
+            ChoosingDiffEnvironment
             => {
                 let local_selected_request = self.get_selected_request_as_local();
                 let selected_request = local_selected_request.read();
@@ -962,13 +1501,23 @@ impl App<'_> {
             CreatingNewRequest |
             RenamingCollection |
             RenamingRequest |
+            CreatingNewFolder |
+            RenamingFolder |
+            MovingElementToFolder |
+            EditingCollectionRunDataFile |
             EditingRequestUrl |
             EditingRequestParam |
             EditingRequestAuthBasicUsername | EditingRequestAuthBasicPassword | EditingRequestAuthBearerToken | EditingRequestAuthJwtSecret | EditingRequestAuthJwtPayload |
-            EditingRequestHeader |
+            EditingRequestAuthOAuth2TokenUrl | EditingRequestAuthOAuth2AuthorizationUrl | EditingRequestAuthOAuth2RedirectUrl | EditingRequestAuthOAuth2ClientId | EditingRequestAuthOAuth2ClientSecret | EditingRequestAuthOAuth2Scope | EditingRequestAuthOAuth2Username | EditingRequestAuthOAuth2Password | EditingRequestAuthOAuth2RefreshToken |
+            EditingRequestAuthAwsSigV4AccessKeyId | EditingRequestAuthAwsSigV4SecretAccessKey | EditingRequestAuthAwsSigV4Region | EditingRequestAuthAwsSigV4Service | EditingRequestAuthAwsSigV4SessionToken |
+            EditingRequestAuthApiKeyKey | EditingRequestAuthApiKeyValue |
+            EditingRequestHeader | EditingRequestCapture |
             EditingRequestBodyTable | EditingRequestBodyFile | EditingRequestBodyString |
+            EditingRequestGraphqlQuery | EditingRequestGraphqlVariables | EditingRequestGraphqlOperationName |
+            EditingRequestGrpcProtoFiles | EditingRequestGrpcService | EditingRequestGrpcMethod | EditingRequestGrpcMessage |
             EditingPreRequestScript | EditingPostRequestScript |
-            EditingRequestSettings => true,
+            EditingRequestSettings | EditingRequestSettingValue |
+            EditingResponseBodyFilter => true,
             _ => false
         }
     }
@@ -34,6 +34,9 @@ get_key_bindings! {
 
         MoveElementUp(EventKeyBinding),
         MoveElementDown(EventKeyBinding),
+        MoveElementToFolder(EventKeyBinding),
+
+        RunCollection(EventKeyBinding),
 
         NextEnvironment(EventKeyBinding),
         DisplayEnvEditor(EventKeyBinding),
@@ -58,11 +61,18 @@ get_key_bindings! {
 
         /* Cookies */
 
+        EditCookie(EventKeyBinding),
         CookiesMoveUp(EventKeyBinding),
         CookiesMoveDown(EventKeyBinding),
         CookiesMoveLeft(EventKeyBinding),
         CookiesMoveRight(EventKeyBinding),
+        CreateCookie(EventKeyBinding),
         DeleteCookie(EventKeyBinding),
+        DuplicateCookie(EventKeyBinding),
+
+        ModifyCookie(EventKeyBinding),
+        CancelEditCookie(EventKeyBinding),
+        KeyEventEditCookie(EventKeyBinding),
 
         /* Logs */
 
@@ -89,6 +99,10 @@ get_key_bindings! {
         CreatingRequestInputRight(EventKeyBinding),
         KeyEventCreateNewRequest(EventKeyBinding),
 
+        CreateNewFolder(EventKeyBinding),
+        CancelCreateNewFolder(EventKeyBinding),
+        KeyEventCreateNewFolder(EventKeyBinding),
+
         DeletingCollectionMoveCursorLeft(EventKeyBinding),
         DeletingCollectionMoveCursorRight(EventKeyBinding),
         DeleteCollection(EventKeyBinding),
@@ -97,6 +111,10 @@ get_key_bindings! {
         DeletingRequestMoveCursorRight(EventKeyBinding),
         DeleteRequest(EventKeyBinding),
 
+        DeletingFolderMoveCursorLeft(EventKeyBinding),
+        DeletingFolderMoveCursorRight(EventKeyBinding),
+        DeleteFolder(EventKeyBinding),
+
         RenameCollection(EventKeyBinding),
         CancelRenameCollection(EventKeyBinding),
         KeyEventRenameCollection(EventKeyBinding),
@@ -105,6 +123,26 @@ get_key_bindings! {
         CancelRenameRequest(EventKeyBinding),
         KeyEventRenameRequest(EventKeyBinding),
 
+        RenameFolder(EventKeyBinding),
+        CancelRenameFolder(EventKeyBinding),
+        KeyEventRenameFolder(EventKeyBinding),
+
+        ConfirmMoveElementToFolder(EventKeyBinding),
+        CancelMoveElementToFolder(EventKeyBinding),
+        KeyEventMoveElementToFolder(EventKeyBinding),
+
+        CollectionRunOptionsMoveUp(EventKeyBinding),
+        CollectionRunOptionsMoveDown(EventKeyBinding),
+        CollectionRunOptionsToggleLeft(EventKeyBinding),
+        CollectionRunOptionsToggleRight(EventKeyBinding),
+        StartCollectionRun(EventKeyBinding),
+
+        ModifyCollectionRunDataFile(EventKeyBinding),
+        CancelModifyCollectionRunDataFile(EventKeyBinding),
+        KeyEventModifyCollectionRunDataFile(EventKeyBinding),
+
+        StopCollectionRun(EventKeyBinding),
+
         /* Request */
 
         GoBackToRequestMenu(EventKeyBinding),
@@ -124,6 +162,8 @@ get_key_bindings! {
         ModifyRequestAuthMethod(EventKeyBinding),
         ModifyRequestBodyContentType(EventKeyBinding),
         ModifyRequestMessageType(EventKeyBinding),
+        IntrospectGraphqlSchema(EventKeyBinding),
+        ListGrpcMethods(EventKeyBinding),
 
         EditRequestQueryParam(EventKeyBinding),
         RequestQueryParamsMoveUp(EventKeyBinding),
@@ -151,6 +191,16 @@ get_key_bindings! {
         ToggleRequestHeader(EventKeyBinding),
         DuplicateRequestHeader(EventKeyBinding),
 
+        EditRequestCapture(EventKeyBinding),
+        RequestCapturesMoveUp(EventKeyBinding),
+        RequestCapturesMoveDown(EventKeyBinding),
+        RequestCapturesMoveLeft(EventKeyBinding),
+        RequestCapturesMoveRight(EventKeyBinding),
+        CreateRequestCapture(EventKeyBinding),
+        DeleteRequestCapture(EventKeyBinding),
+        ToggleRequestCapture(EventKeyBinding),
+        DuplicateRequestCapture(EventKeyBinding),
+
         EditRequestBody(EventKeyBinding),
         RequestBodyTableMoveUp(EventKeyBinding),
         RequestBodyTableMoveDown(EventKeyBinding),
@@ -163,6 +213,13 @@ get_key_bindings! {
 
         EditRequestMessage(EventKeyBinding),
 
+        EditRequestGraphql(EventKeyBinding),
+        RequestGraphqlMoveUp(EventKeyBinding),
+        RequestGraphqlMoveDown(EventKeyBinding),
+        EditRequestGrpc(EventKeyBinding),
+        RequestGrpcMoveUp(EventKeyBinding),
+        RequestGrpcMoveDown(EventKeyBinding),
+
         EditRequestScript(EventKeyBinding),
         // Move up or down
         RequestScriptMove(EventKeyBinding),
@@ -176,6 +233,11 @@ get_key_bindings! {
         ScrollResultLeft(EventKeyBinding),
         ScrollResultRight(EventKeyBinding),
 
+        EditResponseBodyFilter(EventKeyBinding),
+        ModifyResponseBodyFilter(EventKeyBinding),
+        CancelEditResponseBodyFilter(EventKeyBinding),
+        KeyEventEditResponseBodyFilter(EventKeyBinding),
+
         /* Others */
 
         CopyResponsePart(EventKeyBinding),
@@ -193,6 +255,24 @@ get_key_bindings! {
         ScrollRequestExportRight(EventKeyBinding),
         CopyRequestExport(EventKeyBinding),
 
+        /* Response history */
+
+        DisplayResponseHistory(EventKeyBinding),
+        ResponseHistoryMoveUp(EventKeyBinding),
+        ResponseHistoryMoveDown(EventKeyBinding),
+        SelectResponseHistoryEntry(EventKeyBinding),
+        CompareResponseHistoryEntry(EventKeyBinding),
+
+        DiffEnvironments(EventKeyBinding),
+        DiffEnvironmentMoveCursorLeft(EventKeyBinding),
+        DiffEnvironmentMoveCursorRight(EventKeyBinding),
+        SelectDiffEnvironment(EventKeyBinding),
+
+        ScrollResponseDiffUp(EventKeyBinding),
+        ScrollResponseDiffDown(EventKeyBinding),
+        ScrollResponseDiffLeft(EventKeyBinding),
+        ScrollResponseDiffRight(EventKeyBinding),
+
         /* Request Text inputs */
 
         ModifyRequestUrl(EventKeyBinding),
@@ -249,12 +329,82 @@ get_key_bindings! {
         CancelEditRequestAuthDigestOpaque(EventKeyBinding),
         KeyEventEditRequestAuthDigestOpaque(EventKeyBinding),
 
+        ModifyRequestAuthOAuth2TokenUrl(EventKeyBinding),
+        CancelEditRequestAuthOAuth2TokenUrl(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2TokenUrl(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2AuthorizationUrl(EventKeyBinding),
+        CancelEditRequestAuthOAuth2AuthorizationUrl(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2AuthorizationUrl(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2RedirectUrl(EventKeyBinding),
+        CancelEditRequestAuthOAuth2RedirectUrl(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2RedirectUrl(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2ClientId(EventKeyBinding),
+        CancelEditRequestAuthOAuth2ClientId(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2ClientId(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2ClientSecret(EventKeyBinding),
+        CancelEditRequestAuthOAuth2ClientSecret(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2ClientSecret(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2Scope(EventKeyBinding),
+        CancelEditRequestAuthOAuth2Scope(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2Scope(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2Username(EventKeyBinding),
+        CancelEditRequestAuthOAuth2Username(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2Username(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2Password(EventKeyBinding),
+        CancelEditRequestAuthOAuth2Password(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2Password(EventKeyBinding),
+
+        ModifyRequestAuthOAuth2RefreshToken(EventKeyBinding),
+        CancelEditRequestAuthOAuth2RefreshToken(EventKeyBinding),
+        KeyEventEditRequestAuthOAuth2RefreshToken(EventKeyBinding),
+
+        ModifyRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+        CancelEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+        KeyEventEditRequestAuthAwsSigV4AccessKeyId(EventKeyBinding),
+
+        ModifyRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+        CancelEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+        KeyEventEditRequestAuthAwsSigV4SecretAccessKey(EventKeyBinding),
+
+        ModifyRequestAuthAwsSigV4Region(EventKeyBinding),
+        CancelEditRequestAuthAwsSigV4Region(EventKeyBinding),
+        KeyEventEditRequestAuthAwsSigV4Region(EventKeyBinding),
+
+        ModifyRequestAuthAwsSigV4Service(EventKeyBinding),
+        CancelEditRequestAuthAwsSigV4Service(EventKeyBinding),
+        KeyEventEditRequestAuthAwsSigV4Service(EventKeyBinding),
+
+        ModifyRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+        CancelEditRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+        KeyEventEditRequestAuthAwsSigV4SessionToken(EventKeyBinding),
+
+        ModifyRequestAuthApiKeyKey(EventKeyBinding),
+        CancelEditRequestAuthApiKeyKey(EventKeyBinding),
+        KeyEventEditRequestAuthApiKeyKey(EventKeyBinding),
+
+        ModifyRequestAuthApiKeyValue(EventKeyBinding),
+        CancelEditRequestAuthApiKeyValue(EventKeyBinding),
+        KeyEventEditRequestAuthApiKeyValue(EventKeyBinding),
+
         /* Headers */
 
         ModifyRequestHeader(EventKeyBinding),
         CancelEditRequestHeader(EventKeyBinding),
         KeyEventEditRequestHeader(EventKeyBinding),
 
+        /* Captures */
+
+        ModifyRequestCapture(EventKeyBinding),
+        CancelEditRequestCapture(EventKeyBinding),
+        KeyEventEditRequestCapture(EventKeyBinding),
+
         /* Body */
 
         ModifyRequestBodyTable(EventKeyBinding),
@@ -275,6 +425,37 @@ get_key_bindings! {
         CancelEditRequestMessage(EventKeyBinding),
         KeyEventEditRequestMessage(EventKeyBinding),
 
+        /* GraphQL */
+
+        ModifyRequestGraphqlQuery(EventKeyBinding),
+        CancelEditRequestGraphqlQuery(EventKeyBinding),
+        CompleteGraphqlField(EventKeyBinding),
+        KeyEventEditRequestGraphqlQuery(EventKeyBinding),
+
+        ModifyRequestGraphqlVariables(EventKeyBinding),
+        CancelEditRequestGraphqlVariables(EventKeyBinding),
+        KeyEventEditRequestGraphqlVariables(EventKeyBinding),
+
+        ModifyRequestGraphqlOperationName(EventKeyBinding),
+        CancelEditRequestGraphqlOperationName(EventKeyBinding),
+        KeyEventEditRequestGraphqlOperationName(EventKeyBinding),
+
+        ModifyRequestGrpcProtoFiles(EventKeyBinding),
+        CancelEditRequestGrpcProtoFiles(EventKeyBinding),
+        KeyEventEditRequestGrpcProtoFiles(EventKeyBinding),
+
+        ModifyRequestGrpcService(EventKeyBinding),
+        CancelEditRequestGrpcService(EventKeyBinding),
+        KeyEventEditRequestGrpcService(EventKeyBinding),
+
+        ModifyRequestGrpcMethod(EventKeyBinding),
+        CancelEditRequestGrpcMethod(EventKeyBinding),
+        KeyEventEditRequestGrpcMethod(EventKeyBinding),
+
+        ModifyRequestGrpcMessage(EventKeyBinding),
+        CancelEditRequestGrpcMessage(EventKeyBinding),
+        KeyEventEditRequestGrpcMessage(EventKeyBinding),
+
         /* Scripts */
 
         ModifyRequestPreRequestScript(EventKeyBinding),
@@ -293,6 +474,10 @@ get_key_bindings! {
         RequestSettingsToggleSettingRight(EventKeyBinding),
         ModifyRequestSettings(EventKeyBinding),
 
+        ModifyRequestSettingValue(EventKeyBinding),
+        CancelModifyRequestSettingValue(EventKeyBinding),
+        KeyEventModifyRequestSettingValue(EventKeyBinding),
+
         /* Others */
 
         Documentation(EventKeyBinding),
@@ -320,8 +505,14 @@ impl App<'_> {
             }
         }
 
+        if self.collection_runner_popup.run.is_some() {
+            self.tui_collection_run_tick();
+        }
+
         let received_response = *self.received_response.lock();
         if received_response {
+            self.tui_receive_response_diff();
+            self.tui_receive_grpc_methods();
             self.tui_highlight_response_body_and_console();
             self.tui_refresh_result_scrollbars();
 
@@ -400,6 +591,9 @@ impl App<'_> {
 
                 MoveElementUp(_) => self.tui_move_element_up(),
                 MoveElementDown(_) => self.tui_move_element_down(),
+                MoveElementToFolder(_) => self.move_element_to_folder_state(),
+
+                RunCollection(_) => self.run_collection_state(),
 
                 NextEnvironment(_) => self.tui_next_environment(),
                 DisplayEnvEditor(_) => self.display_env_editor_state(),
@@ -432,12 +626,27 @@ impl App<'_> {
 
                 /* Cookies */
 
+                EditCookie(_) => if self.cookies_popup.cookies_table.selection.is_some() {
+                    self.edit_cookie_state();
+                },
                 CookiesMoveUp(_) => self.cookies_popup.cookies_table.up(),
                 CookiesMoveDown(_) => self.cookies_popup.cookies_table.down(),
                 CookiesMoveLeft(_) => self.cookies_popup.cookies_table.left(),
                 CookiesMoveRight(_) => self.cookies_popup.cookies_table.right(),
 
+                CreateCookie(_) => self.tui_create_cookie(),
                 DeleteCookie(_) => self.tui_delete_cookie(),
+                DuplicateCookie(_) => self.tui_duplicate_cookie(),
+
+                ModifyCookie(_) => match self.cookies_popup.cookies_table.selection_text_input.is_in_default_mode() {
+                    true => self.tui_modify_cookie(),
+                    false => self.cookies_popup.cookies_table.selection_text_input.key_event(key, None),
+                },
+                CancelEditCookie(_) => match self.cookies_popup.cookies_table.selection_text_input.is_in_default_mode() {
+                    true => self.display_cookies_state(),
+                    false => self.cookies_popup.cookies_table.selection_text_input.key_event(key, None),
+                },
+                KeyEventEditCookie(_) => self.cookies_popup.cookies_table.selection_text_input.key_event(key, None),
 
                 /* Logs */
 
@@ -479,6 +688,17 @@ impl App<'_> {
                 KeyEventCreateNewRequest(_) => self.new_request_popup.text_input.key_event(key, None),
 
 
+                CreateNewFolder(_) => match self.new_folder_input.is_in_default_mode() {
+                    true => self.tui_new_folder(),
+                    false => self.new_folder_input.key_event(key, None),
+                },
+                CancelCreateNewFolder(_) => match self.new_folder_input.is_in_default_mode() {
+                    true => self.normal_state(),
+                    false => self.new_folder_input.key_event(key, None),
+                },
+                KeyEventCreateNewFolder(_) => self.new_folder_input.key_event(key, None),
+
+
                 DeletingCollectionMoveCursorLeft(_) => self.delete_collection_popup.change_state(),
                 DeletingCollectionMoveCursorRight(_) => self.delete_collection_popup.change_state(),
                 DeleteCollection(_) => match self.delete_collection_popup.state {
@@ -493,6 +713,13 @@ impl App<'_> {
                     false => self.normal_state(),
                 },
 
+                DeletingFolderMoveCursorLeft(_) => self.delete_folder_popup.change_state(),
+                DeletingFolderMoveCursorRight(_) => self.delete_folder_popup.change_state(),
+                DeleteFolder(_) => match self.delete_folder_popup.state {
+                    true => self.tui_delete_folder(),
+                    false => self.normal_state(),
+                },
+
 
                 RenameCollection(_) => match self.rename_collection_input.is_in_default_mode() {
                     true => self.tui_rename_collection(),
@@ -516,6 +743,53 @@ impl App<'_> {
                 KeyEventRenameRequest(_) => self.rename_request_input.key_event(key, None),
 
 
+                RenameFolder(_) => match self.rename_folder_input.is_in_default_mode() {
+                    true => self.tui_rename_folder(),
+                    false => self.rename_folder_input.key_event(key, None),
+                },
+                CancelRenameFolder(_) => match self.rename_folder_input.is_in_default_mode() {
+                    true => self.normal_state(),
+                    false => self.rename_folder_input.key_event(key, None),
+                },
+                KeyEventRenameFolder(_) => self.rename_folder_input.key_event(key, None),
+
+
+                ConfirmMoveElementToFolder(_) => match self.move_to_folder_input.is_in_default_mode() {
+                    true => self.tui_move_element_to_folder(),
+                    false => self.move_to_folder_input.key_event(key, None),
+                },
+                CancelMoveElementToFolder(_) => match self.move_to_folder_input.is_in_default_mode() {
+                    true => self.normal_state(),
+                    false => self.move_to_folder_input.key_event(key, None),
+                },
+                KeyEventMoveElementToFolder(_) => self.move_to_folder_input.key_event(key, None),
+
+
+                CollectionRunOptionsMoveUp(_) => self.collection_runner_popup.previous(),
+                CollectionRunOptionsMoveDown(_) => self.collection_runner_popup.next(),
+                CollectionRunOptionsToggleLeft(_) => match self.collection_runner_popup.is_data_file_selected() {
+                    true => self.edit_collection_run_data_file_state(),
+                    false => self.collection_runner_popup.toggle_option_left(),
+                },
+                CollectionRunOptionsToggleRight(_) => match self.collection_runner_popup.is_data_file_selected() {
+                    true => self.edit_collection_run_data_file_state(),
+                    false => self.collection_runner_popup.toggle_option_right(),
+                },
+                StartCollectionRun(_) => self.tui_start_collection_run(),
+
+                ModifyCollectionRunDataFile(_) => match self.collection_runner_popup.text_input.is_in_default_mode() {
+                    true => self.tui_modify_collection_run_data_file(),
+                    false => self.collection_runner_popup.text_input.key_event(key, None),
+                },
+                CancelModifyCollectionRunDataFile(_) => match self.collection_runner_popup.text_input.is_in_default_mode() {
+                    true => self.choose_collection_run_options_state(),
+                    false => self.collection_runner_popup.text_input.key_event(key, None),
+                },
+                KeyEventModifyCollectionRunDataFile(_) => self.collection_runner_popup.text_input.key_event(key, None),
+
+                StopCollectionRun(_) => self.tui_stop_collection_run(),
+
+
                 /* Selected Request */
 
                 GoBackToRequestMenu(_) => self.select_request_state(),
@@ -534,6 +808,8 @@ impl App<'_> {
                 ModifyRequestAuthMethod(_) => self.tui_next_request_auth(),
                 ModifyRequestBodyContentType(_) => self.tui_next_request_content_type(),
                 ModifyRequestMessageType(_) => self.tui_next_request_message_type(),
+                IntrospectGraphqlSchema(_) => self.tui_introspect_graphql_schema().await,
+                ListGrpcMethods(_) => self.tui_list_grpc_methods(),
 
                 EditRequestQueryParam(_) => match self.query_params_table.is_selected() {
                     true => self.edit_request_param_state(),
@@ -574,6 +850,17 @@ impl App<'_> {
                 ToggleRequestHeader(_) => self.tui_toggle_header(),
                 DuplicateRequestHeader(_) => self.tui_duplicate_header(),
 
+                EditRequestCapture(_) => if self.captures_table.is_selected() {
+                    self.edit_request_capture_state()
+                },
+                RequestCapturesMoveUp(_) => self.captures_table.up(),
+                RequestCapturesMoveDown(_) => self.captures_table.down(),
+                RequestCapturesMoveLeft(_) | RequestCapturesMoveRight(_) => self.captures_table.change_y(),
+                CreateRequestCapture(_) => self.tui_create_new_capture(),
+                DeleteRequestCapture(_) => self.tui_delete_capture(),
+                ToggleRequestCapture(_) => self.tui_toggle_capture(),
+                DuplicateRequestCapture(_) => self.tui_duplicate_capture(),
+
                 EditRequestBody(_) => match self.body_form_table.is_selected() {
                     true => self.edit_request_body_table_state(),
                     false => self.edit_request_body_file_or_string_state(),
@@ -581,6 +868,13 @@ impl App<'_> {
 
                 EditRequestMessage(_) => self.edit_request_message_state(),
 
+                EditRequestGraphql(_) => self.edit_request_graphql_state(),
+                RequestGraphqlMoveUp(_) => self.graphql_editor.previous(),
+                RequestGraphqlMoveDown(_) => self.graphql_editor.next(),
+                EditRequestGrpc(_) => self.edit_request_grpc_state(),
+                RequestGrpcMoveUp(_) => self.grpc_editor.previous(),
+                RequestGrpcMoveDown(_) => self.grpc_editor.next(),
+
                 RequestBodyTableMoveUp(_) => self.body_form_table.up(),
                 RequestBodyTableMoveDown(_) => self.body_form_table.down(),
                 RequestBodyTableMoveLeft(_) | RequestBodyTableMoveRight(_) => self.body_form_table.change_y(),
@@ -603,6 +897,17 @@ impl App<'_> {
                 ScrollResultLeft(_) => self.result_horizontal_scrollbar.page_up(),
                 ScrollResultRight(_) => self.result_horizontal_scrollbar.page_down(),
 
+                EditResponseBodyFilter(_) => self.edit_response_body_filter_state(),
+                ModifyResponseBodyFilter(_) => match self.response_body_filter.text_input.is_in_default_mode() {
+                    true => self.tui_modify_response_body_filter(),
+                    false => self.response_body_filter.text_input.key_event(key, None),
+                },
+                CancelEditResponseBodyFilter(_) => match self.response_body_filter.text_input.is_in_default_mode() {
+                    true => self.tui_cancel_edit_response_body_filter(),
+                    false => self.response_body_filter.text_input.key_event(key, None),
+                },
+                KeyEventEditResponseBodyFilter(_) => self.response_body_filter.text_input.key_event(key, None),
+
                 /* Others */
 
                 #[cfg(feature = "clipboard")]
@@ -631,6 +936,34 @@ impl App<'_> {
                 #[cfg(not(feature = "clipboard"))]
                 CopyRequestExport(_) => {},
 
+                /* Response history */
+
+                DisplayResponseHistory(_) => self.display_response_history_state(),
+
+                ResponseHistoryMoveUp(_) => self.response_history_popup.previous(),
+                ResponseHistoryMoveDown(_) => self.response_history_popup.next(),
+
+                SelectResponseHistoryEntry(_) => self.tui_select_response_history_entry(),
+                CompareResponseHistoryEntry(_) => self.tui_compare_response_history_entry(),
+
+                DiffEnvironments(_) => self.choose_diff_environment_state(),
+                DiffEnvironmentMoveCursorLeft(_) => self.diff_environment_popup.previous(),
+                DiffEnvironmentMoveCursorRight(_) => self.diff_environment_popup.next(),
+                SelectDiffEnvironment(_) => self.tui_diff_environments_responses(),
+
+                ScrollResponseDiffUp(_) => if let Some(response_diff_panel) = &mut self.response_diff_panel {
+                    response_diff_panel.vertical_scrollbar.page_up();
+                },
+                ScrollResponseDiffDown(_) => if let Some(response_diff_panel) = &mut self.response_diff_panel {
+                    response_diff_panel.vertical_scrollbar.page_down();
+                },
+                ScrollResponseDiffLeft(_) => if let Some(response_diff_panel) = &mut self.response_diff_panel {
+                    response_diff_panel.horizontal_scrollbar.page_up();
+                },
+                ScrollResponseDiffRight(_) => if let Some(response_diff_panel) = &mut self.response_diff_panel {
+                    response_diff_panel.horizontal_scrollbar.page_down();
+                },
+
                 /* Url */
 
                 ModifyRequestUrl(_) => match self.url_text_input.is_in_default_mode() {
@@ -779,6 +1112,166 @@ impl App<'_> {
                 },
                 KeyEventEditRequestAuthDigestOpaque(_) => self.auth_digest_opaque_text_input.key_event(key, None),
 
+                ModifyRequestAuthOAuth2TokenUrl(_) => match self.auth_oauth2_token_url_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_token_url(),
+                    false => self.auth_oauth2_token_url_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2TokenUrl(_) => match self.auth_oauth2_token_url_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_token_url_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2TokenUrl(_) => self.auth_oauth2_token_url_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2AuthorizationUrl(_) => match self.auth_oauth2_authorization_url_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_authorization_url(),
+                    false => self.auth_oauth2_authorization_url_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2AuthorizationUrl(_) => match self.auth_oauth2_authorization_url_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_authorization_url_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2AuthorizationUrl(_) => self.auth_oauth2_authorization_url_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2RedirectUrl(_) => match self.auth_oauth2_redirect_url_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_redirect_url(),
+                    false => self.auth_oauth2_redirect_url_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2RedirectUrl(_) => match self.auth_oauth2_redirect_url_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_redirect_url_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2RedirectUrl(_) => self.auth_oauth2_redirect_url_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2ClientId(_) => match self.auth_oauth2_client_id_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_client_id(),
+                    false => self.auth_oauth2_client_id_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2ClientId(_) => match self.auth_oauth2_client_id_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_client_id_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2ClientId(_) => self.auth_oauth2_client_id_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2ClientSecret(_) => match self.auth_oauth2_client_secret_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_client_secret(),
+                    false => self.auth_oauth2_client_secret_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2ClientSecret(_) => match self.auth_oauth2_client_secret_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_client_secret_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2ClientSecret(_) => self.auth_oauth2_client_secret_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2Scope(_) => match self.auth_oauth2_scope_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_scope(),
+                    false => self.auth_oauth2_scope_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2Scope(_) => match self.auth_oauth2_scope_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_scope_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2Scope(_) => self.auth_oauth2_scope_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2Username(_) => match self.auth_oauth2_username_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_username(),
+                    false => self.auth_oauth2_username_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2Username(_) => match self.auth_oauth2_username_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_username_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2Username(_) => self.auth_oauth2_username_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2Password(_) => match self.auth_oauth2_password_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_password(),
+                    false => self.auth_oauth2_password_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2Password(_) => match self.auth_oauth2_password_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_password_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2Password(_) => self.auth_oauth2_password_text_input.key_event(key, None),
+
+                ModifyRequestAuthOAuth2RefreshToken(_) => match self.auth_oauth2_refresh_token_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_oauth2_refresh_token(),
+                    false => self.auth_oauth2_refresh_token_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthOAuth2RefreshToken(_) => match self.auth_oauth2_refresh_token_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_oauth2_refresh_token_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthOAuth2RefreshToken(_) => self.auth_oauth2_refresh_token_text_input.key_event(key, None),
+
+                ModifyRequestAuthAwsSigV4AccessKeyId(_) => match self.auth_aws_sigv4_access_key_id_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_aws_sigv4_access_key_id(),
+                    false => self.auth_aws_sigv4_access_key_id_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthAwsSigV4AccessKeyId(_) => match self.auth_aws_sigv4_access_key_id_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_aws_sigv4_access_key_id_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthAwsSigV4AccessKeyId(_) => self.auth_aws_sigv4_access_key_id_text_input.key_event(key, None),
+
+                ModifyRequestAuthAwsSigV4SecretAccessKey(_) => match self.auth_aws_sigv4_secret_access_key_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_aws_sigv4_secret_access_key(),
+                    false => self.auth_aws_sigv4_secret_access_key_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthAwsSigV4SecretAccessKey(_) => match self.auth_aws_sigv4_secret_access_key_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_aws_sigv4_secret_access_key_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthAwsSigV4SecretAccessKey(_) => self.auth_aws_sigv4_secret_access_key_text_input.key_event(key, None),
+
+                ModifyRequestAuthAwsSigV4Region(_) => match self.auth_aws_sigv4_region_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_aws_sigv4_region(),
+                    false => self.auth_aws_sigv4_region_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthAwsSigV4Region(_) => match self.auth_aws_sigv4_region_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_aws_sigv4_region_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthAwsSigV4Region(_) => self.auth_aws_sigv4_region_text_input.key_event(key, None),
+
+                ModifyRequestAuthAwsSigV4Service(_) => match self.auth_aws_sigv4_service_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_aws_sigv4_service(),
+                    false => self.auth_aws_sigv4_service_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthAwsSigV4Service(_) => match self.auth_aws_sigv4_service_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_aws_sigv4_service_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthAwsSigV4Service(_) => self.auth_aws_sigv4_service_text_input.key_event(key, None),
+
+                ModifyRequestAuthAwsSigV4SessionToken(_) => match self.auth_aws_sigv4_session_token_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_aws_sigv4_session_token(),
+                    false => self.auth_aws_sigv4_session_token_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthAwsSigV4SessionToken(_) => match self.auth_aws_sigv4_session_token_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_aws_sigv4_session_token_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthAwsSigV4SessionToken(_) => self.auth_aws_sigv4_session_token_text_input.key_event(key, None),
+
+                ModifyRequestAuthApiKeyKey(_) => match self.auth_api_key_key_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_api_key_key(),
+                    false => self.auth_api_key_key_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthApiKeyKey(_) => match self.auth_api_key_key_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_api_key_key_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthApiKeyKey(_) => self.auth_api_key_key_text_input.key_event(key, None),
+
+                ModifyRequestAuthApiKeyValue(_) => match self.auth_api_key_value_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_auth_api_key_value(),
+                    false => self.auth_api_key_value_text_input.key_event(key, None),
+                },
+                CancelEditRequestAuthApiKeyValue(_) => match self.auth_api_key_value_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.auth_api_key_value_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestAuthApiKeyValue(_) => self.auth_api_key_value_text_input.key_event(key, None),
+
                 /* Header */
 
                 ModifyRequestHeader(_) => match self.headers_table.selection_text_input.is_in_default_mode() {
@@ -791,6 +1284,18 @@ impl App<'_> {
                 },
                 KeyEventEditRequestHeader(_) => self.headers_table.selection_text_input.key_event(key, None),
 
+                /* Capture */
+
+                ModifyRequestCapture(_) => match self.captures_table.selection_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_capture(),
+                    false => self.captures_table.selection_text_input.key_event(key, None),
+                },
+                CancelEditRequestCapture(_) => match self.captures_table.selection_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.captures_table.selection_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestCapture(_) => self.captures_table.selection_text_input.key_event(key, None),
+
                 /* Body */
 
                 ModifyRequestBodyTable(_) => match self.body_form_table.selection_text_input.is_in_default_mode() {
@@ -837,6 +1342,81 @@ impl App<'_> {
                 },
                 KeyEventEditRequestMessage(_) => self.message_text_area.key_event(key, Some(terminal)),
 
+                /* GraphQL */
+
+                ModifyRequestGraphqlQuery(_) => match self.graphql_editor.query_text_area.is_in_default_mode() {
+                    true => self.tui_modify_request_graphql_query(),
+                    false => self.graphql_editor.query_text_area.key_event(key, Some(terminal)),
+                },
+                CancelEditRequestGraphqlQuery(_) => match self.graphql_editor.query_text_area.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.graphql_editor.query_text_area.key_event(key, Some(terminal)),
+                },
+                CompleteGraphqlField(_) => self.tui_complete_graphql_field(),
+                KeyEventEditRequestGraphqlQuery(_) => self.graphql_editor.query_text_area.key_event(key, Some(terminal)),
+
+                ModifyRequestGraphqlVariables(_) => match self.graphql_editor.variables_text_area.is_in_default_mode() {
+                    true => self.tui_modify_request_graphql_variables(),
+                    false => self.graphql_editor.variables_text_area.key_event(key, Some(terminal)),
+                },
+                CancelEditRequestGraphqlVariables(_) => match self.graphql_editor.variables_text_area.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.graphql_editor.variables_text_area.key_event(key, Some(terminal)),
+                },
+                KeyEventEditRequestGraphqlVariables(_) => self.graphql_editor.variables_text_area.key_event(key, Some(terminal)),
+
+                ModifyRequestGraphqlOperationName(_) => match self.graphql_editor.operation_name_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_graphql_operation_name(),
+                    false => self.graphql_editor.operation_name_text_input.key_event(key, None),
+                },
+                CancelEditRequestGraphqlOperationName(_) => match self.graphql_editor.operation_name_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.graphql_editor.operation_name_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestGraphqlOperationName(_) => self.graphql_editor.operation_name_text_input.key_event(key, None),
+
+                /* gRPC */
+
+                ModifyRequestGrpcProtoFiles(_) => match self.grpc_editor.proto_files_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_grpc_proto_files(),
+                    false => self.grpc_editor.proto_files_text_input.key_event(key, None),
+                },
+                CancelEditRequestGrpcProtoFiles(_) => match self.grpc_editor.proto_files_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.grpc_editor.proto_files_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestGrpcProtoFiles(_) => self.grpc_editor.proto_files_text_input.key_event(key, None),
+
+                ModifyRequestGrpcService(_) => match self.grpc_editor.service_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_grpc_service(),
+                    false => self.grpc_editor.service_text_input.key_event(key, None),
+                },
+                CancelEditRequestGrpcService(_) => match self.grpc_editor.service_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.grpc_editor.service_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestGrpcService(_) => self.grpc_editor.service_text_input.key_event(key, None),
+
+                ModifyRequestGrpcMethod(_) => match self.grpc_editor.method_text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_grpc_method(),
+                    false => self.grpc_editor.method_text_input.key_event(key, None),
+                },
+                CancelEditRequestGrpcMethod(_) => match self.grpc_editor.method_text_input.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.grpc_editor.method_text_input.key_event(key, None),
+                },
+                KeyEventEditRequestGrpcMethod(_) => self.grpc_editor.method_text_input.key_event(key, None),
+
+                ModifyRequestGrpcMessage(_) => match self.grpc_editor.message_text_area.is_in_default_mode() {
+                    true => self.tui_modify_request_grpc_message(),
+                    false => self.grpc_editor.message_text_area.key_event(key, Some(terminal)),
+                },
+                CancelEditRequestGrpcMessage(_) => match self.grpc_editor.message_text_area.is_in_default_mode() {
+                    true => self.select_request_state(),
+                    false => self.grpc_editor.message_text_area.key_event(key, Some(terminal)),
+                },
+                KeyEventEditRequestGrpcMessage(_) => self.grpc_editor.message_text_area.key_event(key, Some(terminal)),
+
                 /* Scripts */
 
                 ModifyRequestPreRequestScript(_) => match self.script_console.pre_request_text_area.is_in_default_mode() {
@@ -864,10 +1444,26 @@ impl App<'_> {
 
                 RequestSettingsMoveUp(_) => self.request_settings_popup.previous(),
                 RequestSettingsMoveDown(_) => self.request_settings_popup.next(),
-                RequestSettingsToggleSettingLeft(_) => self.request_settings_popup.toggle_setting_left(),
-                RequestSettingsToggleSettingRight(_) => self.request_settings_popup.toggle_setting_right(),
+                RequestSettingsToggleSettingLeft(_) => match self.request_settings_popup.is_text_setting_selected() {
+                    true => self.edit_request_setting_value_state(),
+                    false => self.request_settings_popup.toggle_setting_left(),
+                },
+                RequestSettingsToggleSettingRight(_) => match self.request_settings_popup.is_text_setting_selected() {
+                    true => self.edit_request_setting_value_state(),
+                    false => self.request_settings_popup.toggle_setting_right(),
+                },
                 ModifyRequestSettings(_) => self.tui_modify_request_settings(),
 
+                ModifyRequestSettingValue(_) => match self.request_settings_popup.text_input.is_in_default_mode() {
+                    true => self.tui_modify_request_setting_value(),
+                    false => self.request_settings_popup.text_input.key_event(key, None),
+                },
+                CancelModifyRequestSettingValue(_) => match self.request_settings_popup.text_input.is_in_default_mode() {
+                    true => self.back_to_request_settings_state(),
+                    false => self.request_settings_popup.text_input.key_event(key, None),
+                },
+                KeyEventModifyRequestSettingValue(_) => self.request_settings_popup.text_input.key_event(key, None),
+
                 /* Others */
 
                 Documentation(_) => {},
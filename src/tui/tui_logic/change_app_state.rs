@@ -1,14 +1,15 @@
 use crate::app::app::App;
 use crate::app::log::{LOGS, SHOULD_RECORD_LOGS};
+use crate::models::collection::CollectionTreeElement;
 use crate::models::export::ExportFormat;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
+use crate::models::settings::Setting;
 use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
-use crate::tui::utils::stateful::cookie_table::cookie_to_row;
+use crate::tui::utils::stateful::collection_runner_popup::CollectionRunnerPopup;
 use edtui::actions::MoveToEndOfLine;
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
 use strum::VariantArray;
 
 impl App<'_> {
@@ -67,27 +68,25 @@ impl App<'_> {
     }
     
     pub fn display_cookies_state(&mut self) {
-        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
-
-        self.cookies_popup.cookies_table.rows = vec![];
-
-        for cookie in local_cookie_store.read().unwrap().iter_any() {
-            self.cookies_popup.cookies_table.rows.push(cookie_to_row(cookie))
+        // Coming back from an edit keeps the already updated table and its selection
+        if self.state != AppState::EditingCookies {
+            self.cookies_popup.error = None;
+            self.tui_update_cookies_table(None);
         }
 
-        self.tui_update_cookies_table_selection();
         self.set_app_state(AppState::DisplayingCookies);
     }
 
-    #[allow(dead_code)]
     pub fn edit_cookie_state(&mut self) {
         let selection = self.cookies_popup.cookies_table.selection.unwrap();
 
         let input_text = self.cookies_popup.cookies_table.rows[selection.0][selection.1].clone();
 
+        self.cookies_popup.cookies_table.selection_text_input.reset_mode();
         self.cookies_popup.cookies_table.selection_text_input.clear();
         self.cookies_popup.cookies_table.selection_text_input.push_str(&input_text);
         self.cookies_popup.cookies_table.selection_text_input.move_cursor_line_end();
+        self.cookies_popup.error = None;
 
         self.set_app_state(AppState::EditingCookies);
     }
@@ -171,10 +170,10 @@ impl App<'_> {
     }
 
     pub fn rename_request_state(&mut self) {
-        let selected_request_index = self.collections_tree.state.selected();
+        let (collection_index, request_index) = self.get_tree_selected_request().unwrap();
 
         {
-            let selected_request = self.collections[selected_request_index[0]].requests[selected_request_index[1]].read();
+            let selected_request = self.collections[collection_index].requests[request_index].read();
             self.rename_request_input.clear();
             self.rename_request_input.push_str(&selected_request.name);
             self.rename_request_input.state.execute(MoveToEndOfLine());
@@ -182,6 +181,82 @@ impl App<'_> {
 
         self.set_app_state(AppState::RenamingRequest);
     }
+
+    pub fn create_new_folder_state(&mut self) {
+        self.new_folder_input.clear();
+        self.set_app_state(AppState::CreatingNewFolder);
+    }
+
+    pub fn delete_folder_state(&mut self) {
+        self.delete_folder_popup.state = false;
+        self.set_app_state(AppState::DeletingFolder);
+    }
+
+    pub fn rename_folder_state(&mut self) {
+        let (_, folder_path) = self.get_tree_selected_folder().unwrap();
+
+        self.rename_folder_input.clear();
+        self.rename_folder_input.push_str(folder_path.last().unwrap());
+        self.rename_folder_input.state.execute(MoveToEndOfLine());
+
+        self.set_app_state(AppState::RenamingFolder);
+    }
+
+    pub fn move_element_to_folder_state(&mut self) {
+        let folder_path = match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Folder(folder_path))) => folder_path[..folder_path.len() - 1].to_vec(),
+            Some((collection_index, CollectionTreeElement::Request(request_index))) => self.collections[collection_index].requests[request_index].read().folder.clone(),
+            Some((_, CollectionTreeElement::Collection)) | None => return
+        };
+
+        self.move_to_folder_input.clear();
+        self.move_to_folder_input.push_str(&folder_path.join("/"));
+        self.move_to_folder_input.state.execute(MoveToEndOfLine());
+
+        self.set_app_state(AppState::MovingElementToFolder);
+    }
+
+    pub fn run_collection_state(&mut self) {
+        // A run in progress is displayed back instead of starting another one
+        if self.collection_runner_popup.is_running() {
+            self.set_app_state(AppState::RunningCollection);
+            return;
+        }
+
+        let collection_index = match self.get_tree_selection() {
+            None => return,
+            Some((collection_index, _)) => collection_index
+        };
+
+        // Options are kept from a run of the same collection to the next one
+        if self.collection_runner_popup.collection_index != collection_index {
+            self.collection_runner_popup = CollectionRunnerPopup::default();
+            self.collection_runner_popup.collection_index = collection_index;
+        }
+
+        self.collection_runner_popup.selection = 0;
+        self.collection_runner_popup.error = None;
+
+        self.set_app_state(AppState::ChoosingCollectionRunOptions);
+    }
+
+    pub fn choose_collection_run_options_state(&mut self) {
+        self.set_app_state(AppState::ChoosingCollectionRunOptions);
+    }
+
+    pub fn edit_collection_run_data_file_state(&mut self) {
+        let data_file = self.collection_runner_popup.data_file.clone();
+
+        self.collection_runner_popup.text_input.reset_mode();
+        self.collection_runner_popup.text_input.clear();
+        self.collection_runner_popup.text_input.push_str(&data_file);
+        self.collection_runner_popup.text_input.move_cursor_line_end();
+        self.set_app_state(AppState::EditingCollectionRunDataFile);
+    }
+
+    pub fn running_collection_state(&mut self) {
+        self.set_app_state(AppState::RunningCollection);
+    }
     
     pub fn select_request_state(&mut self) {
         self.set_app_state(AppState::SelectedRequest);
@@ -194,6 +269,11 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn edit_response_body_filter_state(&mut self) {
+        self.set_app_state(AppState::EditingResponseBodyFilter);
+        self.response_body_filter.text_input.reset_mode();
+    }
+
     pub fn edit_request_param_state(&mut self) {
         self.set_app_state(AppState::EditingRequestParam);
         self.update_inputs();
@@ -254,11 +334,96 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn edit_request_auth_oauth2_token_url_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2TokenUrl);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_authorization_url_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2AuthorizationUrl);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_redirect_url_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2RedirectUrl);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_client_id_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2ClientId);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_client_secret_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2ClientSecret);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_scope_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2Scope);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_username_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2Username);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_password_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2Password);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth2_refresh_token_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthOAuth2RefreshToken);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_aws_sigv4_access_key_id_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthAwsSigV4AccessKeyId);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_aws_sigv4_secret_access_key_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthAwsSigV4SecretAccessKey);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_aws_sigv4_region_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthAwsSigV4Region);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_aws_sigv4_service_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthAwsSigV4Service);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_aws_sigv4_session_token_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthAwsSigV4SessionToken);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_api_key_key_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthApiKeyKey);
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_api_key_value_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestAuthApiKeyValue);
+        self.update_inputs();
+    }
+
     pub fn edit_request_header_state(&mut self) {
         self.set_app_state(AppState::EditingRequestHeader);
         self.update_inputs();
     }
 
+    pub fn edit_request_capture_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestCapture);
+        self.update_inputs();
+    }
+
     pub fn edit_request_body_table_state(&mut self) {
         let local_selected_request = self.get_selected_request_as_local();
 
@@ -310,6 +475,33 @@ impl App<'_> {
         self.set_app_state(AppState::EditingRequestMessage);
     }
 
+    pub fn edit_request_graphql_state(&mut self) {
+        self.request_param_tab = RequestParamsTabs::Graphql;
+
+        match self.graphql_editor.selection {
+            0 => self.set_app_state(AppState::EditingRequestGraphqlQuery),
+            1 => self.set_app_state(AppState::EditingRequestGraphqlVariables),
+            2 => self.set_app_state(AppState::EditingRequestGraphqlOperationName),
+            _ => {}
+        }
+
+        self.update_inputs();
+    }
+
+    pub fn edit_request_grpc_state(&mut self) {
+        self.request_param_tab = RequestParamsTabs::Grpc;
+
+        match self.grpc_editor.selection {
+            0 => self.set_app_state(AppState::EditingRequestGrpcProtoFiles),
+            1 => self.set_app_state(AppState::EditingRequestGrpcService),
+            2 => self.set_app_state(AppState::EditingRequestGrpcMethod),
+            3 => self.set_app_state(AppState::EditingRequestGrpcMessage),
+            _ => {}
+        }
+
+        self.update_inputs();
+    }
+
     pub fn edit_request_script_state(&mut self) {
         self.request_param_tab = RequestParamsTabs::Scripts;
         
@@ -325,14 +517,35 @@ impl App<'_> {
     pub fn edit_request_settings_state(&mut self) {
         self.request_settings_popup.selection = 0;
 
+        let selected_request_index = self.collections_tree.selected.unwrap();
         let local_selected_request = self.get_selected_request_as_local();
         let selected_request = local_selected_request.read();
 
-        self.request_settings_popup.settings = selected_request.settings.to_vec();
+        self.request_settings_popup.settings = self.collections[selected_request_index.0].defaults
+            .editable_settings(&selected_request.settings)
+            .to_vec();
 
         self.set_app_state(AppState::EditingRequestSettings);
     }
 
+    pub fn edit_request_setting_value_state(&mut self) {
+        let value = match &self.request_settings_popup.settings[self.request_settings_popup.selection].1 {
+            Setting::String(value) => value.clone(),
+            _ => return
+        };
+
+        self.request_settings_popup.text_input.reset_mode();
+        self.request_settings_popup.text_input.clear();
+        self.request_settings_popup.text_input.push_str(&value);
+        self.request_settings_popup.text_input.move_cursor_line_end();
+        self.set_app_state(AppState::EditingRequestSettingValue);
+    }
+
+    /// Go back to the request settings popup, without discarding its pending modifications
+    pub fn back_to_request_settings_state(&mut self) {
+        self.set_app_state(AppState::EditingRequestSettings);
+    }
+
     pub fn choose_request_export_format_state(&mut self) {
         self.export_request.selection = 0;
 
@@ -340,8 +553,10 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
 
         self.export_request.choices = match selected_request.protocol {
-            Protocol::HttpRequest(_) => ExportFormat::VARIANTS.to_vec(),
-            Protocol::WsRequest(_) => vec![ExportFormat::RustReqwest]
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => ExportFormat::VARIANTS.to_vec(),
+            Protocol::WsRequest(_) => vec![ExportFormat::RustReqwest],
+            // No export format can express a gRPC call
+            Protocol::GrpcRequest(_) => return
         };
 
         self.set_app_state(AppState::ChoosingRequestExportFormat);
@@ -350,4 +565,34 @@ impl App<'_> {
     pub fn display_request_export_state(&mut self) {
         self.set_app_state(AppState::DisplayingRequestExport);
     }
+
+    pub fn display_response_history_state(&mut self) {
+        let selected_request_index = self.collections_tree.selected.unwrap();
+
+        let mut entries = self.get_response_history(selected_request_index.0, selected_request_index.1);
+        entries.reverse();
+
+        self.response_history_popup.entries = entries;
+        self.response_history_popup.selection = 0;
+        self.response_history_popup.marked = None;
+
+        self.set_app_state(AppState::DisplayingResponseHistory);
+    }
+
+    pub fn choose_diff_environment_state(&mut self) {
+        self.diff_environment_popup.selection = 0;
+        self.diff_environment_popup.choices = self.environments
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != self.selected_environment)
+            .map(|(_, env)| env.read().name.clone())
+            .collect();
+
+        // Nothing to compare the selected environment with
+        if self.diff_environment_popup.choices.is_empty() {
+            return;
+        }
+
+        self.set_app_state(AppState::ChoosingDiffEnvironment);
+    }
 }
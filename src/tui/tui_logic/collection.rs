@@ -1,9 +1,14 @@
 use crate::app::app::App;
+use crate::app::business_logic::folder::parse_folder_path;
 use crate::models::auth::auth::Auth;
+use crate::models::collection::CollectionTreeElement;
 use crate::models::auth::basic::BasicAuth;
 use crate::models::auth::bearer_token::BearerToken;
 use crate::models::auth::digest::Digest;
 use crate::models::auth::jwt::JwtToken;
+use crate::models::auth::oauth2::OAuth2;
+use crate::models::auth::aws_sigv4::AwsSigV4;
+use crate::models::auth::api_key::ApiKey;
 use crate::models::protocol::http::body::ContentType;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::message_type::MessageType;
@@ -21,6 +26,7 @@ impl App<'_> {
         self.url_text_input.push_str(&selected_request.url_with_params_to_string());
         self.query_params_table.rows = selected_request.params.clone();
         self.headers_table.rows = selected_request.headers.clone();
+        self.captures_table.rows = selected_request.captures.clone();
 
         if !selected_request.params.is_empty() {
             let selection = self.query_params_table.selection.unwrap();
@@ -35,7 +41,7 @@ impl App<'_> {
         }
 
         match &selected_request.auth {
-            Auth::NoAuth => {
+            Auth::NoAuth | Auth::InheritFromCollection => {
                 self.auth_text_input_selection.max_selection = 0;
                 self.auth_text_input_selection.usable = false;
             }
@@ -70,6 +76,37 @@ impl App<'_> {
                 self.auth_digest_nonce_text_input.push_str(nonce);
                 self.auth_digest_opaque_text_input.push_str(opaque);
             }
+            Auth::OAuth2(OAuth2 { token_url, authorization_url, redirect_url, client_id, client_secret, scope, username, password, refresh_token, .. }) => {
+                self.auth_text_input_selection.max_selection = 10;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_oauth2_token_url_text_input.push_str(token_url);
+                self.auth_oauth2_authorization_url_text_input.push_str(authorization_url);
+                self.auth_oauth2_redirect_url_text_input.push_str(redirect_url);
+                self.auth_oauth2_client_id_text_input.push_str(client_id);
+                self.auth_oauth2_client_secret_text_input.push_str(client_secret);
+                self.auth_oauth2_scope_text_input.push_str(scope);
+                self.auth_oauth2_username_text_input.push_str(username);
+                self.auth_oauth2_password_text_input.push_str(password);
+                self.auth_oauth2_refresh_token_text_input.push_str(refresh_token);
+            }
+            Auth::AwsSigV4(AwsSigV4 { access_key_id, secret_access_key, region, service, session_token }) => {
+                self.auth_text_input_selection.max_selection = 5;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_aws_sigv4_access_key_id_text_input.push_str(access_key_id);
+                self.auth_aws_sigv4_secret_access_key_text_input.push_str(secret_access_key);
+                self.auth_aws_sigv4_region_text_input.push_str(region);
+                self.auth_aws_sigv4_service_text_input.push_str(service);
+                self.auth_aws_sigv4_session_token_text_input.push_str(session_token);
+            }
+            Auth::ApiKey(ApiKey { key, value, .. }) => {
+                self.auth_text_input_selection.max_selection = 3;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_api_key_key_text_input.push_str(key);
+                self.auth_api_key_value_text_input.push_str(value);
+            }
         }
 
         if !selected_request.headers.is_empty() {
@@ -84,6 +121,18 @@ impl App<'_> {
             self.headers_table.selection_text_input.push_str(&header_text);
         }
 
+        if !selected_request.captures.is_empty() {
+            let selection = self.captures_table.selection.unwrap();
+
+            let capture_text = match selection {
+                (x, 0) => selected_request.captures[x].data.0.clone(),
+                (x, 1) => selected_request.captures[x].data.1.clone(),
+                _ => unreachable!()
+            };
+
+            self.captures_table.selection_text_input.push_str(&capture_text);
+        }
+
         match &selected_request.protocol {
             Protocol::HttpRequest(http_request) => match &http_request.body {
                 ContentType::NoBody => {
@@ -124,6 +173,20 @@ impl App<'_> {
 
                 self.message_text_area.push_str(&content);
             }
+            Protocol::GraphqlRequest(graphql_request) => {
+                self.graphql_editor.query_text_area.push_str(&graphql_request.query);
+                self.graphql_editor.variables_text_area.push_str(&graphql_request.variables);
+
+                if let Some(operation_name) = &graphql_request.operation_name {
+                    self.graphql_editor.operation_name_text_input.push_str(operation_name);
+                }
+            }
+            Protocol::GrpcRequest(grpc_request) => {
+                self.grpc_editor.proto_files_text_input.push_str(&grpc_request.proto_files.join(", "));
+                self.grpc_editor.service_text_input.push_str(&grpc_request.service);
+                self.grpc_editor.method_text_input.push_str(&grpc_request.method);
+                self.grpc_editor.message_text_area.push_str(&grpc_request.message);
+            }
         }
 
         let pre_request_script = match &selected_request.scripts.pre_request_script {
@@ -140,13 +203,51 @@ impl App<'_> {
         self.script_console.post_request_text_area.push_str(post_request_script);
     }
 
+    /// Collection index and element highlighted in the collections tree
+    pub fn get_tree_selection(&self) -> Option<(usize, CollectionTreeElement)> {
+        let (collection_index, tree_path) = self.collections_tree.state.selected().split_first()?;
+        let element = self.collections.get(*collection_index)?.resolve_tree_path(tree_path)?;
+
+        Some((*collection_index, element))
+    }
+
+    pub fn get_tree_selected_request(&self) -> Option<(usize, usize)> {
+        match self.get_tree_selection()? {
+            (collection_index, CollectionTreeElement::Request(request_index)) => Some((collection_index, request_index)),
+            _ => None
+        }
+    }
+
+    pub fn get_tree_selected_folder(&self) -> Option<(usize, Vec<String>)> {
+        match self.get_tree_selection()? {
+            (collection_index, CollectionTreeElement::Folder(folder_path)) => Some((collection_index, folder_path)),
+            _ => None
+        }
+    }
+
+    /// Folder in which a new element is created: the highlighted folder, or the folder of the highlighted request
+    fn get_tree_selected_parent_folder(&self) -> Option<(usize, Vec<String>)> {
+        match self.get_tree_selection()? {
+            (collection_index, CollectionTreeElement::Collection) => Some((collection_index, vec![])),
+            (collection_index, CollectionTreeElement::Folder(folder_path)) => Some((collection_index, folder_path)),
+            (collection_index, CollectionTreeElement::Request(request_index)) => {
+                let folder_path = self.collections[collection_index].requests[request_index].read().folder.clone();
+                Some((collection_index, folder_path))
+            }
+        }
+    }
+
     pub fn select_request(&mut self) {
-        if self.collections_tree.state.selected().len() == 2 {
-            self.collections_tree.set_selected();
+        if let Some(selected_request_index) = self.get_tree_selected_request() {
+            self.collections_tree.set_selected(selected_request_index);
+            self.close_response_diff();
+            self.viewed_history_entry = None;
+            self.response_body_filter.clear();
             self.tui_update_request_param_tab();
             self.tui_update_request_result_tab();
             self.tui_update_query_params_selection();
             self.tui_update_headers_selection();
+            self.tui_update_captures_selection();
 
             let local_selected_request = self.get_selected_request_as_local();
             let selected_request = local_selected_request.read();
@@ -155,7 +256,7 @@ impl App<'_> {
                 Protocol::HttpRequest(_) => {
                     self.tui_update_body_table_selection();
                 }
-                Protocol::WsRequest(_) => {}
+                Protocol::WsRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {}
             }
 
             *self.received_response.lock() = true;
@@ -170,14 +271,14 @@ impl App<'_> {
     }
 
     pub fn select_request_or_expand_collection(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            1 => {
+        match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Collection | CollectionTreeElement::Folder(_))) => {
                 self.collections_tree.state.toggle_selected();
             },
-            2 => {
+            Some((_, CollectionTreeElement::Request(_))) => {
                 self.select_request();
             },
-            _ => {}
+            None => {}
         }
     }
 
@@ -185,6 +286,7 @@ impl App<'_> {
         match self.creation_popup.selection {
             0 => self.create_new_collection_state(),
             1 => self.create_new_request_state(),
+            2 => self.create_new_folder_state(),
             _ => {}
         }
     }
@@ -206,8 +308,15 @@ impl App<'_> {
         let selected_collection_index = self.new_request_popup.selected_collection;
         let protocol = self.new_request_popup.protocol.clone();
 
+        // The request is created in the highlighted folder if it belongs to the chosen collection
+        let folder = match self.get_tree_selected_parent_folder() {
+            Some((collection_index, folder_path)) if collection_index == selected_collection_index => folder_path,
+            _ => vec![]
+        };
+
         let new_request = Request {
             name: new_request_name,
+            folder,
             protocol,
             headers: DEFAULT_HEADERS.clone(),
             settings: RequestSettings::default(),
@@ -222,13 +331,24 @@ impl App<'_> {
         self.normal_state();
     }
 
+    pub fn tui_new_folder(&mut self) {
+        let new_folder_name = self.new_folder_input.to_string();
+        let (collection_index, parent_path) = self.get_tree_selected_parent_folder().unwrap_or((0, vec![]));
+
+        match self.new_folder(collection_index, &parent_path, new_folder_name) {
+            Ok(_) => {},
+            Err(_) => return
+        }
+
+        self.normal_state();
+    }
+
     pub fn delete_element(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            // Selection on a collection
-            1 => self.delete_collection_state(),
-            // Selection on a request
-            2 => self.delete_request_state(),
-            _ => {}
+        match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Collection)) => self.delete_collection_state(),
+            Some((_, CollectionTreeElement::Folder(_))) => self.delete_folder_state(),
+            Some((_, CollectionTreeElement::Request(_))) => self.delete_request_state(),
+            None => {}
         }
     }
 
@@ -244,9 +364,7 @@ impl App<'_> {
     }
 
     pub fn tui_delete_request(&mut self) {
-        let selected_request_index = self.collections_tree.state.selected().to_vec();
-        let collection_index = selected_request_index[0];
-        let request_index = selected_request_index[1];
+        let (collection_index, request_index) = self.get_tree_selected_request().unwrap();
 
         self.collections_tree.state.select(Vec::new());
         self.collections_tree.selected = None;
@@ -259,13 +377,26 @@ impl App<'_> {
         self.normal_state();
     }
 
+    pub fn tui_delete_folder(&mut self) {
+        let (collection_index, folder_path) = self.get_tree_selected_folder().unwrap();
+
+        self.collections_tree.state.select(Vec::new());
+        self.collections_tree.selected = None;
+
+        match self.delete_folder(collection_index, &folder_path) {
+            Ok(_) => {}
+            Err(_) => return,
+        }
+
+        self.normal_state();
+    }
+
     pub fn rename_element(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            // Selection on a collection
-            1 => self.rename_collection_state(),
-            // Selection on a request
-            2 => self.rename_request_state(),
-            _ => {}
+        match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Collection)) => self.rename_collection_state(),
+            Some((_, CollectionTreeElement::Folder(_))) => self.rename_folder_state(),
+            Some((_, CollectionTreeElement::Request(_))) => self.rename_request_state(),
+            None => {}
         }
     }
 
@@ -283,9 +414,9 @@ impl App<'_> {
 
     pub fn tui_rename_request(&mut self) {
         let new_request_name = self.rename_request_input.to_string();
-        let selected_request_index = self.collections_tree.state.selected();
+        let (collection_index, request_index) = self.get_tree_selected_request().unwrap();
 
-        match self.rename_request(selected_request_index[0], selected_request_index[1], new_request_name) {
+        match self.rename_request(collection_index, request_index, new_request_name) {
             Ok(_) => {}
             Err(_) => return
         }
@@ -293,35 +424,79 @@ impl App<'_> {
         self.normal_state();
     }
 
-    pub fn duplicate_element(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            // Selection on a collection
-            1 => {
-                let selected_request_index = self.collections_tree.state.selected().to_vec();
-                let collection_index = selected_request_index[0];
+    pub fn tui_rename_folder(&mut self) {
+        let new_folder_name = self.rename_folder_input.to_string();
+        let (collection_index, folder_path) = self.get_tree_selected_folder().unwrap();
+
+        match self.rename_folder(collection_index, &folder_path, new_folder_name) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.normal_state();
+    }
+
+    /// Move the selected folder or request into the typed folder, or at the collection root if it is empty
+    pub fn tui_move_element_to_folder(&mut self) {
+        let new_folder_path = parse_folder_path(&self.move_to_folder_input.to_string());
+
+        let (collection_index, tree_path) = match self.get_tree_selection() {
+            Some((collection_index, CollectionTreeElement::Folder(folder_path))) => {
+                match self.move_folder(collection_index, &folder_path, &new_folder_path) {
+                    Ok(_) => {}
+                    Err(_) => return
+                }
+
+                let moved_folder_path = [new_folder_path, vec![folder_path.last().unwrap().clone()]].concat();
+                (collection_index, self.collections[collection_index].tree_path_of_folder(&moved_folder_path))
+            },
+            Some((collection_index, CollectionTreeElement::Request(request_index))) => {
+                match self.move_request_to_folder(collection_index, request_index, &new_folder_path) {
+                    Ok(_) => {}
+                    Err(_) => return
+                }
+
+                (collection_index, self.collections[collection_index].tree_path_of_request(request_index))
+            },
+            Some((_, CollectionTreeElement::Collection)) | None => return
+        };
+
+        // Update the selection in order to move with the element, its new parents are opened to keep it visible
+        let selection = [vec![collection_index], tree_path].concat();
+
+        for depth in 1..selection.len() {
+            self.collections_tree.state.open(selection[..depth].to_vec());
+        }
+
+        self.collections_tree.state.select(selection);
 
+        self.normal_state();
+    }
+
+    pub fn duplicate_element(&mut self) {
+        match self.get_tree_selection() {
+            Some((collection_index, CollectionTreeElement::Collection)) => {
                 match self.duplicate_collection(collection_index) {
                     Ok(_) => {}
                     Err(_) => return
                 }
             }
-            // Selection on a request
-            2 => {
-                let selected_request_index = self.collections_tree.state.selected().to_vec();
-
-                match self.duplicate_request(selected_request_index[0], selected_request_index[1]) {
+            Some((collection_index, CollectionTreeElement::Request(request_index))) => {
+                match self.duplicate_request(collection_index, request_index) {
                     Ok(_) => {}
                     Err(_) => return
                 }
             }
-            _ => {}
+            // Folders cannot be duplicated
+            Some((_, CollectionTreeElement::Folder(_))) | None => {}
         }
     }
     pub fn tui_move_element_up(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            1 => self.tui_move_collection_up(),
-            2 => self.tui_move_request_up(),
-            _ => {}
+        match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Collection)) => self.tui_move_collection_up(),
+            Some((collection_index, CollectionTreeElement::Folder(folder_path))) => self.tui_move_folder(collection_index, &folder_path, -1),
+            Some((collection_index, CollectionTreeElement::Request(request_index))) => self.tui_move_request(collection_index, request_index, -1),
+            None => {}
         }
     }
 
@@ -346,34 +521,48 @@ impl App<'_> {
         self.update_collections_last_position();
     }
 
-    pub fn tui_move_request_up(&mut self) {
-        let mut selection = self.collections_tree.state.selected().to_vec();
-
-        // Cannot decrement selection further
-        if selection[1] == 0 {
-            return;
-        }
+    /// Swap the request with the previous (offset -1) or next (offset 1) request of its folder
+    pub fn tui_move_request(&mut self, collection_index: usize, request_index: usize, offset: isize) {
+        let collection = &mut self.collections[collection_index];
+        let folder_path = collection.requests[request_index].read().folder.clone();
+        let folder_requests_indexes = collection.get_folder_requests_indexes(&folder_path);
 
-        // Retrieve the request
-        let request = self.collections[selection[0]].requests.remove(selection[1]);
+        let position = folder_requests_indexes.iter().position(|index| *index == request_index).unwrap();
 
-        // Decrement selection
-        selection[1] -= 1;
+        // Cannot move the request further
+        let new_request_index = match position.checked_add_signed(offset) {
+            Some(new_position) if new_position < folder_requests_indexes.len() => folder_requests_indexes[new_position],
+            _ => return
+        };
 
-        // Insert the request at its new index
-        self.collections[selection[0]].requests.insert(selection[1], request);
+        collection.requests.swap(request_index, new_request_index);
 
         // Update the selection in order to move with the element
-        self.collections_tree.state.select(selection.clone());
+        let selection = [vec![collection_index], collection.tree_path_of_request(new_request_index)].concat();
+        self.collections_tree.state.select(selection);
 
-        self.save_collection_to_file(selection[0]);
+        self.save_collection_to_file(collection_index);
+    }
+
+    /// Swap the folder with its previous (offset -1) or next (offset 1) sibling folder
+    pub fn tui_move_folder(&mut self, collection_index: usize, folder_path: &[String], offset: isize) {
+        let new_folder_position = match self.move_folder_among_siblings(collection_index, folder_path, offset) {
+            Ok(Some(new_folder_position)) => new_folder_position,
+            Ok(None) | Err(_) => return
+        };
+
+        // Update the selection in order to move with the element
+        let mut selection = self.collections_tree.state.selected().to_vec();
+        *selection.last_mut().unwrap() = new_folder_position;
+        self.collections_tree.state.select(selection);
     }
 
     pub fn tui_move_element_down(&mut self) {
-        match self.collections_tree.state.selected().len() {
-            1 => self.tui_move_collection_down(),
-            2 => self.tui_move_request_down(),
-            _ => {}
+        match self.get_tree_selection() {
+            Some((_, CollectionTreeElement::Collection)) => self.tui_move_collection_down(),
+            Some((collection_index, CollectionTreeElement::Folder(folder_path))) => self.tui_move_folder(collection_index, &folder_path, 1),
+            Some((collection_index, CollectionTreeElement::Request(request_index))) => self.tui_move_request(collection_index, request_index, 1),
+            None => {}
         }
     }
 
@@ -397,27 +586,4 @@ impl App<'_> {
 
         self.update_collections_last_position();
     }
-
-    pub fn tui_move_request_down(&mut self) {
-        let mut selection = self.collections_tree.state.selected().to_vec();
-
-        // Cannot increment selection further
-        if selection[1] == self.collections[selection[0]].requests.len() - 1 {
-            return;
-        }
-
-        // Retrieve the request
-        let request = self.collections[selection[0]].requests.remove(selection[1]);
-
-        // Increment selection
-        selection[1] += 1;
-
-        // Insert the request at its new index
-        self.collections[selection[0]].requests.insert(selection[1], request);
-
-        // Update the selection in order to move with the element
-        self.collections_tree.state.select(selection.clone());
-
-        self.save_collection_to_file(selection[0]);
-    }
-}
\ No newline at end of file
+}
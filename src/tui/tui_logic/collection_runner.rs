@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use tokio::task;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::collection_runner::CollectionRunResult;
+use crate::app::business_logic::request::grpc::send::send_grpc_request;
+use crate::app::business_logic::request::http::send::send_http_request;
+use crate::app::business_logic::request::ws::send::send_ws_request;
+use crate::models::auth::auth::Auth;
+use crate::models::protocol::protocol::Protocol;
+
+impl App<'_> {
+    pub fn tui_start_collection_run(&mut self) {
+        let collection_index = self.collection_runner_popup.collection_index;
+        let options = self.collection_runner_popup.to_options();
+
+        let run = match self.new_collection_run(collection_index, &options) {
+            Ok(run) => run,
+            Err(error) => {
+                self.collection_runner_popup.error = Some(error.to_string());
+                return;
+            }
+        };
+
+        info!("Running collection \"{}\"", self.collections[collection_index].name);
+
+        self.collection_runner_popup.run = Some(run);
+        self.collection_runner_popup.is_request_pending = false;
+        self.collection_runner_popup.last_request_end = None;
+        self.collection_runner_popup.error = None;
+        *self.collection_runner_popup.pending_result.lock() = None;
+
+        self.running_collection_state();
+    }
+
+    pub fn tui_modify_collection_run_data_file(&mut self) {
+        self.collection_runner_popup.data_file = self.collection_runner_popup.text_input.to_string();
+        self.choose_collection_run_options_state();
+    }
+
+    /// Cancel the run if it is in progress, close the popup otherwise
+    pub fn tui_stop_collection_run(&mut self) {
+        let collection_runner_popup = &mut self.collection_runner_popup;
+
+        if let Some(run) = &mut collection_runner_popup.run && !run.is_finished() {
+            info!("Collection run canceled");
+            run.is_canceled = true;
+            return;
+        }
+
+        // The result of a request still pending is awaited before the run is dropped
+        if !collection_runner_popup.is_request_pending {
+            collection_runner_popup.run = None;
+        }
+
+        self.normal_state();
+    }
+
+    /// Called at every tick, sends the next request of the run once the previous one is done and the delay has elapsed
+    pub fn tui_collection_run_tick(&mut self) {
+        let collection_runner_popup = &mut self.collection_runner_popup;
+
+        let run = match &mut collection_runner_popup.run {
+            None => return,
+            Some(run) => run
+        };
+
+        if collection_runner_popup.is_request_pending {
+            let pending_result = collection_runner_popup.pending_result.lock().take();
+
+            match pending_result {
+                None => return,
+                Some(result) => {
+                    run.push_result(result);
+                    collection_runner_popup.is_request_pending = false;
+                    collection_runner_popup.last_request_end = Some(Instant::now());
+                }
+            }
+        }
+
+        if run.is_finished() {
+            return;
+        }
+
+        if let Some(last_request_end) = collection_runner_popup.last_request_end && last_request_end.elapsed() < run.delay {
+            return;
+        }
+
+        let collection_index = run.collection_index;
+        let (iteration, request_index) = run.current();
+        let iteration_values = run.iteration_values(iteration);
+
+        let local_request = self.collections[collection_index].requests[request_index].clone();
+        let collection_defaults = self.collections[collection_index].defaults.clone();
+
+        let mut request_preparer = self.request_preparer();
+        request_preparer.iteration_values = iteration_values;
+
+        let local_pending_result = Arc::clone(&self.collection_runner_popup.pending_result);
+
+        self.collection_runner_popup.is_request_pending = true;
+
+        task::spawn(async move {
+            // Prepared as a copy so that it is not locked across await points
+            let mut request = local_request.read().clone();
+            let request_name = request.name.clone();
+
+            /* PRE-REQUEST SCRIPT */
+
+            let prepared_request = request_preparer.prepare_request(Some((collection_index, request_index)), &mut request, &collection_defaults).await;
+
+            local_request.write().keep_prepared_state(&request);
+
+            let mut prepared_request = match prepared_request {
+                Ok(prepared_request) => prepared_request,
+                Err(prepare_request_error) => {
+                    local_request.write().response.status_code = Some(prepare_request_error.to_string());
+                    *local_pending_result.lock() = Some(CollectionRunResult::from_error(iteration, request_name, prepare_request_error.to_string()));
+                    return;
+                }
+            };
+
+            let response_history_recorder = request_preparer.new_response_history_recorder(collection_index, &request, &mut prepared_request);
+            let protocol = request.protocol.clone();
+
+            let local_env = request_preparer.get_selected_env_as_local();
+            let local_should_refresh_scrollbars = Arc::clone(&request_preparer.received_response);
+
+            /* SEND REQUEST */
+
+            let response = match protocol {
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => send_http_request(prepared_request, local_request.clone(), &collection_defaults, &local_env, local_should_refresh_scrollbars.clone()).await,
+                Protocol::WsRequest(_) => send_ws_request(prepared_request, local_request.clone(), &collection_defaults, &local_env, local_should_refresh_scrollbars.clone()).await,
+                Protocol::GrpcRequest(_) => send_grpc_request(prepared_request, local_request.clone(), &collection_defaults, &local_env).await
+            };
+
+            let result = match response {
+                Ok(response) => {
+                    if let Some(response_history_recorder) = &response_history_recorder {
+                        response_history_recorder.record(&response).await;
+                    }
+
+                    let mut request = local_request.write();
+
+                    match &mut request.auth {
+                        Auth::Digest(digest) => digest.update_from_www_authenticate_header(&response.headers),
+                        Auth::InheritFromCollection => if let Auth::Digest(digest) = &mut *collection_defaults.auth.write() {
+                            digest.update_from_www_authenticate_header(&response.headers);
+                        },
+                        _ => {}
+                    }
+
+                    let result = CollectionRunResult::from_response(iteration, request_name, &protocol, &response, request.test_results.clone());
+                    request.response = response;
+
+                    *local_should_refresh_scrollbars.lock() = true;
+                    result
+                },
+                Err(response_error) => {
+                    local_request.write().response.status_code = Some(response_error.to_string());
+                    CollectionRunResult::from_error(iteration, request_name, response_error.to_string())
+                }
+            };
+
+            *local_pending_result.lock() = Some(result);
+        });
+    }
+}
@@ -13,10 +13,10 @@ use crate::models::request::KeyValue;
 impl App<'_> {
     pub fn tui_next_environment(&mut self) {
         if self.selected_environment + 1 < self.environments.len() {
-            self.selected_environment += 1;
+            self.select_environment(self.selected_environment + 1);
         }
         else {
-            self.selected_environment = 0;
+            self.select_environment(0);
         }
     }
 
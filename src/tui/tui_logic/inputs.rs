@@ -5,10 +5,15 @@ use crate::app::files::key_bindings::{TextAreaMode, KEY_BINDINGS};
 impl App<'_> {
     pub fn reset_inputs_mode(&mut self) {
         self.env_editor_table.selection_text_input.reset_mode();
+        self.cookies_popup.cookies_table.selection_text_input.reset_mode();
         self.new_collection_input.reset_mode();
         self.new_request_popup.text_input.reset_mode();
+        self.request_settings_popup.text_input.reset_mode();
         self.rename_collection_input.reset_mode();
         self.rename_request_input.reset_mode();
+        self.new_folder_input.reset_mode();
+        self.rename_folder_input.reset_mode();
+        self.move_to_folder_input.reset_mode();
         self.url_text_input.reset_mode();
         self.query_params_table.selection_text_input.reset_mode();
         self.auth_basic_username_text_input.reset_mode();
@@ -22,21 +27,51 @@ impl App<'_> {
         self.auth_digest_realm_text_input.reset_mode();
         self.auth_digest_nonce_text_input.reset_mode();
         self.auth_digest_opaque_text_input.reset_mode();
+        self.auth_oauth2_token_url_text_input.reset_mode();
+        self.auth_oauth2_authorization_url_text_input.reset_mode();
+        self.auth_oauth2_redirect_url_text_input.reset_mode();
+        self.auth_oauth2_client_id_text_input.reset_mode();
+        self.auth_oauth2_client_secret_text_input.reset_mode();
+        self.auth_oauth2_scope_text_input.reset_mode();
+        self.auth_oauth2_username_text_input.reset_mode();
+        self.auth_oauth2_password_text_input.reset_mode();
+        self.auth_oauth2_refresh_token_text_input.reset_mode();
+        self.auth_aws_sigv4_access_key_id_text_input.reset_mode();
+        self.auth_aws_sigv4_secret_access_key_text_input.reset_mode();
+        self.auth_aws_sigv4_region_text_input.reset_mode();
+        self.auth_aws_sigv4_service_text_input.reset_mode();
+        self.auth_aws_sigv4_session_token_text_input.reset_mode();
+        self.auth_api_key_key_text_input.reset_mode();
+        self.auth_api_key_value_text_input.reset_mode();
         self.headers_table.selection_text_input.reset_mode();
+        self.captures_table.selection_text_input.reset_mode();
         self.body_text_area.reset_mode();
         self.body_form_table.selection_text_input.reset_mode();
         self.body_file_text_input.reset_mode();
         self.message_text_area.reset_mode();
+        self.graphql_editor.query_text_area.reset_mode();
+        self.graphql_editor.variables_text_area.reset_mode();
+        self.graphql_editor.operation_name_text_input.reset_mode();
+        self.grpc_editor.proto_files_text_input.reset_mode();
+        self.grpc_editor.service_text_input.reset_mode();
+        self.grpc_editor.method_text_input.reset_mode();
+        self.grpc_editor.message_text_area.reset_mode();
         self.script_console.pre_request_text_area.reset_mode();
         self.script_console.post_request_text_area.reset_mode();
+        self.response_body_filter.text_input.reset_mode();
     }
 
     pub fn clear_inputs(&mut self) {
         self.env_editor_table.selection_text_input.clear();
+        self.cookies_popup.cookies_table.selection_text_input.clear();
         self.new_collection_input.clear();
         self.new_request_popup.text_input.clear();
+        self.request_settings_popup.text_input.clear();
         self.rename_collection_input.clear();
         self.rename_request_input.clear();
+        self.new_folder_input.clear();
+        self.rename_folder_input.clear();
+        self.move_to_folder_input.clear();
         self.url_text_input.clear();
         self.query_params_table.selection_text_input.clear();
         self.auth_basic_username_text_input.clear();
@@ -50,21 +85,50 @@ impl App<'_> {
         self.auth_digest_realm_text_input.clear();
         self.auth_digest_nonce_text_input.clear();
         self.auth_digest_opaque_text_input.clear();
+        self.auth_oauth2_token_url_text_input.clear();
+        self.auth_oauth2_authorization_url_text_input.clear();
+        self.auth_oauth2_redirect_url_text_input.clear();
+        self.auth_oauth2_client_id_text_input.clear();
+        self.auth_oauth2_client_secret_text_input.clear();
+        self.auth_oauth2_scope_text_input.clear();
+        self.auth_oauth2_username_text_input.clear();
+        self.auth_oauth2_password_text_input.clear();
+        self.auth_oauth2_refresh_token_text_input.clear();
+        self.auth_aws_sigv4_access_key_id_text_input.clear();
+        self.auth_aws_sigv4_secret_access_key_text_input.clear();
+        self.auth_aws_sigv4_region_text_input.clear();
+        self.auth_aws_sigv4_service_text_input.clear();
+        self.auth_aws_sigv4_session_token_text_input.clear();
+        self.auth_api_key_key_text_input.clear();
+        self.auth_api_key_value_text_input.clear();
         self.headers_table.selection_text_input.clear();
+        self.captures_table.selection_text_input.clear();
         self.body_text_area.clear();
         self.body_form_table.selection_text_input.clear();
         self.body_file_text_input.clear();
         self.message_text_area.clear();
+        self.graphql_editor.query_text_area.clear();
+        self.graphql_editor.variables_text_area.clear();
+        self.graphql_editor.operation_name_text_input.clear();
+        self.grpc_editor.proto_files_text_input.clear();
+        self.grpc_editor.service_text_input.clear();
+        self.grpc_editor.method_text_input.clear();
+        self.grpc_editor.message_text_area.clear();
         self.script_console.pre_request_text_area.clear();
         self.script_console.post_request_text_area.clear();
     }
 
     pub fn reset_cursors(&mut self) {
         self.env_editor_table.selection_text_input.reset_cursor_position();
+        self.cookies_popup.cookies_table.selection_text_input.reset_cursor_position();
         self.new_collection_input.reset_cursor_position();
         self.new_request_popup.text_input.reset_cursor_position();
+        self.request_settings_popup.text_input.reset_cursor_position();
         self.rename_collection_input.reset_cursor_position();
         self.rename_request_input.reset_cursor_position();
+        self.new_folder_input.reset_cursor_position();
+        self.rename_folder_input.reset_cursor_position();
+        self.move_to_folder_input.reset_cursor_position();
         self.url_text_input.reset_cursor_position();
         self.query_params_table.selection_text_input.reset_cursor_position();
         self.auth_basic_username_text_input.reset_cursor_position();
@@ -78,19 +142,49 @@ impl App<'_> {
         self.auth_digest_realm_text_input.reset_cursor_position();
         self.auth_digest_nonce_text_input.reset_cursor_position();
         self.auth_digest_opaque_text_input.reset_cursor_position();
+        self.auth_oauth2_token_url_text_input.reset_cursor_position();
+        self.auth_oauth2_authorization_url_text_input.reset_cursor_position();
+        self.auth_oauth2_redirect_url_text_input.reset_cursor_position();
+        self.auth_oauth2_client_id_text_input.reset_cursor_position();
+        self.auth_oauth2_client_secret_text_input.reset_cursor_position();
+        self.auth_oauth2_scope_text_input.reset_cursor_position();
+        self.auth_oauth2_username_text_input.reset_cursor_position();
+        self.auth_oauth2_password_text_input.reset_cursor_position();
+        self.auth_oauth2_refresh_token_text_input.reset_cursor_position();
+        self.auth_aws_sigv4_access_key_id_text_input.reset_cursor_position();
+        self.auth_aws_sigv4_secret_access_key_text_input.reset_cursor_position();
+        self.auth_aws_sigv4_region_text_input.reset_cursor_position();
+        self.auth_aws_sigv4_service_text_input.reset_cursor_position();
+        self.auth_aws_sigv4_session_token_text_input.reset_cursor_position();
+        self.auth_api_key_key_text_input.reset_cursor_position();
+        self.auth_api_key_value_text_input.reset_cursor_position();
         self.headers_table.selection_text_input.reset_cursor_position();
+        self.captures_table.selection_text_input.reset_cursor_position();
         self.body_text_area.reset_cursor_position();
         self.body_form_table.selection_text_input.reset_cursor_position();
         self.body_file_text_input.reset_cursor_position();
         self.message_text_area.reset_cursor_position();
+        self.graphql_editor.query_text_area.reset_cursor_position();
+        self.graphql_editor.variables_text_area.reset_cursor_position();
+        self.graphql_editor.operation_name_text_input.reset_cursor_position();
+        self.grpc_editor.proto_files_text_input.reset_cursor_position();
+        self.grpc_editor.service_text_input.reset_cursor_position();
+        self.grpc_editor.method_text_input.reset_cursor_position();
+        self.grpc_editor.message_text_area.reset_cursor_position();
         self.script_console.pre_request_text_area.reset_cursor_position();
         self.script_console.post_request_text_area.reset_cursor_position();
+        self.response_body_filter.text_input.reset_cursor_position();
 
         self.env_editor_table.selection_text_input.reset_selection();
+        self.cookies_popup.cookies_table.selection_text_input.reset_selection();
         self.new_collection_input.reset_selection();
         self.new_request_popup.text_input.reset_selection();
+        self.request_settings_popup.text_input.reset_selection();
         self.rename_collection_input.reset_selection();
         self.rename_request_input.reset_selection();
+        self.new_folder_input.reset_selection();
+        self.rename_folder_input.reset_selection();
+        self.move_to_folder_input.reset_selection();
         self.url_text_input.reset_selection();
         self.query_params_table.selection_text_input.reset_selection();
         self.auth_basic_username_text_input.reset_selection();
@@ -104,13 +198,38 @@ impl App<'_> {
         self.auth_digest_realm_text_input.reset_selection();
         self.auth_digest_nonce_text_input.reset_selection();
         self.auth_digest_opaque_text_input.reset_selection();
+        self.auth_oauth2_token_url_text_input.reset_selection();
+        self.auth_oauth2_authorization_url_text_input.reset_selection();
+        self.auth_oauth2_redirect_url_text_input.reset_selection();
+        self.auth_oauth2_client_id_text_input.reset_selection();
+        self.auth_oauth2_client_secret_text_input.reset_selection();
+        self.auth_oauth2_scope_text_input.reset_selection();
+        self.auth_oauth2_username_text_input.reset_selection();
+        self.auth_oauth2_password_text_input.reset_selection();
+        self.auth_oauth2_refresh_token_text_input.reset_selection();
+        self.auth_aws_sigv4_access_key_id_text_input.reset_selection();
+        self.auth_aws_sigv4_secret_access_key_text_input.reset_selection();
+        self.auth_aws_sigv4_region_text_input.reset_selection();
+        self.auth_aws_sigv4_service_text_input.reset_selection();
+        self.auth_aws_sigv4_session_token_text_input.reset_selection();
+        self.auth_api_key_key_text_input.reset_selection();
+        self.auth_api_key_value_text_input.reset_selection();
         self.headers_table.selection_text_input.reset_selection();
+        self.captures_table.selection_text_input.reset_selection();
         self.body_text_area.reset_selection();
         self.body_form_table.selection_text_input.reset_selection();
         self.body_file_text_input.reset_selection();
         self.message_text_area.reset_selection();
+        self.graphql_editor.query_text_area.reset_selection();
+        self.graphql_editor.variables_text_area.reset_selection();
+        self.graphql_editor.operation_name_text_input.reset_selection();
+        self.grpc_editor.proto_files_text_input.reset_selection();
+        self.grpc_editor.service_text_input.reset_selection();
+        self.grpc_editor.method_text_input.reset_selection();
+        self.grpc_editor.message_text_area.reset_selection();
         self.script_console.pre_request_text_area.reset_selection();
         self.script_console.post_request_text_area.reset_selection();
+        self.response_body_filter.text_input.reset_selection();
     }
 
     pub fn update_text_inputs_handler(&mut self) {
@@ -120,10 +239,15 @@ impl App<'_> {
         };
 
         self.env_editor_table.selection_text_input.default_mode = EditorMode::Insert;
+        self.cookies_popup.cookies_table.selection_text_input.default_mode = EditorMode::Insert;
         self.new_collection_input.default_mode = default_mode;
         self.new_request_popup.text_input.default_mode = default_mode;
+        self.request_settings_popup.text_input.default_mode = default_mode;
         self.rename_collection_input.default_mode = default_mode;
         self.rename_request_input.default_mode = default_mode;
+        self.new_folder_input.default_mode = default_mode;
+        self.rename_folder_input.default_mode = default_mode;
+        self.move_to_folder_input.default_mode = default_mode;
         self.url_text_input.default_mode = default_mode;
         self.query_params_table.selection_text_input.default_mode = EditorMode::Insert;
         self.auth_basic_username_text_input.default_mode = default_mode;
@@ -137,21 +261,51 @@ impl App<'_> {
         self.auth_digest_realm_text_input.default_mode = default_mode;
         self.auth_digest_nonce_text_input.default_mode = default_mode;
         self.auth_digest_opaque_text_input.default_mode = default_mode;
+        self.auth_oauth2_token_url_text_input.default_mode = default_mode;
+        self.auth_oauth2_authorization_url_text_input.default_mode = default_mode;
+        self.auth_oauth2_redirect_url_text_input.default_mode = default_mode;
+        self.auth_oauth2_client_id_text_input.default_mode = default_mode;
+        self.auth_oauth2_client_secret_text_input.default_mode = default_mode;
+        self.auth_oauth2_scope_text_input.default_mode = default_mode;
+        self.auth_oauth2_username_text_input.default_mode = default_mode;
+        self.auth_oauth2_password_text_input.default_mode = default_mode;
+        self.auth_oauth2_refresh_token_text_input.default_mode = default_mode;
+        self.auth_aws_sigv4_access_key_id_text_input.default_mode = default_mode;
+        self.auth_aws_sigv4_secret_access_key_text_input.default_mode = default_mode;
+        self.auth_aws_sigv4_region_text_input.default_mode = default_mode;
+        self.auth_aws_sigv4_service_text_input.default_mode = default_mode;
+        self.auth_aws_sigv4_session_token_text_input.default_mode = default_mode;
+        self.auth_api_key_key_text_input.default_mode = default_mode;
+        self.auth_api_key_value_text_input.default_mode = default_mode;
         self.headers_table.selection_text_input.default_mode = EditorMode::Insert;
+        self.captures_table.selection_text_input.default_mode = EditorMode::Insert;
         self.body_text_area.default_mode = default_mode;
         self.body_form_table.selection_text_input.default_mode = EditorMode::Insert;
         self.body_file_text_input.default_mode = default_mode;
         self.message_text_area.default_mode = default_mode;
+        self.graphql_editor.query_text_area.default_mode = default_mode;
+        self.graphql_editor.variables_text_area.default_mode = default_mode;
+        self.graphql_editor.operation_name_text_input.default_mode = default_mode;
+        self.grpc_editor.proto_files_text_input.default_mode = default_mode;
+        self.grpc_editor.service_text_input.default_mode = default_mode;
+        self.grpc_editor.method_text_input.default_mode = default_mode;
+        self.grpc_editor.message_text_area.default_mode = default_mode;
         self.script_console.pre_request_text_area.default_mode = default_mode;
         self.script_console.post_request_text_area.default_mode = default_mode;
+        self.response_body_filter.text_input.default_mode = default_mode;
 
         self.reset_inputs_mode();
 
         self.env_editor_table.selection_text_input.is_single_line = true;
+        self.cookies_popup.cookies_table.selection_text_input.is_single_line = true;
         self.new_collection_input.is_single_line = true;
         self.new_request_popup.text_input.is_single_line = true;
+        self.request_settings_popup.text_input.is_single_line = true;
         self.rename_collection_input.is_single_line = true;
         self.rename_request_input.is_single_line = true;
+        self.new_folder_input.is_single_line = true;
+        self.rename_folder_input.is_single_line = true;
+        self.move_to_folder_input.is_single_line = true;
         self.url_text_input.is_single_line = true;
         self.query_params_table.selection_text_input.is_single_line = true;
         self.auth_basic_username_text_input.is_single_line = true;
@@ -165,24 +319,56 @@ impl App<'_> {
         self.auth_digest_realm_text_input.is_single_line = true;
         self.auth_digest_nonce_text_input.is_single_line = true;
         self.auth_digest_opaque_text_input.is_single_line = true;
+        self.auth_oauth2_token_url_text_input.is_single_line = true;
+        self.auth_oauth2_authorization_url_text_input.is_single_line = true;
+        self.auth_oauth2_redirect_url_text_input.is_single_line = true;
+        self.auth_oauth2_client_id_text_input.is_single_line = true;
+        self.auth_oauth2_client_secret_text_input.is_single_line = true;
+        self.auth_oauth2_scope_text_input.is_single_line = true;
+        self.auth_oauth2_username_text_input.is_single_line = true;
+        self.auth_oauth2_password_text_input.is_single_line = true;
+        self.auth_oauth2_refresh_token_text_input.is_single_line = true;
+        self.auth_aws_sigv4_access_key_id_text_input.is_single_line = true;
+        self.auth_aws_sigv4_secret_access_key_text_input.is_single_line = true;
+        self.auth_aws_sigv4_region_text_input.is_single_line = true;
+        self.auth_aws_sigv4_service_text_input.is_single_line = true;
+        self.auth_aws_sigv4_session_token_text_input.is_single_line = true;
+        self.auth_api_key_key_text_input.is_single_line = true;
+        self.auth_api_key_value_text_input.is_single_line = true;
         self.headers_table.selection_text_input.is_single_line = true;
+        self.captures_table.selection_text_input.is_single_line = true;
         self.body_text_area.is_single_line = false;
         self.body_form_table.selection_text_input.is_single_line = true;
         self.body_file_text_input.is_single_line = true;
         self.message_text_area.is_single_line = false;
+        self.graphql_editor.query_text_area.is_single_line = false;
+        self.graphql_editor.variables_text_area.is_single_line = false;
+        self.graphql_editor.operation_name_text_input.is_single_line = true;
+        self.grpc_editor.proto_files_text_input.is_single_line = true;
+        self.grpc_editor.service_text_input.is_single_line = true;
+        self.grpc_editor.method_text_input.is_single_line = true;
+        self.grpc_editor.message_text_area.is_single_line = false;
         self.script_console.pre_request_text_area.is_single_line = false;
         self.script_console.post_request_text_area.is_single_line = false;
+        self.response_body_filter.text_input.is_single_line = true;
 
         self.env_editor_table.selection_text_input.insert_mode_only = true;
+        self.cookies_popup.cookies_table.selection_text_input.insert_mode_only = true;
         self.query_params_table.selection_text_input.insert_mode_only = true;
         self.headers_table.selection_text_input.insert_mode_only = true;
+        self.captures_table.selection_text_input.insert_mode_only = true;
         self.body_form_table.selection_text_input.insert_mode_only = true;
 
         self.env_editor_table.selection_text_input.update_handler();
+        self.cookies_popup.cookies_table.selection_text_input.update_handler();
         self.new_collection_input.update_handler();
         self.new_request_popup.text_input.update_handler();
+        self.request_settings_popup.text_input.update_handler();
         self.rename_collection_input.update_handler();
         self.rename_request_input.update_handler();
+        self.new_folder_input.update_handler();
+        self.rename_folder_input.update_handler();
+        self.move_to_folder_input.update_handler();
         self.url_text_input.update_handler();
         self.query_params_table.selection_text_input.update_handler();
         self.auth_basic_username_text_input.update_handler();
@@ -196,12 +382,37 @@ impl App<'_> {
         self.auth_digest_realm_text_input.update_handler();
         self.auth_digest_nonce_text_input.update_handler();
         self.auth_digest_opaque_text_input.update_handler();
+        self.auth_oauth2_token_url_text_input.update_handler();
+        self.auth_oauth2_authorization_url_text_input.update_handler();
+        self.auth_oauth2_redirect_url_text_input.update_handler();
+        self.auth_oauth2_client_id_text_input.update_handler();
+        self.auth_oauth2_client_secret_text_input.update_handler();
+        self.auth_oauth2_scope_text_input.update_handler();
+        self.auth_oauth2_username_text_input.update_handler();
+        self.auth_oauth2_password_text_input.update_handler();
+        self.auth_oauth2_refresh_token_text_input.update_handler();
+        self.auth_aws_sigv4_access_key_id_text_input.update_handler();
+        self.auth_aws_sigv4_secret_access_key_text_input.update_handler();
+        self.auth_aws_sigv4_region_text_input.update_handler();
+        self.auth_aws_sigv4_service_text_input.update_handler();
+        self.auth_aws_sigv4_session_token_text_input.update_handler();
+        self.auth_api_key_key_text_input.update_handler();
+        self.auth_api_key_value_text_input.update_handler();
         self.headers_table.selection_text_input.update_handler();
+        self.captures_table.selection_text_input.update_handler();
         self.body_text_area.update_handler();
         self.body_form_table.selection_text_input.update_handler();
         self.body_file_text_input.update_handler();
         self.message_text_area.update_handler();
+        self.graphql_editor.query_text_area.update_handler();
+        self.graphql_editor.variables_text_area.update_handler();
+        self.graphql_editor.operation_name_text_input.update_handler();
+        self.grpc_editor.proto_files_text_input.update_handler();
+        self.grpc_editor.service_text_input.update_handler();
+        self.grpc_editor.method_text_input.update_handler();
+        self.grpc_editor.message_text_area.update_handler();
         self.script_console.pre_request_text_area.update_handler();
         self.script_console.post_request_text_area.update_handler();
+        self.response_body_filter.text_input.update_handler();
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 pub mod change_app_state;
 mod collection;
+mod collection_runner;
 mod request;
 mod param_tabs;
 mod result_tabs;
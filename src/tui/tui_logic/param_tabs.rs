@@ -12,7 +12,8 @@ impl App<'_> {
                 RequestParamsTabs::QueryParams => RequestParamsTabs::Auth,
                 RequestParamsTabs::Auth => RequestParamsTabs::Headers,
                 RequestParamsTabs::Headers => RequestParamsTabs::Body,
-                RequestParamsTabs::Body => RequestParamsTabs::Scripts,
+                RequestParamsTabs::Body => RequestParamsTabs::Captures,
+                RequestParamsTabs::Captures => RequestParamsTabs::Scripts,
                 RequestParamsTabs::Scripts => RequestParamsTabs::QueryParams,
                 _ => unreachable!()
             },
@@ -20,7 +21,26 @@ impl App<'_> {
                 RequestParamsTabs::QueryParams => RequestParamsTabs::Auth,
                 RequestParamsTabs::Auth => RequestParamsTabs::Headers,
                 RequestParamsTabs::Headers => RequestParamsTabs::Message,
-                RequestParamsTabs::Message => RequestParamsTabs::Scripts,
+                RequestParamsTabs::Message => RequestParamsTabs::Captures,
+                RequestParamsTabs::Captures => RequestParamsTabs::Scripts,
+                RequestParamsTabs::Scripts => RequestParamsTabs::QueryParams,
+                _ => unreachable!()
+            },
+            Protocol::GraphqlRequest(_) => match self.request_param_tab {
+                RequestParamsTabs::QueryParams => RequestParamsTabs::Auth,
+                RequestParamsTabs::Auth => RequestParamsTabs::Headers,
+                RequestParamsTabs::Headers => RequestParamsTabs::Graphql,
+                RequestParamsTabs::Graphql => RequestParamsTabs::Captures,
+                RequestParamsTabs::Captures => RequestParamsTabs::Scripts,
+                RequestParamsTabs::Scripts => RequestParamsTabs::QueryParams,
+                _ => unreachable!()
+            },
+            Protocol::GrpcRequest(_) => match self.request_param_tab {
+                RequestParamsTabs::QueryParams => RequestParamsTabs::Auth,
+                RequestParamsTabs::Auth => RequestParamsTabs::Headers,
+                RequestParamsTabs::Headers => RequestParamsTabs::Grpc,
+                RequestParamsTabs::Grpc => RequestParamsTabs::Captures,
+                RequestParamsTabs::Captures => RequestParamsTabs::Scripts,
                 RequestParamsTabs::Scripts => RequestParamsTabs::QueryParams,
                 _ => unreachable!()
             }
@@ -34,8 +54,10 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
 
         match selected_request.protocol {
-            Protocol::HttpRequest(_) if self.request_param_tab == RequestParamsTabs::Message => self.request_param_tab = RequestParamsTabs::QueryParams,
-            Protocol::WsRequest(_) if self.request_param_tab == RequestParamsTabs::Body => self.request_param_tab = RequestParamsTabs::QueryParams,
+            Protocol::HttpRequest(_) if matches!(self.request_param_tab, RequestParamsTabs::Message | RequestParamsTabs::Graphql | RequestParamsTabs::Grpc) => self.request_param_tab = RequestParamsTabs::QueryParams,
+            Protocol::WsRequest(_) if matches!(self.request_param_tab, RequestParamsTabs::Body | RequestParamsTabs::Graphql | RequestParamsTabs::Grpc) => self.request_param_tab = RequestParamsTabs::QueryParams,
+            Protocol::GraphqlRequest(_) if matches!(self.request_param_tab, RequestParamsTabs::Body | RequestParamsTabs::Message | RequestParamsTabs::Grpc) => self.request_param_tab = RequestParamsTabs::QueryParams,
+            Protocol::GrpcRequest(_) if matches!(self.request_param_tab, RequestParamsTabs::Body | RequestParamsTabs::Message | RequestParamsTabs::Graphql) => self.request_param_tab = RequestParamsTabs::QueryParams,
             _ => {}
         };
     }
@@ -47,6 +69,9 @@ impl App<'_> {
             RequestParamsTabs::Headers => self.tui_load_request_headers_tab(),
             RequestParamsTabs::Body => self.tui_load_request_body_param_tab(),
             RequestParamsTabs::Message => self.tui_load_request_message_param_tab(),
+            RequestParamsTabs::Graphql => self.tui_load_request_graphql_param_tab(),
+            RequestParamsTabs::Grpc => self.tui_load_request_grpc_param_tab(),
+            RequestParamsTabs::Captures => self.tui_load_request_captures_tab(),
             RequestParamsTabs::Scripts => {}
         }
     }
@@ -72,6 +97,13 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn tui_load_request_captures_tab(&mut self) {
+        self.tui_update_captures_selection();
+
+        self.request_param_tab = RequestParamsTabs::Captures;
+        self.update_inputs();
+    }
+
     pub fn tui_load_request_body_param_tab(&mut self) {
         self.request_param_tab = RequestParamsTabs::Body;
         self.update_inputs();
@@ -81,4 +113,14 @@ impl App<'_> {
         self.request_param_tab = RequestParamsTabs::Message;
         self.update_inputs();
     }
+
+    pub fn tui_load_request_graphql_param_tab(&mut self) {
+        self.request_param_tab = RequestParamsTabs::Graphql;
+        self.update_inputs();
+    }
+
+    pub fn tui_load_request_grpc_param_tab(&mut self) {
+        self.request_param_tab = RequestParamsTabs::Grpc;
+        self.update_inputs();
+    }
 }
\ No newline at end of file
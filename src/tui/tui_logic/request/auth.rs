@@ -1,9 +1,11 @@
 use tracing::info;
 use crate::app::app::App;
-use crate::models::auth::auth::Auth::{NoAuth, BasicAuth, BearerToken, JwtToken, Digest};
+use crate::models::auth::auth::Auth::{NoAuth, BasicAuth, BearerToken, JwtToken, Digest, OAuth2, AwsSigV4, ApiKey, InheritFromCollection};
 use crate::models::auth::auth::next_auth;
 use crate::models::auth::digest::{next_digest_algorithm, next_digest_qop, previous_digest_algorithm, previous_digest_qop, toggle_digest_charset};
 use crate::models::auth::jwt::{next_jwt_algorithm, next_jwt_secret_type, previous_jwt_algorithm, previous_jwt_secret_type};
+use crate::models::auth::api_key::{next_api_key_placement, previous_api_key_placement};
+use crate::models::auth::oauth2::{next_oauth2_grant_type, previous_oauth2_grant_type};
 use crate::tui::ui::views::RequestView;
 
 impl App<'_> {
@@ -26,7 +28,7 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
 
         match selected_request.auth {
-            NoAuth => {}
+            NoAuth | InheritFromCollection => {}
             BasicAuth(_) => match self.auth_text_input_selection.selected {
                 0 => self.edit_request_auth_username_state(),
                 1 => self.edit_request_auth_password_state(),
@@ -52,6 +54,32 @@ impl App<'_> {
                 5 => self.edit_request_auth_digest_opaque_state(),
                 _ => {}
             }
+            OAuth2(_) => match self.auth_text_input_selection.selected {
+                0 => {},
+                1 => self.edit_request_auth_oauth2_token_url_state(),
+                2 => self.edit_request_auth_oauth2_authorization_url_state(),
+                3 => self.edit_request_auth_oauth2_redirect_url_state(),
+                4 => self.edit_request_auth_oauth2_client_id_state(),
+                5 => self.edit_request_auth_oauth2_client_secret_state(),
+                6 => self.edit_request_auth_oauth2_scope_state(),
+                7 => self.edit_request_auth_oauth2_username_state(),
+                8 => self.edit_request_auth_oauth2_password_state(),
+                9 => self.edit_request_auth_oauth2_refresh_token_state(),
+                _ => {}
+            }
+            AwsSigV4(_) => match self.auth_text_input_selection.selected {
+                0 => self.edit_request_auth_aws_sigv4_access_key_id_state(),
+                1 => self.edit_request_auth_aws_sigv4_secret_access_key_state(),
+                2 => self.edit_request_auth_aws_sigv4_region_state(),
+                3 => self.edit_request_auth_aws_sigv4_service_state(),
+                4 => self.edit_request_auth_aws_sigv4_session_token_state(),
+                _ => {}
+            }
+            ApiKey(_) => match self.auth_text_input_selection.selected {
+                0 => self.edit_request_auth_api_key_key_state(),
+                1 => self.edit_request_auth_api_key_value_state(),
+                _ => {}
+            }
         }
     }
 
@@ -94,7 +122,7 @@ impl App<'_> {
         };
 
         match request_auth {
-            NoAuth => {}
+            NoAuth | InheritFromCollection => {}
             BasicAuth(_) => {}
             BearerToken(_) => {}
             JwtToken(_) => match self.auth_text_input_selection.selected {
@@ -109,6 +137,13 @@ impl App<'_> {
                 9 => self.tui_request_auth_toggle_digest_user_hash(),
                 10 => self.tui_request_auth_toggle_digest_charset(),
                 _ => {}
+            },
+            OAuth2(_) => if self.auth_text_input_selection.selected == 0 {
+                self.tui_request_auth_previous_oauth2_grant_type();
+            }
+            AwsSigV4(_) => {}
+            ApiKey(_) => if self.auth_text_input_selection.selected == 2 {
+                self.tui_request_auth_previous_api_key_placement();
             }
         }
     }
@@ -125,7 +160,7 @@ impl App<'_> {
         };
 
         match request_auth {
-            NoAuth => {}
+            NoAuth | InheritFromCollection => {}
             BasicAuth(_) => {}
             BearerToken(_) => {}
             JwtToken(_) => match self.auth_text_input_selection.selected {
@@ -140,6 +175,13 @@ impl App<'_> {
                 9 => self.tui_request_auth_toggle_digest_user_hash(),
                 10 => self.tui_request_auth_toggle_digest_charset(),
                 _ => {}
+            },
+            OAuth2(_) => if self.auth_text_input_selection.selected == 0 {
+                self.tui_request_auth_next_oauth2_grant_type();
+            }
+            AwsSigV4(_) => {}
+            ApiKey(_) => if self.auth_text_input_selection.selected == 2 {
+                self.tui_request_auth_next_api_key_placement();
             }
         }
     }
@@ -412,4 +454,226 @@ impl App<'_> {
 
         self.select_request_state();
     }
+
+    pub fn tui_modify_request_auth_oauth2_token_url(&mut self) {
+        let input_text = self.auth_oauth2_token_url_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_token_url(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_authorization_url(&mut self) {
+        let input_text = self.auth_oauth2_authorization_url_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_authorization_url(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_redirect_url(&mut self) {
+        let input_text = self.auth_oauth2_redirect_url_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_redirect_url(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_client_id(&mut self) {
+        let input_text = self.auth_oauth2_client_id_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_client_id(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_client_secret(&mut self) {
+        let input_text = self.auth_oauth2_client_secret_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_client_secret(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_scope(&mut self) {
+        let input_text = self.auth_oauth2_scope_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_scope(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_username(&mut self) {
+        let input_text = self.auth_oauth2_username_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_username(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_password(&mut self) {
+        let input_text = self.auth_oauth2_password_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_password(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth2_refresh_token(&mut self) {
+        let input_text = self.auth_oauth2_refresh_token_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_oauth2_refresh_token(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_request_auth_previous_oauth2_grant_type(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            let previous_grant_type = previous_oauth2_grant_type(&oauth2.grant_type);
+
+            info!("Auth OAuth2 grant type set to \"{}\"", previous_grant_type);
+
+            oauth2.grant_type = previous_grant_type;
+            oauth2.clear_access_token();
+        }
+
+        self.save_collection_to_file(selected_request_index.0);
+        self.select_request_state();
+    }
+
+    pub fn tui_request_auth_next_oauth2_grant_type(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let oauth2 = selected_request.auth.get_oauth2_mut();
+
+            let next_grant_type = next_oauth2_grant_type(&oauth2.grant_type);
+
+            info!("Auth OAuth2 grant type set to \"{}\"", next_grant_type);
+
+            oauth2.grant_type = next_grant_type;
+            oauth2.clear_access_token();
+        }
+
+        self.save_collection_to_file(selected_request_index.0);
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_aws_sigv4_access_key_id(&mut self) {
+        let input_text = self.auth_aws_sigv4_access_key_id_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_aws_sigv4_access_key_id(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_aws_sigv4_secret_access_key(&mut self) {
+        let input_text = self.auth_aws_sigv4_secret_access_key_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_aws_sigv4_secret_access_key(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_aws_sigv4_region(&mut self) {
+        let input_text = self.auth_aws_sigv4_region_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_aws_sigv4_region(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_aws_sigv4_service(&mut self) {
+        let input_text = self.auth_aws_sigv4_service_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_aws_sigv4_service(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_aws_sigv4_session_token(&mut self) {
+        let input_text = self.auth_aws_sigv4_session_token_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_aws_sigv4_session_token(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_api_key_key(&mut self) {
+        let input_text = self.auth_api_key_key_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_api_key_key(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_api_key_value(&mut self) {
+        let input_text = self.auth_api_key_value_text_input.to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        self.modify_request_auth_api_key_value(selected_request_index.0, selected_request_index.1, input_text);
+
+        self.select_request_state();
+    }
+
+    pub fn tui_request_auth_previous_api_key_placement(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let api_key = selected_request.auth.get_api_key_mut();
+
+            let previous_placement = previous_api_key_placement(&api_key.placement);
+
+            info!("Auth API key placement set to \"{}\"", previous_placement);
+
+            api_key.placement = previous_placement;
+        }
+
+        self.save_collection_to_file(selected_request_index.0);
+        self.select_request_state();
+    }
+
+    pub fn tui_request_auth_next_api_key_placement(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        {
+            let mut selected_request = local_selected_request.write();
+            let api_key = selected_request.auth.get_api_key_mut();
+
+            let next_placement = next_api_key_placement(&api_key.placement);
+
+            info!("Auth API key placement set to \"{}\"", next_placement);
+
+            api_key.placement = next_placement;
+        }
+
+        self.save_collection_to_file(selected_request_index.0);
+        self.select_request_state();
+    }
 }
@@ -0,0 +1,89 @@
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Reset selection if captures are provided, either set it to none
+    pub fn tui_update_captures_selection(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        match selected_request.captures.is_empty() {
+            false => self.captures_table.update_selection(Some((0, 0))),
+            true => self.captures_table.update_selection(None)
+        }
+    }
+
+    pub fn tui_modify_request_capture(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        let selection = self.captures_table.selection.unwrap();
+        let input_text = self.captures_table.selection_text_input.to_string();
+
+        match self.modify_request_capture(selected_request_index.0, selected_request_index.1, input_text, selection.1, selection.0) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.select_request_state();
+    }
+
+    pub fn tui_create_new_capture(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.create_new_capture(selected_request_index.0, selected_request_index.1, String::from("variable"), String::from("body $.")) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.tui_update_captures_selection();
+        self.update_inputs();
+    }
+
+    pub fn tui_delete_capture(&mut self) {
+        if self.captures_table.rows.is_empty() || self.captures_table.selection.is_none() {
+            return;
+        }
+
+        let selection = self.captures_table.selection.unwrap();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.delete_capture(selected_request_index.0, selected_request_index.1, selection.0) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.tui_update_captures_selection();
+        self.update_inputs();
+    }
+
+    pub fn tui_toggle_capture(&mut self) {
+        if self.captures_table.rows.is_empty() || self.captures_table.selection.is_none() {
+            return;
+        }
+
+        let row = self.captures_table.selection.unwrap().0;
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.toggle_capture(selected_request_index.0, selected_request_index.1, None, row) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.update_inputs();
+    }
+
+    pub fn tui_duplicate_capture(&mut self) {
+        if self.captures_table.rows.is_empty() || self.captures_table.selection.is_none() {
+            return;
+        }
+
+        let row = self.captures_table.selection.unwrap().0;
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.duplicate_capture(selected_request_index.0, selected_request_index.1, row) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.update_inputs();
+    }
+}
@@ -1,6 +1,41 @@
 use crate::app::app::App;
+use crate::models::cookie::{cookie_row_key, cookie_to_row, COOKIES_COLUMNS_NUMBER};
 
 impl App<'_> {
+    /// Rebuild the rows from the cookie store, selecting the given (domain, path, name) cookie if any
+    pub fn tui_update_cookies_table(&mut self, selected_cookie_key: Option<(String, String, String)>) {
+        let mut rows: Vec<[String; COOKIES_COLUMNS_NUMBER]> = self.cookies_popup.cookie_store
+            .read()
+            .unwrap()
+            .iter_any()
+            .map(cookie_to_row)
+            .collect();
+
+        // The store does not keep any order
+        rows.sort_by_key(cookie_row_key);
+
+        self.cookies_popup.cookies_table.rows = rows;
+
+        let selected_row = selected_cookie_key.and_then(|selected_cookie_key| {
+            self.cookies_popup.cookies_table.rows
+                .iter()
+                .position(|row| cookie_row_key(row) == selected_cookie_key)
+        });
+
+        match selected_row {
+            Some(row) => {
+                let column = self.cookies_popup.cookies_table.selection.map(|(_, column)| column).unwrap_or(0);
+
+                self.cookies_popup.cookies_table.selection = Some((row, column));
+
+                for table_state in self.cookies_popup.cookies_table.lists_states.iter_mut() {
+                    table_state.select(Some(row));
+                }
+            },
+            None => self.tui_update_cookies_table_selection()
+        }
+    }
+
     pub fn tui_update_cookies_table_selection(&mut self) {
         match self.cookies_popup.cookies_table.rows.is_empty() {
             false => {
@@ -20,24 +55,78 @@ impl App<'_> {
         }
     }
 
+    pub fn tui_modify_cookie(&mut self) {
+        let (row, column) = self.cookies_popup.cookies_table.selection.unwrap();
+
+        let previous_cookie_row = self.cookies_popup.cookies_table.rows[row].clone();
+        let mut cookie_row = previous_cookie_row.clone();
+        cookie_row[column] = self.cookies_popup.cookies_table.selection_text_input.to_string();
+
+        // The error is displayed but does not lock the current state, e.g. invalid date or already existing cookie
+        let result = self.insert_cookie(Some(&previous_cookie_row), &cookie_row);
+        self.tui_handle_inserted_cookie(result);
+
+        self.display_cookies_state();
+    }
+
+    pub fn tui_create_cookie(&mut self) {
+        let cookie_row = [
+            String::from("localhost"),
+            String::from("name"),
+            String::from("value"),
+            String::from("/"),
+            String::from("session"),
+            String::from("false"),
+            String::from("false"),
+            String::new()
+        ];
+
+        let result = self.insert_cookie(None, &cookie_row);
+        self.tui_handle_inserted_cookie(result);
+    }
+
+    pub fn tui_duplicate_cookie(&mut self) {
+        if self.cookies_popup.cookies_table.rows.is_empty() || self.cookies_popup.cookies_table.selection.is_none() {
+            return;
+        }
+
+        let (row, _) = self.cookies_popup.cookies_table.selection.unwrap();
+
+        let mut cookie_row = self.cookies_popup.cookies_table.rows[row].clone();
+        cookie_row[1] = format!("{}_copy", cookie_row[1]);
+
+        let result = self.insert_cookie(None, &cookie_row);
+        self.tui_handle_inserted_cookie(result);
+    }
+
+    fn tui_handle_inserted_cookie(&mut self, result: anyhow::Result<(String, String, String)>) {
+        match result {
+            Ok(cookie_key) => {
+                self.cookies_popup.error = None;
+                self.tui_update_cookies_table(Some(cookie_key));
+            },
+            Err(error) => self.cookies_popup.error = Some(error.to_string())
+        }
+    }
+
     pub fn tui_delete_cookie(&mut self) {
         if self.cookies_popup.cookies_table.rows.is_empty() || self.cookies_popup.cookies_table.selection.is_none() {
             return;
         }
 
+        self.cookies_popup.error = None;
+
         let selection = self.cookies_popup.cookies_table.selection.unwrap();
         let cookie_row = self.cookies_popup.cookies_table.rows.remove(selection.0);
-        
+        let (domain, path, name) = cookie_row_key(&cookie_row);
+
         {
             let mut local_cookie_store = self.cookies_popup.cookie_store.write().unwrap();
 
-            local_cookie_store.remove(
-                &cookie_row[0],
-                &cookie_row[3],
-                &cookie_row[1],
-            );
+            local_cookie_store.remove(&domain, &path, &name);
         }
 
+        self.save_cookie_jar();
         self.tui_update_cookies_table_selection();
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use tokio::task;
+
+use crate::app::app::App;
+use crate::app::business_logic::response_diff::ResponseDiff;
+use crate::models::response::RequestResponse;
+use crate::tui::ui::views::RequestView;
+use crate::tui::utils::stateful::response_diff_panel::ResponseDiffPanel;
+
+impl App<'_> {
+    /// Send the selected request with the selected environment and the chosen one, then compare both responses
+    pub fn tui_diff_environments_responses(&mut self) {
+        let (collection_index, request_index) = match self.collections_tree.selected {
+            None => return,
+            Some(selected_request_index) => selected_request_index
+        };
+
+        let left_env_index = self.selected_environment;
+        let right_env_name = self.diff_environment_popup.get_selection().clone();
+
+        let right_env_index = match self.find_environment(&right_env_name) {
+            Ok(env_index) => env_index,
+            Err(_) => return
+        };
+
+        let left_env_name = self.environments[left_env_index].read().name.clone();
+
+        let left_request_preparer = self.request_preparer_with_environment(left_env_index);
+        let right_request_preparer = self.request_preparer_with_environment(right_env_index);
+
+        // Displayed until both responses are received
+        self.response_diff_panel = Some(ResponseDiffPanel::new(ResponseDiff {
+            left_title: format!("{left_env_name} (sending...)"),
+            right_title: format!("{right_env_name} (sending...)"),
+            rows: vec![],
+        }));
+        self.request_view = RequestView::Diff;
+
+        let local_received_response_diff = Arc::clone(&self.received_response_diff);
+        let local_should_refresh = Arc::clone(&self.received_response);
+
+        // Prepared in the background too, e.g. an OAuth2 token may be fetched
+        task::spawn(async move {
+            let left_response = match left_request_preparer.prepare_environment_request(collection_index, request_index).await {
+                Ok(left_request) => left_request.send().await,
+                Err(error) => Err(error)
+            };
+
+            let right_response = match right_request_preparer.prepare_environment_request(collection_index, request_index).await {
+                Ok(right_request) => right_request.send().await,
+                Err(error) => Err(error)
+            };
+
+            // An error is shown as the status, as for a request sent from the TUI
+            let to_response = |response: anyhow::Result<RequestResponse>| response.unwrap_or_else(|error| RequestResponse {
+                status_code: Some(error.to_string()),
+                ..Default::default()
+            });
+
+            let diff = ResponseDiff::new(
+                left_env_name,
+                &to_response(left_response),
+                right_env_name,
+                &to_response(right_response),
+                &ResponseDiff::default_ignored_headers()
+            );
+
+            *local_received_response_diff.lock() = Some((collection_index, request_index, diff));
+            *local_should_refresh.lock() = true;
+        });
+
+        self.select_request_state();
+    }
+
+    /// Display the environments diff computed in the background, unless its request is not the selected one anymore or the diff was closed
+    pub fn tui_receive_response_diff(&mut self) {
+        let received_response_diff = self.received_response_diff.lock().take();
+
+        if let Some((collection_index, request_index, diff)) = received_response_diff
+            && self.collections_tree.selected == Some((collection_index, request_index))
+            && self.response_diff_panel.is_some() {
+            self.response_diff_panel = Some(ResponseDiffPanel::new(diff));
+        }
+    }
+}
@@ -7,12 +7,13 @@ impl App<'_> {
     pub fn tui_export_request(&mut self) {
 
         let local_selected_request = self.get_selected_request_as_local();
+        let selected_request_index = self.collections_tree.selected.unwrap();
 
         {
             let selected_request = local_selected_request.read();
 
             let export_format = self.export_request.get_selection();
-            let export_result = self.export_request_to_string_with_format(&export_format, &selected_request).unwrap_or_else(|error| error.to_string());
+            let export_result = self.export_request_to_string_with_format(&export_format, &selected_request, &self.collections[selected_request_index.0].defaults).unwrap_or_else(|error| error.to_string());
 
             self.display_request_export.content = export_result.clone();
             self.display_request_export.title = export_format.to_string();
@@ -0,0 +1,88 @@
+use tokio::task;
+use tracing::{error, info};
+
+use crate::app::app::App;
+
+impl App<'_> {
+    pub fn tui_modify_request_graphql_query(&mut self) {
+        let query = self.graphql_editor.query_text_area.to_string();
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_graphql_query(selected_request_index.0, selected_request_index.1, query).ok();
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_graphql_variables(&mut self) {
+        let variables = self.graphql_editor.variables_text_area.to_string();
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_graphql_variables(selected_request_index.0, selected_request_index.1, variables).ok();
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_graphql_operation_name(&mut self) {
+        let operation_name = self.graphql_editor.operation_name_text_input.to_string();
+
+        let operation_name = match operation_name.trim().is_empty() {
+            true => None,
+            false => Some(operation_name)
+        };
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_graphql_operation_name(selected_request_index.0, selected_request_index.1, operation_name).ok();
+
+        self.select_request_state();
+    }
+
+    /// The introspection query is sent in the background, like any other request
+    pub async fn tui_introspect_graphql_schema(&mut self) {
+        let selected_request_index = self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(&selected_request_index);
+
+        let introspection = match self.prepare_graphql_introspection(selected_request_index.0, selected_request_index.1).await {
+            Ok(introspection) => introspection,
+            Err(introspection_error) => {
+                error!("{introspection_error}");
+                local_selected_request.write().response.status_code = Some(introspection_error.to_string());
+                return;
+            }
+        };
+
+        task::spawn(async move {
+            if let Err(introspection_error) = introspection.send().await {
+                error!("{introspection_error}");
+                local_selected_request.write().response.status_code = Some(introspection_error.to_string());
+            }
+        });
+    }
+
+    /// Insert the rest of the first schema name matching the word before the cursor
+    pub fn tui_complete_graphql_field(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        let suffix = {
+            let selected_request = local_selected_request.read();
+
+            let Ok(graphql_request) = selected_request.get_graphql_request() else {
+                return;
+            };
+
+            let Some(schema) = &graphql_request.schema else {
+                return;
+            };
+
+            let prefix = self.graphql_editor.query_text_area.word_before_cursor();
+
+            match schema.complete(&prefix).first() {
+                Some(completion) => completion[prefix.len()..].to_string(),
+                None => return
+            }
+        };
+
+        info!("Completing GraphQL field");
+
+        self.graphql_editor.query_text_area.insert_str_at_cursor(&suffix);
+    }
+}
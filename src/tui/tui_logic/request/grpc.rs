@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use tokio::task;
+use tracing::error;
+
+use crate::app::app::App;
+use crate::models::protocol::grpc::grpc::parse_proto_files;
+
+impl App<'_> {
+    pub fn tui_modify_request_grpc_proto_files(&mut self) {
+        let proto_files = parse_proto_files(&self.grpc_editor.proto_files_text_input.to_string());
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_grpc_proto_files(selected_request_index.0, selected_request_index.1, proto_files).ok();
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_grpc_service(&mut self) {
+        let service = self.grpc_editor.service_text_input.to_string();
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_grpc_service(selected_request_index.0, selected_request_index.1, service.trim().to_string()).ok();
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_grpc_method(&mut self) {
+        let method = self.grpc_editor.method_text_input.to_string();
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_grpc_method(selected_request_index.0, selected_request_index.1, method.trim().to_string()).ok();
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_grpc_message(&mut self) {
+        let message = self.grpc_editor.message_text_area.to_string();
+
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        self.modify_request_grpc_message(selected_request_index.0, selected_request_index.1, message).ok();
+
+        self.select_request_state();
+    }
+
+    /// Listed in the background, the server reflection may take up to the request timeout
+    pub fn tui_list_grpc_methods(&mut self) {
+        let (collection_index, request_index) = self.collections_tree.selected.unwrap();
+
+        let request_preparer = self.request_preparer();
+        let local_received_grpc_methods = Arc::clone(&self.received_grpc_methods);
+        let local_should_refresh = Arc::clone(&self.received_response);
+
+        task::spawn(async move {
+            let result = request_preparer
+                .load_grpc_methods(collection_index, request_index)
+                .await
+                .map_err(|listing_error| listing_error.to_string());
+
+            *local_received_grpc_methods.lock() = Some((collection_index, request_index, result));
+            *local_should_refresh.lock() = true;
+        });
+    }
+
+    /// Save the methods listed in the background, or show why they could not be
+    pub fn tui_receive_grpc_methods(&mut self) {
+        let Some((collection_index, request_index, result)) = self.received_grpc_methods.lock().take() else {
+            return;
+        };
+
+        match result {
+            Ok(_) => {
+                self.save_collection_to_file(collection_index);
+
+                if self.collections_tree.selected == Some((collection_index, request_index)) {
+                    self.update_inputs();
+                }
+            },
+            Err(listing_error) => {
+                error!("{listing_error}");
+
+                let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                local_request.write().response.status_code = Some(listing_error);
+            }
+        }
+    }
+}
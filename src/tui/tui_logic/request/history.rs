@@ -0,0 +1,68 @@
+use crate::app::app::App;
+use crate::app::business_logic::response_diff::ResponseDiff;
+use crate::tui::ui::views::RequestView;
+use crate::tui::utils::stateful::response_diff_panel::ResponseDiffPanel;
+
+impl App<'_> {
+    /// Display the selected past response in the result tabs, without sending the request
+    pub fn tui_select_response_history_entry(&mut self) {
+        let entry = match self.response_history_popup.entries.get(self.response_history_popup.selection) {
+            None => return,
+            Some(entry) => entry
+        };
+
+        // Only viewed, the request's own response is neither replaced nor saved
+        self.viewed_history_entry = Some(entry.clone());
+
+        *self.received_response.lock() = true;
+
+        self.select_request_state();
+    }
+
+    /// The first call marks the selected entry, the second one compares it with the newly selected entry
+    pub fn tui_compare_response_history_entry(&mut self) {
+        let selection = self.response_history_popup.selection;
+
+        if selection >= self.response_history_popup.entries.len() {
+            return;
+        }
+
+        let marked = match self.response_history_popup.marked {
+            None => {
+                self.response_history_popup.marked = Some(selection);
+                return;
+            },
+            Some(marked) if marked == selection => {
+                self.response_history_popup.marked = None;
+                return;
+            },
+            Some(marked) => marked
+        };
+
+        // Entries are the most recent first, the oldest one is displayed on the left
+        let (left_index, right_index) = (marked.max(selection), marked.min(selection));
+        let left_entry = &self.response_history_popup.entries[left_index];
+        let right_entry = &self.response_history_popup.entries[right_index];
+
+        let diff = ResponseDiff::new(
+            format!("#{left_index} {}", left_entry.summary()),
+            &left_entry.to_response(),
+            format!("#{right_index} {}", right_entry.summary()),
+            &right_entry.to_response(),
+            &ResponseDiff::default_ignored_headers()
+        );
+
+        self.response_diff_panel = Some(ResponseDiffPanel::new(diff));
+        self.request_view = RequestView::Diff;
+
+        self.select_request_state();
+    }
+
+    pub fn close_response_diff(&mut self) {
+        self.response_diff_panel = None;
+
+        if self.request_view == RequestView::Diff {
+            self.request_view = RequestView::Normal;
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod headers;
+pub mod captures;
 pub mod query_params;
 pub mod send;
 pub mod settings;
@@ -8,5 +9,9 @@ pub(super) mod utils;
 mod cookies;
 pub mod scripts;
 mod export;
+mod history;
+mod diff;
 pub mod http;
 pub mod ws;
+pub mod graphql;
+pub mod grpc;
@@ -2,12 +2,15 @@ use std::sync::Arc;
 use futures_util::SinkExt;
 use reqwest_websocket::CloseCode;
 use tokio::task;
+use tokio_util::sync::CancellationToken;
 use tracing::info;
 use crate::app::app::App;
+use crate::app::business_logic::request::grpc::send::send_grpc_request;
 use crate::app::business_logic::request::http::send::send_http_request;
 use crate::app::business_logic::request::ws::send::send_ws_request;
 use crate::models::auth::auth::Auth;
 use crate::models::protocol::protocol::Protocol;
+use crate::models::response::RequestResponse;
 
 impl App<'_> {
     pub async fn tui_send_request(&mut self) {
@@ -21,12 +24,20 @@ impl App<'_> {
                 info!("Request canceled");
                 return;
             }
+
+            if selected_request.is_streaming {
+                selected_request.cancellation_token.cancel();
+                info!("Event stream canceled");
+                return;
+            }
         }
         
+        self.viewed_history_entry = None;
+
         let mut selected_request = local_selected_request.write();
 
         match &mut selected_request.protocol {
-            Protocol::HttpRequest(_) => {}
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => {}
             Protocol::WsRequest(ws_request) => if ws_request.is_connected {
                 if let Some(websocket) = ws_request.websocket.clone() {
                     drop(websocket.rx);
@@ -50,36 +61,73 @@ impl App<'_> {
             }
         }
 
-        /* PRE-REQUEST SCRIPT */
+        drop(selected_request);
 
-        let prepared_request = match self.prepare_request(&mut selected_request).await {
-            Ok(result) => result,
-            Err(prepare_request_error) => {
-                selected_request.response.status_code = Some(prepare_request_error.to_string());
-                return;
-            }
-        };
+        let selected_request_index = self.collections_tree.selected.unwrap();
+        let collection_defaults = self.collections[selected_request_index.0].defaults.clone();
+        let request_preparer = self.request_preparer();
 
-        let protocol = selected_request.protocol.clone();
-        let local_selected_request = self.get_selected_request_as_local();
-        let local_env = self.get_selected_env_as_local();
+        // Pending as soon as it is prepared, fetching an OAuth2 token or sending the dependencies can take long and may be canceled
+        local_selected_request.write().is_pending = true;
 
-        let local_should_refresh_scrollbars = Arc::clone(&self.received_response);
+        task::spawn(async move {
+            /* PRE-REQUEST SCRIPT */
 
-        /* SEND REQUEST */
+            // Prepared on a copy, the request must not stay locked while an OAuth2 token is fetched
+            let mut request = local_selected_request.read().clone();
+            let cancellation_token = request.cancellation_token.clone();
 
-        task::spawn(async move {
-            let response = match protocol {
-                Protocol::HttpRequest(_) => send_http_request(prepared_request, local_selected_request.clone(), &local_env).await,
-                Protocol::WsRequest(_) => send_ws_request(prepared_request, local_selected_request.clone(), &local_env, local_should_refresh_scrollbars.clone()).await
+            let prepare_result = tokio::select! {
+                _ = cancellation_token.cancelled() => {
+                    let mut selected_request = local_selected_request.write();
+                    selected_request.response = RequestResponse {
+                        status_code: Some(String::from("CANCELED")),
+                        ..RequestResponse::default()
+                    };
+                    selected_request.is_pending = false;
+                    selected_request.cancellation_token = CancellationToken::new();
+                    return;
+                },
+                prepare_result = request_preparer.prepare_request(Some(selected_request_index), &mut request, &collection_defaults) => prepare_result
+            };
+
+            local_selected_request.write().keep_prepared_state(&request);
+
+            let mut prepared_request = match prepare_result {
+                Ok(result) => result,
+                Err(prepare_request_error) => {
+                    let mut selected_request = local_selected_request.write();
+                    selected_request.response.status_code = Some(prepare_request_error.to_string());
+                    selected_request.is_pending = false;
+                    return;
+                }
+            };
+
+            let response_history_recorder = request_preparer.new_response_history_recorder(selected_request_index.0, &request, &mut prepared_request);
+            let local_env = request_preparer.get_selected_env_as_local();
+            let local_should_refresh_scrollbars = Arc::clone(&request_preparer.received_response);
+
+            /* SEND REQUEST */
+
+            let response = match request.protocol {
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) => send_http_request(prepared_request, local_selected_request.clone(), &collection_defaults, &local_env, local_should_refresh_scrollbars.clone()).await,
+                Protocol::WsRequest(_) => send_ws_request(prepared_request, local_selected_request.clone(), &collection_defaults, &local_env, local_should_refresh_scrollbars.clone()).await,
+                Protocol::GrpcRequest(_) => send_grpc_request(prepared_request, local_selected_request.clone(), &collection_defaults, &local_env).await
             };
 
             match response {
                 Ok(response) => {
+                    if let Some(response_history_recorder) = &response_history_recorder {
+                        response_history_recorder.record(&response).await;
+                    }
+
                     let mut selected_request = local_selected_request.write();
 
                     match &mut selected_request.auth {
                         Auth::Digest(digest) => digest.update_from_www_authenticate_header(&response.headers),
+                        Auth::InheritFromCollection => if let Auth::Digest(digest) = &mut *collection_defaults.auth.write() {
+                            digest.update_from_www_authenticate_header(&response.headers);
+                        },
                         _ => {}
                     }
 
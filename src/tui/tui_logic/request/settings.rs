@@ -1,4 +1,5 @@
 use crate::app::app::App;
+use crate::models::settings::Setting;
 
 impl App<'_> {
     pub fn tui_modify_request_settings(&mut self) {
@@ -8,10 +9,23 @@ impl App<'_> {
         {
             let mut selected_request = local_selected_request.write();
 
-            selected_request.settings.update_from_vec(&self.request_settings_popup.settings)
+            let shown_settings = self.collections[selected_request_index.0].defaults
+                .editable_settings(&selected_request.settings)
+                .to_vec();
+
+            selected_request.settings.update_from_vec(&self.request_settings_popup.settings, &shown_settings)
         }
 
         self.save_collection_to_file(selected_request_index.0);
         self.select_request_state();
     }
+
+    pub fn tui_modify_request_setting_value(&mut self) {
+        let selection = self.request_settings_popup.selection;
+        let value = self.request_settings_popup.text_input.to_string();
+
+        self.request_settings_popup.settings[selection].1 = Setting::String(value);
+
+        self.back_to_request_settings_state();
+    }
 }
\ No newline at end of file
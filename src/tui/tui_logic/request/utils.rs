@@ -16,9 +16,11 @@ impl App<'_> {
 
         let local_selected_request = self.get_selected_request_as_local();
         let selected_request = local_selected_request.read();
+        let viewed_response = self.viewed_history_entry.as_ref().map(|entry| entry.to_response());
+        let response = viewed_response.as_ref().unwrap_or(&selected_request.response);
 
         match self.request_result_tab {
-            RequestResultTabs::Body => match &selected_request.response.content {
+            RequestResultTabs::Body => match &response.content {
                 None => {}
                 Some(content) => match content {
                     ResponseContent::Body(body) => {
@@ -68,7 +70,27 @@ impl App<'_> {
                         .expect("Could not copy messages content to clipboard");
                 }
             },
-            RequestResultTabs::Cookies => match &selected_request.response.cookies {
+            RequestResultTabs::Events => {
+                let text = selected_request.events
+                    .iter()
+                    .map(|event| format!(
+                        "=== {} - New {} event ===\n{}",
+                        event.timestamp.format("%H:%M:%S %d/%m/%Y"),
+                        event.event,
+                        event.data
+                    ))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+
+                if !text.is_empty() {
+                    self.clipboard
+                        .as_mut()
+                        .unwrap()
+                        .set_text(text)
+                        .expect("Could not copy events to clipboard");
+                }
+            },
+            RequestResultTabs::Cookies => match &response.cookies {
                 None => {}
                 Some(cookies) => {
                     self.clipboard
@@ -79,7 +101,7 @@ impl App<'_> {
                 }
             }
             RequestResultTabs::Headers => {
-                let headers_string: String = selected_request.response.headers
+                let headers_string: String = response.headers
                     .par_iter()
                     .map(|(header, value)| format!("{}: {}\n", header, value))
                     .collect();
@@ -108,6 +130,23 @@ impl App<'_> {
                         .expect("Could not copy console output to clipboard")
                 }
             }
+            RequestResultTabs::Tests => {
+                let tests_string: String = selected_request.test_results
+                    .iter()
+                    .map(|test_result| match &test_result.error {
+                        None => format!("PASS {}\n", test_result.name),
+                        Some(error) => format!("FAIL {}: {}\n", test_result.name, error)
+                    })
+                    .collect();
+
+                if !tests_string.is_empty() {
+                    self.clipboard
+                        .as_mut()
+                        .unwrap()
+                        .set_text(tests_string)
+                        .expect("Could not copy test results to clipboard");
+                }
+            }
         }
     }
 
@@ -1,6 +1,7 @@
 use std::str::Lines;
 use ratatui::prelude::{Line, Stylize};
 use crate::app::app::App;
+use crate::app::business_logic::response_filter::filter_response_body;
 use crate::app::files::theme::THEME;
 use crate::models::protocol::http::body::find_file_format_in_content_type;
 use crate::models::protocol::protocol::Protocol;
@@ -14,23 +15,27 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
 
         self.request_result_tab = match self.request_result_tab {
+            RequestResultTabs::Body if selected_request.is_streaming || !selected_request.events.is_empty() => RequestResultTabs::Events,
             RequestResultTabs::Body => RequestResultTabs::Cookies,
             RequestResultTabs::Messages => RequestResultTabs::Cookies,
+            RequestResultTabs::Events => RequestResultTabs::Cookies,
             RequestResultTabs::Cookies => RequestResultTabs::Headers,
             RequestResultTabs::Headers => {
                 let local_selected_request = self.get_selected_request_as_local();
                 let selected_request = local_selected_request.read();
 
                 match (&selected_request.console_output.pre_request_output, &selected_request.console_output.post_request_output) {
+                    (None, None) if !selected_request.test_results.is_empty() => RequestResultTabs::Tests,
                     (None, None) => match selected_request.protocol {
-                        Protocol::HttpRequest(_) => RequestResultTabs::Body,
+                        Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => RequestResultTabs::Body,
                         Protocol::WsRequest(_) => RequestResultTabs::Messages
                     },
                     (_, _) => RequestResultTabs::Console
                 }
             },
-            RequestResultTabs::Console => match selected_request.protocol {
-                Protocol::HttpRequest(_) => RequestResultTabs::Body,
+            RequestResultTabs::Console if !selected_request.test_results.is_empty() => RequestResultTabs::Tests,
+            RequestResultTabs::Console | RequestResultTabs::Tests => match selected_request.protocol {
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => RequestResultTabs::Body,
                 Protocol::WsRequest(_) => RequestResultTabs::Messages
             }
         };
@@ -42,30 +47,71 @@ impl App<'_> {
         let local_selected_request = self.get_selected_request_as_local();
         let selected_request = local_selected_request.read();
 
-        if self.request_result_tab == RequestResultTabs::Console && selected_request.console_output.pre_request_output.is_none() && selected_request.console_output.post_request_output.is_none() {
+        let has_no_console_output = self.request_result_tab == RequestResultTabs::Console && selected_request.console_output.pre_request_output.is_none() && selected_request.console_output.post_request_output.is_none();
+        let has_no_test_results = self.request_result_tab == RequestResultTabs::Tests && selected_request.test_results.is_empty();
+
+        if has_no_console_output || has_no_test_results {
             self.request_result_tab = match selected_request.protocol {
-                Protocol::HttpRequest(_) => RequestResultTabs::Body,
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => RequestResultTabs::Body,
                 Protocol::WsRequest(_) => RequestResultTabs::Messages
             };
         }
         else {
+            let has_events = selected_request.is_streaming || !selected_request.events.is_empty();
+
             match selected_request.protocol {
-                Protocol::HttpRequest(_) if self.request_result_tab == RequestResultTabs::Messages => self.request_result_tab = RequestResultTabs::Body,
-                Protocol::WsRequest(_) if self.request_result_tab == RequestResultTabs::Body => self.request_result_tab = RequestResultTabs::Messages,
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) if self.request_result_tab == RequestResultTabs::Messages => self.request_result_tab = RequestResultTabs::Body,
+                Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) if self.request_result_tab == RequestResultTabs::Events && !has_events => self.request_result_tab = RequestResultTabs::Body,
+                Protocol::WsRequest(_) if matches!(self.request_result_tab, RequestResultTabs::Body | RequestResultTabs::Events) => self.request_result_tab = RequestResultTabs::Messages,
                 _ => {}
             };
         }
     }
 
+    pub fn tui_modify_response_body_filter(&mut self) {
+        self.response_body_filter.filter = self.response_body_filter.text_input.to_string().trim().to_string();
+
+        self.result_vertical_scrollbar.top();
+        self.result_horizontal_scrollbar.top();
+
+        *self.received_response.lock() = true;
+
+        self.select_request_state();
+    }
+
+    pub fn tui_cancel_edit_response_body_filter(&mut self) {
+        let filter = self.response_body_filter.filter.clone();
+
+        self.response_body_filter.text_input.clear();
+        self.response_body_filter.text_input.push_str(&filter);
+
+        self.select_request_state();
+    }
+
     pub fn tui_highlight_response_body_and_console(&mut self) {
         let local_selected_request = self.get_selected_request_as_local();
-        let selected_request = local_selected_request.write();
+        let selected_request = local_selected_request.read();
+        let viewed_response = self.viewed_history_entry.as_ref().map(|entry| entry.to_response());
+        let response = viewed_response.as_ref().unwrap_or(&selected_request.response);
 
         self.syntax_highlighting.highlighted_body = None;
         self.syntax_highlighting.highlighted_console_output = vec![];
 
-        if let Some(file_format) = find_file_format_in_content_type(&selected_request.response.headers) {
-            if let Some(ResponseContent::Body(response_content)) = &selected_request.response.content.as_ref() {
+        self.response_body_filter.filtered_body = None;
+
+        if self.response_body_filter.is_active() {
+            if let Some(ResponseContent::Body(response_content)) = &response.content.as_ref() {
+                match filter_response_body(response_content, &self.response_body_filter.filter) {
+                    Ok(filtered_body) => {
+                        self.syntax_highlighting.highlighted_body = highlight(&filtered_body.content, filtered_body.extension);
+                        self.response_body_filter.filtered_body = Some(Ok(filtered_body.content));
+                    },
+                    Err(error) => self.response_body_filter.filtered_body = Some(Err(error.to_string()))
+                }
+            }
+        }
+        else if let Some(file_format) = find_file_format_in_content_type(&response.headers) {
+            if let Some(ResponseContent::Body(response_content)) = &response.content.as_ref() {
                 self.syntax_highlighting.highlighted_body = highlight(response_content, &file_format);
             }
         }
@@ -98,16 +144,24 @@ impl App<'_> {
 
         let local_selected_request = self.get_selected_request_as_local();
         let selected_request = local_selected_request.read();
+        let viewed_response = self.viewed_history_entry.as_ref().map(|entry| entry.to_response());
+        let response = viewed_response.as_ref().unwrap_or(&selected_request.response);
 
         match self.request_result_tab {
             RequestResultTabs::Body => {
-                match &selected_request.response.content {
+                match &response.content {
                     None => {
                         vertical_max = 0;
                         horizontal_max = 0;
                     },
                     Some(content) => match content {
                         ResponseContent::Body(body) => {
+                            let body = match &self.response_body_filter.filtered_body {
+                                Some(Ok(filtered_body)) => filtered_body,
+                                Some(Err(error)) => error,
+                                None => body
+                            };
+
                             vertical_max = body.lines().count() as u16;
                             horizontal_max = App::get_max_str_len(body.lines()) as u16;
                         }
@@ -131,8 +185,18 @@ impl App<'_> {
 
                 horizontal_max = 0;
             },
+            RequestResultTabs::Events => {
+                let lines: Vec<&str> = selected_request.events
+                    .iter()
+                    .flat_map(|event| event.data.lines())
+                    .collect();
+
+                // One details line and one blank line per event
+                vertical_max = (lines.len() + 2 * selected_request.events.len()) as u16;
+                horizontal_max = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16;
+            },
             RequestResultTabs::Cookies => {
-                match &selected_request.response.cookies {
+                match &response.cookies {
                     None => {
                         vertical_max = 0;
                         horizontal_max = 0;
@@ -144,11 +208,11 @@ impl App<'_> {
                 }
             },
             RequestResultTabs::Headers => {
-                vertical_max = selected_request.response.headers.len()  as u16;
+                vertical_max = response.headers.len()  as u16;
 
                 let mut max_tmp: u16 = 0;
 
-                for (header, value) in &selected_request.response.headers {
+                for (header, value) in &response.headers {
                     let str_len = (header.len() + value.len()) as u16;
                     if str_len > max_tmp {
                         max_tmp = str_len;
@@ -175,6 +239,15 @@ impl App<'_> {
                         horizontal_max = App::get_max_str_len(console_output.lines()) as u16;
                     }
                 }
+            },
+            RequestResultTabs::Tests => {
+                // One summary line and one blank line before the tests
+                vertical_max = (selected_request.test_results.len() + 2) as u16;
+                horizontal_max = selected_request.test_results
+                    .iter()
+                    .map(|test_result| test_result.name.len() + test_result.error.as_ref().map_or(0, |error| error.len() + 2) + 2)
+                    .max()
+                    .unwrap_or(0) as u16;
             }
         }
 
@@ -193,4 +266,4 @@ impl App<'_> {
 
         return max_tmp;
     }
-}
\ No newline at end of file
+}
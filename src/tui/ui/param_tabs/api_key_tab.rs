@@ -0,0 +1,79 @@
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{SelectedRequest, EditingRequestAuthApiKeyKey, EditingRequestAuthApiKeyValue};
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::Stylize;
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+
+impl App<'_> {
+    pub(super) fn render_api_key_tab(&mut self, frame: &mut Frame, area: Rect) {
+        let api_key_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(4)
+            .split(area);
+
+        let placement = {
+            let local_selected_request = self.get_selected_request_as_local();
+            let selected_request = local_selected_request.read();
+
+            selected_request.auth.get_api_key().placement.clone()
+        };
+
+        let mut should_color_blocks = false;
+        let mut should_display_cursor = false;
+
+        // Prevent from rendering the cursor while no input text has been selected
+        match self.state {
+            SelectedRequest => {
+                should_color_blocks = true;
+            },
+            EditingRequestAuthApiKeyKey | EditingRequestAuthApiKeyValue => {
+                should_color_blocks = true;
+                should_display_cursor = true;
+            },
+            _ => {}
+        };
+
+        let input_selected = self.auth_text_input_selection.selected;
+
+        let text_inputs = [
+            &mut self.auth_api_key_key_text_input,
+            &mut self.auth_api_key_value_text_input,
+        ];
+
+        for (index, text_input) in text_inputs.into_iter().enumerate() {
+            let is_selected = should_color_blocks && input_selected == index;
+
+            text_input.highlight_text = is_selected;
+            text_input.highlight_block = is_selected;
+            text_input.display_cursor = is_selected && should_display_cursor;
+
+            frame.render_widget(SingleLineTextInput(text_input), api_key_layout[index]);
+        }
+
+        let mut placement_block = Block::new()
+            .title("Placement ← →")
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let mut placement_paragraph = Paragraph::new(placement.to_string())
+            .fg(THEME.read().ui.font_color);
+
+        if input_selected == 2 && should_color_blocks {
+            placement_block = placement_block.fg(THEME.read().others.selection_highlight_color);
+            placement_paragraph = placement_paragraph.fg(THEME.read().others.selection_highlight_color);
+        }
+
+        frame.render_widget(placement_paragraph.block(placement_block), api_key_layout[2]);
+    }
+}
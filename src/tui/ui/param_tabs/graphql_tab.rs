@@ -0,0 +1,87 @@
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::app::App;
+use crate::app::files::key_bindings::KEY_BINDINGS;
+use crate::app::files::theme::THEME;
+use crate::models::request::Request;
+use crate::tui::app_states::AppState::{EditingRequestGraphqlOperationName, EditingRequestGraphqlQuery, EditingRequestGraphqlVariables, SelectedRequest};
+use crate::tui::utils::stateful::text_input::{MultiLineTextInput, SingleLineTextInput};
+use crate::tui::utils::syntax_highlighting::{ENV_VARIABLE_SYNTAX_REF, JSON_SYNTAX_REF};
+
+impl App<'_> {
+    pub(super) fn render_graphql_tab(&mut self, frame: &mut Frame, area: Rect, request: &Request) {
+        let graphql_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(1)
+            ]
+        )
+            .split(area);
+
+        let editors_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(60),
+                Constraint::Percentage(40)
+            ]
+        )
+            .split(graphql_layout[1]);
+
+        let should_highlight = matches!(
+            self.state,
+            SelectedRequest | EditingRequestGraphqlQuery | EditingRequestGraphqlVariables | EditingRequestGraphqlOperationName
+        );
+
+        let selection = self.graphql_editor.selection;
+
+        self.graphql_editor.query_text_area.highlight_text = should_highlight && selection == 0;
+        self.graphql_editor.query_text_area.highlight_block = should_highlight && selection == 0;
+        self.graphql_editor.query_text_area.display_cursor = matches!(self.state, EditingRequestGraphqlQuery);
+        self.graphql_editor.variables_text_area.highlight_text = should_highlight && selection == 1;
+        self.graphql_editor.variables_text_area.highlight_block = should_highlight && selection == 1;
+        self.graphql_editor.variables_text_area.display_cursor = matches!(self.state, EditingRequestGraphqlVariables);
+        self.graphql_editor.operation_name_text_input.highlight_text = should_highlight && selection == 2;
+        self.graphql_editor.operation_name_text_input.highlight_block = should_highlight && selection == 2;
+        self.graphql_editor.operation_name_text_input.display_cursor = matches!(self.state, EditingRequestGraphqlOperationName);
+
+        frame.render_widget(SingleLineTextInput(&mut self.graphql_editor.operation_name_text_input), graphql_layout[0]);
+        frame.render_widget(MultiLineTextInput(&mut self.graphql_editor.query_text_area, ENV_VARIABLE_SYNTAX_REF.clone()), editors_layout[0]);
+        frame.render_widget(MultiLineTextInput(&mut self.graphql_editor.variables_text_area, JSON_SYNTAX_REF.clone()), editors_layout[1]);
+
+        // SCHEMA LINE
+
+        let graphql_request = request.get_graphql_request().unwrap();
+
+        let schema_line = match &graphql_request.schema {
+            None => Line::from(format!(
+                "No schema (introspect with {})",
+                KEY_BINDINGS.read().request_selected.param_tabs.introspect_graphql_schema
+            ))
+                .fg(THEME.read().ui.secondary_foreground_color),
+            Some(schema) if matches!(self.state, EditingRequestGraphqlQuery) => {
+                let prefix = self.graphql_editor.query_text_area.word_before_cursor();
+                let completions = schema.complete(&prefix);
+
+                match completions.is_empty() {
+                    true => Line::from(""),
+                    false => Line::from(completions.join(" ")).fg(THEME.read().ui.font_color)
+                }
+            },
+            Some(schema) => Line::from(format!(
+                "Schema: {} types, {} fields",
+                schema.type_names.len(),
+                schema.field_names.len()
+            ))
+                .fg(THEME.read().ui.secondary_foreground_color)
+        };
+
+        frame.render_widget(Paragraph::new(schema_line), graphql_layout[2]);
+    }
+}
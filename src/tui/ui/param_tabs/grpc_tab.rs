@@ -0,0 +1,86 @@
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+use ratatui::Frame;
+
+use crate::app::app::App;
+use crate::app::files::key_bindings::KEY_BINDINGS;
+use crate::app::files::theme::THEME;
+use crate::models::request::Request;
+use crate::tui::app_states::AppState::{EditingRequestGrpcMessage, EditingRequestGrpcMethod, EditingRequestGrpcProtoFiles, EditingRequestGrpcService, SelectedRequest};
+use crate::tui::utils::stateful::text_input::{MultiLineTextInput, SingleLineTextInput};
+use crate::tui::utils::syntax_highlighting::JSON_SYNTAX_REF;
+
+impl App<'_> {
+    pub(super) fn render_grpc_tab(&mut self, frame: &mut Frame, area: Rect, request: &Request) {
+        let grpc_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
+                Constraint::Length(1)
+            ]
+        )
+            .split(area);
+
+        let service_method_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(60),
+                Constraint::Percentage(40)
+            ]
+        )
+            .split(grpc_layout[1]);
+
+        let should_highlight = matches!(
+            self.state,
+            SelectedRequest | EditingRequestGrpcProtoFiles | EditingRequestGrpcService | EditingRequestGrpcMethod | EditingRequestGrpcMessage
+        );
+
+        let selection = self.grpc_editor.selection;
+
+        self.grpc_editor.proto_files_text_input.highlight_text = should_highlight && selection == 0;
+        self.grpc_editor.proto_files_text_input.highlight_block = should_highlight && selection == 0;
+        self.grpc_editor.proto_files_text_input.display_cursor = matches!(self.state, EditingRequestGrpcProtoFiles);
+        self.grpc_editor.service_text_input.highlight_text = should_highlight && selection == 1;
+        self.grpc_editor.service_text_input.highlight_block = should_highlight && selection == 1;
+        self.grpc_editor.service_text_input.display_cursor = matches!(self.state, EditingRequestGrpcService);
+        self.grpc_editor.method_text_input.highlight_text = should_highlight && selection == 2;
+        self.grpc_editor.method_text_input.highlight_block = should_highlight && selection == 2;
+        self.grpc_editor.method_text_input.display_cursor = matches!(self.state, EditingRequestGrpcMethod);
+        self.grpc_editor.message_text_area.highlight_text = should_highlight && selection == 3;
+        self.grpc_editor.message_text_area.highlight_block = should_highlight && selection == 3;
+        self.grpc_editor.message_text_area.display_cursor = matches!(self.state, EditingRequestGrpcMessage);
+
+        frame.render_widget(SingleLineTextInput(&mut self.grpc_editor.proto_files_text_input), grpc_layout[0]);
+        frame.render_widget(SingleLineTextInput(&mut self.grpc_editor.service_text_input), service_method_layout[0]);
+        frame.render_widget(SingleLineTextInput(&mut self.grpc_editor.method_text_input), service_method_layout[1]);
+        frame.render_widget(MultiLineTextInput(&mut self.grpc_editor.message_text_area, JSON_SYNTAX_REF.clone()), grpc_layout[2]);
+
+        // METHODS LINE
+
+        let grpc_request = request.get_grpc_request().unwrap();
+
+        let methods_line = match grpc_request.methods.is_empty() {
+            true => Line::from(format!(
+                "No methods listed (list with {})",
+                KEY_BINDINGS.read().request_selected.param_tabs.list_grpc_methods
+            ))
+                .fg(THEME.read().ui.secondary_foreground_color),
+            false => {
+                let methods = grpc_request.methods
+                    .iter()
+                    .map(|method| format!("{}/{} ({})", method.service, method.name, method.kind))
+                    .collect::<Vec<String>>()
+                    .join("  ");
+
+                Line::from(methods).fg(THEME.read().ui.secondary_foreground_color)
+            }
+        };
+
+        frame.render_widget(Paragraph::new(methods_line), grpc_layout[3]);
+    }
+}
@@ -4,4 +4,9 @@ mod bearer_token_tab;
 mod jwt_token_tab;
 mod body_file_tab;
 mod digest_tab;
+mod oauth2_tab;
+mod aws_sigv4_tab;
+mod api_key_tab;
 mod script;
+mod graphql_tab;
+mod grpc_tab;
@@ -0,0 +1,129 @@
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{SelectedRequest, EditingRequestAuthOAuth2TokenUrl, EditingRequestAuthOAuth2AuthorizationUrl, EditingRequestAuthOAuth2RedirectUrl, EditingRequestAuthOAuth2ClientId, EditingRequestAuthOAuth2ClientSecret, EditingRequestAuthOAuth2Scope, EditingRequestAuthOAuth2Username, EditingRequestAuthOAuth2Password, EditingRequestAuthOAuth2RefreshToken};
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::prelude::{Position, Size, StatefulWidget, Stylize};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+use tui_scrollview::{ScrollView, ScrollViewState};
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+
+impl App<'_> {
+    pub(super) fn render_oauth2_tab(&mut self, frame: &mut Frame, area: Rect) {
+        let scroll_view_size = Size::new(area.width.saturating_sub(1), 35);
+        let mut oauth2_auth_scroll_view = ScrollView::new(scroll_view_size);
+
+        let oauth2_auth_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(4)
+            .split(oauth2_auth_scroll_view.area());
+
+        let oauth2 = {
+            let local_selected_request = self.get_selected_request_as_local();
+            let selected_request = local_selected_request.read();
+
+            selected_request.auth.get_oauth2().clone()
+        };
+
+        let mut grant_type_block = Block::new()
+            .title("Grant type ← →")
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let access_token_block = Block::new()
+            .title("Access token")
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.secondary_foreground_color);
+
+        let mut should_color_blocks = false;
+        let mut should_display_cursor = false;
+
+        // Prevent from rendering the cursor while no input text has been selected
+        match self.state {
+            SelectedRequest => {
+                should_color_blocks = true;
+            },
+            EditingRequestAuthOAuth2TokenUrl | EditingRequestAuthOAuth2AuthorizationUrl | EditingRequestAuthOAuth2RedirectUrl |
+            EditingRequestAuthOAuth2ClientId | EditingRequestAuthOAuth2ClientSecret | EditingRequestAuthOAuth2Scope |
+            EditingRequestAuthOAuth2Username | EditingRequestAuthOAuth2Password | EditingRequestAuthOAuth2RefreshToken => {
+                should_color_blocks = true;
+                should_display_cursor = true;
+            },
+            _ => {}
+        };
+
+        let mut grant_type_paragraph = Paragraph::new(oauth2.grant_type.to_string())
+            .fg(THEME.read().ui.font_color);
+        let access_token_paragraph = Paragraph::new(oauth2.access_token_status())
+            .fg(THEME.read().ui.secondary_foreground_color)
+            .block(access_token_block);
+
+        let input_selected = self.auth_text_input_selection.selected;
+
+        if input_selected == 0 && should_color_blocks {
+            grant_type_block = grant_type_block.fg(THEME.read().others.selection_highlight_color);
+            grant_type_paragraph = grant_type_paragraph.fg(THEME.read().others.selection_highlight_color);
+        }
+
+        grant_type_paragraph = grant_type_paragraph.block(grant_type_block);
+
+        let text_inputs = [
+            &mut self.auth_oauth2_token_url_text_input,
+            &mut self.auth_oauth2_authorization_url_text_input,
+            &mut self.auth_oauth2_redirect_url_text_input,
+            &mut self.auth_oauth2_client_id_text_input,
+            &mut self.auth_oauth2_client_secret_text_input,
+            &mut self.auth_oauth2_scope_text_input,
+            &mut self.auth_oauth2_username_text_input,
+            &mut self.auth_oauth2_password_text_input,
+            &mut self.auth_oauth2_refresh_token_text_input,
+        ];
+
+        oauth2_auth_scroll_view.render_widget(grant_type_paragraph, oauth2_auth_layout[0]);
+
+        for (index, text_input) in text_inputs.into_iter().enumerate() {
+            // The grant type takes the first selection index
+            let is_selected = should_color_blocks && input_selected == index + 1;
+
+            text_input.highlight_text = is_selected;
+            text_input.highlight_block = is_selected;
+            text_input.display_cursor = is_selected && should_display_cursor;
+
+            oauth2_auth_scroll_view.render_widget(SingleLineTextInput(text_input), oauth2_auth_layout[index + 1]);
+        }
+
+        oauth2_auth_scroll_view.render_widget(access_token_paragraph, oauth2_auth_layout[10]);
+
+        let mut scrollbar_state = ScrollViewState::new();
+
+        let scroll_adjustment = match area.height {
+            0 => 0,
+            _ => area.height / 3
+        };
+
+        let scroll_offset = match input_selected {
+            0 => 0,
+            _ => ((input_selected as u16 + 1) * 3).saturating_sub(area.height.saturating_sub(scroll_adjustment + 2))
+        };
+
+        scrollbar_state.set_offset(Position::new(0, scroll_offset));
+
+        oauth2_auth_scroll_view.render(area, frame.buffer_mut(), &mut scrollbar_state)
+    }
+}
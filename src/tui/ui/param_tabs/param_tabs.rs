@@ -8,11 +8,11 @@ use strum::{Display, EnumIter, FromRepr};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
-use crate::models::auth::auth::Auth::{BasicAuth, BearerToken, Digest, JwtToken, NoAuth};
+use crate::models::auth::auth::Auth::{BasicAuth, BearerToken, Digest, JwtToken, NoAuth, OAuth2, AwsSigV4, ApiKey, InheritFromCollection};
 use crate::models::protocol::http::body::ContentType::*;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::request::Request;
-use crate::tui::app_states::AppState::{EditingRequestBodyString, EditingRequestBodyTable, EditingRequestHeader, EditingRequestMessage, EditingRequestParam};
+use crate::tui::app_states::AppState::{EditingRequestBodyString, EditingRequestBodyTable, EditingRequestCapture, EditingRequestHeader, EditingRequestMessage, EditingRequestParam};
 use crate::tui::tui_logic::utils::key_value_vec_to_items_list;
 use crate::tui::utils::stateful::text_input::MultiLineTextInput;
 use crate::tui::utils::syntax_highlighting::{ENV_VARIABLE_SYNTAX_REF, HTML_SYNTAX_REF, JSON_SYNTAX_REF, JS_SYNTAX_REF, XML_SYNTAX_REF};
@@ -30,6 +30,12 @@ pub enum RequestParamsTabs {
     Body,
     #[strum(to_string = "Message")]
     Message,
+    #[strum(to_string = "GraphQL")]
+    Graphql,
+    #[strum(to_string = "gRPC")]
+    Grpc,
+    #[strum(to_string = "Captures")]
+    Captures,
     #[strum(to_string = "Scripts")]
     Scripts
 }
@@ -53,6 +59,7 @@ impl App<'_> {
                 RequestParamsTabs::Auth,
                 RequestParamsTabs::Headers,
                 RequestParamsTabs::Body,
+                RequestParamsTabs::Captures,
                 RequestParamsTabs::Scripts
             ],
             Protocol::WsRequest(_) => vec![
@@ -60,6 +67,23 @@ impl App<'_> {
                 RequestParamsTabs::Auth,
                 RequestParamsTabs::Headers,
                 RequestParamsTabs::Message,
+                RequestParamsTabs::Captures,
+                RequestParamsTabs::Scripts
+            ],
+            Protocol::GraphqlRequest(_) => vec![
+                RequestParamsTabs::QueryParams,
+                RequestParamsTabs::Auth,
+                RequestParamsTabs::Headers,
+                RequestParamsTabs::Graphql,
+                RequestParamsTabs::Captures,
+                RequestParamsTabs::Scripts
+            ],
+            Protocol::GrpcRequest(_) => vec![
+                RequestParamsTabs::QueryParams,
+                RequestParamsTabs::Auth,
+                RequestParamsTabs::Headers,
+                RequestParamsTabs::Grpc,
+                RequestParamsTabs::Captures,
                 RequestParamsTabs::Scripts
             ]
         };
@@ -74,7 +98,7 @@ impl App<'_> {
                     },
                     RequestParamsTabs::Auth => match request.auth {
                         NoAuth => tab.to_string(),
-                        BasicAuth(_) | BearerToken(_) | JwtToken(_) | Digest(_) => format!("{} ({})", tab.to_string(), request.auth.to_string())
+                        BasicAuth(_) | BearerToken(_) | JwtToken(_) | Digest(_) | OAuth2(_) | AwsSigV4(_) | ApiKey(_) | InheritFromCollection => format!("{} ({})", tab.to_string(), request.auth.to_string())
                     },
                     RequestParamsTabs::Headers => match request.headers.is_empty() {
                         true => tab.to_string(),
@@ -93,6 +117,26 @@ impl App<'_> {
 
                         format!("{} ({})", tab.to_string(), ws_request.message_type.to_string())
                     },
+                    RequestParamsTabs::Graphql => {
+                        let graphql_request = request.get_graphql_request().unwrap();
+
+                        match &graphql_request.operation_name {
+                            None => tab.to_string(),
+                            Some(operation_name) => format!("{} ({})", tab, operation_name)
+                        }
+                    },
+                    RequestParamsTabs::Grpc => {
+                        let grpc_request = request.get_grpc_request().unwrap();
+
+                        match grpc_request.method.is_empty() {
+                            true => tab.to_string(),
+                            false => format!("{} ({})", tab, grpc_request.method)
+                        }
+                    },
+                    RequestParamsTabs::Captures => match request.captures.is_empty() {
+                        true => tab.to_string(),
+                        false => format!("{} ({})", tab, request.captures.len())
+                    },
                     RequestParamsTabs::Scripts => tab.to_string(),
                 };
 
@@ -105,7 +149,8 @@ impl App<'_> {
                 RequestParamsTabs::Auth => 1,
                 RequestParamsTabs::Headers => 2,
                 RequestParamsTabs::Body => 3,
-                RequestParamsTabs::Scripts => 4,
+                RequestParamsTabs::Captures => 4,
+                RequestParamsTabs::Scripts => 5,
                 _ => unreachable!()
             }
             Protocol::WsRequest(_) => match self.request_param_tab {
@@ -113,7 +158,26 @@ impl App<'_> {
                 RequestParamsTabs::Auth => 1,
                 RequestParamsTabs::Headers => 2,
                 RequestParamsTabs::Message => 3,
-                RequestParamsTabs::Scripts => 4,
+                RequestParamsTabs::Captures => 4,
+                RequestParamsTabs::Scripts => 5,
+                _ => unreachable!()
+            }
+            Protocol::GraphqlRequest(_) => match self.request_param_tab {
+                RequestParamsTabs::QueryParams => 0,
+                RequestParamsTabs::Auth => 1,
+                RequestParamsTabs::Headers => 2,
+                RequestParamsTabs::Graphql => 3,
+                RequestParamsTabs::Captures => 4,
+                RequestParamsTabs::Scripts => 5,
+                _ => unreachable!()
+            }
+            Protocol::GrpcRequest(_) => match self.request_param_tab {
+                RequestParamsTabs::QueryParams => 0,
+                RequestParamsTabs::Auth => 1,
+                RequestParamsTabs::Headers => 2,
+                RequestParamsTabs::Grpc => 3,
+                RequestParamsTabs::Captures => 4,
+                RequestParamsTabs::Scripts => 5,
                 _ => unreachable!()
             }
         };
@@ -155,6 +219,23 @@ impl App<'_> {
                     BearerToken(_) => self.render_bearer_token_tab(frame, request_params_layout[1]),
                     JwtToken(_) => self.render_jwt_token_tab(frame, request_params_layout[1]),
                     Digest(_) => self.render_digest_tab(frame, request_params_layout[1]),
+                    OAuth2(_) => self.render_oauth2_tab(frame, request_params_layout[1]),
+                    AwsSigV4(_) => self.render_aws_sigv4_tab(frame, request_params_layout[1]),
+                    ApiKey(_) => self.render_api_key_tab(frame, request_params_layout[1]),
+                    InheritFromCollection => {
+                        let collection_index = self.collections_tree.selected.unwrap().0;
+                        let collection_auth = self.collections[collection_index].defaults.auth.read().to_string();
+
+                        let auth_lines = vec![
+                            Line::default(),
+                            Line::from(format!("Inherited from collection ({collection_auth})")).fg(THEME.read().ui.font_color),
+                            Line::from("(Change auth method with ^a)").fg(THEME.read().ui.secondary_foreground_color)
+                        ];
+
+                        let auth_paragraph = Paragraph::new(auth_lines).centered();
+
+                        frame.render_widget(auth_paragraph, request_params_layout[1]);
+                    }
                 }
             }
             RequestParamsTabs::Headers => {
@@ -212,6 +293,19 @@ impl App<'_> {
                 self.message_text_area.display_cursor = display_cursor;
 
                 frame.render_widget(MultiLineTextInput(&mut self.message_text_area, ENV_VARIABLE_SYNTAX_REF.clone()), request_params_layout[1]);            }
+            RequestParamsTabs::Graphql => {
+                self.render_graphql_tab(frame, request_params_layout[1], request);
+            }
+            RequestParamsTabs::Grpc => {
+                self.render_grpc_tab(frame, request_params_layout[1], request);
+            }
+            RequestParamsTabs::Captures => {
+                self.captures_table.is_editing = matches!(self.state, EditingRequestCapture);
+
+                let mut rows = key_value_vec_to_items_list(&self.get_selected_env_as_local(), &self.captures_table.rows);
+
+                frame.render_stateful_widget(&mut self.captures_table, request_params_layout[1], &mut rows);
+            }
             RequestParamsTabs::Scripts => {
                 self.render_request_script(frame, request_params_layout[1]);
             }
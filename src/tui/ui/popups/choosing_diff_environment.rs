@@ -0,0 +1,57 @@
+use crate::app::app::App;
+use crate::tui::utils::centered_rect::centered_rect;
+use ratatui::Frame;
+use ratatui::layout::Direction::Horizontal;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Color, Style};
+use ratatui::style::Color::Yellow;
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+impl App<'_> {
+    pub fn render_diff_environment_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("Compare with environment")
+            .borders(Borders::ALL)
+            .style(Style::default().bg(Color::DarkGray));
+
+        let nb_elements = self.diff_environment_popup.choices.len() as u16;
+
+        let element_width = self.diff_environment_popup.choices
+            .iter()
+            .map(|env_name| env_name.chars().count() as u16 + 4)
+            .max()
+            .unwrap_or(0)
+            .max(15);
+
+        let area = centered_rect((nb_elements * element_width).max(28), 4, frame.area());
+
+        let element_percentage = 100 / nb_elements;
+        let mut constraints: Vec<Constraint> = vec![];
+
+        for _ in &self.diff_environment_popup.choices {
+            constraints.push(Constraint::Percentage(element_percentage));
+        }
+
+        let environments_layout = Layout::new(
+            Horizontal,
+            constraints
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .split(area);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        for (index, env_name) in self.diff_environment_popup.choices.iter().enumerate() {
+            let mut paragraph = Paragraph::new(env_name.as_str()).centered();
+
+            if index == self.diff_environment_popup.selection {
+                paragraph = paragraph.fg(Yellow).bold();
+            }
+
+            frame.render_widget(paragraph, environments_layout[index]);
+        }
+    }
+}
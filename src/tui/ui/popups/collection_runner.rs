@@ -0,0 +1,199 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::{Color, Line, Span};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::EditingCollectionRunDataFile;
+use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::collection_runner_popup::COLLECTION_RUNNER_OPTIONS;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
+
+impl App<'_> {
+    pub fn render_collection_runner_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title("Run collection")
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let options_number = COLLECTION_RUNNER_OPTIONS.len() as u16;
+
+        let area = centered_rect(60, 2 + 1 + 2 * options_number + 1, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let collection_runner_layout = Layout::new(
+            Vertical,
+            vec![
+                Constraint::Fill(1),
+                Constraint::Length(1),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .split(area);
+
+        let options_layout = Layout::new(
+            Horizontal,
+            vec![
+                Constraint::Percentage(40),
+                Constraint::Percentage(60),
+            ]
+        )
+            .split(collection_runner_layout[0]);
+
+        let mut constraints: Vec<Constraint> = vec![Constraint::Length(1)];
+
+        for _ in 0..options_number {
+            constraints.push(Constraint::Length(2))
+        }
+
+        let options_names_layout = Layout::new(
+            Vertical,
+            constraints.clone()
+        )
+            .horizontal_margin(1)
+            .split(options_layout[0]);
+
+        let options_values_layout = Layout::new(
+            Vertical,
+            constraints
+        )
+            .split(options_layout[1]);
+
+        let is_editing_data_file = self.state == EditingCollectionRunDataFile;
+        let option_values = self.collection_runner_popup.option_values();
+
+        for (index, (option_name, option_value)) in COLLECTION_RUNNER_OPTIONS.iter().zip(option_values).enumerate() {
+            let option_name_paragraph = Paragraph::new(option_name.to_string()).fg(THEME.read().ui.font_color).centered();
+            frame.render_widget(option_name_paragraph, options_names_layout[index + 1]);
+
+            let is_selected = index == self.collection_runner_popup.selection;
+
+            if is_selected && is_editing_data_file {
+                continue;
+            }
+
+            let mut option_value_paragraph = Paragraph::new(option_value).fg(THEME.read().ui.font_color).centered();
+
+            if is_selected {
+                option_value_paragraph = option_value_paragraph.fg(THEME.read().others.selection_highlight_color)
+            }
+
+            frame.render_widget(option_value_paragraph, options_values_layout[index + 1]);
+        }
+
+        if is_editing_data_file {
+            let text_input = &mut self.collection_runner_popup.text_input;
+            text_input.highlight_text = true;
+            text_input.display_cursor = true;
+
+            let mut value_area = options_values_layout[self.collection_runner_popup.selection + 1];
+            value_area.height = 1;
+
+            frame.render_widget(SingleLineTextInput(text_input), value_area);
+        }
+
+        if let Some(error) = &self.collection_runner_popup.error {
+            let error_paragraph = Paragraph::new(error.lines().next().unwrap_or_default().to_string()).fg(Color::Red).centered();
+            frame.render_widget(error_paragraph, collection_runner_layout[1]);
+        }
+    }
+
+    pub fn render_running_collection_popup(&mut self, frame: &mut Frame) {
+        let run = match &self.collection_runner_popup.run {
+            None => return,
+            Some(run) => run
+        };
+
+        let title = match (run.is_canceled, run.is_finished()) {
+            (true, _) => "Collection run canceled",
+            (false, true) => "Collection run finished",
+            (false, false) => "Running collection"
+        };
+
+        let popup_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 25, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let running_collection_layout = Layout::new(
+            Vertical,
+            vec![
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Fill(1),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .split(area);
+
+        let total = run.total();
+        let done = run.results.len();
+
+        let ratio = match total {
+            0 => 1.0,
+            total => done as f64 / total as f64
+        };
+
+        let gauge = Gauge::default()
+            .ratio(ratio)
+            .label(format!("{done}/{total}"))
+            .gauge_style(THEME.read().others.selection_highlight_color);
+
+        frame.render_widget(gauge, running_collection_layout[0]);
+
+        let summary_line = Line::from(vec![
+            Span::raw(format!("Iteration {}/{}  ", (done / run.requests_indexes.len() + 1).min(run.iterations), run.iterations)).fg(THEME.read().ui.font_color),
+            Span::raw(format!("{} passed", run.passed_count())).fg(Color::Green),
+            Span::raw(", ").fg(THEME.read().ui.font_color),
+            Span::raw(format!("{} failed", run.failed_count())).fg(Color::Red),
+        ]);
+
+        frame.render_widget(Paragraph::new(summary_line), running_collection_layout[1]);
+
+        let results_area = running_collection_layout[3];
+
+        // Only the latest results that fit are displayed
+        let lines: Vec<Line> = run.results
+            .iter()
+            .rev()
+            .take(results_area.height as usize)
+            .rev()
+            .map(|result| {
+                let (symbol, color) = match run.is_passed(result) {
+                    true => ("✔", Color::Green),
+                    false => ("✘", Color::Red)
+                };
+
+                let duration = match &result.duration {
+                    None => String::new(),
+                    Some(duration) => format!(" ({duration})")
+                };
+
+                Line::from(vec![
+                    Span::raw(format!("{symbol} ")).fg(color),
+                    Span::raw(format!("#{} ", result.iteration + 1)).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{} ", result.request_name)).fg(THEME.read().ui.font_color),
+                    Span::raw(result.outcome()).fg(color),
+                    Span::raw(duration).fg(THEME.read().ui.secondary_foreground_color),
+                ])
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), results_area);
+    }
+}
@@ -2,22 +2,29 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::prelude::{Line, Modifier, Style};
-use ratatui::style::Stylize;
+use ratatui::style::{Color, Stylize};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
+use crate::models::cookie::COOKIES_COLUMNS_NUMBER;
+use crate::tui::app_states::AppState::EditingCookies;
 use crate::tui::utils::centered_rect::centered_rect;
-use crate::tui::utils::stateful::cookie_table::{CookieColumns, COOKIES_COLUMNS_NUMBER};
+use crate::tui::utils::stateful::cookie_table::CookieColumns;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
 
 impl App<'_> {
     pub fn render_cookies_popup(&mut self, frame: &mut Frame) {
-        let popup_block = Block::default()
+        let mut popup_block = Block::default()
             .title("Cookies")
             .borders(Borders::ALL)
             .fg(THEME.read().ui.font_color)
             .bg(THEME.read().ui.main_background_color);
 
+        if let Some(error) = &self.cookies_popup.error {
+            popup_block = popup_block.title_bottom(Line::from(error.as_str()).fg(Color::Red).centered());
+        }
+
         let area = centered_rect(120, 25, frame.area());
 
         frame.render_widget(Clear, area);
@@ -67,17 +74,14 @@ impl App<'_> {
                 let cookies_lines = vec![
                     Line::default(),
                     Line::from("No cookies"),
-                    Line::from("(Add one by sending a request)".fg(THEME.read().ui.font_color))
+                    Line::from("(Add one by sending a request or by creating it)".fg(THEME.read().ui.font_color))
                 ];
 
                 let cookies_paragraph = Paragraph::new(cookies_lines).centered();
 
                 frame.render_widget(cookies_paragraph, cookies_layout[1]);
             },
-            Some(selection) => {
-                self.render_cookie_list(selection, frame, cookies_layout[1]);
-                //self.render_cookie_cursor(selection, horizontal_margin + 1, frame, cookies_layout[1]);
-            }
+            Some(selection) => self.render_cookie_list(selection, frame, cookies_layout[1])
         }
     }
 
@@ -130,37 +134,25 @@ impl App<'_> {
                 &mut self.cookies_popup.cookies_table.lists_states[index].clone()
             );
         }
-    }
 
-    /*
-    fn render_cookie_cursor(&mut self, selection: (usize, usize), horizontal_margin: u16, frame: &mut Frame, area: Rect) {
-        if self.state == EditingCookies {
-            let cell_with = area.width / COOKIES_COLUMNS_NUMBER as u16;
-
-            let even_odd_adjustment = match area.width % 2 {
-                1 => 1,
-                0 => 2,
-                _ => 0
-            };
+        // Form input & cursor
 
-            let width_adjustment = cell_with - even_odd_adjustment;
+        let text_input = &mut self.cookies_popup.cookies_table.selection_text_input;
 
+        if self.state == EditingCookies {
+            let cell_area = table_layout[selection.1];
             let height_adjustment = (selection.0 - self.cookies_popup.cookies_table.lists_states[0].offset()) as u16 % area.height;
 
-            let selection_position_x = area.x + width_adjustment + horizontal_margin;
-            let selection_position_y = area.y + height_adjustment;
+            let text_rect = Rect::new(cell_area.x, area.y + height_adjustment, cell_area.width, 1);
 
-            let param_text = self.cookies_popup.cookies_table.selection_text_input.text.clone();
-
-            let text_input = Paragraph::new(format!("{:fill$}", param_text, fill = (cell_with - horizontal_margin) as usize));
-            let text_rect = Rect::new(selection_position_x, selection_position_y, cell_with, 1);
-
-            frame.render_widget(text_input, text_rect);
-
-            frame.set_cursor(
-                selection_position_x + self.cookies_popup.cookies_table.selection_text_input.cursor_position as u16,
-                selection_position_y
-            );
+            text_input.display_cursor = true;
+            text_input.highlight_text = true;
+            frame.render_widget(Clear, text_rect);
+            frame.render_widget(SingleLineTextInput(text_input), text_rect);
+        }
+        else {
+            text_input.display_cursor = false;
+            text_input.highlight_text = false;
         }
-    }*/
+    }
 }
\ No newline at end of file
@@ -81,7 +81,7 @@ impl App<'_> {
             None => None
         };
 
-        let events = &self.help_popup.selection.get_available_events(self.request_view, self.request_param_tab, protocol, is_there_any_env);
+        let events = &self.help_popup.selection.get_available_events(self.request_view, self.request_param_tab, self.request_result_tab, protocol, is_there_any_env);
         let keys = event_available_keys_to_spans(
             events,
             THEME.read().ui.font_color,
@@ -8,6 +8,13 @@ pub mod deleting_request;
 pub mod request_settings;
 pub mod renaming_collection;
 pub mod renaming_request;
+pub mod creating_new_folder;
+pub mod deleting_folder;
+pub mod renaming_folder;
+pub mod moving_element_to_folder;
+pub mod collection_runner;
 pub mod creating_element;
 pub mod choosing_export_format;
 pub mod env_editor;
+pub mod response_history;
+pub mod choosing_diff_environment;
@@ -6,7 +6,10 @@ use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
+use crate::models::settings::Setting;
+use crate::tui::app_states::AppState::EditingRequestSettingValue;
 use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
 
 impl App<'_> {
     pub fn render_request_settings_popup(&mut self, frame: &mut Frame) {
@@ -18,7 +21,7 @@ impl App<'_> {
 
         let settings_number = self.request_settings_popup.settings.len() as u16;
 
-        let area = centered_rect(50, 2 + 1 + 2 * settings_number, frame.area());
+        let area = centered_rect(60, 2 + 1 + 2 * settings_number, frame.area());
 
         frame.render_widget(Clear, area);
         frame.render_widget(popup_block, area);
@@ -26,8 +29,8 @@ impl App<'_> {
         let request_settings_layout = Layout::new(
             Horizontal,
             vec![
-                Constraint::Percentage(70),
-                Constraint::Percentage(30),
+                Constraint::Percentage(60),
+                Constraint::Percentage(40),
             ]
         )
             .vertical_margin(1)
@@ -53,17 +56,42 @@ impl App<'_> {
         )
             .split(request_settings_layout[1]);
 
+        let is_editing_value = self.state == EditingRequestSettingValue;
+
         // Render settings
         for (index, (setting_name, setting_value)) in self.request_settings_popup.settings.iter().enumerate() {
             let setting_name_paragraph = Paragraph::new(setting_name.to_string()).fg(THEME.read().ui.font_color).centered();
-            let mut setting_value_paragraph = Paragraph::new(setting_value.to_string()).fg(THEME.read().ui.font_color).centered();
+            frame.render_widget(setting_name_paragraph, settings_names_layout[index + 1]);
+
+            let is_selected = index == self.request_settings_popup.selection;
+
+            if is_selected && is_editing_value {
+                continue;
+            }
+
+            let setting_value = match setting_value {
+                Setting::String(string) if string.is_empty() => String::from("None"),
+                _ => setting_value.to_string()
+            };
 
-            if index == self.request_settings_popup.selection {
+            let mut setting_value_paragraph = Paragraph::new(setting_value).fg(THEME.read().ui.font_color).centered();
+
+            if is_selected {
                 setting_value_paragraph = setting_value_paragraph.fg(THEME.read().others.selection_highlight_color)
             }
 
-            frame.render_widget(setting_name_paragraph, settings_names_layout[index + 1]);
             frame.render_widget(setting_value_paragraph, settings_values_layout[index + 1]);
         }
+
+        if is_editing_value {
+            let text_input = &mut self.request_settings_popup.text_input;
+            text_input.highlight_text = true;
+            text_input.display_cursor = true;
+
+            let mut value_area = settings_values_layout[self.request_settings_popup.selection + 1];
+            value_area.height = 1;
+
+            frame.render_widget(SingleLineTextInput(text_input), value_area);
+        }
     }
 }
\ No newline at end of file
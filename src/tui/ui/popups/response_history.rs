@@ -0,0 +1,96 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::layout::Direction::Vertical;
+use ratatui::prelude::{Color, Line, Span};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_response_history_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(format!("Response history ({})", self.response_history_popup.entries.len()))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 25, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let response_history_layout = Layout::new(
+            Vertical,
+            vec![
+                Constraint::Fill(1),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .split(area);
+
+        let entries_area = response_history_layout[0];
+
+        if self.response_history_popup.entries.is_empty() {
+            let no_history_paragraph = Paragraph::new("No response received yet").fg(THEME.read().ui.secondary_foreground_color).centered();
+            frame.render_widget(no_history_paragraph, entries_area);
+            return;
+        }
+
+        // Scroll so that the selected entry is always visible
+        let height = entries_area.height as usize;
+        let offset = (self.response_history_popup.selection + 1).saturating_sub(height);
+
+        let lines: Vec<Line> = self.response_history_popup.entries
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(height)
+            .map(|(index, entry)| {
+                let status_code = entry.status_code.as_deref().unwrap_or("NO RESPONSE");
+
+                let status_color = match status_code.chars().next() {
+                    Some('2') | Some('3') => Color::Green,
+                    _ => Color::Red
+                };
+
+                let duration = match &entry.duration {
+                    None => String::new(),
+                    Some(duration) => format!(" ({duration})")
+                };
+
+                let is_selected = index == self.response_history_popup.selection;
+
+                let selection_symbol = match is_selected {
+                    true => ">",
+                    false => " "
+                };
+
+                // Entry waiting to be compared with another one
+                let marked_symbol = match self.response_history_popup.marked == Some(index) {
+                    true => "*",
+                    false => " "
+                };
+
+                let mut line = Line::from(vec![
+                    Span::raw(format!("{selection_symbol}{marked_symbol} ")).fg(THEME.read().others.selection_highlight_color),
+                    Span::raw(format!("{} ", entry.timestamp.format("%d/%m/%Y %H:%M:%S"))).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(status_code.to_string()).fg(status_color),
+                    Span::raw(duration).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!(" {}", entry.url)).fg(THEME.read().ui.font_color),
+                ]);
+
+                if is_selected {
+                    line = line.bold();
+                }
+
+                line
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines), entries_area);
+    }
+}
@@ -0,0 +1,89 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::layout::Direction::Horizontal;
+use ratatui::prelude::{Color, Line, Style};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation};
+
+use crate::app::app::App;
+use crate::app::business_logic::response_diff::DiffRowKind;
+use crate::app::files::theme::THEME;
+
+impl App<'_> {
+    /// Both responses side by side, removed lines in red on the left and added lines in green on the right
+    pub fn render_response_diff(&mut self, frame: &mut Frame, rect: Rect) {
+        let response_diff_panel = match &mut self.response_diff_panel {
+            None => return,
+            Some(response_diff_panel) => response_diff_panel
+        };
+
+        let diff_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(50)
+            ],
+        )
+            .split(rect);
+
+        let differences_count = response_diff_panel.diff.differences_count();
+
+        let left_block = Block::new()
+            .title(response_diff_panel.diff.left_title.clone())
+            .borders(Borders::TOP | Borders::RIGHT)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let right_block = Block::new()
+            .title(response_diff_panel.diff.right_title.clone())
+            .title_bottom(Line::from(format!("{differences_count} line(s) differ")).right_aligned())
+            .borders(Borders::TOP)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let mut left_lines = vec![];
+        let mut right_lines = vec![];
+
+        for row in &response_diff_panel.diff.rows {
+            let left = row.left.clone().unwrap_or_default();
+            let right = row.right.clone().unwrap_or_default();
+
+            let (left_line, right_line) = match row.kind {
+                DiffRowKind::Section => (
+                    Line::from(left).fg(THEME.read().ui.secondary_foreground_color).bold(),
+                    Line::from(right).fg(THEME.read().ui.secondary_foreground_color).bold()
+                ),
+                DiffRowKind::Unchanged => (
+                    Line::from(left).fg(THEME.read().ui.font_color),
+                    Line::from(right).fg(THEME.read().ui.font_color)
+                ),
+                DiffRowKind::Changed | DiffRowKind::Removed | DiffRowKind::Added => (
+                    Line::from(left).fg(Color::Red),
+                    Line::from(right).fg(Color::Green)
+                ),
+            };
+
+            left_lines.push(left_line);
+            right_lines.push(right_line);
+        }
+
+        let scroll = (response_diff_panel.vertical_scrollbar.scroll, response_diff_panel.horizontal_scrollbar.scroll);
+
+        let left_paragraph = Paragraph::new(left_lines).scroll(scroll).block(left_block);
+        let right_paragraph = Paragraph::new(right_lines).scroll(scroll).block(right_block);
+
+        frame.render_widget(left_paragraph, diff_layout[0]);
+        frame.render_widget(right_paragraph, diff_layout[1]);
+
+        let vertical_scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .style(Style::new().fg(THEME.read().ui.font_color));
+
+        frame.render_stateful_widget(
+            vertical_scrollbar,
+            rect.inner(Margin {
+                // using an inner vertical margin of 1 unit makes the scrollbar inside the block
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut response_diff_panel.vertical_scrollbar.state
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
+use crate::models::protocol::http::method::Method;
+use crate::models::protocol::protocol::Protocol;
 use crate::models::request::Request;
 use crate::tui::app_states::AppState;
 use crate::tui::ui::views::RequestView;
@@ -45,11 +47,15 @@ impl App<'_> {
 
         // REQUEST METHOD
 
-        let http_request = request.get_http_request().unwrap();
-        let method = http_request.method.clone();
+        // GraphQL and gRPC requests are always sent as POST
+        let (method, method_title) = match &request.protocol {
+            Protocol::GraphqlRequest(_) => (Method::POST, "GraphQL"),
+            Protocol::GrpcRequest(_) => (Method::POST, "gRPC"),
+            _ => (request.get_http_request().unwrap().method, "Method")
+        };
 
         let method_block = Block::new()
-            .title("Method").title_alignment(Alignment::Center)
+            .title(method_title).title_alignment(Alignment::Center)
             .borders(Borders::ALL)
             .padding(Padding::horizontal(1))
             .fg(THEME.read().ui.main_foreground_color);
@@ -83,6 +89,10 @@ impl App<'_> {
             RequestView::OnlyParams => [
                 Constraint::Percentage(100),
                 Constraint::Percentage(0)
+            ],
+            RequestView::Diff => [
+                Constraint::Percentage(0),
+                Constraint::Percentage(0)
             ]
         };
 
@@ -96,7 +106,8 @@ impl App<'_> {
         let (should_render_params, should_render_result) = match self.request_view {
             RequestView::Normal => (true, true),
             RequestView::OnlyResult => (false, true),
-            RequestView::OnlyParams => (true, false)
+            RequestView::OnlyParams => (true, false),
+            RequestView::Diff => (false, false)
         };
 
         // REQUEST PARAMS
@@ -121,5 +132,11 @@ impl App<'_> {
             frame.render_widget(result_block, request_main_layout[1]);
             self.render_request_result(frame, result_block_area, &request);
         }
+
+        // RESPONSE DIFF
+
+        if self.request_view == RequestView::Diff {
+            self.render_response_diff(frame, request_layout[2]);
+        }
     }
 }
\ No newline at end of file
@@ -1,2 +1,3 @@
 pub mod http;
-pub mod ws;
\ No newline at end of file
+pub mod ws;
+pub mod diff;
\ No newline at end of file
@@ -89,6 +89,10 @@ impl App<'_> {
             RequestView::OnlyParams => [
                 Constraint::Percentage(100),
                 Constraint::Percentage(0)
+            ],
+            RequestView::Diff => [
+                Constraint::Percentage(0),
+                Constraint::Percentage(0)
             ]
         };
 
@@ -102,7 +106,8 @@ impl App<'_> {
         let (should_render_params, should_render_result) = match self.request_view {
             RequestView::Normal => (true, true),
             RequestView::OnlyResult => (false, true),
-            RequestView::OnlyParams => (true, false)
+            RequestView::OnlyParams => (true, false),
+            RequestView::Diff => (false, false)
         };
 
         // REQUEST PARAMS
@@ -127,5 +132,11 @@ impl App<'_> {
             frame.render_widget(result_block, request_main_layout[1]);
             self.render_request_result(frame, result_block_area, &request);
         }
+
+        // RESPONSE DIFF
+
+        if self.request_view == RequestView::Diff {
+            self.render_response_diff(frame, request_layout[2]);
+        }
     }
 }
\ No newline at end of file
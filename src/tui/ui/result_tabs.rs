@@ -2,7 +2,7 @@ use chrono::Local;
 use ratatui::layout::Direction::Vertical;
 use ratatui::layout::{Constraint, Layout, Margin, Rect};
 use ratatui::prelude::{Alignment, Style};
-use ratatui::style::Stylize;
+use ratatui::style::{Color, Stylize};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, Tabs, Wrap};
 use ratatui::Frame;
@@ -19,7 +19,9 @@ use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::ws::Sender;
 use crate::models::request::Request;
 use crate::models::response::ResponseContent;
+use crate::tui::app_states::AppState;
 use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::text_input::SingleLineTextInput;
 
 #[derive(Default, Clone, Copy, PartialOrd, PartialEq, Display, FromRepr, EnumIter)]
 pub enum RequestResultTabs {
@@ -28,16 +30,24 @@ pub enum RequestResultTabs {
     Body,
     #[strum(to_string = "Messages")]
     Messages,
+    #[strum(to_string = "Events")]
+    Events,
     #[strum(to_string = "Cookies")]
     Cookies,
     #[strum(to_string = "Headers")]
     Headers,
     #[strum(to_string = "Console")]
     Console,
+    #[strum(to_string = "Tests")]
+    Tests,
 }
 
 impl App<'_> {
     pub(super) fn render_request_result(&mut self, frame: &mut Frame, rect: Rect, request: &Request) {
+        // The past response picked in the history is only displayed, the request keeps its own one
+        let viewed_response = self.viewed_history_entry.as_ref().map(|entry| entry.to_response());
+        let response = viewed_response.as_ref().unwrap_or(&request.response);
+
         let request_result_layout = Layout::new(
             Vertical,
             [
@@ -52,17 +62,20 @@ impl App<'_> {
         // REQUEST RESULT TABS
 
         let allowed_tabs = match &request.protocol {
-            Protocol::HttpRequest(_) => vec![
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => vec![
                 RequestResultTabs::Body,
+                RequestResultTabs::Events,
                 RequestResultTabs::Cookies,
                 RequestResultTabs::Headers,
-                RequestResultTabs::Console
+                RequestResultTabs::Console,
+                RequestResultTabs::Tests
             ],
             Protocol::WsRequest(_) => vec![
                 RequestResultTabs::Messages,
                 RequestResultTabs::Cookies,
                 RequestResultTabs::Headers,
-                RequestResultTabs::Console
+                RequestResultTabs::Console,
+                RequestResultTabs::Tests
             ]
         };
 
@@ -71,7 +84,7 @@ impl App<'_> {
             .filter_map(|tab| {
                 let text = match tab {
                     RequestResultTabs::Body => {
-                        if let Some(duration) = &request.response.duration {
+                        if let Some(duration) = &response.duration {
                             Some(format!("{} ({})", tab.to_string(), duration))
                         }
                         else {
@@ -88,12 +101,24 @@ impl App<'_> {
                             Some(format!("{}", tab.to_string()))
                         }
                     },
+                    RequestResultTabs::Events => match (request.is_streaming, request.events.is_empty()) {
+                        (false, true) => None,
+                        (false, false) => Some(format!("{} ({})", tab, request.events.len())),
+                        (true, _) => Some(format!("{} ({}, streaming)", tab, request.events.len()))
+                    },
                     RequestResultTabs::Cookies | RequestResultTabs::Headers => Some(tab.to_string()),
                     RequestResultTabs::Console => {
                         match (&request.console_output.pre_request_output, &request.console_output.post_request_output) {
                             (None, None) => None,
                             (_, _) => Some(tab.to_string())
                         }
+                    },
+                    RequestResultTabs::Tests => match request.test_results.is_empty() {
+                        true => None,
+                        false => {
+                            let passed_tests_count = request.test_results.len() - request.failed_tests_count();
+                            Some(format!("{} ({}/{})", tab, passed_tests_count, request.test_results.len()))
+                        }
                     }
                 };
 
@@ -104,12 +129,26 @@ impl App<'_> {
             })
             .collect();
 
+        // The events tab is only displayed when the response is an event stream
+        let events_tab_offset = match request.is_streaming || !request.events.is_empty() {
+            true => 1,
+            false => 0
+        };
+
+        // The console tab is only displayed when a script has been run
+        let console_tab_offset = match request.console_output.pre_request_output.is_some() || request.console_output.post_request_output.is_some() {
+            true => 1,
+            false => 0
+        };
+
         let selected_result_tab_index = match &request.protocol {
-            Protocol::HttpRequest(_) => match self.request_result_tab {
+            Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => match self.request_result_tab {
                 RequestResultTabs::Body => 0,
-                RequestResultTabs::Cookies => 1,
-                RequestResultTabs::Headers => 2,
-                RequestResultTabs::Console => 3,
+                RequestResultTabs::Events => 1,
+                RequestResultTabs::Cookies => 1 + events_tab_offset,
+                RequestResultTabs::Headers => 2 + events_tab_offset,
+                RequestResultTabs::Console => 3 + events_tab_offset,
+                RequestResultTabs::Tests => 3 + events_tab_offset + console_tab_offset,
                 _ => unreachable!()
             }
             Protocol::WsRequest(_) => match self.request_result_tab {
@@ -117,6 +156,7 @@ impl App<'_> {
                 RequestResultTabs::Cookies => 1,
                 RequestResultTabs::Headers => 2,
                 RequestResultTabs::Console => 3,
+                RequestResultTabs::Tests => 3 + console_tab_offset,
                 _ => unreachable!()
             }
         };
@@ -149,11 +189,16 @@ impl App<'_> {
         else {
             // REQUEST RESULT STATUS CODE
 
-            let status_code = match &request.response.status_code {
+            let status_code = match &response.status_code {
                 None => "",
                 Some(status_code) => status_code
             };
 
+            let status_code = match &self.viewed_history_entry {
+                None => status_code.to_string(),
+                Some(entry) => format!("{status_code} (history, {})", entry.timestamp.format("%d/%m/%Y %H:%M:%S"))
+            };
+
             let status_code_paragraph = Paragraph::new(status_code)
                 .centered()
                 .fg(THEME.read().ui.secondary_foreground_color);
@@ -163,18 +208,38 @@ impl App<'_> {
             // REQUEST RESULT CONTENT
 
             match self.request_result_tab {
-                RequestResultTabs::Body => match &request.response.content {
+                RequestResultTabs::Body => match &response.content {
                     None => {},
                     Some(content) => match content {
                         ResponseContent::Body(body) => {
-                            let lines: Vec<Line>;
-                            if !self.config.is_syntax_highlighting_disabled() && self.syntax_highlighting.highlighted_body.is_some() {
-                                lines = self.syntax_highlighting.highlighted_body.clone().unwrap();
-                            }
-                            else {
-                                lines = body.lines().map(|line| Line::raw(line)).collect();
+                            let mut body_area = request_result_layout[2];
+
+                            if self.response_body_filter.is_active() || self.state == AppState::EditingResponseBodyFilter {
+                                let body_filter_layout = Layout::new(
+                                    Vertical,
+                                    [
+                                        Constraint::Length(3),
+                                        Constraint::Fill(1)
+                                    ]
+                                )
+                                    .split(body_area);
+
+                                self.response_body_filter.text_input.display_cursor = self.state == AppState::EditingResponseBodyFilter;
+                                frame.render_widget(SingleLineTextInput(&mut self.response_body_filter.text_input), body_filter_layout[0]);
+
+                                body_area = body_filter_layout[1];
                             }
 
+                            let lines: Vec<Line> = match &self.response_body_filter.filtered_body {
+                                Some(Err(error)) => error
+                                    .lines()
+                                    .map(|line| Line::raw(line).fg(THEME.read().ui.secondary_foreground_color))
+                                    .collect(),
+                                _ if !self.config.is_syntax_highlighting_disabled() && self.syntax_highlighting.highlighted_body.is_some() => self.syntax_highlighting.highlighted_body.clone().unwrap(),
+                                Some(Ok(filtered_body)) => filtered_body.lines().map(|line| Line::raw(line)).collect(),
+                                None => body.lines().map(|line| Line::raw(line)).collect()
+                            };
+
                             let mut body_paragraph = Paragraph::new(lines);
 
                             if self.config.should_wrap_body() {
@@ -193,7 +258,7 @@ impl App<'_> {
                                     ));
                             }
 
-                            frame.render_widget(body_paragraph, request_result_layout[2]);
+                            frame.render_widget(body_paragraph, body_area);
                         }
                         ResponseContent::Image(image_response) => match &image_response.image {
                             _ if self.config.is_image_preview_disabled() => {
@@ -310,8 +375,43 @@ impl App<'_> {
 
                     frame.render_widget(messages_paragraph, inner_area);
                 },
+                RequestResultTabs::Events => {
+                    let mut events = vec![];
+
+                    for event in &request.events {
+                        let timestamp_format = match Local::now().date_naive() == event.timestamp.date_naive() {
+                            true => "%H:%M:%S",
+                            false => "%H:%M:%S %d/%m/%Y"
+                        };
+
+                        let mut details = vec![
+                            Span::raw(event.event.clone()).bold().fg(THEME.read().ui.secondary_foreground_color),
+                            Span::raw(format!(" {}", event.timestamp.format(timestamp_format))).fg(THEME.read().ui.secondary_foreground_color),
+                        ];
+
+                        if let Some(id) = &event.id {
+                            details.push(Span::raw(format!(" #{id}")).fg(THEME.read().ui.secondary_foreground_color));
+                        }
+
+                        events.push(Line::from(details));
+
+                        for line in event.data.lines() {
+                            events.push(Line::raw(line.to_string()).fg(THEME.read().ui.font_color));
+                        }
+
+                        events.push(Line::default());
+                    }
+
+                    let events_paragraph = Paragraph::new(events)
+                        .scroll((
+                            self.result_vertical_scrollbar.scroll,
+                            self.result_horizontal_scrollbar.scroll
+                        ));
+
+                    frame.render_widget(events_paragraph, request_result_layout[2]);
+                },
                 RequestResultTabs::Cookies => {
-                    let result_cookies = match &request.response.cookies {
+                    let result_cookies = match &response.cookies {
                         None => "",
                         Some(cookies) => cookies
                     };
@@ -326,7 +426,7 @@ impl App<'_> {
                     frame.render_widget(cookies_paragraph, request_result_layout[2]);
                 },
                 RequestResultTabs::Headers => {
-                    let result_headers: Vec<Line> = request.response.headers
+                    let result_headers: Vec<Line> = response.headers
                         .par_iter()
                         .map(
                             |(header, value)| 
@@ -354,6 +454,44 @@ impl App<'_> {
                         ));
 
                     frame.render_widget(console_paragraph, request_result_layout[2]);
+                },
+                RequestResultTabs::Tests => {
+                    let failed_tests_count = request.failed_tests_count();
+
+                    let mut tests = vec![
+                        Line::from(vec![
+                            Span::raw(format!("{} passed", request.test_results.len() - failed_tests_count)).fg(Color::Green),
+                            Span::raw(", ").fg(THEME.read().ui.font_color),
+                            Span::raw(format!("{} failed", failed_tests_count)).fg(Color::Red),
+                        ]),
+                        Line::default()
+                    ];
+
+                    for test_result in &request.test_results {
+                        let (symbol, color) = match test_result.passed {
+                            true => ("✔", Color::Green),
+                            false => ("✘", Color::Red)
+                        };
+
+                        let mut line = vec![
+                            Span::raw(format!("{symbol} ")).fg(color),
+                            Span::raw(test_result.name.clone()).fg(THEME.read().ui.font_color),
+                        ];
+
+                        if let Some(error) = &test_result.error {
+                            line.push(Span::raw(format!(": {error}")).fg(color));
+                        }
+
+                        tests.push(Line::from(line));
+                    }
+
+                    let tests_paragraph = Paragraph::new(tests)
+                        .scroll((
+                            self.result_vertical_scrollbar.scroll,
+                            self.result_horizontal_scrollbar.scroll
+                        ));
+
+                    frame.render_widget(tests_paragraph, request_result_layout[2]);
                 }
             };
         }
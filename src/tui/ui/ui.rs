@@ -105,7 +105,7 @@ impl App<'_> {
                 let selected_request = self.get_request_as_local_from_indexes(&selection).read().clone();
 
                 match selected_request.protocol {
-                    Protocol::HttpRequest(_) => self.render_http_request(frame, inner_layout[1], selected_request),
+                    Protocol::HttpRequest(_) | Protocol::GraphqlRequest(_) | Protocol::GrpcRequest(_) => self.render_http_request(frame, inner_layout[1], selected_request),
                     Protocol::WsRequest(_) => self.render_ws_request(frame, inner_layout[1], selected_request),
                 }
             }
@@ -144,11 +144,19 @@ impl App<'_> {
             CreatingNewRequest => self.render_creating_new_request_popup(frame),
             DeletingCollection => self.render_deleting_collection_popup(frame),
             DeletingRequest => self.render_deleting_request_popup(frame),
-            EditingRequestSettings => self.render_request_settings_popup(frame),
+            CreatingNewFolder => self.render_creating_new_folder_popup(frame),
+            DeletingFolder => self.render_deleting_folder_popup(frame),
+            EditingRequestSettings | EditingRequestSettingValue => self.render_request_settings_popup(frame),
             RenamingCollection => self.render_renaming_collection_popup(frame),
             RenamingRequest => self.render_renaming_request_popup(frame),
+            RenamingFolder => self.render_renaming_folder_popup(frame),
+            MovingElementToFolder => self.render_moving_element_to_folder_popup(frame),
+            ChoosingCollectionRunOptions | EditingCollectionRunDataFile => self.render_collection_runner_popup(frame),
+            RunningCollection => self.render_running_collection_popup(frame),
             ChoosingRequestExportFormat => self.render_export_format_popup(frame),
             DisplayingRequestExport => self.display_request_export.render(frame),
+            DisplayingResponseHistory => self.render_response_history_popup(frame),
+            ChoosingDiffEnvironment => self.render_diff_environment_popup(frame),
             _ => {}
         }
 
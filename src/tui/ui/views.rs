@@ -5,6 +5,8 @@ pub enum RequestView {
     Normal,
     OnlyResult,
     OnlyParams,
+    /// Only available once two responses have been compared
+    Diff,
 }
 
 impl App<'_> {
@@ -12,7 +14,8 @@ impl App<'_> {
         self.request_view = match self.request_view {
             RequestView::Normal => RequestView::OnlyResult,
             RequestView::OnlyResult => RequestView::OnlyParams,
-            RequestView::OnlyParams => RequestView::Normal
+            RequestView::OnlyParams if self.response_diff_panel.is_some() => RequestView::Diff,
+            RequestView::OnlyParams | RequestView::Diff => RequestView::Normal
         };
     }
 }
\ No newline at end of file
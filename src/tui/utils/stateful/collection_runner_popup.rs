@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::app::business_logic::collection_runner::{CollectionRun, CollectionRunOptions, CollectionRunResult};
+use crate::app::files::utils::expand_tilde;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+pub const COLLECTION_RUNNER_OPTIONS: [&str; 5] = ["Iterations", "Data file", "Delay (ms)", "Bail on failure", "Fail on error status"];
+
+pub struct CollectionRunnerPopup {
+    pub collection_index: usize,
+
+    /// 0 means one iteration per data file row
+    pub iterations: u32,
+    pub data_file: String,
+    pub delay: u32,
+    pub bail: bool,
+    pub fail_on_status: bool,
+
+    pub selection: usize,
+    /// Used to edit the data file path
+    pub text_input: TextInput,
+
+    pub run: Option<CollectionRun>,
+    /// Filled by the background task sending the current request
+    pub pending_result: Arc<Mutex<Option<CollectionRunResult>>>,
+    pub is_request_pending: bool,
+    pub last_request_end: Option<Instant>,
+    /// Why the run could not be started, e.g. an invalid data file
+    pub error: Option<String>,
+}
+
+impl Default for CollectionRunnerPopup {
+    fn default() -> Self {
+        CollectionRunnerPopup {
+            collection_index: 0,
+            iterations: 0,
+            data_file: String::new(),
+            delay: 0,
+            bail: false,
+            fail_on_status: false,
+            selection: 0,
+            text_input: TextInput::new(None),
+            run: None,
+            pending_result: Arc::new(Mutex::new(None)),
+            is_request_pending: false,
+            last_request_end: None,
+            error: None,
+        }
+    }
+}
+
+impl CollectionRunnerPopup {
+    pub fn next(&mut self) {
+        if self.selection + 1 < COLLECTION_RUNNER_OPTIONS.len() {
+            self.selection += 1;
+        }
+        else {
+            self.selection = 0;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if self.selection > 0 {
+            self.selection -= 1;
+        }
+        else {
+            self.selection = COLLECTION_RUNNER_OPTIONS.len() - 1;
+        }
+    }
+
+    pub fn toggle_option_left(&mut self) {
+        match self.selection {
+            0 => self.iterations = self.iterations.saturating_sub(1),
+            2 => self.delay = self.delay.saturating_sub(100),
+            3 => self.bail = false,
+            4 => self.fail_on_status = false,
+            _ => {}
+        }
+    }
+
+    pub fn toggle_option_right(&mut self) {
+        match self.selection {
+            0 => self.iterations = self.iterations.saturating_add(1),
+            2 => self.delay = (self.delay + 100).min(60000),
+            3 => self.bail = true,
+            4 => self.fail_on_status = true,
+            _ => {}
+        }
+    }
+
+    pub fn is_data_file_selected(&self) -> bool {
+        self.selection == 1
+    }
+
+    pub fn option_values(&self) -> [String; 5] {
+        let iterations = match self.iterations {
+            0 => String::from("Auto"),
+            iterations => iterations.to_string()
+        };
+
+        let data_file = match self.data_file.is_empty() {
+            true => String::from("None"),
+            false => self.data_file.clone()
+        };
+
+        [iterations, data_file, self.delay.to_string(), self.bail.to_string(), self.fail_on_status.to_string()]
+    }
+
+    pub fn to_options(&self) -> CollectionRunOptions {
+        CollectionRunOptions {
+            iterations: match self.iterations {
+                0 => None,
+                iterations => Some(iterations)
+            },
+            data_file: match self.data_file.trim().is_empty() {
+                true => None,
+                false => Some(expand_tilde(PathBuf::from(self.data_file.trim())))
+            },
+            delay: Duration::from_millis(self.delay as u64),
+            bail: self.bail,
+            fail_on_status: self.fail_on_status,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.run.as_ref().is_some_and(|run| !run.is_finished())
+    }
+}
@@ -1,7 +1,7 @@
-use cookie_store::Cookie;
 use ratatui::layout::Constraint;
 use ratatui::widgets::ListState;
 use strum::{Display, FromRepr};
+use crate::models::cookie::COOKIES_COLUMNS_NUMBER;
 use crate::tui::utils::stateful::text_input::TextInput;
 
 
@@ -28,11 +28,11 @@ pub enum CookieColumns {
 impl CookieColumns {
     pub fn constraints() -> [Constraint; 8] {
         [
-            Constraint::Percentage(10),
+            Constraint::Percentage(14),
             Constraint::Percentage(15),
-            Constraint::Percentage(37),
-            Constraint::Percentage(10),
+            Constraint::Percentage(26),
             Constraint::Percentage(10),
+            Constraint::Percentage(17),
             Constraint::Percentage(6),
             Constraint::Percentage(6),
             Constraint::Percentage(6)
@@ -40,8 +40,6 @@ impl CookieColumns {
     }
 }
 
-pub const COOKIES_COLUMNS_NUMBER: usize = 8;
-
 pub struct StatefulCookieTable {
     pub lists_states: [ListState; COOKIES_COLUMNS_NUMBER],
     /// (x, y)
@@ -173,37 +171,3 @@ impl StatefulCookieTable {
         }
     }
 }
-
-pub fn cookie_to_row(cookie: &Cookie) -> [String; COOKIES_COLUMNS_NUMBER]{
-    [
-        match cookie.domain() {
-            None => String::new(),
-            Some(domain) => domain.to_string()
-        },
-        cookie.name().to_string(),
-        cookie.value().to_string(),
-        match cookie.path() {
-            None => String::new(),
-            Some(path) => path.to_string()
-        },
-        match cookie.expires() {
-            None => String::new(),
-            Some(expiration) => match expiration.is_datetime() {
-                true => expiration.datetime().unwrap().to_string(),
-                false => String::from("session")
-            }
-        },
-        match cookie.http_only() {
-            None => String::new(),
-            Some(http_only) => http_only.to_string()
-        },
-        match cookie.secure() {
-            None => String::new(),
-            Some(secure) => secure.to_string()
-        },
-        match cookie.same_site() {
-            None => String::new(),
-            Some(same_site) => same_site.to_string()
-        }
-    ]
-}
\ No newline at end of file
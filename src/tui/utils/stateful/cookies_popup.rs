@@ -1,10 +1,16 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use parking_lot::RwLock;
 use reqwest_cookie_store::CookieStoreRwLock;
 use crate::tui::utils::stateful::cookie_table::StatefulCookieTable;
 
 #[derive(Default)]
 pub struct CookiesPopup {
     pub cookies_table: StatefulCookieTable,
-    pub cookie_store: Arc<CookieStoreRwLock>
+    pub cookie_store: Arc<CookieStoreRwLock>,
+    /// Jar of the selected environment, None when the cookies are not persisted
+    pub cookie_jar_path: Arc<RwLock<Option<PathBuf>>>,
+    /// Why the last cookie could not be created or modified, until the next cookie action
+    pub error: Option<String>
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::tui::utils::stateful::text_input::TextInput;
+
+pub struct GraphqlEditor {
+    pub query_text_area: TextInput,
+    pub variables_text_area: TextInput,
+    pub operation_name_text_input: TextInput,
+    /// 0: query, 1: variables, 2: operation name
+    pub selection: u16,
+}
+
+impl GraphqlEditor {
+    pub fn next(&mut self) {
+        self.selection = match self.selection {
+            0 => 1,
+            1 => 2,
+            _ => 0
+        }
+    }
+
+    pub fn previous(&mut self) {
+        self.selection = match self.selection {
+            0 => 2,
+            1 => 0,
+            _ => 1
+        }
+    }
+}
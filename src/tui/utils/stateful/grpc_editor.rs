@@ -0,0 +1,30 @@
+use crate::tui::utils::stateful::text_input::TextInput;
+
+pub struct GrpcEditor {
+    pub proto_files_text_input: TextInput,
+    pub service_text_input: TextInput,
+    pub method_text_input: TextInput,
+    pub message_text_area: TextInput,
+    /// 0: proto files, 1: service, 2: method, 3: message
+    pub selection: u16,
+}
+
+impl GrpcEditor {
+    pub fn next(&mut self) {
+        self.selection = match self.selection {
+            0 => 1,
+            1 => 2,
+            2 => 3,
+            _ => 0
+        }
+    }
+
+    pub fn previous(&mut self) {
+        self.selection = match self.selection {
+            0 => 3,
+            1 => 0,
+            2 => 1,
+            _ => 2
+        }
+    }
+}
@@ -3,7 +3,13 @@ pub mod cookie_table;
 pub mod cookies_popup;
 pub mod help_popup;
 pub mod script_console;
+pub mod graphql_editor;
+pub mod grpc_editor;
 pub mod settings_popup;
+pub mod collection_runner_popup;
+pub mod response_history_popup;
+pub mod response_diff_panel;
+pub mod response_body_filter;
 pub mod stateful_custom_table;
 pub mod stateful_list;
 pub mod stateful_scrollbar;
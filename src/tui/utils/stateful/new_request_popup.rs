@@ -1,3 +1,5 @@
+use crate::models::protocol::graphql::graphql::GraphqlRequest;
+use crate::models::protocol::grpc::grpc::GrpcRequest;
 use crate::models::protocol::http::http::HttpRequest;
 use crate::models::protocol::protocol::Protocol;
 use crate::models::protocol::ws::ws::WsRequest;
@@ -84,14 +86,18 @@ impl NewRequestPopup {
     pub fn next_protocol(&mut self) {
         self.protocol = match self.protocol {
             Protocol::HttpRequest(_) => Protocol::WsRequest(WsRequest::default()),
-            Protocol::WsRequest(_) => Protocol::HttpRequest(HttpRequest::default()),
+            Protocol::WsRequest(_) => Protocol::GraphqlRequest(GraphqlRequest::default()),
+            Protocol::GraphqlRequest(_) => Protocol::GrpcRequest(GrpcRequest::default()),
+            Protocol::GrpcRequest(_) => Protocol::HttpRequest(HttpRequest::default()),
         }
     }
 
     pub fn previous_protocol(&mut self) {
         self.protocol = match self.protocol {
-            Protocol::HttpRequest(_) => Protocol::WsRequest(WsRequest::default()),
+            Protocol::HttpRequest(_) => Protocol::GrpcRequest(GrpcRequest::default()),
             Protocol::WsRequest(_) => Protocol::HttpRequest(HttpRequest::default()),
+            Protocol::GraphqlRequest(_) => Protocol::WsRequest(WsRequest::default()),
+            Protocol::GrpcRequest(_) => Protocol::GraphqlRequest(GraphqlRequest::default()),
         }
     }
 }
\ No newline at end of file
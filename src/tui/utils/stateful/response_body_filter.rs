@@ -0,0 +1,21 @@
+use crate::tui::utils::stateful::text_input::TextInput;
+
+pub struct ResponseBodyFilter {
+    pub text_input: TextInput,
+    /// Last confirmed filter, empty when the whole body is displayed
+    pub filter: String,
+    /// Filtered body, or the reason why the filter could not be applied
+    pub filtered_body: Option<Result<String, String>>,
+}
+
+impl ResponseBodyFilter {
+    pub fn is_active(&self) -> bool {
+        !self.filter.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.text_input.clear();
+        self.filter.clear();
+        self.filtered_body = None;
+    }
+}
@@ -0,0 +1,31 @@
+use crate::app::business_logic::response_diff::ResponseDiff;
+use crate::tui::utils::stateful::stateful_scrollbar::StatefulScrollbar;
+
+pub struct ResponseDiffPanel {
+    pub diff: ResponseDiff,
+    pub vertical_scrollbar: StatefulScrollbar,
+    pub horizontal_scrollbar: StatefulScrollbar,
+}
+
+impl ResponseDiffPanel {
+    pub fn new(diff: ResponseDiff) -> Self {
+        let mut vertical_scrollbar = StatefulScrollbar::default();
+        let mut horizontal_scrollbar = StatefulScrollbar::default();
+
+        let max_line_length = diff.rows
+            .iter()
+            .flat_map(|row| [&row.left, &row.right])
+            .map(|line| line.as_ref().map_or(0, |line| line.len()))
+            .max()
+            .unwrap_or(0);
+
+        vertical_scrollbar.set_max_scroll(diff.rows.len().saturating_sub(1) as u16);
+        horizontal_scrollbar.set_max_scroll(max_line_length as u16);
+
+        ResponseDiffPanel {
+            diff,
+            vertical_scrollbar,
+            horizontal_scrollbar,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::models::history::ResponseHistoryEntry;
+
+#[derive(Default)]
+pub struct ResponseHistoryPopup {
+    /// Most recent first
+    pub entries: Vec<ResponseHistoryEntry>,
+    pub selection: usize,
+    /// First entry chosen to be compared
+    pub marked: Option<usize>,
+}
+
+impl ResponseHistoryPopup {
+    pub fn next(&mut self) {
+        if self.selection + 1 < self.entries.len() {
+            self.selection += 1;
+        }
+        else {
+            self.selection = 0;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        if self.selection > 0 {
+            self.selection -= 1;
+        }
+        else {
+            self.selection = self.entries.len().saturating_sub(1);
+        }
+    }
+}
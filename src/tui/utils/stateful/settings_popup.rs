@@ -1,9 +1,21 @@
 use crate::models::settings::Setting;
+use crate::tui::utils::stateful::text_input::TextInput;
 
-#[derive(Default)]
 pub struct SettingsPopup {
     pub settings: Vec<(String, Setting)>,
     pub selection: usize,
+    /// Used to edit the text settings, such as file paths
+    pub text_input: TextInput,
+}
+
+impl Default for SettingsPopup {
+    fn default() -> Self {
+        SettingsPopup {
+            settings: vec![],
+            selection: 0,
+            text_input: TextInput::new(None),
+        }
+    }
 }
 
 impl SettingsPopup {
@@ -31,7 +43,8 @@ impl SettingsPopup {
             Setting::U32(u32) => match u32 > 100 {
                 true => self.settings[self.selection].1 = Setting::U32(u32 - 100),
                 false => self.settings[self.selection].1 = Setting::U32(100),
-            }
+            },
+            Setting::String(_) => {}
         }
     }
 
@@ -41,7 +54,12 @@ impl SettingsPopup {
             Setting::U32(u32) => match u32 < 100000 {
                 true => self.settings[self.selection].1 = Setting::U32(u32 + 100),
                 false => self.settings[self.selection].1 = Setting::U32(100000),
-            }
+            },
+            Setting::String(_) => {}
         }
     }
+
+    pub fn is_text_setting_selected(&self) -> bool {
+        matches!(self.settings[self.selection].1, Setting::String(_))
+    }
 }
@@ -276,6 +276,31 @@ impl TextInput {
         self.state.execute(InsertChar(char));
     }
 
+    /// Identifier characters right before the cursor, used for completion
+    pub fn word_before_cursor(&self) -> String {
+        let cursor = self.state.cursor;
+
+        let Some(line) = self.to_lines().into_iter().nth(cursor.row) else {
+            return String::new();
+        };
+
+        let before_cursor: Vec<char> = line.chars().take(cursor.col).collect();
+
+        let word: String = before_cursor
+            .iter()
+            .rev()
+            .take_while(|char| char.is_alphanumeric() || **char == '_')
+            .collect();
+
+        word.chars().rev().collect()
+    }
+
+    pub fn insert_str_at_cursor(&mut self, text: &str) {
+        for char in text.chars() {
+            self.state.execute(InsertChar(char));
+        }
+    }
+
     pub fn push_str(&mut self, line: &str) {
         for line in line.split('\n') {
             self.state.execute(PushLine(line));